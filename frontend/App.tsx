@@ -1,19 +1,20 @@
 import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 import Header from "./components/Header";
 import TabButton from "./components/TabButton";
-import InstallTab from "./components/InstallTab";
+import InstallTab, { GitStatus } from "./components/InstallTab";
 import SyncTab from "./components/SyncTab";
 import SyncDisclaimerDialog from "./components/SyncDisclaimerDialog";
 import AppOutdatedModal from "./components/AppOutdatedModal";
 import FetchErrorModal from "./components/FetchErrorModal";
+import ResetAppPanel from "./components/ResetAppPanel";
 
 interface AppState {
   textures_path: string | null;
   initial_setup_done: boolean;
   last_sync_commit: string | null;
   last_sync_timestamp: string | null;
-  github_token: string | null;
   sync_disclaimer_acknowledged: boolean;
 }
 
@@ -21,10 +22,29 @@ interface InstallerData {
   min_download_app_version: string;
   total_size: string;
   downloader_app_url: string;
+  changelog_url: string | null;
+  announcement: string | null;
+  recommended_pcsx2_settings: Record<string, unknown> | null;
+  pack_file_count: string | null;
+  download_mirrors: string[];
 }
 
-interface InstallerDataResult {
-  data: InstallerData | null;
+interface VersionCheckResult {
+  status: "ok" | "warn" | "block";
+  stale: boolean;
+  message: string | null;
+  app_version: string;
+  required_version: string | null;
+  installer_data: InstallerData | null;
+}
+
+interface AutoSyncPending {
+  latest_commit_sha: string;
+  seconds: number;
+}
+
+interface AutoSyncFinished {
+  result: { new_commit_sha: string; failed_files: unknown[] } | null;
   error: string | null;
 }
 
@@ -33,7 +53,7 @@ type Tab = "install" | "sync";
 function App() {
   const [texturesDir, setTexturesDir] = useState("");
   const [gitAvailable, setGitAvailable] = useState<boolean | null>(null);
-  const [gitError, setGitError] = useState("");
+  const [gitStatus, setGitStatus] = useState<GitStatus | null>(null);
   const [activeTab, setActiveTab] = useState<Tab>("install");
   const [initialSetupDone, setInitialSetupDone] = useState(false);
   const [lastSyncCommit, setLastSyncCommit] = useState<string | null>(null);
@@ -49,6 +69,8 @@ function App() {
   const [installerDataError, setInstallerDataError] = useState<string | null>(null);
   const [isAppOutdated, setIsAppOutdated] = useState(false);
   const [requiredVersion, setRequiredVersion] = useState<string>("");
+  const [autoSyncCountdown, setAutoSyncCountdown] = useState<number | null>(null);
+  const [autoSyncError, setAutoSyncError] = useState<string | null>(null);
 
   // Load saved state on mount
   useEffect(() => {
@@ -61,7 +83,7 @@ function App() {
         setInitialSetupDone(state.initial_setup_done);
         setLastSyncCommit(state.last_sync_commit);
         setLastSyncTimestamp(state.last_sync_timestamp);
-        setGithubToken(state.github_token);
+        setGithubToken(await invoke<string | null>("get_github_token"));
         setSyncDisclaimerAcknowledged(state.sync_disclaimer_acknowledged || false);
 
         // If setup is done, default to sync tab
@@ -80,96 +102,91 @@ function App() {
     loadAppState();
   }, []);
 
-  // Check if git is available on mount
+  // Auto-sync on launch: the backend announces it, then runs it unless cancelled
   useEffect(() => {
-    const checkGit = async () => {
-      try {
-        const available = await invoke<boolean>("check_git_installed");
-        setGitAvailable(available);
-        if (!available) {
-          const error = await invoke<string>("get_git_error");
-          setGitError(error);
-        }
-      } catch (e) {
-        setGitAvailable(false);
-        setGitError("Failed to check git availability");
+    const unlistenPending = listen<AutoSyncPending>("auto-sync-pending", (event) => {
+      setAutoSyncError(null);
+      setAutoSyncCountdown(event.payload.seconds);
+      setActiveTab("sync");
+    });
+    const unlistenFinished = listen<AutoSyncFinished>("auto-sync-finished", (event) => {
+      setAutoSyncCountdown(null);
+      if (event.payload.result && event.payload.result.failed_files.length === 0) {
+        setLastSyncCommit(event.payload.result.new_commit_sha);
+        setLastSyncTimestamp(new Date().toISOString());
+      }
+      if (event.payload.error) {
+        setAutoSyncError(event.payload.error);
       }
+    });
+
+    return () => {
+      unlistenPending.then((fn) => fn());
+      unlistenFinished.then((fn) => fn());
     };
-    checkGit();
   }, []);
 
-  // Fetch app version and installer data on mount
+  // Tick the auto-sync countdown down to the moment the backend starts syncing
   useEffect(() => {
-    const fetchAppInfo = async () => {
-      try {
-        // Get app version
-        const version = await invoke<string>("get_app_version");
-        setAppVersion(version);
-
-        // Fetch installer data from repo
-        const result = await invoke<InstallerDataResult>("fetch_installer_data");
-
-        if (result.error) {
-          setInstallerDataError(result.error);
-          return;
-        }
-
-        if (result.data) {
-          setInstallerData(result.data);
+    if (autoSyncCountdown === null || autoSyncCountdown <= 0) return;
+    const timer = setTimeout(() => setAutoSyncCountdown(autoSyncCountdown - 1), 1000);
+    return () => clearTimeout(timer);
+  }, [autoSyncCountdown]);
+
+  const handleCancelAutoSync = async () => {
+    await invoke("cancel_auto_sync");
+    setAutoSyncCountdown(null);
+  };
 
-          // Check version compatibility
-          const comparison = await invoke<number>("compare_versions", {
-            v1: version,
-            v2: result.data.min_download_app_version,
-          });
+  // Check if git is available (on mount, and again after downloading it)
+  const checkGit = async () => {
+    try {
+      const status = await invoke<GitStatus>("get_git_status");
+      setGitStatus(status);
+      setGitAvailable(status.found && status.remediation === null);
+    } catch (e) {
+      setGitAvailable(false);
+      setGitStatus(null);
+    }
+  };
 
-          if (comparison < 0) {
-            // App is outdated
-            setIsAppOutdated(true);
-            setRequiredVersion(result.data.min_download_app_version);
-          }
-        }
-      } catch (e) {
-        console.error("Failed to fetch app info:", e);
-        setInstallerDataError(String(e));
-      }
-    };
-    fetchAppInfo();
+  useEffect(() => {
+    checkGit();
   }, []);
 
-  // Retry fetching installer data
-  const handleRetryFetch = async () => {
+  // Check app version compatibility (decided by the backend)
+  const checkVersion = async () => {
     setInstallerDataError(null);
     try {
-      const result = await invoke<InstallerDataResult>("fetch_installer_data");
+      // Detect broken IPv6 routes first, so the requests below don't hang on them
+      await invoke("check_connectivity").catch((e) => console.error("Connectivity check failed:", e));
+      const result = await invoke<VersionCheckResult>("check_version_compatibility");
+      setAppVersion(result.app_version);
 
-      if (result.error) {
-        setInstallerDataError(result.error);
-        return;
+      if (result.installer_data) {
+        setInstallerData(result.installer_data);
       }
 
-      if (result.data) {
-        setInstallerData(result.data);
-
-        // Check version compatibility
-        if (appVersion) {
-          const comparison = await invoke<number>("compare_versions", {
-            v1: appVersion,
-            v2: result.data.min_download_app_version,
-          });
-
-          if (comparison < 0) {
-            setIsAppOutdated(true);
-            setRequiredVersion(result.data.min_download_app_version);
-          }
-        }
+      if (result.status === "block") {
+        setIsAppOutdated(true);
+        setRequiredVersion(result.required_version || "");
+      } else if (result.status === "warn") {
+        setInstallerDataError(result.message || "Failed to fetch installer data");
       }
     } catch (e) {
-      console.error("Retry failed:", e);
+      console.error("Failed to check app version:", e);
       setInstallerDataError(String(e));
     }
   };
 
+  // Fetch app version and installer data on mount
+  useEffect(() => {
+    checkVersion();
+  }, []);
+
+  // Retry fetching installer data
+  const handleRetryFetch = checkVersion;
+
   // Save textures path when it changes
   const handleTexturesDirChange = async (dir: string) => {
     setTexturesDir(dir);
@@ -298,6 +315,24 @@ function App() {
       <div className="max-w-xl mx-auto space-y-6 flex-1">
         <Header version={appVersion || undefined} />
 
+        {/* Auto-sync announcement */}
+        {autoSyncCountdown !== null && autoSyncCountdown > 0 && (
+          <div className="bg-blue-900/40 border border-blue-700 rounded-lg p-3 flex items-center justify-between text-sm">
+            <span className="text-blue-200">Updates found. Syncing automatically in {autoSyncCountdown}s...</span>
+            <button
+              onClick={handleCancelAutoSync}
+              className="px-3 py-1 text-blue-200 hover:text-white border border-blue-600 rounded transition-colors"
+            >
+              Cancel
+            </button>
+          </div>
+        )}
+        {autoSyncError && (
+          <div className="bg-red-900/30 border border-red-700 rounded-lg p-3 text-sm text-red-300">
+            Auto-sync failed: {autoSyncError}
+          </div>
+        )}
+
         {/* Tabs */}
         <div className="flex gap-1 border-b border-zinc-700">
           <TabButton
@@ -329,7 +364,8 @@ function App() {
                 texturesDir={texturesDir}
                 setTexturesDir={handleTexturesDirChange}
                 gitAvailable={gitAvailable}
-                gitError={gitError}
+                gitStatus={gitStatus}
+                githubToken={githubToken}
                 onInstallComplete={handleInstallComplete}
               />
 
@@ -368,6 +404,9 @@ function App() {
             </>
           )}
         </section>
+
+        {/* Factory reset */}
+        <ResetAppPanel />
       </div>
 
       {/* Footer with version */}
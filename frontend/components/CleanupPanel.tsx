@@ -0,0 +1,87 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface CleanupReport {
+  removed_dirs: string[];
+  removed_files: string[];
+  dry_run: boolean;
+}
+
+interface CleanupPanelProps {
+  texturesDir: string;
+  disabled?: boolean;
+}
+
+function CleanupPanel({ texturesDir, disabled }: CleanupPanelProps) {
+  const [report, setReport] = useState<CleanupReport | null>(null);
+  const [isRunning, setIsRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const runCleanup = async (dryRun: boolean) => {
+    setIsRunning(true);
+    setError(null);
+    try {
+      setReport(await invoke<CleanupReport>("run_cleanup", { texturesDir, dryRun }));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsRunning(false);
+    }
+  };
+
+  const total = report ? report.removed_files.length + report.removed_dirs.length : 0;
+
+  return (
+    <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+      <div className="flex items-center justify-between">
+        <div>
+          <span className="text-sm text-zinc-200">Clean up</span>
+          <p className="text-xs text-zinc-500">Remove junk files (like Thumbs.db) and empty folders</p>
+        </div>
+        <div className="flex gap-3">
+          <button
+            onClick={() => runCleanup(true)}
+            disabled={!texturesDir || isRunning || disabled}
+            className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+          >
+            Preview
+          </button>
+          <button
+            onClick={() => runCleanup(false)}
+            disabled={!texturesDir || isRunning || disabled}
+            className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+          >
+            {isRunning ? "Cleaning..." : "Clean up now"}
+          </button>
+        </div>
+      </div>
+
+      {report && (
+        <div className="text-xs text-zinc-400">
+          {total === 0 ? (
+            <p>Nothing to clean up.</p>
+          ) : (
+            <>
+              <p>
+                {report.dry_run ? "Would remove" : "Removed"} {report.removed_files.length} junk file(s) and{" "}
+                {report.removed_dirs.length} empty folder(s)
+              </p>
+              <ul className="mt-1 max-h-32 overflow-y-auto font-mono text-zinc-500 space-y-0.5">
+                {report.removed_files.map((path) => (
+                  <li key={path} className="truncate">{path}</li>
+                ))}
+                {report.removed_dirs.map((path) => (
+                  <li key={path} className="truncate">{path}/</li>
+                ))}
+              </ul>
+            </>
+          )}
+        </div>
+      )}
+
+      {error && <p className="text-xs text-red-400">{error}</p>}
+    </div>
+  );
+}
+
+export default CleanupPanel;
@@ -1,5 +1,18 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
 import { open } from "@tauri-apps/plugin-dialog";
 
+interface ValidationReport {
+  exists: boolean;
+  is_directory: boolean;
+  writable: boolean;
+  free_bytes: number | null;
+  path_length: number;
+  long_path_risk: boolean;
+  problems: string[];
+  valid: boolean;
+}
+
 interface DirectoryPickerProps {
   value: string;
   onChange: (value: string) => void;
@@ -7,6 +20,19 @@ interface DirectoryPickerProps {
 }
 
 function DirectoryPicker({ value, onChange, disabled }: DirectoryPickerProps) {
+  const [report, setReport] = useState<ValidationReport | null>(null);
+
+  // Check the chosen directory (write test, free space, path length)
+  useEffect(() => {
+    if (!value) {
+      setReport(null);
+      return;
+    }
+    invoke<ValidationReport>("validate_directory", { path: value })
+      .then(setReport)
+      .catch((e) => console.error("Failed to validate directory:", e));
+  }, [value]);
+
   const handleBrowse = async () => {
     try {
       const selected = await open({
@@ -53,6 +79,18 @@ function DirectoryPicker({ value, onChange, disabled }: DirectoryPickerProps) {
       <p className="mt-1 text-xs text-zinc-500">
         Example: C:\PCSX2\textures or ~/Library/Application Support/PCSX2/textures
       </p>
+      {report && report.problems.length > 0 && (
+        <ul className={`mt-1 text-xs space-y-0.5 ${report.valid ? "text-yellow-400" : "text-red-400"}`}>
+          {report.problems.map((problem) => (
+            <li key={problem}>{problem}</li>
+          ))}
+        </ul>
+      )}
+      {report?.free_bytes != null && (
+        <p className="mt-1 text-xs text-zinc-500">
+          Free space: {(report.free_bytes / 1024 / 1024 / 1024).toFixed(1)} GB
+        </p>
+      )}
     </div>
   );
 }
@@ -0,0 +1,224 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+type DiskUsageCategory = "install" | "backup" | "cache" | "temp";
+
+interface DiskUsageEntry {
+  category: DiskUsageCategory;
+  path: string;
+  file_count: number;
+  total_bytes: number;
+  removable: boolean;
+}
+
+interface DiskUsage {
+  install_bytes: number;
+  backup_bytes: number;
+  cache_bytes: number;
+  temp_bytes: number;
+  entries: DiskUsageEntry[];
+}
+
+interface UninstallReport {
+  dry_run: boolean;
+  entries: DiskUsageEntry[];
+  total_bytes: number;
+  failed: string[];
+}
+
+const CATEGORY_LABELS: Record<DiskUsageCategory, string> = {
+  install: "Textures",
+  backup: "Backups",
+  cache: "Download cache",
+  temp: "Temporary files",
+};
+
+interface DiskUsagePanelProps {
+  texturesDir: string;
+  disabled?: boolean;
+}
+
+function formatSize(bytes: number): string {
+  if (bytes >= 1024 * 1024 * 1024) return `${(bytes / 1024 / 1024 / 1024).toFixed(2)} GB`;
+  return `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+}
+
+function DiskUsagePanel({ texturesDir, disabled }: DiskUsagePanelProps) {
+  const [usage, setUsage] = useState<DiskUsage | null>(null);
+  const [isMeasuring, setIsMeasuring] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const [includeBackups, setIncludeBackups] = useState(false);
+  const [includeCache, setIncludeCache] = useState(false);
+  const [uninstallPreview, setUninstallPreview] = useState<UninstallReport | null>(null);
+  const [isUninstalling, setIsUninstalling] = useState(false);
+
+  const measure = async () => {
+    setIsMeasuring(true);
+    setError(null);
+    try {
+      setUsage(await invoke<DiskUsage>("get_disk_usage", { texturesDir }));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsMeasuring(false);
+    }
+  };
+
+  const handleRemove = async (entry: DiskUsageEntry) => {
+    if (!confirm(`Permanently delete ${entry.path} (${formatSize(entry.total_bytes)})?`)) return;
+    try {
+      await invoke("remove_disk_usage_entry", { texturesDir, path: entry.path });
+      await measure();
+    } catch (e) {
+      setError(String(e));
+    }
+  };
+
+  const runUninstall = async (dryRun: boolean) => {
+    setIsUninstalling(true);
+    setError(null);
+    try {
+      const report = await invoke<UninstallReport>("uninstall_pack", {
+        texturesDir,
+        includeBackups,
+        includeCache,
+        dryRun,
+      });
+      if (!dryRun && report.failed.length === 0) {
+        // The app no longer has an install to sync
+        window.location.reload();
+        return;
+      }
+      setUninstallPreview(report);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsUninstalling(false);
+    }
+  };
+
+  const totals: Record<DiskUsageCategory, number> | null = usage && {
+    install: usage.install_bytes,
+    backup: usage.backup_bytes,
+    cache: usage.cache_bytes,
+    temp: usage.temp_bytes,
+  };
+
+  return (
+    <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+      <div className="flex items-center justify-between">
+        <span className="text-sm text-zinc-200">Disk usage</span>
+        <button
+          onClick={measure}
+          disabled={!texturesDir || isMeasuring || disabled}
+          className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+        >
+          {isMeasuring ? "Measuring..." : usage ? "Refresh" : "Show disk usage"}
+        </button>
+      </div>
+
+      {totals && usage && (
+        <div className="space-y-2">
+          {(Object.keys(CATEGORY_LABELS) as DiskUsageCategory[]).map((category) => (
+            <div key={category}>
+              <div className="flex justify-between text-xs text-zinc-400">
+                <span>{CATEGORY_LABELS[category]}</span>
+                <span className="text-zinc-200">{formatSize(totals[category])}</span>
+              </div>
+              {usage.entries
+                .filter((entry) => entry.category === category && entry.removable)
+                .map((entry) => (
+                  <div key={entry.path} className="flex items-center justify-between gap-2 pl-3 text-xs text-zinc-500">
+                    <span className="truncate font-mono" title={entry.path}>{entry.path}</span>
+                    <span className="flex-shrink-0">{formatSize(entry.total_bytes)}</span>
+                    <button
+                      onClick={() => handleRemove(entry)}
+                      disabled={disabled}
+                      className="flex-shrink-0 text-red-400 hover:text-red-300 disabled:text-zinc-600"
+                    >
+                      Delete
+                    </button>
+                  </div>
+                ))}
+            </div>
+          ))}
+        </div>
+      )}
+
+      {/* Uninstall */}
+      {usage && (
+        <div className="pt-2 border-t border-zinc-700 space-y-1">
+          <div className="flex items-center justify-between">
+            <span className="text-xs text-zinc-400">Uninstall the textures</span>
+            <button
+              onClick={() => runUninstall(true)}
+              disabled={isUninstalling || disabled}
+              className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+            >
+              Preview uninstall
+            </button>
+          </div>
+          <label className="flex items-center gap-2 text-xs text-zinc-500 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={includeBackups}
+              onChange={(e) => {
+                setIncludeBackups(e.target.checked);
+                setUninstallPreview(null);
+              }}
+              className="w-3 h-3 rounded bg-zinc-700 border-zinc-600"
+            />
+            Also remove backups
+          </label>
+          <label className="flex items-center gap-2 text-xs text-zinc-500 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={includeCache}
+              onChange={(e) => {
+                setIncludeCache(e.target.checked);
+                setUninstallPreview(null);
+              }}
+              className="w-3 h-3 rounded bg-zinc-700 border-zinc-600"
+            />
+            Also remove the download cache
+          </label>
+
+          {uninstallPreview && (
+            <div className="text-xs text-zinc-400 space-y-1">
+              <p>
+                {uninstallPreview.dry_run ? "Will remove" : "Removed"} {formatSize(uninstallPreview.total_bytes)}:
+              </p>
+              <ul className="max-h-32 overflow-y-auto font-mono text-zinc-500 space-y-0.5">
+                {uninstallPreview.entries.map((entry) => (
+                  <li key={entry.path} className="truncate" title={entry.path}>
+                    [{CATEGORY_LABELS[entry.category]}] {entry.path}
+                  </li>
+                ))}
+              </ul>
+              {uninstallPreview.failed.length > 0 && (
+                <ul className="max-h-32 overflow-y-auto font-mono text-red-400 space-y-0.5">
+                  {uninstallPreview.failed.map((failure) => (
+                    <li key={failure} className="truncate">{failure}</li>
+                  ))}
+                </ul>
+              )}
+              {uninstallPreview.dry_run && (
+                <button
+                  onClick={() => runUninstall(false)}
+                  disabled={isUninstalling || disabled}
+                  className="px-3 py-1 bg-red-700 hover:bg-red-600 disabled:bg-zinc-700 text-white rounded"
+                >
+                  {isUninstalling ? "Uninstalling..." : "Uninstall"}
+                </button>
+              )}
+            </div>
+          )}
+        </div>
+      )}
+
+      {error && <p className="text-xs text-red-400">{error}</p>}
+    </div>
+  );
+}
+
+export default DiskUsagePanel;
@@ -1,16 +1,33 @@
+import { useState } from "react";
+
+export interface ExistingFolderInfo {
+  file_count: number;
+  total_bytes: number;
+  last_modified: string | null;
+  is_git_clone: boolean;
+  matches_managed_layout: boolean;
+  pack_version: string | null;
+  pack_version_source: "manifest" | "last_sync" | null;
+}
+
 interface ExistingFolderDialogProps {
   folderName: string;
-  onBackup: () => void;
+  /** Details of the folder, null while it's being inspected */
+  info: ExistingFolderInfo | null;
+  onBackup: (compress: boolean) => void;
   onDelete: () => void;
   onCancel: () => void;
 }
 
 function ExistingFolderDialog({
   folderName,
+  info,
   onBackup,
   onDelete,
   onCancel,
 }: ExistingFolderDialogProps) {
+  const [compress, setCompress] = useState(false);
+
   return (
     <div className="fixed inset-0 bg-black/70 flex items-center justify-center p-4 z-50">
       <div className="bg-zinc-800 rounded-lg p-6 max-w-md w-full border border-zinc-700 shadow-xl">
@@ -22,9 +39,30 @@ function ExistingFolderDialog({
           was found. What would you like to do?
         </p>
 
+        {info && (
+          <div className="mb-4 p-3 bg-zinc-900 border border-zinc-700 rounded text-xs text-zinc-400 space-y-1">
+            <div>
+              {info.file_count.toLocaleString()} files, {(info.total_bytes / 1024 / 1024 / 1024).toFixed(2)} GB
+              {info.last_modified && <>, last changed {new Date(info.last_modified).toLocaleDateString()}</>}
+            </div>
+            {info.pack_version && (
+              <div>
+                Pack version {info.pack_version.slice(0, 7)}
+                {info.pack_version_source === "manifest" ? " (from its integrity manifest)" : " (from your last sync)"}
+              </div>
+            )}
+            {info.is_git_clone && <div className="text-yellow-400">This folder is a git clone.</div>}
+            {!info.matches_managed_layout && (
+              <div className="text-yellow-400">
+                This folder has no <span className="font-mono">replacements</span> folder, it may not be an NCAA NEXT install.
+              </div>
+            )}
+          </div>
+        )}
+
         <div className="space-y-2">
           <button
-            onClick={onBackup}
+            onClick={() => onBackup(compress)}
             className="w-full py-2.5 px-4 bg-blue-600 hover:bg-blue-500 text-white rounded-lg
                        font-medium transition-colors text-left flex items-start gap-3"
           >
@@ -32,10 +70,21 @@ function ExistingFolderDialog({
             <div>
               <div>Back up existing folder</div>
               <div className="text-xs text-blue-200 font-normal mt-0.5">
-                Rename to {folderName}_backup_[timestamp]
+                {compress
+                  ? `Compress into ${folderName}_backup_[timestamp].zip`
+                  : `Rename to ${folderName}_backup_[timestamp]`}
               </div>
             </div>
           </button>
+          <label className="flex items-center gap-2 px-1 text-xs text-zinc-400 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={compress}
+              onChange={(e) => setCompress(e.target.checked)}
+              className="w-3.5 h-3.5 rounded bg-zinc-700 border-zinc-600"
+            />
+            Compress the backup into a zip (slower, saves space)
+          </label>
 
           <button
             onClick={onDelete}
@@ -46,7 +95,7 @@ function ExistingFolderDialog({
             <div>
               <div>Delete existing folder</div>
               <div className="text-xs text-zinc-400 font-normal mt-0.5">
-                Permanently remove the existing folder
+                Move the existing folder to the Recycle Bin/Trash
               </div>
             </div>
           </button>
@@ -0,0 +1,94 @@
+import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+
+interface FileDonePayload {
+  operation_id: number;
+  path: string;
+  action: string;
+  outcome: "ok" | "failed" | "skipped";
+}
+
+/** Height of one row in px (rows are fixed-height so only the visible ones are rendered) */
+const ROW_HEIGHT = 18;
+const LIST_HEIGHT = 160;
+/** Rows rendered above and below the visible ones */
+const OVERSCAN = 10;
+
+const OUTCOME_STYLES: Record<FileDonePayload["outcome"], string> = {
+  ok: "text-zinc-400",
+  failed: "text-red-400",
+  skipped: "text-zinc-600",
+};
+
+/** Live list of the files the current sync has finished, fed by "file-done" events */
+function FileDoneList() {
+  const [files, setFiles] = useState<FileDonePayload[]>([]);
+  const [scrollTop, setScrollTop] = useState(0);
+  const containerRef = useRef<HTMLDivElement>(null);
+  const followRef = useRef(true);
+
+  useEffect(() => {
+    let operationId: number | null = null;
+    const unlisten = listen<FileDonePayload>("file-done", (event) => {
+      const file = event.payload;
+      // A new operation starts a new list
+      if (file.operation_id !== operationId) {
+        operationId = file.operation_id;
+        setFiles([file]);
+        return;
+      }
+      setFiles((files) => [...files, file]);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Stay at the bottom unless the user scrolled up
+  useEffect(() => {
+    const container = containerRef.current;
+    if (container && followRef.current) {
+      container.scrollTop = container.scrollHeight;
+    }
+  }, [files]);
+
+  if (files.length === 0) return null;
+
+  const first = Math.max(0, Math.floor(scrollTop / ROW_HEIGHT) - OVERSCAN);
+  const last = Math.min(files.length, Math.ceil((scrollTop + LIST_HEIGHT) / ROW_HEIGHT) + OVERSCAN);
+  const failed = files.filter((file) => file.outcome === "failed").length;
+
+  return (
+    <div className="space-y-1">
+      <div className="text-xs text-zinc-500">
+        {files.length} files done{failed > 0 && <span className="text-red-400"> ({failed} failed)</span>}
+      </div>
+      <div
+        ref={containerRef}
+        onScroll={(e) => {
+          const container = e.currentTarget;
+          setScrollTop(container.scrollTop);
+          followRef.current = container.scrollTop + container.clientHeight >= container.scrollHeight - ROW_HEIGHT;
+        }}
+        className="bg-zinc-950 border border-zinc-700 rounded-lg overflow-y-auto font-mono text-xs"
+        style={{ height: LIST_HEIGHT }}
+      >
+        <div style={{ height: files.length * ROW_HEIGHT, position: "relative" }}>
+          {files.slice(first, last).map((file, i) => (
+            <div
+              key={first + i}
+              className={`absolute left-0 right-0 px-2 truncate ${OUTCOME_STYLES[file.outcome]}`}
+              style={{ top: (first + i) * ROW_HEIGHT, height: ROW_HEIGHT }}
+              title={file.path}
+            >
+              [{file.action}] {file.path}
+            </div>
+          ))}
+        </div>
+      </div>
+    </div>
+  );
+}
+
+export default FileDoneList;
@@ -4,22 +4,67 @@ import { listen } from "@tauri-apps/api/event";
 import DirectoryPicker from "./DirectoryPicker";
 import InstallButton from "./InstallButton";
 import ProgressDisplay from "./ProgressDisplay";
-import ExistingFolderDialog from "./ExistingFolderDialog";
+import ExistingFolderDialog, { ExistingFolderInfo } from "./ExistingFolderDialog";
 import { TARGET_FOLDER } from "../config";
+import { MessageParams, formatProgressMessage } from "../progressMessages";
 
 interface ProgressPayload {
   stage: string;
+  key: string;
+  params: MessageParams;
   message: string;
   percent: number | null;
+  bytes_received: number | null;
+  speed: number | null;
 }
 
+interface InstallVerification {
+  files_to_download: { path: string; to_disabled: boolean; sha: string | null; reason: string }[];
+  files_to_delete: string[];
+  orphaned_files: string[];
+  junk_files: string[];
+  has_discrepancies: boolean;
+  plan_token: string;
+}
+
+interface InstallVerifyResult {
+  commit_sha: string;
+  verification: InstallVerification;
+}
+
+interface VerificationFixResult {
+  files_downloaded: number;
+  files_deleted: number;
+  failed_files: { path: string; error: string }[];
+}
+
+export interface GitStatus {
+  found: boolean;
+  path: string | null;
+  version: string | null;
+  source: "bundled" | "system" | null;
+  searched_locations: string[];
+  remediation: "install_git_for_windows" | "install_xcode_tools" | "install_package" | "update_git" | null;
+  message: string;
+}
+
+type PartialInstall = "none" | "interrupted_download" | "not_moved" | "partially_moved" | "cleanup_pending";
+
+const PARTIAL_INSTALL_DESCRIPTIONS: Record<Exclude<PartialInstall, "none">, string> = {
+  interrupted_download: "A previous installation stopped while downloading.",
+  not_moved: "A previous installation downloaded the textures but stopped before moving them into place.",
+  partially_moved: "A previous installation stopped while moving the textures into place.",
+  cleanup_pending: "A previous installation finished but left its temporary download behind.",
+};
+
 type InstallStatus = "idle" | "installing" | "complete" | "error";
 
 interface InstallTabProps {
   texturesDir: string;
   setTexturesDir: (dir: string) => void;
   gitAvailable: boolean | null;
-  gitError: string;
+  gitStatus: GitStatus | null;
+  githubToken: string | null;
   onInstallComplete: (commitSha: string) => void;
 }
 
@@ -27,40 +72,77 @@ function InstallTab({
   texturesDir,
   setTexturesDir,
   gitAvailable,
-  gitError,
+  gitStatus,
+  githubToken,
   onInstallComplete,
 }: InstallTabProps) {
   const [showFolderDialog, setShowFolderDialog] = useState(false);
+  const [existingFolderInfo, setExistingFolderInfo] = useState<ExistingFolderInfo | null>(null);
   const [installStatus, setInstallStatus] = useState<InstallStatus>("idle");
   const [progressMessages, setProgressMessages] = useState<string[]>([]);
   const [progressPercent, setProgressPercent] = useState<number | null>(null);
   const [currentStage, setCurrentStage] = useState<string | null>(null);
+  const [bytesReceived, setBytesReceived] = useState<number | null>(null);
+  const [transferSpeed, setTransferSpeed] = useState<number | null>(null);
   const [errorMessage, setErrorMessage] = useState<string | null>(null);
+  const [isBackingUp, setIsBackingUp] = useState(false);
+  const [partialInstall, setPartialInstall] = useState<PartialInstall>("none");
+  // Problems the post-install check found, until they're fixed or dismissed
+  const [verification, setVerification] = useState<InstallVerification | null>(null);
+  const [isFixing, setIsFixing] = useState(false);
+  const [fixResult, setFixResult] = useState<VerificationFixResult | null>(null);
+
+  // Look for an installation that was cut off in the chosen directory
+  useEffect(() => {
+    if (!texturesDir || installStatus === "installing") {
+      return;
+    }
+    invoke<PartialInstall>("detect_partial_install", { texturesDir })
+      .then(setPartialInstall)
+      .catch((e) => console.error("Failed to check for an interrupted installation:", e));
+  }, [texturesDir, installStatus]);
+
+  // Listen for backup progress (copied or compressed backups only, renames are instant)
+  useEffect(() => {
+    const unlisten = listen<ProgressPayload>("backup-progress", (event) => {
+      const { stage, key, params, message, percent, bytes_received, speed } = event.payload;
+      setCurrentStage(stage);
+      setProgressMessages((prev) => [...prev, formatProgressMessage(key, params, message)]);
+      setProgressPercent(percent);
+      setBytesReceived(bytes_received);
+      setTransferSpeed(speed);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
 
   // Listen for progress events
   useEffect(() => {
     const unlisten = listen<ProgressPayload>("install-progress", (event) => {
-      const { stage, message, percent } = event.payload;
+      const { stage, key, params, message, percent, bytes_received, speed } = event.payload;
 
       setCurrentStage(stage);
-      setProgressMessages((prev) => [...prev, message]);
+      setProgressMessages((prev) => [...prev, formatProgressMessage(key, params, message)]);
       if (percent !== null) {
         setProgressPercent(percent);
       }
+      // Keep the last transfer figures while git resolves deltas or checks out files
+      if (bytes_received !== null) {
+        setBytesReceived(bytes_received);
+      }
+      setTransferSpeed(speed);
 
       if (stage === "complete") {
         setInstallStatus("complete");
-        // Get the latest commit SHA and notify parent
-        invoke<string>("get_latest_commit")
-          .then((sha) => onInstallComplete(sha))
-          .catch(console.error);
       }
     });
 
     return () => {
       unlisten.then((fn) => fn());
     };
-  }, [onInstallComplete]);
+  }, []);
 
   const handleStartInstall = async () => {
     if (!texturesDir) {
@@ -74,7 +156,11 @@ function InstallTab({
       });
 
       if (exists) {
+        setExistingFolderInfo(null);
         setShowFolderDialog(true);
+        invoke<ExistingFolderInfo>("inspect_existing_folder", { texturesDir })
+          .then(setExistingFolderInfo)
+          .catch((e) => console.error("Failed to inspect existing folder:", e));
         return;
       }
 
@@ -89,29 +175,95 @@ function InstallTab({
     setProgressMessages([]);
     setProgressPercent(0);
     setCurrentStage(null);
+    setBytesReceived(null);
+    setTransferSpeed(null);
     setErrorMessage(null);
+    setVerification(null);
+    setFixResult(null);
 
     try {
-      await invoke("start_installation", { texturesDir });
+      // Installs, records the cloned commit and verifies the result in one pass
+      const result = await invoke<InstallVerifyResult>("install_and_verify", {
+        texturesDir,
+        githubToken,
+      });
+      if (result.verification.has_discrepancies) {
+        setVerification(result.verification);
+      }
+      onInstallComplete(result.commit_sha);
     } catch (e) {
       setInstallStatus("error");
-      setErrorMessage(`Installation failed: ${e}`);
+      // Git too old: the backend's message already explains what to update
+      const error = String(e);
+      if (error.startsWith("GIT_TOO_OLD: ")) {
+        setErrorMessage(error.slice("GIT_TOO_OLD: ".length));
+      } else {
+        setErrorMessage(`Installation failed: ${error}`);
+      }
     }
   };
 
-  const handleBackup = async () => {
+  const handleApplyFixes = async () => {
+    if (!verification) return;
+    setIsFixing(true);
+    setErrorMessage(null);
+    try {
+      const result = await invoke<VerificationFixResult>("apply_verification_fixes", {
+        texturesDir,
+        filesToDownload: verification.files_to_download,
+        filesToDelete: verification.files_to_delete,
+        planToken: verification.plan_token || null,
+        githubToken,
+      });
+      setFixResult(result);
+      setVerification(null);
+    } catch (e) {
+      setErrorMessage(`Applying fixes failed: ${e}`);
+    } finally {
+      setIsFixing(false);
+    }
+  };
+
+  const handleRepair = async () => {
+    setInstallStatus("installing");
+    setProgressMessages([]);
+    setProgressPercent(0);
+    setCurrentStage(null);
+    setErrorMessage(null);
+
+    try {
+      const commitSha = await invoke<string>("repair_install", { texturesDir });
+      setPartialInstall("none");
+      onInstallComplete(commitSha);
+    } catch (e) {
+      setInstallStatus("error");
+      setErrorMessage(`Repair failed: ${e}`);
+    }
+  };
+
+  const handleBackup = async (compress: boolean) => {
     setShowFolderDialog(false);
+    setIsBackingUp(true);
+    setProgressMessages([]);
+    setProgressPercent(null);
+    setErrorMessage(null);
     try {
-      const backupName = await invoke<string>("backup_existing_folder", {
+      await invoke<string>("backup_existing_folder", {
         texturesDir,
+        compress,
       });
-      setProgressMessages([`Backed up existing folder to: ${backupName}`]);
+      setIsBackingUp(false);
       await startInstallation();
     } catch (e) {
+      setIsBackingUp(false);
       setErrorMessage(`Backup failed: ${e}`);
     }
   };
 
+  const handleCancelBackup = () => {
+    invoke("cancel_backup").catch((e) => console.error("Failed to cancel backup:", e));
+  };
+
   const handleDelete = async () => {
     setShowFolderDialog(false);
     try {
@@ -132,11 +284,33 @@ function InstallTab({
   return (
     <>
       <div className="space-y-4">
-        {/* Git availability warning */}
+        {/* Git availability warning, with guidance for the platform */}
         {gitAvailable === false && (
           <div className="bg-red-900/50 border border-red-700 rounded-lg p-4">
-            <p className="text-red-200 font-medium">Git not available</p>
-            <p className="text-red-300 text-sm mt-1">{gitError}</p>
+            <p className="text-red-200 font-medium">
+              {gitStatus?.remediation === "update_git" ? "Git is too old" : "Git not available"}
+            </p>
+            <p className="text-red-300 text-sm mt-1">
+              {gitStatus?.message || "Failed to check git availability"}
+            </p>
+            {gitStatus?.remediation === "install_xcode_tools" && (
+              <pre className="text-red-200 text-xs mt-2 bg-black/30 rounded p-2">xcode-select --install</pre>
+            )}
+            {gitStatus?.remediation === "install_package" && (
+              <pre className="text-red-200 text-xs mt-2 bg-black/30 rounded p-2">
+                {"sudo apt install git    # Debian, Ubuntu\nsudo dnf install git    # Fedora\nsudo pacman -S git      # Arch"}
+              </pre>
+            )}
+            {gitStatus && !gitStatus.found && gitStatus.searched_locations.length > 1 && (
+              <details className="mt-3 text-red-300 text-xs">
+                <summary className="cursor-pointer">Searched locations</summary>
+                <ul className="mt-1 space-y-0.5">
+                  {gitStatus.searched_locations.map((location) => (
+                    <li key={location} className="break-all">{location}</li>
+                  ))}
+                </ul>
+              </details>
+            )}
           </div>
         )}
 
@@ -146,9 +320,23 @@ function InstallTab({
           disabled={isInstalling}
         />
 
+        {/* Interrupted installation */}
+        {partialInstall !== "none" && !isInstalling && (
+          <div className="bg-yellow-900/30 border border-yellow-700 rounded-lg p-4">
+            <p className="text-yellow-200 text-sm">{PARTIAL_INSTALL_DESCRIPTIONS[partialInstall]}</p>
+            <button
+              onClick={handleRepair}
+              disabled={isBackingUp}
+              className="mt-3 px-3 py-1.5 bg-yellow-700 hover:bg-yellow-600 disabled:opacity-50 text-white text-sm rounded-lg transition-colors"
+            >
+              Repair Installation
+            </button>
+          </div>
+        )}
+
         <InstallButton
           onClick={handleStartInstall}
-          disabled={!texturesDir || isInstalling || gitAvailable === false}
+          disabled={!texturesDir || isInstalling || isBackingUp || gitAvailable === false}
           isInstalling={isInstalling}
         />
 
@@ -159,21 +347,82 @@ function InstallTab({
           </div>
         )}
 
+        {/* Problems the post-install check found */}
+        {verification && !isInstalling && (() => {
+          const missing = verification.files_to_download.filter((f) => f.reason === "missing").length;
+          const mismatched = verification.files_to_download.length - missing;
+          const extra = verification.files_to_delete.length;
+          return (
+            <div className="bg-yellow-900/30 border border-yellow-700 rounded-lg p-4">
+              <p className="text-yellow-200 text-sm font-medium">The installed textures don't fully match the pack</p>
+              <ul className="text-yellow-300 text-sm mt-1 space-y-0.5">
+                {mismatched > 0 && <li>{mismatched} file{mismatched === 1 ? "" : "s"} with the wrong content</li>}
+                {missing > 0 && <li>{missing} missing file{missing === 1 ? "" : "s"}</li>}
+                {extra > 0 && <li>{extra} extra file{extra === 1 ? "" : "s"} to remove</li>}
+              </ul>
+              <div className="flex gap-3 mt-3">
+                <button
+                  onClick={handleApplyFixes}
+                  disabled={isFixing}
+                  className="px-3 py-1.5 bg-yellow-700 hover:bg-yellow-600 disabled:opacity-50 text-white text-sm rounded-lg transition-colors"
+                >
+                  {isFixing ? "Applying Fixes..." : "Apply Fixes"}
+                </button>
+                <button
+                  onClick={() => setVerification(null)}
+                  disabled={isFixing}
+                  className="text-sm text-zinc-400 hover:text-zinc-200 underline disabled:opacity-50"
+                >
+                  Dismiss
+                </button>
+              </div>
+            </div>
+          );
+        })()}
+
+        {fixResult && (
+          <div className="p-3 bg-zinc-900 border border-zinc-700 rounded text-sm text-zinc-300">
+            <p>
+              Fixed {fixResult.files_downloaded} file{fixResult.files_downloaded === 1 ? "" : "s"} and removed{" "}
+              {fixResult.files_deleted} extra file{fixResult.files_deleted === 1 ? "" : "s"}.
+            </p>
+            {fixResult.failed_files.length > 0 && (
+              <ul className="mt-1 text-red-300 text-xs space-y-0.5">
+                {fixResult.failed_files.map((f) => (
+                  <li key={f.path} className="break-all">{f.path}: {f.error}</li>
+                ))}
+              </ul>
+            )}
+          </div>
+        )}
+
         {/* Progress display */}
-        {(isInstalling || installStatus === "complete") && (
+        {(isInstalling || isBackingUp || installStatus === "complete") && (
           <ProgressDisplay
             messages={progressMessages}
             percent={progressPercent}
             stage={currentStage}
+            bytesReceived={bytesReceived}
+            speed={transferSpeed}
             isComplete={installStatus === "complete"}
           />
         )}
+
+        {isBackingUp && (
+          <button
+            onClick={handleCancelBackup}
+            className="w-full py-2 text-sm text-zinc-400 hover:text-zinc-200 border border-zinc-700 rounded-lg transition-colors"
+          >
+            Cancel Backup
+          </button>
+        )}
       </div>
 
       {/* Existing folder dialog */}
       {showFolderDialog && (
         <ExistingFolderDialog
           folderName={TARGET_FOLDER}
+          info={existingFolderInfo}
           onBackup={handleBackup}
           onDelete={handleDelete}
           onCancel={handleCancel}
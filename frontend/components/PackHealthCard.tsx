@@ -0,0 +1,92 @@
+import { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+type HealthLevel = "good" | "attention" | "problem";
+
+interface PackHealth {
+  level: HealthLevel;
+  installed: boolean;
+  partial_install: string;
+  last_sync_commit: string | null;
+  last_sync_timestamp: string | null;
+  days_since_sync: number | null;
+  verification: {
+    days_since: number | null;
+    all_good: boolean;
+    reverify_suggested: boolean;
+  };
+  local_changes: { changed_files: number } | null;
+  disabled_count: number;
+  sync_interrupted: boolean;
+  known_failures: { path: string }[];
+  disk_usage: { install_bytes: number; backup_bytes: number; cache_bytes: number; temp_bytes: number } | null;
+}
+
+const LEVEL_STYLES: Record<HealthLevel, string> = {
+  good: "bg-green-900/30 border-green-800 text-green-300",
+  attention: "bg-yellow-900/30 border-yellow-700 text-yellow-300",
+  problem: "bg-red-900/30 border-red-700 text-red-300",
+};
+
+const LEVEL_LABELS: Record<HealthLevel, string> = {
+  good: "Textures are up to date and verified",
+  attention: "Textures need attention",
+  problem: "Textures have problems",
+};
+
+interface PackHealthCardProps {
+  texturesDir: string;
+  // Reloads the card when it changes (e.g. the sync status)
+  refreshKey?: string;
+}
+
+function formatDays(days: number | null): string {
+  if (days === null) return "never";
+  return days === 0 ? "today" : `${days} days ago`;
+}
+
+function PackHealthCard({ texturesDir, refreshKey }: PackHealthCardProps) {
+  const [health, setHealth] = useState<PackHealth | null>(null);
+
+  useEffect(() => {
+    if (!texturesDir) return;
+    invoke<PackHealth>("get_pack_health", { texturesDir })
+      .then(setHealth)
+      .catch((e) => console.error("Failed to load pack health:", e));
+  }, [texturesDir, refreshKey]);
+
+  if (!health) return null;
+
+  const problems: string[] = [];
+  if (!health.installed) problems.push("Not installed");
+  if (health.partial_install !== "none") problems.push("An installation was cut off");
+  if (health.sync_interrupted) problems.push("A sync was interrupted and can be resumed");
+  if (health.known_failures.length > 0) problems.push(`${health.known_failures.length} files failed to sync`);
+  if (health.verification.days_since !== null && !health.verification.all_good) {
+    problems.push("The last verification found problems");
+  }
+  if (health.local_changes) problems.push(`${health.local_changes.changed_files} files changed by other programs`);
+  if (health.verification.reverify_suggested) problems.push("Verification is due");
+
+  const installGb = health.disk_usage ? (health.disk_usage.install_bytes / 1024 / 1024 / 1024).toFixed(2) : null;
+
+  return (
+    <div className={`p-3 rounded-lg border text-sm space-y-1 ${LEVEL_STYLES[health.level]}`}>
+      <p className="font-medium">{LEVEL_LABELS[health.level]}</p>
+      <p className="text-xs text-zinc-400">
+        Synced {formatDays(health.days_since_sync)} · Verified {formatDays(health.verification.days_since)}
+        {health.disabled_count > 0 && ` · ${health.disabled_count} disabled`}
+        {installGb && ` · ${installGb} GB`}
+      </p>
+      {problems.length > 0 && (
+        <ul className="text-xs list-disc pl-5 space-y-0.5">
+          {problems.map((problem) => (
+            <li key={problem}>{problem}</li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
+
+export default PackHealthCard;
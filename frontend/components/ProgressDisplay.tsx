@@ -4,6 +4,9 @@ interface ProgressDisplayProps {
   messages: string[];
   percent: number | null;
   stage: string | null;
+  // Bytes downloaded so far and current speed (bytes/s), when the download reports them
+  bytesReceived?: number | null;
+  speed?: number | null;
   isComplete?: boolean;
 }
 
@@ -18,10 +21,22 @@ const STAGE_LABELS: Record<string, string> = {
   complete: "Installation complete!",
 };
 
+// Human-readable size in git's binary units
+function formatBytes(bytes: number): string {
+  const units = ["B", "KiB", "MiB", "GiB"];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit++;
+  }
+  return unit === 0 ? `${value} ${units[unit]}` : `${value.toFixed(1)} ${units[unit]}`;
+}
+
 // Stages that show indeterminate progress (no percentage available)
 const INDETERMINATE_STAGES = ["preparing", "moving", "cleanup"];
 
-function ProgressDisplay({ messages, percent, stage, isComplete }: ProgressDisplayProps) {
+function ProgressDisplay({ messages, percent, stage, bytesReceived, speed, isComplete }: ProgressDisplayProps) {
   const messagesEndRef = useRef<HTMLDivElement>(null);
   const [elapsedTime, setElapsedTime] = useState(0);
   const startTimeRef = useRef<number>(Date.now());
@@ -131,6 +146,13 @@ function ProgressDisplay({ messages, percent, stage, isComplete }: ProgressDispl
             />
           )}
         </div>
+        {/* Transfer indicator */}
+        {bytesReceived != null && !isComplete && (
+          <div className="flex justify-between text-xs text-zinc-500">
+            <span>Received: {formatBytes(bytesReceived)}</span>
+            {speed != null && <span>{formatBytes(speed)}/s</span>}
+          </div>
+        )}
       </div>
 
       {/* Terminal-style output */}
@@ -0,0 +1,112 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface ResetReport {
+  removed: string[];
+  failed: string[];
+  pack_removed: boolean;
+}
+
+interface ResetAppPanelProps {
+  disabled?: boolean;
+}
+
+function ResetAppPanel({ disabled }: ResetAppPanelProps) {
+  const [expanded, setExpanded] = useState(false);
+  const [confirmToken, setConfirmToken] = useState("");
+  const [keepInstalledPack, setKeepInstalledPack] = useState(true);
+  const [isRunning, setIsRunning] = useState(false);
+  const [report, setReport] = useState<ResetReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  const runReset = async () => {
+    setIsRunning(true);
+    setError(null);
+    try {
+      const result = await invoke<ResetReport>("reset_app", { confirmToken, keepInstalledPack });
+      if (result.failed.length === 0) {
+        // Start over with the default state
+        window.location.reload();
+        return;
+      }
+      setReport(result);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsRunning(false);
+    }
+  };
+
+  if (!expanded) {
+    return (
+      <button
+        onClick={() => setExpanded(true)}
+        className="text-xs text-zinc-500 hover:text-zinc-300 underline"
+      >
+        Reset app...
+      </button>
+    );
+  }
+
+  return (
+    <div className="bg-zinc-900 border border-red-900 rounded-lg p-4 space-y-2">
+      <span className="text-sm text-zinc-200">Reset app</span>
+      <p className="text-xs text-zinc-500">
+        Clears all settings, the saved GitHub token, the download cache, sync reports and logs, as if the app was just
+        installed.
+      </p>
+      <label className="flex items-center gap-2 text-xs text-zinc-400 cursor-pointer">
+        <input
+          type="checkbox"
+          checked={keepInstalledPack}
+          onChange={(e) => setKeepInstalledPack(e.target.checked)}
+          className="w-3 h-3 rounded bg-zinc-700 border-zinc-600"
+        />
+        Keep the installed textures
+      </label>
+      <div className="flex items-center gap-2">
+        <input
+          type="text"
+          value={confirmToken}
+          onChange={(e) => setConfirmToken(e.target.value)}
+          placeholder="Type RESET to confirm"
+          className="flex-1 px-2 py-1 text-xs bg-zinc-800 border border-zinc-600 rounded text-zinc-200"
+        />
+        <button
+          onClick={runReset}
+          disabled={confirmToken.trim() !== "RESET" || isRunning || disabled}
+          className="px-3 py-1 bg-red-700 hover:bg-red-600 disabled:bg-zinc-700 disabled:text-zinc-500 text-white rounded text-xs"
+        >
+          {isRunning ? "Resetting..." : "Reset"}
+        </button>
+        <button
+          onClick={() => setExpanded(false)}
+          disabled={isRunning}
+          className="text-xs text-zinc-500 hover:text-zinc-300 underline"
+        >
+          Cancel
+        </button>
+      </div>
+
+      {report && (
+        <div className="text-xs text-yellow-300">
+          <p>Reset done, but {report.failed.length} item(s) could not be removed (close any program using them):</p>
+          <ul className="mt-1 max-h-32 overflow-y-auto font-mono text-zinc-500 space-y-0.5">
+            {report.failed.map((failure) => (
+              <li key={failure} className="truncate">{failure}</li>
+            ))}
+          </ul>
+          <button
+            onClick={() => window.location.reload()}
+            className="mt-1 text-blue-400 hover:text-blue-300 underline"
+          >
+            Restart now
+          </button>
+        </div>
+      )}
+      {error && <p className="text-xs text-red-400">{error}</p>}
+    </div>
+  );
+}
+
+export default ResetAppPanel;
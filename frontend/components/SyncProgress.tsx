@@ -1,20 +1,56 @@
 import { useEffect, useRef, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { MessageParams, formatProgressMessage } from "../progressMessages";
+import FileDoneList from "./FileDoneList";
 
 interface SyncProgressPayload {
   stage: string;
+  key: string;
+  params: MessageParams;
   message: string;
   current: number | null;
   total: number | null;
+  /** Progress across all phases of the session (sync, cleanup, verification) */
+  overall_percent?: number;
+  /** Operation the event belongs to, and its order among all progress events */
+  operation_id?: number;
+  seq?: number;
+}
+
+interface HeartbeatPayload {
+  operation_id: number;
+  stage: string;
+  elapsed_secs: number;
+}
+
+interface DownloadProgressPayload {
+  operation_id: number;
+  path: string;
+  received_bytes: number;
+  total_bytes: number;
+}
+
+export interface SyncLogEntry {
+  level: "info" | "warn" | "error";
+  category: string;
+  path: string | null;
+  detail: string;
+  timestamp: string;
 }
 
 interface SyncProgressProps {
   messages: SyncProgressPayload[];
+  logEntries?: SyncLogEntry[];
   isComplete: boolean;
   result?: {
     files_downloaded: number;
     files_deleted: number;
     files_renamed: number;
     files_skipped: number;
+    failed_files?: { path: string }[];
+    actions?: { path: string; action: string; outcome: string }[];
+    actions_report_path?: string | null;
+    cleanup?: { removed_dirs: string[]; removed_files: string[] } | null;
   } | null;
 }
 
@@ -30,16 +66,52 @@ const STAGE_LABELS: Record<string, string> = {
   complete: "Sync complete!",
 };
 
-function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
+function SyncProgress({ messages, logEntries = [], isComplete, result }: SyncProgressProps) {
   const messagesEndRef = useRef<HTMLDivElement>(null);
+  const [showLog, setShowLog] = useState(false);
   const [elapsedTime, setElapsedTime] = useState(0);
   const startTimeRef = useRef<number>(Date.now());
 
-  // Auto-scroll to bottom
+  const [waitingSecs, setWaitingSecs] = useState<number | null>(null);
+  const [largeDownloads, setLargeDownloads] = useState<Record<string, DownloadProgressPayload>>({});
+
+  // Auto-scroll to bottom; a new message also ends the wait the last heartbeat reported
   useEffect(() => {
     messagesEndRef.current?.scrollIntoView({ behavior: "smooth" });
+    setWaitingSecs(null);
   }, [messages]);
 
+  // Heartbeats arrive while a long step (e.g. fetching the file list) reports nothing itself
+  useEffect(() => {
+    const unlisten = listen<HeartbeatPayload>("progress-heartbeat", (event) => {
+      setWaitingSecs(event.payload.elapsed_secs);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  // Bytes received of large files, which would otherwise sit on "Downloading: path" for minutes
+  useEffect(() => {
+    const unlisten = listen<DownloadProgressPayload>("download-progress", (event) => {
+      const download = event.payload;
+      setLargeDownloads((downloads) => {
+        const next = { ...downloads };
+        if (download.received_bytes >= download.total_bytes) {
+          delete next[download.path];
+        } else {
+          next[download.path] = download;
+        }
+        return next;
+      });
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Track elapsed time
   useEffect(() => {
     if (isComplete) return;
@@ -61,10 +133,10 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
   const currentStage = lastMessage?.stage || "fetching";
   const stageLabel = STAGE_LABELS[currentStage] || currentStage;
 
-  // Calculate progress percentage if we have current/total
-  const progress = lastMessage?.current && lastMessage?.total
-    ? Math.round((lastMessage.current / lastMessage.total) * 100)
-    : null;
+  const problemCount = logEntries.filter((entry) => entry.level !== "info").length;
+
+  // One bar for the whole session, weighted by phase in the backend
+  const progress = isComplete ? 100 : lastMessage?.overall_percent ?? null;
 
   return (
     <div className="mt-6 space-y-3">
@@ -101,17 +173,20 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
           <span className={`text-sm font-medium ${isComplete ? "text-green-400" : "text-zinc-200"}`}>
             {stageLabel}
           </span>
+          {!isComplete && waitingSecs !== null && (
+            <span className="text-xs text-zinc-500">still working ({formatTime(waitingSecs)})</span>
+          )}
         </div>
         <span className="text-xs text-zinc-500">
           Elapsed: {formatTime(elapsedTime)}
         </span>
       </div>
 
-      {/* Progress bar (only show when syncing/downloading/deleting/verifying) */}
-      {(currentStage === "syncing" || currentStage === "downloading" || currentStage === "deleting" || currentStage === "verifying") && lastMessage?.total && (
+      {/* Overall progress bar, with the file count of the current phase when it has one */}
+      {progress !== null && (
         <div className="space-y-1">
           <div className="flex justify-between text-xs text-zinc-400">
-            <span>{lastMessage.current} / {lastMessage.total} files</span>
+            <span>{!isComplete && lastMessage?.total ? `${lastMessage.current} / ${lastMessage.total} files` : ""}</span>
             <span>{progress}%</span>
           </div>
           <div className="w-full h-2 bg-zinc-700 rounded-full overflow-hidden">
@@ -123,6 +198,28 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
         </div>
       )}
 
+      {/* Large files being downloaded */}
+      {!isComplete &&
+        Object.values(largeDownloads).map((download) => (
+          <div key={download.path} className="space-y-1">
+            <div className="flex justify-between gap-2 text-xs text-zinc-500">
+              <span className="truncate font-mono">{download.path}</span>
+              <span className="flex-shrink-0">
+                {(download.received_bytes / 1024 / 1024).toFixed(1)} / {(download.total_bytes / 1024 / 1024).toFixed(1)} MB
+              </span>
+            </div>
+            <div className="w-full h-1 bg-zinc-700 rounded-full overflow-hidden">
+              <div
+                className="h-full bg-blue-400 transition-all duration-300"
+                style={{ width: `${(download.received_bytes / download.total_bytes) * 100}%` }}
+              />
+            </div>
+          </div>
+        ))}
+
+      {/* Files finished so far */}
+      <FileDoneList />
+
       {/* Output log */}
       <div className="bg-zinc-950 border border-zinc-700 rounded-lg p-3 max-h-48 overflow-y-auto font-mono text-xs">
         <div className="text-zinc-400 mb-2 pb-2 border-b border-zinc-800">
@@ -143,7 +240,7 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
                     : "text-zinc-300"
                 }`}
               >
-                {msg.message}
+                {formatProgressMessage(msg.key, msg.params, msg.message)}
               </p>
             ))}
             <div ref={messagesEndRef} />
@@ -151,6 +248,37 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
         )}
       </div>
 
+      {/* Per-file operation log */}
+      {logEntries.length > 0 && (
+        <div className="text-xs">
+          <button
+            onClick={() => setShowLog(!showLog)}
+            className="text-zinc-400 hover:text-zinc-200"
+          >
+            {showLog ? "Hide" : "Show"} file log ({logEntries.length} entries
+            {problemCount > 0 && <span className="text-yellow-400">, {problemCount} warnings/errors</span>})
+          </button>
+          {showLog && (
+            <div className="mt-2 bg-zinc-950 border border-zinc-700 rounded-lg p-3 max-h-48 overflow-y-auto font-mono space-y-0.5">
+              {logEntries.map((entry, i) => (
+                <p
+                  key={i}
+                  className={
+                    entry.level === "error"
+                      ? "text-red-400"
+                      : entry.level === "warn"
+                      ? "text-yellow-400"
+                      : "text-zinc-400"
+                  }
+                >
+                  [{entry.category}] {entry.path ? `${entry.path}: ` : ""}{entry.detail}
+                </p>
+              ))}
+            </div>
+          )}
+        </div>
+      )}
+
       {/* Completion summary */}
       {isComplete && result && (
         <div className="bg-green-900/30 border border-green-700 rounded-lg p-3 text-green-300 text-sm">
@@ -160,7 +288,41 @@ function SyncProgress({ messages, isComplete, result }: SyncProgressProps) {
             <li>Files deleted: {result.files_deleted}</li>
             {result.files_renamed > 0 && <li>Files renamed/moved: {result.files_renamed}</li>}
             {result.files_skipped > 0 && <li>Files skipped: {result.files_skipped}</li>}
+            {result.failed_files && result.failed_files.length > 0 && (
+              <li className="text-yellow-300">Files failed: {result.failed_files.length}</li>
+            )}
           </ul>
+          {result.cleanup && result.cleanup.removed_dirs.length + result.cleanup.removed_files.length > 0 && (
+            <details className="mt-2 text-xs">
+              <summary className="cursor-pointer">
+                Cleaned up {result.cleanup.removed_files.length} junk files and{" "}
+                {result.cleanup.removed_dirs.length} empty directories
+              </summary>
+              <ul className="mt-1 max-h-32 overflow-y-auto font-mono space-y-0.5">
+                {result.cleanup.removed_files.map((path) => (
+                  <li key={`file-${path}`}>{path}</li>
+                ))}
+                {result.cleanup.removed_dirs.map((path) => (
+                  <li key={`dir-${path}`}>{path}/</li>
+                ))}
+              </ul>
+            </details>
+          )}
+          {result.actions && result.actions.length > 0 && (
+            <details className="mt-2 text-xs">
+              <summary className="cursor-pointer">File details</summary>
+              <ul className="mt-1 max-h-32 overflow-y-auto font-mono space-y-0.5">
+                {result.actions.map((a, i) => (
+                  <li key={i} className={a.outcome === "failed" ? "text-red-400" : a.outcome === "skipped" ? "text-yellow-300" : ""}>
+                    [{a.action}] {a.path}
+                  </li>
+                ))}
+              </ul>
+              {result.actions_report_path && (
+                <p className="mt-1 text-zinc-400">Full report: {result.actions_report_path}</p>
+              )}
+            </details>
+          )}
         </div>
       )}
     </div>
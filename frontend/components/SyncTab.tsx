@@ -1,14 +1,37 @@
 import { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
-import SyncProgress from "./SyncProgress";
-import SyncWarningDialog from "./SyncWarningDialog";
+import SyncProgress, { SyncLogEntry } from "./SyncProgress";
+import SyncWarningDialog, { DiskDelta } from "./SyncWarningDialog";
+import WhatsNew, { UpdatedFolder } from "./WhatsNew";
+import DiskUsagePanel from "./DiskUsagePanel";
+import CleanupPanel from "./CleanupPanel";
+import TruncatedFilesPanel from "./TruncatedFilesPanel";
+import PackHealthCard from "./PackHealthCard";
+import { MessageParams } from "../progressMessages";
 
 interface SyncStatusResult {
   latest_commit_sha: string;
   latest_commit_date: string;
   last_sync_commit: string | null;
   has_changes: boolean;
+  commits_behind: number | null;
+  commits_behind_capped: boolean;
+  days_behind: number | null;
+  local_changes: LocalChanges | null;
+}
+
+interface SparsePathRelocation {
+  old_path: string;
+  new_path: string;
+  source: "alias" | "detected";
+  other_candidates: string[];
+}
+
+interface LocalChanges {
+  changed_files: number;
+  last_path: string;
+  first_detected: string;
 }
 
 interface SyncResult {
@@ -17,24 +40,101 @@ interface SyncResult {
   files_renamed: number;
   files_skipped: number;
   new_commit_sha: string;
+  failed_files: SyncFailure[];
+  actions: FileActionResult[];
+  actions_report_path: string | null;
+  cleanup: CleanupReport | null;
+  verification: PostSyncVerification | null;
+  retry_plan_token: string;
+}
+
+interface PostSyncVerification {
+  verification: {
+    files_to_download: unknown[];
+    orphaned_files: string[];
+    junk_files: string[];
+    has_discrepancies: boolean;
+  } | null;
+  fixes: VerificationFixResult | null;
+  error: string | null;
+}
+
+interface CleanupReport {
+  removed_dirs: string[];
+  removed_files: string[];
+  dry_run: boolean;
+}
+
+interface FileActionResult {
+  path: string;
+  action: "download" | "delete" | "rename" | "copy" | "skip";
+  outcome: "ok" | "failed" | "skipped";
+  error: string | null;
+}
+
+interface SyncFailure {
+  path: string;
+  action: "download" | "delete" | "rename";
+  to_disabled: boolean;
+  sha: string | null;
+  /** Old path of a failed rename */
+  from?: string | null;
+  error: string;
+}
+
+interface VerificationFixResult {
+  files_downloaded: number;
+  files_deleted: number;
+  failed_files: SyncFailure[];
 }
 
 interface SyncProgressPayload {
   stage: string;
+  key: string;
+  params: MessageParams;
   message: string;
   current: number | null;
   total: number | null;
+  /** Progress across all phases of the session (sync, cleanup, verification) */
+  overall_percent?: number;
+  /** Operation the event belongs to, and its order among all progress events */
+  operation_id?: number;
+  seq?: number;
 }
 
 interface QuickCheckResult {
   local_count: number;
   remote_count: number;
   counts_match: boolean;
+  folder_counts: FolderCount[];
+  spot_check_discrepancies: FolderSpotCheck[];
+  spot_check_sampled: number;
+}
+
+interface VerificationStatus {
+  last: { timestamp: string; mode: string; files_to_download: number; files_to_delete: number } | null;
+  days_since: number | null;
+  all_good: boolean;
+  reverify_suggested: boolean;
+}
+
+interface FolderCount {
+  folder: string;
+  local_count: number;
+  remote_count: number;
+  delta: number;
+}
+
+interface FolderSpotCheck {
+  folder: string;
+  sampled: number;
+  mismatched: string[];
 }
 
 interface SyncFile {
   path: string;
   to_disabled: boolean;
+  sha?: string | null;
 }
 
 interface SyncAnalysis {
@@ -42,6 +142,26 @@ interface SyncAnalysis {
   files_to_replace: SyncFile[];
   files_to_delete: string[];
   commit_sha: string;
+  folders: FolderChangeSummary[];
+  plan_token: string;
+}
+
+interface PendingSync {
+  textures_dir: string;
+  commit_sha: string;
+  files_to_download: SyncFile[];
+  files_to_delete: string[];
+  files_downloaded: number;
+  files_deleted: number;
+  started_at: string;
+}
+
+interface FolderChangeSummary {
+  folder: string;
+  downloads: number;
+  deletes: number;
+  download_bytes: number;
+  delete_bytes: number;
 }
 
 type SyncStatus = "idle" | "checking" | "syncing" | "complete" | "error";
@@ -73,6 +193,54 @@ function formatDate(isoDate: string | null): string {
   }
 }
 
+interface AutoSyncSettings {
+  enabled: boolean;
+  cooldown_hours: number;
+}
+
+interface AutoVerifySettings {
+  enabled: boolean;
+  auto_fix: boolean;
+  max_auto_fix_files: number;
+}
+
+interface FullSyncPolicy {
+  every_syncs: number | null;
+  every_days: number | null;
+}
+
+function PostSyncVerificationSummary({ result }: { result: PostSyncVerification }) {
+  const { verification, fixes, error } = result;
+  const fixesFailed = fixes ? fixes.failed_files.length : 0;
+  const allGood = !error && verification && (!verification.has_discrepancies || (fixes && fixesFailed === 0));
+  return (
+    <div className={`p-3 rounded text-sm ${
+      allGood
+        ? "bg-green-900/30 border border-green-800 text-green-300"
+        : "bg-yellow-900/30 border border-yellow-700 text-yellow-300"
+    }`}>
+      {!verification ? (
+        <p>Verification after the sync failed: {error}</p>
+      ) : !verification.has_discrepancies ? (
+        <p>All files verified against the repository</p>
+      ) : fixes ? (
+        <p>
+          Verification fixed {fixes.files_downloaded} files and removed {fixes.files_deleted}
+          {fixesFailed > 0 && `, ${fixesFailed} files still failed`}
+          {verification.orphaned_files.length > 0 &&
+            `. ${verification.orphaned_files.length} files not in the repository were left in place`}
+        </p>
+      ) : (
+        <p>
+          Verification found {verification.files_to_download.length} files to download and{" "}
+          {verification.orphaned_files.length + verification.junk_files.length} to delete.
+          {error ? ` Fixing them failed: ${error}` : " Run a full verification to review and fix them."}
+        </p>
+      )}
+    </div>
+  );
+}
+
 function SyncTab({
   texturesDir,
   lastSyncCommit,
@@ -85,6 +253,7 @@ function SyncTab({
   const [statusResult, setStatusResult] = useState<SyncStatusResult | null>(null);
   const [syncResult, setSyncResult] = useState<SyncResult | null>(null);
   const [progressMessages, setProgressMessages] = useState<SyncProgressPayload[]>([]);
+  const [logEntries, setLogEntries] = useState<SyncLogEntry[]>([]);
   const [errorMessage, setErrorMessage] = useState<string | null>(null);
   const [syncMode, setSyncMode] = useState<SyncMode>("incremental");
   const [tokenInput, setTokenInput] = useState(githubToken || "");
@@ -93,12 +262,31 @@ function SyncTab({
   const [tokenSectionExpanded, setTokenSectionExpanded] = useState(!githubToken);
   const [showTokenRequired, setShowTokenRequired] = useState(false);
   const [quickCheckResult, setQuickCheckResult] = useState<QuickCheckResult | null>(null);
+  const [relocation, setRelocation] = useState<SparsePathRelocation | null>(null);
   const [pendingAnalysis, setPendingAnalysis] = useState<SyncAnalysis | null>(null);
   const [showWarningDialog, setShowWarningDialog] = useState(false);
+  const [diskDelta, setDiskDelta] = useState<DiskDelta | null>(null);
+  const [pendingSync, setPendingSync] = useState<PendingSync | null>(null);
+  const [whatsNew, setWhatsNew] = useState<UpdatedFolder[]>([]);
+  const [transactionalSync, setTransactionalSync] = useState(false);
+  const [useTrash, setUseTrash] = useState(true);
+  const [autoSync, setAutoSync] = useState<AutoSyncSettings>({ enabled: false, cooldown_hours: 24 });
+  const [autoVerify, setAutoVerify] = useState<AutoVerifySettings>({
+    enabled: false,
+    auto_fix: false,
+    max_auto_fix_files: 100,
+  });
+  const [fullSyncPolicy, setFullSyncPolicy] = useState<FullSyncPolicy>({ every_syncs: null, every_days: null });
+  const [verificationStatus, setVerificationStatus] = useState<VerificationStatus | null>(null);
 
   // Listen for sync progress events
   useEffect(() => {
+    let lastSeq = 0;
     const unlisten = listen<SyncProgressPayload>("sync-progress", (event) => {
+      // Drop events that arrive after a newer one (e.g. from an operation that was retried)
+      const seq = event.payload.seq ?? 0;
+      if (seq !== 0 && seq <= lastSeq) return;
+      lastSeq = seq;
       setProgressMessages((prev) => [...prev, event.payload]);
 
       if (event.payload.stage === "complete") {
@@ -111,6 +299,17 @@ function SyncTab({
     };
   }, []);
 
+  // Listen for structured per-file log entries
+  useEffect(() => {
+    const unlisten = listen<SyncLogEntry>("sync-log", (event) => {
+      setLogEntries((prev) => [...prev, event.payload]);
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
   // Check status when tab is opened or texturesDir changes
   useEffect(() => {
     if (texturesDir) {
@@ -118,6 +317,122 @@ function SyncTab({
     }
   }, [texturesDir, githubToken]);
 
+  // Watch the install for changes made by other programs
+  useEffect(() => {
+    if (!texturesDir) return;
+
+    invoke("start_folder_watch", { texturesDir }).catch((e) =>
+      console.error("Failed to watch textures folder:", e)
+    );
+
+    const unlisten = listen<LocalChanges>("local-changes-detected", (event) => {
+      setStatusResult((prev) => (prev ? { ...prev, local_changes: event.payload } : prev));
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [texturesDir]);
+
+  // Check for a sync that was interrupted (crash or app closed mid-sync)
+  useEffect(() => {
+    invoke<PendingSync | null>("get_pending_sync")
+      .then(setPendingSync)
+      .catch((e) => console.error("Failed to check for interrupted sync:", e));
+  }, []);
+
+  // Load the all-or-nothing full sync and trash settings
+  useEffect(() => {
+    invoke<{ transactional_sync: boolean; delete_permanently: boolean }>("load_state")
+      .then((state) => {
+        setTransactionalSync(state.transactional_sync);
+        setUseTrash(!state.delete_permanently);
+      })
+      .catch((e) => console.error("Failed to load sync settings:", e));
+  }, []);
+
+  // Load when the pack was last verified (again after each sync, which runs a verification)
+  useEffect(() => {
+    if (syncStatus === "syncing") return;
+    invoke<VerificationStatus>("get_verification_status")
+      .then(setVerificationStatus)
+      .catch((e) => console.error("Failed to load verification status:", e));
+  }, [syncStatus, quickCheckResult]);
+
+  // Load the sync-on-launch setting
+  useEffect(() => {
+    invoke<AutoSyncSettings>("get_auto_sync_settings")
+      .then(setAutoSync)
+      .catch((e) => console.error("Failed to load auto-sync settings:", e));
+  }, []);
+
+  const handleAutoSyncChange = async (settings: AutoSyncSettings) => {
+    const previous = autoSync;
+    setAutoSync(settings);
+    try {
+      await invoke("set_auto_sync_settings", { settings });
+    } catch (e) {
+      setAutoSync(previous);
+      setErrorMessage(`Failed to save setting: ${e}`);
+    }
+  };
+
+  // Load the verify-after-sync setting
+  useEffect(() => {
+    invoke<AutoVerifySettings>("get_auto_verify_settings")
+      .then(setAutoVerify)
+      .catch((e) => console.error("Failed to load auto-verify settings:", e));
+  }, []);
+
+  const handleAutoVerifyChange = async (settings: AutoVerifySettings) => {
+    const previous = autoVerify;
+    setAutoVerify(settings);
+    try {
+      await invoke("set_auto_verify_settings", { settings });
+    } catch (e) {
+      setAutoVerify(previous);
+      setErrorMessage(`Failed to save setting: ${e}`);
+    }
+  };
+
+  // Load the periodic full sync policy
+  useEffect(() => {
+    invoke<FullSyncPolicy>("get_full_sync_policy")
+      .then(setFullSyncPolicy)
+      .catch((e) => console.error("Failed to load full sync policy:", e));
+  }, []);
+
+  const handleFullSyncPolicyChange = async (policy: FullSyncPolicy) => {
+    const previous = fullSyncPolicy;
+    setFullSyncPolicy(policy);
+    try {
+      await invoke("set_full_sync_policy", { policy });
+    } catch (e) {
+      setFullSyncPolicy(previous);
+      setErrorMessage(`Failed to save setting: ${e}`);
+    }
+  };
+
+  const handleUseTrashChange = async (enabled: boolean) => {
+    setUseTrash(enabled);
+    try {
+      await invoke("set_delete_permanently", { enabled: !enabled });
+    } catch (e) {
+      setUseTrash(!enabled);
+      setErrorMessage(`Failed to save setting: ${e}`);
+    }
+  };
+
+  const handleTransactionalSyncChange = async (enabled: boolean) => {
+    setTransactionalSync(enabled);
+    try {
+      await invoke("set_transactional_sync", { enabled });
+    } catch (e) {
+      setTransactionalSync(!enabled);
+      setErrorMessage(`Failed to save setting: ${e}`);
+    }
+  };
+
   // Update token input when prop changes
   useEffect(() => {
     setTokenInput(githubToken || "");
@@ -148,6 +463,29 @@ function SyncTab({
     }
   };
 
+  // A sync failing because the pack folder is gone from the repository: look for where it went
+  const reportSyncError = (e: unknown) => {
+    setErrorMessage(`Sync failed: ${e}`);
+    setSyncStatus("error");
+    if (String(e).includes("not found in repository")) {
+      invoke<SparsePathRelocation | null>("detect_sparse_path_relocation", { githubToken })
+        .then(setRelocation)
+        .catch((err) => setErrorMessage(`Sync failed: ${err}`));
+    }
+  };
+
+  const handleApplyRelocation = async (newPath: string) => {
+    try {
+      await invoke("apply_sparse_path_relocation", { newPath });
+      setRelocation(null);
+      setErrorMessage(null);
+      // An incremental sync across the move would download every file again
+      setSyncMode("full");
+    } catch (e) {
+      setErrorMessage(`Failed to update the pack location: ${e}`);
+    }
+  };
+
   const handleRunSync = async () => {
     // Check for GitHub token first
     if (!githubToken) {
@@ -159,7 +497,9 @@ function SyncTab({
 
     setSyncStatus("syncing");
     setProgressMessages([]);
+    setLogEntries([]);
     setSyncResult(null);
+    setWhatsNew([]);
     setQuickCheckResult(null);
     setErrorMessage(null);
     setShowOutput(true);
@@ -176,7 +516,17 @@ function SyncTab({
         if (analysis.files_to_replace.length > 0 || analysis.files_to_delete.length > 0) {
           // Show warning dialog and wait for confirmation
           setPendingAnalysis(analysis);
+          setDiskDelta(null);
           setShowWarningDialog(true);
+          invoke<DiskDelta>("preview_disk_delta", {
+            texturesDir,
+            filesToDownload: [...analysis.files_to_add, ...analysis.files_to_replace],
+            filesToDelete: analysis.files_to_delete,
+            commitSha: analysis.commit_sha,
+            githubToken,
+          })
+            .then(setDiskDelta)
+            .catch((e) => console.error("Failed to preview disk usage:", e));
           setSyncStatus("idle"); // Pause until user confirms
           return;
         }
@@ -195,8 +545,7 @@ function SyncTab({
         await finishSync(result);
       }
     } catch (e) {
-      setErrorMessage(`Sync failed: ${e}`);
-      setSyncStatus("error");
+      reportSyncError(e);
     }
   };
 
@@ -211,32 +560,108 @@ function SyncTab({
         filesToReplace: analysis.files_to_replace,
         filesToDelete: analysis.files_to_delete,
         commitSha: analysis.commit_sha,
+        planToken: analysis.plan_token,
         githubToken,
       });
 
       await finishSync(result);
     } catch (e) {
-      setErrorMessage(`Sync failed: ${e}`);
-      setSyncStatus("error");
+      reportSyncError(e);
+    }
+  };
+
+  const handleAcknowledgeLocalChanges = async () => {
+    try {
+      await invoke("acknowledge_local_changes");
+      setStatusResult((prev) => (prev ? { ...prev, local_changes: null } : prev));
+    } catch (e) {
+      console.error("Failed to acknowledge local changes:", e);
+    }
+  };
+
+  const handleResumeSync = async () => {
+    setSyncStatus("syncing");
+    setShowOutput(true);
+    setPendingSync(null);
+
+    try {
+      const result = await invoke<SyncResult>("resume_analyzed_sync", { githubToken });
+      await finishSync(result);
+    } catch (e) {
+      reportSyncError(e);
+    }
+  };
+
+  const handleDiscardPendingSync = async () => {
+    try {
+      await invoke("discard_pending_sync");
+      setPendingSync(null);
+    } catch (e) {
+      setErrorMessage(`Failed to discard interrupted sync: ${e}`);
     }
   };
 
   const finishSync = async (result: SyncResult) => {
-    // Run quick count check (fast, no SHA computation)
+    // Run quick count check (fast, no SHA computation), unless the sync already verified the pack
+    if (result.verification) {
+      setQuickCheckResult(null);
+    } else {
+      try {
+        const quickCheck = await invoke<QuickCheckResult>("run_quick_count_check", {
+          texturesDir,
+          githubToken,
+        });
+        setQuickCheckResult(quickCheck);
+      } catch (countError) {
+        console.error("Quick count check failed:", countError);
+      }
+    }
+
+    setSyncResult(result);
+    // Leave the last synced commit alone while files failed, so the next sync picks them up again
+    const synced = result.failed_files.length === 0;
+    if (synced) {
+      onSyncComplete(result.new_commit_sha);
+    }
+    setSyncStatus("complete");
+    await checkSyncStatus(synced ? result.new_commit_sha : undefined);
+
+    // Show which textures were just updated
+    if (result.files_downloaded > 0) {
+      invoke<UpdatedFolder[]>("get_whats_new", { githubToken })
+        .then(setWhatsNew)
+        .catch((e) => console.error("Failed to load what's new:", e));
+    }
+  };
+
+  const handleRetryFailed = async () => {
+    if (!syncResult || syncResult.failed_files.length === 0) return;
+    const failures = syncResult.failed_files;
+
+    setSyncStatus("syncing");
+    setErrorMessage(null);
+
     try {
-      const quickCheck = await invoke<QuickCheckResult>("run_quick_count_check", {
+      const result = await invoke<VerificationFixResult>("apply_verification_fixes", {
         texturesDir,
+        // A rename that still failed is fixed by downloading the file at its new path
+        filesToDownload: failures
+          .filter((f) => f.action === "download" || f.action === "rename")
+          .map((f) => ({ path: f.path, to_disabled: f.to_disabled, sha: f.sha })),
+        filesToDelete: failures.filter((f) => f.action === "delete").map((f) => f.path),
+        planToken: syncResult.retry_plan_token || null,
         githubToken,
       });
-      setQuickCheckResult(quickCheck);
-    } catch (countError) {
-      console.error("Quick count check failed:", countError);
+      // The token is used up; another retry of a short list doesn't need one
+      setSyncResult({ ...syncResult, failed_files: result.failed_files, retry_plan_token: "" });
+      if (result.failed_files.length === 0) {
+        onSyncComplete(syncResult.new_commit_sha);
+      }
+      setSyncStatus("complete");
+    } catch (e) {
+      setErrorMessage(`Retry failed: ${e}`);
+      setSyncStatus("error");
     }
-
-    setSyncResult(result);
-    onSyncComplete(result.new_commit_sha);
-    setSyncStatus("complete");
-    await checkSyncStatus(result.new_commit_sha);
   };
 
   const handleWarningConfirm = async () => {
@@ -253,7 +678,7 @@ function SyncTab({
     setSyncStatus("idle");
     setProgressMessages((prev) => [
       ...prev,
-      { stage: "cancelled", message: "Sync cancelled by user.", current: null, total: null },
+      { stage: "cancelled", key: "sync.cancelled", params: {}, message: "Sync cancelled by user.", current: null, total: null },
     ]);
   };
 
@@ -269,6 +694,9 @@ function SyncTab({
 
   return (
     <div className="space-y-4">
+      {/* Install, sync and verification status at a glance */}
+      <PackHealthCard texturesDir={texturesDir} refreshKey={syncStatus} />
+
       {/* GitHub API Token */}
       <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4">
         <button
@@ -333,6 +761,30 @@ function SyncTab({
         )}
       </div>
 
+      {/* Interrupted Sync */}
+      {pendingSync && !isSyncing && (
+        <div className="bg-yellow-900/30 border border-yellow-700 rounded-lg p-4 space-y-3">
+          <p className="text-sm text-yellow-300">
+            A previous sync was interrupted with {pendingSync.files_to_download.length} downloads and{" "}
+            {pendingSync.files_to_delete.length} deletions remaining.
+          </p>
+          <div className="flex gap-2">
+            <button
+              onClick={handleResumeSync}
+              className="px-3 py-2 bg-yellow-700 hover:bg-yellow-600 text-sm rounded transition-colors"
+            >
+              Resume Sync
+            </button>
+            <button
+              onClick={handleDiscardPendingSync}
+              className="px-3 py-2 bg-zinc-700 hover:bg-zinc-600 text-sm rounded transition-colors"
+            >
+              Discard
+            </button>
+          </div>
+        </div>
+      )}
+
       {/* Sync Status */}
       <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-3">
         <div className="flex items-center justify-between">
@@ -375,7 +827,23 @@ function SyncTab({
                   <svg className="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
                     <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z" />
                   </svg>
-                  <span>Updates available</span>
+                  <span>
+                    Updates available
+                    {statusResult.commits_behind !== null &&
+                      ` (${statusResult.commits_behind}${statusResult.commits_behind_capped ? "+" : ""} updates` +
+                        (statusResult.days_behind !== null ? `, ${statusResult.days_behind} days behind)` : ")")}
+                  </span>
+                </div>
+              )}
+              {statusResult.local_changes && (
+                <div className="flex items-center justify-between gap-2 mt-2 text-xs text-orange-400">
+                  <span>
+                    Local changes detected: {statusResult.local_changes.changed_files} files changed by another
+                    program (last: {statusResult.local_changes.last_path}). Run a full sync to restore them.
+                  </span>
+                  <button onClick={handleAcknowledgeLocalChanges} className="text-zinc-400 hover:text-zinc-300">
+                    Dismiss
+                  </button>
                 </div>
               )}
             </div>
@@ -428,6 +896,165 @@ function SyncTab({
               <p className="text-xs text-zinc-500">Compare all files against repository (slower)</p>
             </div>
           </label>
+          {syncMode === "full" && (
+            <label className="flex items-center gap-3 cursor-pointer pl-7">
+              <input
+                type="checkbox"
+                checked={transactionalSync}
+                onChange={(e) => handleTransactionalSyncChange(e.target.checked)}
+                disabled={isSyncing}
+                className="w-4 h-4 rounded bg-zinc-700 border-zinc-600"
+              />
+              <div>
+                <span className="text-sm text-zinc-200">All-or-nothing</span>
+                <p className="text-xs text-zinc-500">
+                  Download everything first and only then apply it, so a failed sync changes nothing
+                </p>
+              </div>
+            </label>
+          )}
+        </div>
+      </div>
+
+      {/* Deleted files */}
+      <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4">
+        <label className="flex items-center gap-3 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={useTrash}
+            onChange={(e) => handleUseTrashChange(e.target.checked)}
+            disabled={isSyncing}
+            className="w-4 h-4 rounded bg-zinc-700 border-zinc-600"
+          />
+          <div>
+            <span className="text-sm text-zinc-200">Move deleted files to the Recycle Bin/Trash</span>
+            <p className="text-xs text-zinc-500">Turn off to delete them permanently (faster for large syncs)</p>
+          </div>
+        </label>
+      </div>
+
+      {/* When the pack was last verified */}
+      {verificationStatus && (
+        <p className={`text-xs ${verificationStatus.reverify_suggested ? "text-yellow-400" : "text-zinc-500"}`}>
+          {verificationStatus.last === null || verificationStatus.days_since === null
+            ? "Never verified."
+            : `Last verified ${
+                verificationStatus.days_since === 0 ? "today" : `${verificationStatus.days_since} days ago`
+              } (${verificationStatus.last.mode}) \u2014 ${verificationStatus.all_good ? "all good" : "problems found"}.`}
+          {verificationStatus.reverify_suggested && " Consider running a full verification."}
+        </p>
+      )}
+
+      {/* Space used by the install, backups, cache and temp files */}
+      <DiskUsagePanel texturesDir={texturesDir} disabled={isSyncing} />
+
+      {/* Junk files and empty folders */}
+      <CleanupPanel texturesDir={texturesDir} disabled={isSyncing} />
+
+      {/* Empty and cut-off files from failed downloads */}
+      <TruncatedFilesPanel texturesDir={texturesDir} githubToken={githubToken} disabled={isSyncing} />
+
+      {/* Sync on launch */}
+      <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+        <label className="flex items-center gap-3 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={autoSync.enabled}
+            onChange={(e) => handleAutoSyncChange({ ...autoSync, enabled: e.target.checked })}
+            disabled={isSyncing}
+            className="w-4 h-4 rounded bg-zinc-700 border-zinc-600"
+          />
+          <span className="text-sm text-zinc-200">Download new content when the app opens</span>
+        </label>
+        {autoSync.enabled && (
+          <label className="flex items-center gap-2 pl-7 text-xs text-zinc-500">
+            At most once every
+            <input
+              type="number"
+              min={1}
+              value={autoSync.cooldown_hours}
+              onChange={(e) => {
+                const hours = parseInt(e.target.value, 10);
+                if (hours >= 1) handleAutoSyncChange({ ...autoSync, cooldown_hours: hours });
+              }}
+              className="w-16 px-2 py-1 bg-zinc-800 border border-zinc-600 rounded text-zinc-200"
+            />
+            hours
+          </label>
+        )}
+      </div>
+
+      {/* Verify after sync */}
+      <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+        <label className="flex items-center gap-3 cursor-pointer">
+          <input
+            type="checkbox"
+            checked={autoVerify.enabled}
+            onChange={(e) => handleAutoVerifyChange({ ...autoVerify, enabled: e.target.checked })}
+            disabled={isSyncing}
+            className="w-4 h-4 rounded bg-zinc-700 border-zinc-600"
+          />
+          <span className="text-sm text-zinc-200">Verify all files after every sync</span>
+        </label>
+        {autoVerify.enabled && (
+          <label className="flex items-center gap-2 pl-7 text-xs text-zinc-500">
+            <input
+              type="checkbox"
+              checked={autoVerify.auto_fix}
+              onChange={(e) => handleAutoVerifyChange({ ...autoVerify, auto_fix: e.target.checked })}
+              disabled={isSyncing}
+              className="w-3 h-3 rounded bg-zinc-700 border-zinc-600"
+            />
+            Fix problems automatically when there are at most
+            <input
+              type="number"
+              min={1}
+              value={autoVerify.max_auto_fix_files}
+              onChange={(e) => {
+                const files = parseInt(e.target.value, 10);
+                if (files >= 1) handleAutoVerifyChange({ ...autoVerify, max_auto_fix_files: files });
+              }}
+              disabled={isSyncing || !autoVerify.auto_fix}
+              className="w-20 px-2 py-1 bg-zinc-800 border border-zinc-600 rounded text-zinc-200"
+            />
+            files
+          </label>
+        )}
+      </div>
+
+      {/* Periodic full sync */}
+      <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+        <p className="text-sm text-zinc-200">Run a full sync instead of a quick one</p>
+        <p className="text-xs text-zinc-500">
+          Quick syncs only download what changed upstream. A full sync now and then also repairs files changed by hand.
+          Leave empty to never force one.
+        </p>
+        <div className="flex items-center gap-2 text-xs text-zinc-500">
+          Every
+          <input
+            type="number"
+            min={1}
+            value={fullSyncPolicy.every_syncs ?? ""}
+            onChange={(e) => {
+              const syncs = parseInt(e.target.value, 10);
+              handleFullSyncPolicyChange({ ...fullSyncPolicy, every_syncs: syncs >= 1 ? syncs : null });
+            }}
+            disabled={isSyncing}
+            className="w-16 px-2 py-1 bg-zinc-800 border border-zinc-600 rounded text-zinc-200"
+          />
+          syncs or every
+          <input
+            type="number"
+            min={1}
+            value={fullSyncPolicy.every_days ?? ""}
+            onChange={(e) => {
+              const days = parseInt(e.target.value, 10);
+              handleFullSyncPolicyChange({ ...fullSyncPolicy, every_days: days >= 1 ? days : null });
+            }}
+            disabled={isSyncing}
+            className="w-16 px-2 py-1 bg-zinc-800 border border-zinc-600 rounded text-zinc-200"
+          />
+          days
         </div>
       </div>
 
@@ -462,6 +1089,29 @@ function SyncTab({
         </div>
       )}
 
+      {/* The pack moved within the repository */}
+      {relocation && (
+        <div className="p-3 bg-yellow-900/30 border border-yellow-700 rounded text-yellow-200 text-sm space-y-2">
+          <p>
+            The texture pack moved in the repository from{" "}
+            <span className="font-mono">{relocation.old_path}</span> to{" "}
+            <span className="font-mono">{relocation.new_path}</span>
+            {relocation.source === "detected" && " (found by its folder name, please double-check)"}.
+            Your textures folder stays where it is.
+          </p>
+          {[relocation.new_path, ...relocation.other_candidates].map((path) => (
+            <button
+              key={path}
+              onClick={() => handleApplyRelocation(path)}
+              className="mr-2 px-3 py-1.5 bg-yellow-700 hover:bg-yellow-600 text-white text-xs rounded transition-colors"
+            >
+              Use {path}
+            </button>
+          ))}
+          <p className="text-xs text-yellow-300/80">A full sync is selected afterwards to bring your files in line.</p>
+        </div>
+      )}
+
       {/* Error message */}
       {errorMessage && (
         <div className="p-3 bg-red-900/30 border border-red-800 rounded text-red-300 text-sm">
@@ -473,11 +1123,40 @@ function SyncTab({
       {showOutput && progressMessages.length > 0 && (
         <SyncProgress
           messages={progressMessages}
+          logEntries={logEntries}
           isComplete={!isSyncing && syncResult !== null}
           result={syncResult}
         />
       )}
 
+      {/* Files that failed even after the automatic retry */}
+      {syncResult && syncResult.failed_files.length > 0 && !isSyncing && (
+        <div className="p-3 bg-yellow-900/30 border border-yellow-700 rounded text-yellow-300 text-sm space-y-2">
+          <p>{syncResult.failed_files.length} files could not be updated. The rest of the sync completed, and the next sync will try them again.</p>
+          <ul className="text-xs font-mono max-h-32 overflow-y-auto space-y-0.5">
+            {syncResult.failed_files.map((f) => (
+              <li key={`${f.action}:${f.path}`}>
+                [{f.action}] {f.path}: {f.error}
+              </li>
+            ))}
+          </ul>
+          <button
+            onClick={handleRetryFailed}
+            className="px-3 py-1 bg-yellow-700 hover:bg-yellow-600 text-white rounded text-xs"
+          >
+            Retry failed files
+          </button>
+        </div>
+      )}
+
+      {/* Verification run at the end of the sync */}
+      {syncResult?.verification && !isSyncing && (
+        <PostSyncVerificationSummary result={syncResult.verification} />
+      )}
+
+      {/* Recently updated textures */}
+      {syncResult && !isSyncing && <WhatsNew folders={whatsNew} />}
+
       {/* Quick count check result - show when we have a result and sync is done (status is complete or idle after sync) */}
       {quickCheckResult && syncResult && (syncStatus === "complete" || syncStatus === "idle") && (
         <div className={`p-3 rounded text-sm ${
@@ -504,6 +1183,18 @@ function SyncTab({
                 Local: {quickCheckResult.local_count} files, Repository: {quickCheckResult.remote_count} files.
                 Run "Full Sync" to fix discrepancies.
               </p>
+              {quickCheckResult.folder_counts.some((f) => f.delta !== 0) && (
+                <ul className="text-xs ml-6 mt-1 font-mono space-y-0.5">
+                  {quickCheckResult.folder_counts
+                    .filter((f) => f.delta !== 0)
+                    .map((f) => (
+                      <li key={f.folder}>
+                        {f.folder}: {f.local_count} local, {f.remote_count} in repository (
+                        {f.delta > 0 ? `${f.delta} extra` : `${-f.delta} missing`})
+                      </li>
+                    ))}
+                </ul>
+              )}
             </div>
           )}
         </div>
@@ -525,6 +1216,10 @@ function SyncTab({
         <SyncWarningDialog
           filesToReplace={pendingAnalysis.files_to_replace}
           filesToDelete={pendingAnalysis.files_to_delete}
+          texturesDir={texturesDir}
+          githubToken={githubToken}
+          downloadBytes={pendingAnalysis.folders.reduce((sum, f) => sum + f.download_bytes, 0)}
+          diskDelta={diskDelta}
           onConfirm={handleWarningConfirm}
           onCancel={handleWarningCancel}
         />
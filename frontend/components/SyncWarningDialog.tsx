@@ -1,23 +1,84 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
 interface SyncFile {
   path: string;
   to_disabled: boolean;
 }
 
+export interface DiskDelta {
+  bytes_added: number;
+  bytes_removed: number;
+  net_bytes: number;
+  unknown_sizes: number;
+}
+
+interface SpeedTestResult {
+  bytes_per_second: number;
+  estimated_seconds: number;
+}
+
 interface SyncWarningDialogProps {
   filesToReplace: SyncFile[];
   filesToDelete: string[];
+  texturesDir: string;
+  githubToken: string | null;
+  /** Total bytes the sync will download */
+  downloadBytes: number;
+  /** Disk space the sync adds and frees, null while it's being computed */
+  diskDelta: DiskDelta | null;
   onConfirm: () => void;
   onCancel: () => void;
 }
 
+function formatMegabytes(bytes: number): string {
+  return `${(bytes / 1024 / 1024).toFixed(1)} MB`;
+}
+
+function formatDuration(seconds: number): string {
+  if (seconds < 60) return `${seconds} seconds`;
+  const minutes = Math.round(seconds / 60);
+  if (minutes < 60) return `${minutes} minutes`;
+  return `${Math.floor(minutes / 60)} h ${minutes % 60} min`;
+}
+
 function SyncWarningDialog({
   filesToReplace,
   filesToDelete,
+  texturesDir,
+  githubToken,
+  downloadBytes,
+  diskDelta,
   onConfirm,
   onCancel,
 }: SyncWarningDialogProps) {
   const hasReplacements = filesToReplace.length > 0;
   const hasDeletions = filesToDelete.length > 0;
+  const [speedTest, setSpeedTest] = useState<SpeedTestResult | null>(null);
+  const [speedTestState, setSpeedTestState] = useState<"idle" | "running" | "failed">("idle");
+
+  // Show a local file in Explorer/Finder, e.g. to copy it to user-customs first
+  const handleReveal = (path: string) => {
+    invoke("reveal_in_file_manager", { path, texturesDir }).catch((e) =>
+      console.error("Failed to reveal file:", e)
+    );
+  };
+
+  const handleMeasureSpeed = async () => {
+    setSpeedTestState("running");
+    try {
+      const result = await invoke<SpeedTestResult>("measure_download_speed", {
+        texturesDir,
+        pendingBytes: downloadBytes,
+        githubToken,
+      });
+      setSpeedTest(result);
+      setSpeedTestState("idle");
+    } catch (e) {
+      console.error("Speed test failed:", e);
+      setSpeedTestState("failed");
+    }
+  };
 
   return (
     <div className="fixed inset-0 bg-black/70 flex items-center justify-center z-50 p-4">
@@ -51,7 +112,13 @@ function SyncWarningDialog({
                 <ul className="text-xs text-zinc-300 font-mono space-y-0.5">
                   {filesToReplace.map((file) => (
                     <li key={file.path} className="truncate">
-                      {file.to_disabled ? `-${file.path}` : file.path}
+                      <button
+                        onClick={() => handleReveal(file.path)}
+                        title="Show in file manager"
+                        className="hover:text-blue-400 hover:underline text-left"
+                      >
+                        {file.to_disabled ? `-${file.path}` : file.path}
+                      </button>
                     </li>
                   ))}
                 </ul>
@@ -75,7 +142,15 @@ function SyncWarningDialog({
               <div className="bg-zinc-900 border border-zinc-700 rounded p-2 max-h-60 overflow-y-auto">
                 <ul className="text-xs text-zinc-300 font-mono space-y-0.5">
                   {filesToDelete.map((path) => (
-                    <li key={path} className="truncate">{path}</li>
+                    <li key={path} className="truncate">
+                      <button
+                        onClick={() => handleReveal(path)}
+                        title="Show in file manager"
+                        className="hover:text-blue-400 hover:underline text-left"
+                      >
+                        {path}
+                      </button>
+                    </li>
                   ))}
                 </ul>
               </div>
@@ -83,6 +158,44 @@ function SyncWarningDialog({
           )}
         </div>
 
+        {/* Disk space change */}
+        {diskDelta && (
+          <div className="px-4 py-2 border-t border-zinc-700 text-sm text-zinc-400 flex items-center gap-3">
+            <span>Disk space:</span>
+            <span className="text-green-400">+{formatMegabytes(diskDelta.bytes_added)}</span>
+            <span className="text-red-400">-{formatMegabytes(diskDelta.bytes_removed)}</span>
+            <span className="text-zinc-200">
+              net {diskDelta.net_bytes >= 0 ? "+" : "-"}
+              {formatMegabytes(Math.abs(diskDelta.net_bytes))}
+            </span>
+            {diskDelta.unknown_sizes > 0 && (
+              <span className="text-zinc-500">({diskDelta.unknown_sizes} file(s) of unknown size)</span>
+            )}
+          </div>
+        )}
+
+        {/* Download time estimate */}
+        {downloadBytes > 0 && (
+          <div className="px-4 py-2 border-t border-zinc-700 text-sm text-zinc-400 flex items-center gap-3">
+            <span>{(downloadBytes / 1024 / 1024).toFixed(1)} MB to download.</span>
+            {speedTest ? (
+              <span>
+                About {formatDuration(speedTest.estimated_seconds)} at{" "}
+                {(speedTest.bytes_per_second / 1024 / 1024).toFixed(1)} MB/s
+              </span>
+            ) : (
+              <button
+                onClick={handleMeasureSpeed}
+                disabled={speedTestState === "running"}
+                className="text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+              >
+                {speedTestState === "running" ? "Measuring..." : "Estimate download time"}
+              </button>
+            )}
+            {speedTestState === "failed" && <span className="text-yellow-400">Speed test failed</span>}
+          </div>
+        )}
+
         {/* Footer */}
         <div className="p-4 border-t border-zinc-700 flex justify-end gap-3">
           <button
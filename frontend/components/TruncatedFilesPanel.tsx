@@ -0,0 +1,121 @@
+import { useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+
+interface VerificationFile {
+  path: string;
+  to_disabled: boolean;
+  sha: string | null;
+  reason: string;
+}
+
+interface VerificationFixResult {
+  files_downloaded: number;
+  failed_files: { path: string; error: string }[];
+}
+
+interface TruncatedFilesPanelProps {
+  texturesDir: string;
+  githubToken: string | null;
+  disabled?: boolean;
+}
+
+function TruncatedFilesPanel({ texturesDir, githubToken, disabled }: TruncatedFilesPanelProps) {
+  const [files, setFiles] = useState<VerificationFile[] | null>(null);
+  const [isBusy, setIsBusy] = useState(false);
+  const [repaired, setRepaired] = useState<number | null>(null);
+  const [failed, setFailed] = useState<{ path: string; error: string }[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  const scan = async () => {
+    setIsBusy(true);
+    setError(null);
+    setRepaired(null);
+    setFailed([]);
+    try {
+      setFiles(await invoke<VerificationFile[]>("scan_truncated_files", { texturesDir, githubToken }));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  const repair = async () => {
+    if (!files || files.length === 0) return;
+    setIsBusy(true);
+    setError(null);
+    try {
+      const result = await invoke<VerificationFixResult>("apply_verification_fixes", {
+        texturesDir,
+        filesToDownload: files,
+        filesToDelete: [],
+        planToken: null,
+        githubToken,
+      });
+      setRepaired(result.files_downloaded);
+      setFailed(result.failed_files);
+      setFiles([]);
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setIsBusy(false);
+    }
+  };
+
+  return (
+    <div className="bg-zinc-900 border border-zinc-700 rounded-lg p-4 space-y-2">
+      <div className="flex items-center justify-between">
+        <div>
+          <span className="text-sm text-zinc-200">Incomplete files</span>
+          <p className="text-xs text-zinc-500">Find empty or cut-off files left by failed downloads (fast, compares sizes)</p>
+        </div>
+        <div className="flex gap-3">
+          <button
+            onClick={scan}
+            disabled={!texturesDir || isBusy || disabled}
+            className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+          >
+            {isBusy && !files ? "Checking..." : "Check"}
+          </button>
+          {files && files.length > 0 && (
+            <button
+              onClick={repair}
+              disabled={isBusy || disabled}
+              className="text-xs text-blue-400 hover:text-blue-300 underline disabled:text-zinc-500"
+            >
+              {isBusy ? "Repairing..." : `Redownload ${files.length} file(s)`}
+            </button>
+          )}
+        </div>
+      </div>
+
+      {files && files.length === 0 && repaired === null && (
+        <p className="text-xs text-zinc-400">No empty or incomplete files found.</p>
+      )}
+      {repaired !== null && <p className="text-xs text-green-400">Redownloaded {repaired} file(s).</p>}
+      {failed.length > 0 && (
+        <ul className="max-h-32 overflow-y-auto font-mono text-xs text-red-400 space-y-0.5">
+          {failed.map((file) => (
+            <li key={file.path} className="truncate" title={file.error}>
+              {file.path}: {file.error}
+            </li>
+          ))}
+        </ul>
+      )}
+      {files && files.length > 0 && (
+        <ul className="max-h-32 overflow-y-auto font-mono text-xs text-zinc-500 space-y-0.5">
+          {files.map((file) => (
+            <li key={file.path} className="truncate">
+              {file.to_disabled && <span className="text-yellow-400">[-] </span>}
+              {file.path}
+            </li>
+          ))}
+        </ul>
+      )}
+
+      {error && <p className="text-xs text-red-400">{error}</p>}
+    </div>
+  );
+}
+
+export default TruncatedFilesPanel;
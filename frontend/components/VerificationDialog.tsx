@@ -1,24 +1,52 @@
+type DownloadReason = "missing" | "corrupted" | "disabled_outdated" | "truncated";
+
 interface VerificationFile {
   path: string;
   to_disabled: boolean;
+  reason: DownloadReason;
 }
 
 interface VerificationDialogProps {
   filesToDownload: VerificationFile[];
-  filesToDelete: string[];
+  orphanedFiles: string[];
+  junkFiles: string[];
   onConfirm: () => void;
   onCancel: () => void;
   isApplying: boolean;
 }
 
+const DOWNLOAD_SECTIONS: { reason: DownloadReason; title: string; explanation: string }[] = [
+  {
+    reason: "missing",
+    title: "Missing files",
+    explanation: "These files are in the repository but not in your folder. They will be downloaded.",
+  },
+  {
+    reason: "truncated",
+    title: "Empty or incomplete files",
+    explanation: "These files were cut short, usually by a download that failed. They will be downloaded again.",
+  },
+  {
+    reason: "corrupted",
+    title: "Corrupted or modified files",
+    explanation: "These files don't match the repository (damaged or edited). They will be replaced with the original.",
+  },
+  {
+    reason: "disabled_outdated",
+    title: "Outdated disabled files",
+    explanation: "You disabled these files, and the repository has since updated them. The new version will be downloaded and stay disabled.",
+  },
+];
+
 function VerificationDialog({
   filesToDownload,
-  filesToDelete,
+  orphanedFiles,
+  junkFiles,
   onConfirm,
   onCancel,
   isApplying,
 }: VerificationDialogProps) {
-  const totalChanges = filesToDownload.length + filesToDelete.length;
+  const totalChanges = filesToDownload.length + orphanedFiles.length + junkFiles.length;
 
   return (
     <div className="fixed inset-0 bg-black/70 flex items-center justify-center z-50 p-4">
@@ -34,40 +62,67 @@ function VerificationDialog({
         </div>
 
         <div className="flex-1 overflow-y-auto p-4 space-y-4">
-          {filesToDownload.length > 0 && (
+          {DOWNLOAD_SECTIONS.map(({ reason, title, explanation }) => {
+            const files = filesToDownload.filter((file) => file.reason === reason);
+            if (files.length === 0) return null;
+            return (
+              <div key={reason}>
+                <h4 className="text-sm font-medium text-blue-400 mb-1 flex items-center gap-2">
+                  <svg className="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
+                    <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-4l-4 4m0 0l-4-4m4 4V4" />
+                  </svg>
+                  {title} ({files.length})
+                </h4>
+                <p className="text-xs text-zinc-400 mb-2">{explanation}</p>
+                <div className="bg-zinc-900 border border-zinc-700 rounded p-2 max-h-40 overflow-y-auto">
+                  <ul className="text-xs text-zinc-300 space-y-0.5 font-mono">
+                    {files.map((file, i) => (
+                      <li key={i} className="truncate">
+                        {file.to_disabled ? (
+                          <span className="text-yellow-400">[-] </span>
+                        ) : null}
+                        {file.path}
+                      </li>
+                    ))}
+                  </ul>
+                </div>
+              </div>
+            );
+          })}
+
+          {orphanedFiles.length > 0 && (
             <div>
-              <h4 className="text-sm font-medium text-blue-400 mb-2 flex items-center gap-2">
+              <h4 className="text-sm font-medium text-red-400 mb-1 flex items-center gap-2">
                 <svg className="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
-                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-4l-4 4m0 0l-4-4m4 4V4" />
+                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16" />
                 </svg>
-                Files to download ({filesToDownload.length})
+                Files not in the repository ({orphanedFiles.length})
               </h4>
+              <p className="text-xs text-zinc-400 mb-2">
+                These were removed from the texture pack or added by hand. They will be deleted - move any
+                of your own textures to user-customs first.
+              </p>
               <div className="bg-zinc-900 border border-zinc-700 rounded p-2 max-h-40 overflow-y-auto">
                 <ul className="text-xs text-zinc-300 space-y-0.5 font-mono">
-                  {filesToDownload.map((file, i) => (
-                    <li key={i} className="truncate">
-                      {file.to_disabled ? (
-                        <span className="text-yellow-400">[-] </span>
-                      ) : null}
-                      {file.path}
-                    </li>
+                  {orphanedFiles.map((path, i) => (
+                    <li key={i} className="truncate">{path}</li>
                   ))}
                 </ul>
               </div>
             </div>
           )}
 
-          {filesToDelete.length > 0 && (
+          {junkFiles.length > 0 && (
             <div>
-              <h4 className="text-sm font-medium text-red-400 mb-2 flex items-center gap-2">
-                <svg className="h-4 w-4" fill="none" viewBox="0 0 24 24" stroke="currentColor">
-                  <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M19 7l-.867 12.142A2 2 0 0116.138 21H7.862a2 2 0 01-1.995-1.858L5 7m5 4v6m4-6v6m1-10V4a1 1 0 00-1-1h-4a1 1 0 00-1 1v3M4 7h16" />
-                </svg>
-                Files to delete ({filesToDelete.length})
+              <h4 className="text-sm font-medium text-zinc-400 mb-1">
+                Junk files ({junkFiles.length})
               </h4>
+              <p className="text-xs text-zinc-400 mb-2">
+                System files (like Thumbs.db) and leftovers from interrupted downloads. Safe to delete.
+              </p>
               <div className="bg-zinc-900 border border-zinc-700 rounded p-2 max-h-40 overflow-y-auto">
                 <ul className="text-xs text-zinc-300 space-y-0.5 font-mono">
-                  {filesToDelete.map((path, i) => (
+                  {junkFiles.map((path, i) => (
                     <li key={i} className="truncate">{path}</li>
                   ))}
                 </ul>
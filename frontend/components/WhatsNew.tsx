@@ -0,0 +1,55 @@
+export interface UpdatedTexture {
+  path: string;
+  status: "added" | "modified" | "renamed";
+  updated_at: string;
+}
+
+export interface UpdatedFolder {
+  folder: string;
+  updated_at: string;
+  textures: UpdatedTexture[];
+}
+
+interface WhatsNewProps {
+  folders: UpdatedFolder[];
+}
+
+function formatDay(isoDate: string): string {
+  const date = new Date(isoDate);
+  return isNaN(date.getTime())
+    ? isoDate
+    : date.toLocaleDateString(undefined, { year: "numeric", month: "short", day: "numeric" });
+}
+
+function WhatsNew({ folders }: WhatsNewProps) {
+  if (folders.length === 0) return null;
+
+  return (
+    <div className="p-3 bg-zinc-800/50 border border-zinc-700 rounded text-sm space-y-2">
+      <p className="font-medium text-zinc-200">What's new</p>
+      <ul className="max-h-48 overflow-y-auto space-y-1">
+        {folders.map((folder) => (
+          <li key={folder.folder}>
+            <details>
+              <summary className="cursor-pointer text-zinc-300">
+                {folder.folder || "(top level)"}{" "}
+                <span className="text-xs text-zinc-500">
+                  {folder.textures.length} updated, {formatDay(folder.updated_at)}
+                </span>
+              </summary>
+              <ul className="mt-1 ml-4 text-xs font-mono text-zinc-400 space-y-0.5">
+                {folder.textures.map((texture) => (
+                  <li key={texture.path}>
+                    [{texture.status}] {texture.path.slice(folder.folder.length).replace(/^\//, "")}
+                  </li>
+                ))}
+              </ul>
+            </details>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+export default WhatsNew;
@@ -0,0 +1,97 @@
+// Message templates for progress events, keyed by the backend's stable message keys
+// To translate the app, provide templates for another locale with the same keys and {param} placeholders
+
+export type MessageParams = Record<string, string>;
+
+const MESSAGES_EN: Record<string, string> = {
+  // Install
+  "backup.copying": "Copying backup: {files} of {total} files",
+  "backup.compressing": "Compressing backup: {files} of {total} files",
+  "backup.complete": "Backed up existing folder to: {path}",
+  "size.counting": "Counting files: {files} so far",
+  "install.preparing": "Preparing installation...",
+  "install.temp_resume": "Resuming the interrupted download...",
+  "install.temp_repaired": "Repaired the interrupted download (removed {files}), resuming...",
+  "install.temp_wipe": "Discarding the interrupted download ({reason}), starting over...",
+  "install.initializing_repo": "Initializing repository...",
+  "install.starting_download": "Starting download of {path}...",
+  "install.git_output": "{line}",
+  "install.moving": "Moving {folder} to final location...",
+  "install.cleanup": "Cleaning up temporary files...",
+  "install.complete": "Installation complete! Textures installed to: {path}",
+  // LAN install
+  "lan.connecting": "Connecting to {peer}...",
+  "lan.copying_file": "Copying from peer: {path}",
+  "lan.complete": "Installation complete! Copied {count} files from {peer}",
+  // Sync
+  "sync.fetching_changes": "Fetching changes since last sync...",
+  "sync.up_to_date": "Already up to date!",
+  "sync.changes_found_count": "Found {count} changed files",
+  "sync.processing_file": "[{status}] {path}",
+  "sync.fetching_tree": "Fetching repository tree (this may take a while)...",
+  "sync.remote_files_found": "Found {count} files in repository",
+  "sync.scanning_local": "Scanning local files (this may take a few minutes)...",
+  "sync.local_files_found": "Found {count} local files (excluding user-customs)...",
+  "sync.comparing_hashes": "Comparing file hashes...",
+  "sync.comparing_hashes_progress": "Comparing file hashes ({current}/{total}) {percent}%...",
+  "sync.changes_found": "Changes: {downloads} to download, {deletes} to delete",
+  "sync.downloading_file": "Downloading: {path}",
+  "sync.strategy_per_file": "Downloading {count} files ({mb} MB) one by one",
+  "sync.strategy_archive": "Downloading {count} files ({mb} MB) through a pack archive, faster than one by one",
+  "sync.downloading_archive": "Downloading pack archive: {mb} MB...",
+  "sync.extracting_archive": "Extracting {count} files from the pack archive...",
+  "sync.deleting_file": "Deleting: {path}",
+  "sync.starting": "Starting sync: {downloads} to download, {deletes} to delete",
+  "sync.staging": "Staging sync: {downloads} to download, {deletes} to delete. Nothing changes until every download is done",
+  "sync.swapping": "All files downloaded, applying changes...",
+  "sync.transaction_aborted": "{count} files failed to download. The sync was cancelled and no files were changed",
+  "sync.transaction_rolled_back": "{error}",
+  "sync.resuming": "Resuming interrupted sync ({downloaded} already downloaded, {deleted} already deleted)",
+  "sync.complete": "Sync complete! Downloaded: {downloaded}, Deleted: {deleted}",
+  "sync.complete_incremental":
+    "Sync complete! Downloaded: {downloaded}, Deleted: {deleted}, Renamed: {renamed}, Skipped: {skipped}",
+  "sync.complete_verifying":
+    "Sync complete! Downloaded: {downloaded}, Deleted: {deleted}, Renamed: {renamed}. Running verification...",
+  "sync.analysis_complete": "Analysis complete: {new} new, {replace} to replace, {delete} to delete",
+  "sync.fallback_commit_not_found": "Previous sync commit not found, running full sync...",
+  "sync.fallback_too_many_changes": "Too many changes since last sync (300+), running full sync...",
+  "sync.forced_full_sync_count":
+    "{count} incremental syncs since the last full sync, running a full sync to catch drift...",
+  "sync.forced_full_sync_days": "Last full sync was {days} days ago, running a full sync to catch drift...",
+  "sync.forced_full_sync_never": "No full sync recorded yet, running a full sync to catch drift...",
+  "sync.cancelled": "Sync cancelled by user.",
+  "sync.chaining_commits": "Too many changes for one comparison, fetching changes commit by commit...",
+  "sync.retrying_failed": "Retrying {count} failed files...",
+  // Verification
+  "verify.fetching_file_list": "Fetching repository file list...",
+  "verify.scanning_local": "Scanning local files and computing hashes (this may take a few minutes)...",
+  "verify.comparing": "Comparing {local} local files against {remote} repo files (this may take a few minutes)...",
+  "verify.discrepancies_found": "Found {downloads} files to download, {deletes} files to delete",
+  "verify.no_discrepancies": "Verification complete - no discrepancies found!",
+  "verify.checking_sizes": "Checking file sizes...",
+  "verify.truncated_found": "Found {count} empty or incomplete files",
+  "verify.downloading_files": "Downloading {count} files...",
+  "verify.downloading_file": "Downloading: {path}",
+  "verify.deleting_file": "Deleting: {path}",
+  "verify.fixes_applied": "Verification fixes applied! Downloaded: {downloaded}, Deleted: {deleted}",
+  // Cleanup
+  "cleanup.empty_dirs": "Cleaning up empty directories...",
+  "cleanup.empty_dirs_removed": "Removed {count} empty directories",
+  // Quick count check
+  "count.counting_local": "Counting local files...",
+  "count.fetching_remote": "Local: {local} files. Fetching remote count...",
+  "count.result": "Local: {local} files, Remote: {remote} files. Match: {match}",
+  "count.spot_checking": "Spot-checking {count} files in {folders} folders...",
+  // Manifest and dedup
+  "manifest.fetching_file_list": "Fetching file list for commit {commit}...",
+  "manifest.written": "Manifest written with {count} files",
+  "dedup.scanning": "Scanning {folders} folders for duplicate files...",
+  "dedup.hashing": "Hashing candidates ({current}/{total})...",
+};
+
+/// Format a progress message from its key and params, falling back to the backend's English text
+export function formatProgressMessage(key: string, params: MessageParams, fallback: string): string {
+  const template = MESSAGES_EN[key];
+  if (!template) return fallback;
+  return template.replace(/\{(\w+)\}/g, (match, name) => params[name] ?? match);
+}
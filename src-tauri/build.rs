@@ -1,3 +1,29 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
 fn main() {
+    // Embed the git commit of the build so support can identify exact builds
+    let commit = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=GIT_COMMIT_HASH={}", commit);
+    println!("cargo:rerun-if-changed=../.git/HEAD");
+    // A new commit on the checked-out branch moves the ref HEAD points to, not HEAD itself
+    // (paths that don't exist are left out, cargo would rerun the script on every build)
+    let head_ref = fs::read_to_string("../.git/HEAD")
+        .ok()
+        .and_then(|head| head.strip_prefix("ref: ").map(|r| r.trim().to_string()));
+    let mut watched = vec!["../.git/packed-refs".to_string()];
+    watched.extend(head_ref.map(|r| format!("../.git/{}", r)));
+    for path in watched.iter().filter(|path| Path::new(path).exists()) {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
     tauri_build::build()
 }
@@ -1,7 +1,14 @@
-use crate::config::{REPO_NAME, REPO_OWNER};
-use reqwest::Client;
+use crate::commands::install::find_bundled_mingit;
+use crate::commands::network::{describe_request_error, http_client, TracedSend};
+use crate::commands::{load_state, with_state_mut};
+use crate::config::{resolve_repo_config, RepoConfig};
+use chrono::Utc;
+use semver::Version;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::process::Command;
 
 /// Custom deserializer that accepts both strings and numbers, converting to string
 fn string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
@@ -16,7 +23,23 @@ where
     }
 }
 
+/// Like string_or_number, but for optional fields (null/missing -> None)
+pub(crate) fn optional_string_or_number<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    match value {
+        Value::Null => Ok(None),
+        Value::String(s) => Ok(Some(s)),
+        Value::Number(n) => Ok(Some(n.to_string())),
+        _ => Err(serde::de::Error::custom("expected string or number")),
+    }
+}
+
 /// Installer data from the mod repository
+/// Unknown keys are ignored and new fields are optional, so the repo can add information
+/// without breaking older app versions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstallerData {
     /// Minimum required version of this download app
@@ -27,6 +50,24 @@ pub struct InstallerData {
     pub total_size: String,
     /// URL where users can download the latest version of the app
     pub downloader_app_url: String,
+    /// URL of the texture pack changelog
+    #[serde(default)]
+    pub changelog_url: Option<String>,
+    /// Announcement message to show to users
+    #[serde(default)]
+    pub announcement: Option<String>,
+    /// Recommended PCSX2 settings (setting name -> value)
+    #[serde(default)]
+    pub recommended_pcsx2_settings: Option<HashMap<String, Value>>,
+    /// Number of files in the texture pack
+    #[serde(default, deserialize_with = "optional_string_or_number")]
+    pub pack_file_count: Option<String>,
+    /// Extra servers hosting the repository's files (see RepoConfig::mirrors)
+    #[serde(default)]
+    pub download_mirrors: Vec<String>,
+    /// Places the pack folder may move to (see RepoConfig::sparse_path_aliases)
+    #[serde(default)]
+    pub sparse_path_aliases: Vec<String>,
 }
 
 /// Result of fetching installer data
@@ -34,73 +75,213 @@ pub struct InstallerData {
 pub struct InstallerDataResult {
     pub data: Option<InstallerData>,
     pub error: Option<String>,
+    /// True when the network was unavailable and data is the last cached payload
+    pub stale: bool,
+    /// When the data was fetched (ISO 8601 UTC)
+    pub fetched_at: Option<String>,
+}
+
+/// Last successfully fetched installer data (stored in state for offline use)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedInstallerData {
+    /// URL the data was fetched from (so cached data is only used for the same repository)
+    pub url: String,
+    pub data: InstallerData,
+    /// When the data was fetched (ISO 8601 UTC)
+    pub fetched_at: String,
+}
+
+/// App and platform information for diagnosing user environments
+#[derive(Debug, Clone, Serialize)]
+pub struct AppInfo {
+    /// App version from Cargo.toml/tauri.conf.json
+    pub version: String,
+    /// Git commit the app was built from
+    pub git_commit: String,
+    /// Operating system (e.g. "windows", "macos", "linux")
+    pub os: String,
+    /// CPU architecture (e.g. "x86_64", "aarch64")
+    pub arch: String,
+    /// Whether this is an ARM build
+    pub is_arm: bool,
+    /// Whether the bundled MinGit was found
+    pub bundled_git_present: bool,
+    /// Version reported by the bundled MinGit, if present
+    pub bundled_git_version: Option<String>,
 }
 
 /// Get the app version from Cargo.toml/tauri.conf.json
-#[tauri::command]
-pub fn get_app_version(app_handle: tauri::AppHandle) -> String {
+pub fn get_app_version(app_handle: &tauri::AppHandle) -> String {
     app_handle
         .package_info()
         .version
         .to_string()
 }
 
+/// Get the app version, build and platform details
+#[tauri::command]
+pub fn get_app_info(app_handle: tauri::AppHandle) -> AppInfo {
+    let bundled_git = find_bundled_mingit();
+    let bundled_git_version = bundled_git.as_ref().and_then(|path| {
+        Command::new(path)
+            .arg("--version")
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    });
+
+    AppInfo {
+        version: get_app_version(&app_handle),
+        git_commit: env!("GIT_COMMIT_HASH").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        is_arm: cfg!(target_arch = "aarch64"),
+        bundled_git_present: bundled_git.is_some(),
+        bundled_git_version,
+    }
+}
+
 /// Fetch installer-data.json from the mod repository
 #[tauri::command]
-pub async fn fetch_installer_data() -> InstallerDataResult {
-    let client = Client::new();
-    let url = format!(
-        "https://raw.githubusercontent.com/{}/{}/main/installer-data.json",
-        REPO_OWNER, REPO_NAME
-    );
-
-    match client
-        .get(&url)
-        .header("User-Agent", "PS2-Textures-Downloader")
-        .send()
-        .await
-    {
-        Ok(response) => {
-            if !response.status().is_success() {
-                return InstallerDataResult {
-                    data: None,
-                    error: Some(format!(
-                        "Failed to fetch installer data: HTTP {}",
-                        response.status()
-                    )),
-                };
+pub async fn fetch_installer_data(app_handle: tauri::AppHandle) -> InstallerDataResult {
+    let config = match resolve_repo_config(&app_handle) {
+        Ok(config) => config,
+        Err(e) => {
+            return InstallerDataResult {
+                data: None,
+                error: Some(e),
+                stale: false,
+                fetched_at: None,
             }
+        }
+    };
 
-            match response.json::<InstallerData>().await {
-                Ok(data) => InstallerDataResult {
-                    data: Some(data),
+    let url = config.raw_url("installer-data.json");
+
+    match download_installer_data(&url).await {
+        Ok(data) => {
+            let fetched_at = Utc::now().to_rfc3339();
+
+            // Cache the payload so the app can still start when offline
+            let _ = with_state_mut(&app_handle, |state| {
+                state.installer_data_cache = Some(CachedInstallerData {
+                    url,
+                    data: data.clone(),
+                    fetched_at: fetched_at.clone(),
+                });
+                Ok(())
+            });
+
+            InstallerDataResult {
+                data: Some(data),
+                error: None,
+                stale: false,
+                fetched_at: Some(fetched_at),
+            }
+        }
+        Err(InstallerDataError::Network(e)) => {
+            // Offline - fall back to the last successful payload for this repository
+            let cached = load_state(app_handle)
+                .ok()
+                .and_then(|state| state.installer_data_cache)
+                .filter(|cache| cache.url == url);
+
+            match cached {
+                Some(cache) => InstallerDataResult {
+                    data: Some(cache.data),
                     error: None,
+                    stale: true,
+                    fetched_at: Some(cache.fetched_at),
                 },
-                Err(e) => InstallerDataResult {
+                None => InstallerDataResult {
                     data: None,
-                    error: Some(format!("Failed to parse installer data: {}", e)),
+                    error: Some(format!("Network error: {}", e)),
+                    stale: false,
+                    fetched_at: None,
                 },
             }
         }
-        Err(e) => InstallerDataResult {
+        Err(InstallerDataError::Other(e)) => InstallerDataResult {
             data: None,
-            error: Some(format!("Network error: {}", e)),
+            error: Some(e),
+            stale: false,
+            fetched_at: None,
         },
     }
 }
 
+/// Error fetching installer data, distinguishing network failures (eligible for the cache fallback)
+enum InstallerDataError {
+    Network(String),
+    Other(String),
+}
+
+/// Download and parse installer-data.json
+async fn download_installer_data(url: &str) -> Result<InstallerData, InstallerDataError> {
+    let client = http_client();
+    let response = client
+        .get(url)
+        .header("User-Agent", "PS2-Textures-Downloader")
+        .send_traced()
+        .await
+        .map_err(|e| InstallerDataError::Network(describe_request_error(&e)))?;
+
+    if !response.status().is_success() {
+        return Err(InstallerDataError::Other(format!(
+            "Failed to fetch installer data: HTTP {}",
+            response.status()
+        )));
+    }
+
+    response
+        .json::<InstallerData>()
+        .await
+        .map_err(|e| InstallerDataError::Other(format!("Failed to parse installer data: {}", e)))
+}
+
+/// Parse a version string leniently
+/// Accepts a leading "v" and pads missing minor/patch components ("2.1" -> "2.1.0")
+fn parse_version(v: &str) -> Option<Version> {
+    let v = v.trim();
+    let v = v.strip_prefix(['v', 'V']).unwrap_or(v);
+
+    if let Ok(version) = Version::parse(v) {
+        return Some(version);
+    }
+
+    // Pad the numeric core, keeping any pre-release/build suffix
+    let core_end = v.find(['-', '+']).unwrap_or(v.len());
+    let (core, suffix) = v.split_at(core_end);
+    let mut parts: Vec<&str> = core.split('.').collect();
+    while parts.len() < 3 {
+        parts.push("0");
+    }
+    Version::parse(&format!("{}{}", parts.join("."), suffix)).ok()
+}
+
 /// Compare two semver version strings
+/// Pre-release versions sort before their release ("1.2.0-beta.1" < "1.2.0"), build metadata is ignored
 /// Returns: -1 if v1 < v2, 0 if equal, 1 if v1 > v2
 #[tauri::command]
 pub fn compare_versions(v1: String, v2: String) -> i32 {
-    let parse_version = |v: &str| -> Vec<u32> {
+    if let (Some(a), Some(b)) = (parse_version(&v1), parse_version(&v2)) {
+        return match a.cmp_precedence(&b) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        };
+    }
+
+    // Not valid semver - fall back to comparing the numeric components
+    let parse_numeric = |v: &str| -> Vec<u32> {
         v.split('.')
             .filter_map(|s| s.parse::<u32>().ok())
             .collect()
     };
 
-    let v1_parts = parse_version(&v1);
-    let v2_parts = parse_version(&v2);
+    let v1_parts = parse_numeric(&v1);
+    let v2_parts = parse_numeric(&v2);
 
     // Compare each part
     let max_len = v1_parts.len().max(v2_parts.len());
@@ -118,3 +299,204 @@ pub fn compare_versions(v1: String, v2: String) -> i32 {
 
     0 // Equal
 }
+
+/// Outcome of the app version compatibility check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VersionStatus {
+    /// App is up to date
+    Ok,
+    /// Compatibility could not be verified (e.g. installer data unavailable)
+    Warn,
+    /// App is below the minimum required version and must be updated
+    Block,
+}
+
+/// Result of check_version_compatibility
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionCheckResult {
+    pub status: VersionStatus,
+    /// True when the decision was based on cached installer data (offline)
+    pub stale: bool,
+    pub message: Option<String>,
+    pub app_version: String,
+    pub required_version: Option<String>,
+    pub installer_data: Option<InstallerData>,
+}
+
+/// Decide whether this app version may be used, based on the repo's installer data
+/// Combines get_app_version, fetch_installer_data and compare_versions so the gate lives in the backend
+#[tauri::command]
+pub async fn check_version_compatibility(app_handle: tauri::AppHandle) -> VersionCheckResult {
+    let app_version = get_app_version(&app_handle);
+    let installer = fetch_installer_data(app_handle).await;
+
+    let data = match installer.data {
+        Some(data) => data,
+        None => {
+            return VersionCheckResult {
+                status: VersionStatus::Warn,
+                stale: false,
+                message: installer.error,
+                app_version,
+                required_version: None,
+                installer_data: None,
+            }
+        }
+    };
+
+    let required_version = data.min_download_app_version.clone();
+    let (status, message) = if compare_versions(app_version.clone(), required_version.clone()) < 0 {
+        (
+            VersionStatus::Block,
+            Some(format!(
+                "This app version ({}) is no longer supported. Please update to version {} or newer.",
+                app_version, required_version
+            )),
+        )
+    } else {
+        (VersionStatus::Ok, None)
+    };
+
+    VersionCheckResult {
+        status,
+        stale: installer.stale,
+        message,
+        app_version,
+        required_version: Some(required_version),
+        installer_data: Some(data),
+    }
+}
+
+/// How long the cached contributor list is used before asking GitHub again
+const CONTRIBUTORS_CACHE_HOURS: i64 = 24;
+
+/// Maximum contributor pages fetched (100 contributors per page)
+const MAX_CONTRIBUTOR_PAGES: u32 = 5;
+
+/// A contributor to the texture repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contributor {
+    pub login: String,
+    pub avatar_url: String,
+    pub html_url: String,
+    /// Number of commits
+    pub contributions: u32,
+}
+
+/// Last fetched contributor list (stored in state to save rate limit)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedContributors {
+    /// "owner/name" of the repository the list belongs to
+    pub repo: String,
+    pub contributors: Vec<Contributor>,
+    /// When the list was fetched (ISO 8601 UTC)
+    pub fetched_at: String,
+}
+
+/// Result of fetching contributors
+#[derive(Debug, Clone, Serialize)]
+pub struct ContributorsResult {
+    pub contributors: Vec<Contributor>,
+    /// True when GitHub couldn't be reached and the list is an older cached copy
+    pub stale: bool,
+    /// When the list was fetched (ISO 8601 UTC)
+    pub fetched_at: String,
+}
+
+/// Download the contributor list of a repository, most active first
+async fn download_contributors(config: &RepoConfig, github_token: &Option<String>) -> Result<Vec<Contributor>, String> {
+    let client = http_client();
+    let mut contributors = Vec::new();
+
+    for page in 1..=MAX_CONTRIBUTOR_PAGES {
+        let url = format!("{}/contributors?per_page=100&page={}", config.api_repo_url(), page);
+        let mut request = client
+            .get(&url)
+            .header("User-Agent", "NCAA-NEXT-Textures-Downloader")
+            .header("Accept", "application/vnd.github.v3+json");
+        if let Some(token) = github_token {
+            request = request.header("Authorization", config.forge.authorization(token));
+        }
+
+        let response = request
+            .send_traced()
+            .await
+            .map_err(|e| format!("Failed to fetch contributors: {}", describe_request_error(&e)))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to fetch contributors: HTTP {}", response.status()));
+        }
+
+        let page_contributors: Vec<Contributor> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse contributors: {}", e))?;
+
+        let is_last_page = page_contributors.len() < 100;
+        contributors.extend(page_contributors);
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(contributors)
+}
+
+/// Fetch the texture repository's contributors for the credits screen
+/// Uses the cached list for a day unless force_refresh is set, and falls back to it when GitHub is unreachable
+#[tauri::command]
+pub async fn fetch_contributors(
+    app_handle: tauri::AppHandle,
+    github_token: Option<String>,
+    force_refresh: Option<bool>,
+) -> Result<ContributorsResult, String> {
+    let config = resolve_repo_config(&app_handle)?;
+    let repo = format!("{}/{}", config.repo_owner, config.repo_name);
+
+    let cached = load_state(app_handle.clone())
+        .ok()
+        .and_then(|state| state.contributors_cache)
+        .filter(|cache| cache.repo == repo);
+
+    if let Some(cache) = cached.as_ref().filter(|_| !force_refresh.unwrap_or(false)) {
+        let fresh = chrono::DateTime::parse_from_rfc3339(&cache.fetched_at)
+            .map(|fetched| Utc::now().signed_duration_since(fetched) < chrono::Duration::hours(CONTRIBUTORS_CACHE_HOURS))
+            .unwrap_or(false);
+        if fresh {
+            return Ok(ContributorsResult {
+                contributors: cache.contributors.clone(),
+                stale: false,
+                fetched_at: cache.fetched_at.clone(),
+            });
+        }
+    }
+
+    match download_contributors(&config, &github_token).await {
+        Ok(contributors) => {
+            let fetched_at = Utc::now().to_rfc3339();
+            let _ = with_state_mut(&app_handle, |state| {
+                state.contributors_cache = Some(CachedContributors {
+                    repo,
+                    contributors: contributors.clone(),
+                    fetched_at: fetched_at.clone(),
+                });
+                Ok(())
+            });
+
+            Ok(ContributorsResult {
+                contributors,
+                stale: false,
+                fetched_at,
+            })
+        }
+        Err(e) => match cached {
+            Some(cache) => Ok(ContributorsResult {
+                contributors: cache.contributors,
+                stale: true,
+                fetched_at: cache.fetched_at,
+            }),
+            None => Err(e),
+        },
+    }
+}
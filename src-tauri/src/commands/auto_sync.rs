@@ -0,0 +1,145 @@
+//! Sync on app launch, for users who just want "open the app, get the updates"
+
+use crate::commands::state::{get_github_token, load_state, update_last_sync_commit, with_state_mut};
+use crate::commands::sync::{check_sync_status, run_sync, SyncResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// Delay after launch before checking, so startup requests (version check, connectivity) go first
+const AUTO_SYNC_STARTUP_DELAY: Duration = Duration::from_secs(5);
+
+/// How long the user has to cancel an announced auto-sync
+const AUTO_SYNC_CANCEL_SECONDS: u64 = 15;
+
+/// Set by cancel_auto_sync while the cancel window is open
+static AUTO_SYNC_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Auto-sync preferences
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoSyncSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Minimum hours since the last sync before launching the app syncs again
+    #[serde(default = "default_cooldown_hours")]
+    pub cooldown_hours: u32,
+}
+
+fn default_cooldown_hours() -> u32 {
+    24
+}
+
+impl Default for AutoSyncSettings {
+    fn default() -> Self {
+        AutoSyncSettings {
+            enabled: false,
+            cooldown_hours: default_cooldown_hours(),
+        }
+    }
+}
+
+/// Announced before an auto-sync starts ("auto-sync-pending")
+#[derive(Clone, Serialize)]
+struct AutoSyncPending {
+    latest_commit_sha: String,
+    seconds: u64,
+}
+
+/// Outcome of an auto-sync ("auto-sync-finished")
+#[derive(Clone, Serialize)]
+struct AutoSyncFinished {
+    result: Option<SyncResult>,
+    error: Option<String>,
+}
+
+/// Whether the cooldown since the last sync has passed (no recorded sync counts as passed)
+fn cooldown_elapsed(last_sync_timestamp: Option<&str>, cooldown_hours: u32, now: DateTime<Utc>) -> bool {
+    let Some(last_sync) = last_sync_timestamp.and_then(|ts| DateTime::parse_from_rfc3339(ts).ok()) else {
+        return true;
+    };
+    now.signed_duration_since(last_sync) >= chrono::Duration::hours(cooldown_hours as i64)
+}
+
+/// Start the launch timer (called once from setup)
+pub fn start_auto_sync(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(AUTO_SYNC_STARTUP_DELAY).await;
+        if let Err(e) = run_auto_sync(&app).await {
+            let _ = app.emit("auto-sync-finished", AutoSyncFinished { result: None, error: Some(e) });
+        }
+    });
+}
+
+/// Check, announce and run the sync; Ok without syncing whenever there's nothing to do
+async fn run_auto_sync(app: &AppHandle) -> Result<(), String> {
+    let state = load_state(app.clone())?;
+    let settings = &state.auto_sync;
+    let Some(textures_dir) = state.textures_path.clone() else {
+        return Ok(());
+    };
+    if !settings.enabled || !state.initial_setup_done || !state.sync_disclaimer_acknowledged {
+        return Ok(());
+    }
+    if !cooldown_elapsed(state.last_sync_timestamp.as_deref(), settings.cooldown_hours, Utc::now()) {
+        return Ok(());
+    }
+    let github_token = get_github_token(app.clone())?;
+
+    let status = check_sync_status(
+        textures_dir.clone(),
+        state.last_sync_commit.clone(),
+        github_token.clone(),
+        app.clone(),
+    )
+    .await?;
+    if !status.has_changes {
+        return Ok(());
+    }
+
+    AUTO_SYNC_CANCELLED.store(false, Ordering::SeqCst);
+    let _ = app.emit("auto-sync-pending", AutoSyncPending {
+        latest_commit_sha: status.latest_commit_sha,
+        seconds: AUTO_SYNC_CANCEL_SECONDS,
+    });
+    for _ in 0..AUTO_SYNC_CANCEL_SECONDS * 4 {
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        if AUTO_SYNC_CANCELLED.load(Ordering::SeqCst) {
+            let _ = app.emit("auto-sync-finished", AutoSyncFinished { result: None, error: None });
+            return Ok(());
+        }
+    }
+
+    let window = app.get_window("main").ok_or("The main window is gone")?;
+    let result = run_sync(textures_dir, state.last_sync_commit, github_token, false, window).await?;
+    if result.failed_files.is_empty() {
+        update_last_sync_commit(app.clone(), result.new_commit_sha.clone())?;
+    }
+    let _ = app.emit("auto-sync-finished", AutoSyncFinished { result: Some(result), error: None });
+    Ok(())
+}
+
+/// Cancel an announced auto-sync (no effect once it started)
+#[tauri::command]
+pub fn cancel_auto_sync() {
+    AUTO_SYNC_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+/// Get the auto-sync preferences
+#[tauri::command]
+pub fn get_auto_sync_settings(app: AppHandle) -> Result<AutoSyncSettings, String> {
+    Ok(load_state(app)?.auto_sync)
+}
+
+/// Save the auto-sync preferences (take effect on the next launch)
+#[tauri::command]
+pub fn set_auto_sync_settings(app: AppHandle, settings: AutoSyncSettings) -> Result<(), String> {
+    if settings.cooldown_hours == 0 {
+        return Err("The cooldown must be at least 1 hour".to_string());
+    }
+    with_state_mut(&app, |state| {
+        state.auto_sync = settings;
+        Ok(())
+    })
+}
@@ -0,0 +1,96 @@
+//! Verification chained onto the end of a sync
+
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync::{apply_fixes, run_verification_scan, VerificationFixResult, VerificationResult};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Window};
+
+/// Verify-after-sync preferences
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoVerifySettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Apply the fixes right away when there are at most max_auto_fix_files of them
+    #[serde(default)]
+    pub auto_fix: bool,
+    #[serde(default = "default_max_auto_fix_files")]
+    pub max_auto_fix_files: usize,
+}
+
+fn default_max_auto_fix_files() -> usize {
+    100
+}
+
+impl Default for AutoVerifySettings {
+    fn default() -> Self {
+        AutoVerifySettings {
+            enabled: false,
+            auto_fix: false,
+            max_auto_fix_files: default_max_auto_fix_files(),
+        }
+    }
+}
+
+/// What the verification after a sync found and did
+#[derive(Debug, Clone, Serialize)]
+pub struct PostSyncVerification {
+    /// None if the verification failed (see error)
+    pub verification: Option<VerificationResult>,
+    /// Set when the fixes were applied automatically
+    pub fixes: Option<VerificationFixResult>,
+    /// Why the verification or its fixes failed (the sync itself succeeded)
+    pub error: Option<String>,
+}
+
+/// Verify the pack after a sync when that's enabled, applying the fixes when allowed
+/// None when verifying after syncs is off
+pub(crate) async fn verify_after_sync(
+    textures_dir: &str,
+    github_token: &Option<String>,
+    window: &Window,
+) -> Option<PostSyncVerification> {
+    let settings = load_state(window.app_handle().clone()).ok()?.auto_verify;
+    if !settings.enabled {
+        return None;
+    }
+
+    let verification =
+        match run_verification_scan(textures_dir.to_string(), github_token.clone(), window.clone()).await {
+            Ok(verification) => verification,
+            Err(e) => return Some(PostSyncVerification { verification: None, fixes: None, error: Some(e) }),
+        };
+
+    // Missing or changed files and junk are fixed; extra files may be the user's own
+    let fix_count = verification.files_to_download.len() + verification.junk_files.len();
+    if !settings.auto_fix || fix_count == 0 || fix_count > settings.max_auto_fix_files {
+        return Some(PostSyncVerification { verification: Some(verification), fixes: None, error: None });
+    }
+
+    let fixes = apply_fixes(
+        textures_dir.to_string(),
+        verification.files_to_download.clone(),
+        verification.junk_files.clone(),
+        github_token.clone(),
+        window.clone(),
+    )
+    .await;
+    Some(match fixes {
+        Ok(fixes) => PostSyncVerification { verification: Some(verification), fixes: Some(fixes), error: None },
+        Err(e) => PostSyncVerification { verification: Some(verification), fixes: None, error: Some(e) },
+    })
+}
+
+/// Get the verify-after-sync preferences
+#[tauri::command]
+pub fn get_auto_verify_settings(app: AppHandle) -> Result<AutoVerifySettings, String> {
+    Ok(load_state(app)?.auto_verify)
+}
+
+/// Save the verify-after-sync preferences
+#[tauri::command]
+pub fn set_auto_verify_settings(app: AppHandle, settings: AutoVerifySettings) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.auto_verify = settings;
+        Ok(())
+    })
+}
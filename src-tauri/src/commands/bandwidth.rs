@@ -0,0 +1,114 @@
+//! Download rate cap, so a sync doesn't saturate the household connection
+
+use crate::commands::state::{load_state, with_state_mut};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+
+/// Pacing shared by every download in the process
+struct Throttle {
+    /// 0 = unlimited
+    bytes_per_second: u64,
+    /// When the bytes read so far have "paid off" at the configured rate
+    next_slot: Option<Instant>,
+}
+
+static DOWNLOAD_THROTTLE: Mutex<Throttle> = Mutex::new(Throttle {
+    bytes_per_second: 0,
+    next_slot: None,
+});
+
+/// Most connections one file download may use
+pub const MAX_DOWNLOAD_CONNECTIONS: u64 = 8;
+
+/// Connections used for a very large file (1 = a single connection)
+static DOWNLOAD_CONNECTIONS: AtomicU64 = AtomicU64::new(1);
+
+fn set_throttle_rate(limit_kb_per_sec: Option<u64>) {
+    if let Ok(mut throttle) = DOWNLOAD_THROTTLE.lock() {
+        throttle.bytes_per_second = limit_kb_per_sec.unwrap_or(0) * 1024;
+        throttle.next_slot = None;
+    }
+}
+
+/// Load the rate cap and connection count from state (before a sync starts downloading)
+pub(crate) fn configure_download_throttle(app: &AppHandle) {
+    set_throttle_rate(load_download_rate_limit(app));
+    let connections = load_state(app.clone()).ok().and_then(|state| state.download_connections);
+    DOWNLOAD_CONNECTIONS.store(connections.unwrap_or(1), Ordering::Relaxed);
+}
+
+/// Connections a very large file may be downloaded over
+pub(crate) fn download_connections() -> u64 {
+    DOWNLOAD_CONNECTIONS.load(Ordering::Relaxed)
+}
+
+/// Download rate cap from state in KB/s (None = unlimited, also if the state can't be read)
+pub(crate) fn load_download_rate_limit(app: &AppHandle) -> Option<u64> {
+    load_state(app.clone())
+        .ok()
+        .and_then(|state| state.download_rate_limit_kb)
+}
+
+/// Wait until a chunk of `bytes` just read fits within the rate cap
+pub(crate) async fn throttle_download(bytes: usize) {
+    let wait = {
+        let Ok(mut throttle) = DOWNLOAD_THROTTLE.lock() else {
+            return;
+        };
+        if throttle.bytes_per_second == 0 {
+            return;
+        }
+
+        let now = Instant::now();
+        let start = throttle.next_slot.filter(|slot| *slot > now).unwrap_or(now);
+        let end = start + Duration::from_secs_f64(bytes as f64 / throttle.bytes_per_second as f64);
+        throttle.next_slot = Some(end);
+        end.saturating_duration_since(now)
+    };
+
+    if !wait.is_zero() {
+        tokio::time::sleep(wait).await;
+    }
+}
+
+/// Get the download rate cap in KB/s (None = unlimited)
+#[tauri::command]
+pub fn get_download_rate_limit(app: AppHandle) -> Result<Option<u64>, String> {
+    Ok(load_state(app)?.download_rate_limit_kb)
+}
+
+/// Set the download rate cap in KB/s (None or 0 = unlimited)
+/// Takes effect immediately, including for a sync that is already running
+#[tauri::command]
+pub fn set_download_rate_limit(app: AppHandle, limit_kb: Option<u64>) -> Result<(), String> {
+    let limit_kb = limit_kb.filter(|limit| *limit > 0);
+    with_state_mut(&app, |state| {
+        state.download_rate_limit_kb = limit_kb;
+        Ok(())
+    })?;
+    set_throttle_rate(limit_kb);
+    Ok(())
+}
+
+/// Get how many connections a very large file is downloaded over (None = one)
+#[tauri::command]
+pub fn get_download_connections(app: AppHandle) -> Result<Option<u64>, String> {
+    Ok(load_state(app)?.download_connections)
+}
+
+/// Set how many connections a very large file is downloaded over (None or 1 = one connection)
+/// Only used where the server supports byte ranges; capped at MAX_DOWNLOAD_CONNECTIONS
+#[tauri::command]
+pub fn set_download_connections(app: AppHandle, connections: Option<u64>) -> Result<(), String> {
+    let connections = connections
+        .filter(|connections| *connections > 1)
+        .map(|connections| connections.min(MAX_DOWNLOAD_CONNECTIONS));
+    with_state_mut(&app, |state| {
+        state.download_connections = connections;
+        Ok(())
+    })?;
+    DOWNLOAD_CONNECTIONS.store(connections.unwrap_or(1), Ordering::Relaxed);
+    Ok(())
+}
@@ -0,0 +1,287 @@
+//! Content-addressed cache of downloaded files, keyed by git blob SHA
+
+use crate::commands::file_index::FileIndex;
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync::{compute_sha_for_content, download_temp_path};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tauri::{AppHandle, Manager};
+
+/// Default cache size limit in MB
+pub const DEFAULT_CACHE_SIZE_LIMIT_MB: u64 = 2048;
+
+/// Handle to the blob cache directory
+pub struct BlobCache {
+    dir: PathBuf,
+    limit_bytes: u64,
+    /// Files already in the SLUS folder, by content (see with_local_copies)
+    local_copies: HashMap<String, PathBuf>,
+}
+
+/// Cache usage summary
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheInfo {
+    pub path: String,
+    pub size_bytes: u64,
+    pub file_count: usize,
+    pub limit_mb: u64,
+}
+
+fn get_cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    let cache_dir = app
+        .path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to get app cache directory: {}", e))?;
+    Ok(cache_dir.join("blobs"))
+}
+
+/// Folder of interrupted downloads kept for resuming, next to the blob folder
+fn partials_dir(cache_dir: &Path) -> PathBuf {
+    cache_dir.with_file_name("partials")
+}
+
+/// Number and total size of the interrupted downloads kept for resuming
+fn partials_usage(cache_dir: &Path) -> (usize, u64) {
+    let Ok(entries) = fs::read_dir(partials_dir(cache_dir)) else {
+        return (0, 0);
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .fold((0, 0), |(count, size), metadata| (count + 1, size + metadata.len()))
+}
+
+/// List all cached blobs with their size and last use time
+fn list_blobs(dir: &Path) -> Vec<(PathBuf, u64, SystemTime)> {
+    let mut blobs = Vec::new();
+    let Ok(shards) = fs::read_dir(dir) else {
+        return blobs;
+    };
+
+    for shard in shards.filter_map(|e| e.ok()) {
+        let Ok(entries) = fs::read_dir(shard.path()) else {
+            continue;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            if let Ok(metadata) = entry.metadata() {
+                if metadata.is_file() {
+                    let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                    blobs.push((entry.path(), metadata.len(), modified));
+                }
+            }
+        }
+    }
+
+    blobs
+}
+
+impl BlobCache {
+    /// Open the cache using the size limit from app state
+    pub fn open(app: &AppHandle) -> Result<Self, String> {
+        let limit_mb = load_state(app.clone())?
+            .cache_size_limit_mb
+            .unwrap_or(DEFAULT_CACHE_SIZE_LIMIT_MB);
+
+        Ok(BlobCache {
+            dir: get_cache_dir(app)?,
+            limit_bytes: limit_mb * 1024 * 1024,
+            local_copies: HashMap::new(),
+        })
+    }
+
+    /// Also reuse files already in the SLUS folder with the wanted content (duplicate textures
+    /// are common), found through the hashes in its file index
+    pub fn with_local_copies(mut self, slus_path: &Path) -> Self {
+        self.local_copies = FileIndex::open(slus_path)
+            .and_then(|index| index.paths_by_sha())
+            .map(|paths| paths.into_iter().map(|(sha, path)| (sha, slus_path.join(path))).collect())
+            .unwrap_or_default();
+        self
+    }
+
+    fn blob_path(&self, sha: &str) -> PathBuf {
+        let shard = &sha[..2.min(sha.len())];
+        self.dir.join(shard).join(sha)
+    }
+
+    /// Where an interrupted download of this content is kept until it can be resumed
+    pub fn partial_path(&self, sha: &str) -> PathBuf {
+        partials_dir(&self.dir).join(format!("{}.partial", sha))
+    }
+
+    /// Copy a cached blob to dest_path
+    /// Returns false if the blob isn't cached (or couldn't be copied). A cached blob whose content
+    /// no longer matches its SHA is removed instead of installed
+    pub fn restore(&self, sha: &str, dest_path: &Path) -> bool {
+        if self.limit_bytes == 0 {
+            return false;
+        }
+
+        let blob_path = self.blob_path(sha);
+        let Ok(content) = fs::read(&blob_path) else {
+            return false;
+        };
+        if compute_sha_for_content(&content) != sha {
+            let _ = fs::remove_file(&blob_path);
+            return false;
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            if fs::create_dir_all(parent).is_err() {
+                return false;
+            }
+        }
+
+        // Write next to the destination, then swap it in (never overwrite a possibly hardlinked file in place)
+        let temp_path = download_temp_path(dest_path);
+        if fs::write(&temp_path, &content).is_err() || fs::rename(&temp_path, dest_path).is_err() {
+            let _ = fs::remove_file(&temp_path);
+            return false;
+        }
+
+        // Mark as recently used so eviction keeps it
+        if let Ok(file) = fs::File::options().write(true).open(&blob_path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+
+        true
+    }
+
+    /// Copy a file in the SLUS folder with this content to dest_path
+    /// Returns false if there's none (or it changed since it was indexed)
+    pub fn restore_local(&self, sha: &str, dest_path: &Path) -> bool {
+        let Some(source_path) = self.local_copies.get(sha) else {
+            return false;
+        };
+        if source_path == dest_path {
+            return false;
+        }
+
+        let Ok(content) = fs::read(source_path) else {
+            return false;
+        };
+        if compute_sha_for_content(&content) != sha {
+            return false;
+        }
+
+        if let Some(parent) = dest_path.parent() {
+            if fs::create_dir_all(parent).is_err() {
+                return false;
+            }
+        }
+
+        let temp_path = download_temp_path(dest_path);
+        if fs::write(&temp_path, &content).is_err() || fs::rename(&temp_path, dest_path).is_err() {
+            let _ = fs::remove_file(&temp_path);
+            return false;
+        }
+
+        true
+    }
+
+    /// Store downloaded content (best effort - caching never fails a download)
+    /// Content is only cached if it matches the expected SHA
+    pub fn store(&self, sha: &str, content: &[u8]) {
+        if self.limit_bytes == 0 || content.len() as u64 > self.limit_bytes {
+            return;
+        }
+
+        if compute_sha_for_content(content) != sha {
+            return;
+        }
+
+        let blob_path = self.blob_path(sha);
+        if blob_path.exists() {
+            return;
+        }
+
+        if let Some(parent) = blob_path.parent() {
+            if fs::create_dir_all(parent).is_err() {
+                return;
+            }
+        }
+
+        let temp_path = blob_path.with_extension("tmp");
+        if fs::write(&temp_path, content).is_ok() {
+            let _ = fs::rename(&temp_path, &blob_path);
+        }
+    }
+
+    /// Evict least recently used blobs until the cache fits its size limit
+    /// Returns the number of bytes removed
+    pub fn enforce_limit(&self) -> u64 {
+        let mut blobs = list_blobs(&self.dir);
+        let mut total: u64 = blobs.iter().map(|(_, size, _)| size).sum();
+        let mut removed: u64 = 0;
+
+        if total <= self.limit_bytes {
+            return 0;
+        }
+
+        // Oldest first
+        blobs.sort_by_key(|(_, _, modified)| *modified);
+
+        for (path, size, _) in blobs {
+            if total <= self.limit_bytes {
+                break;
+            }
+            if fs::remove_file(&path).is_ok() {
+                total -= size;
+                removed += size;
+            }
+        }
+
+        removed
+    }
+}
+
+/// Get the cache location, size and limit (interrupted downloads count towards the size)
+#[tauri::command]
+pub fn get_cache_info(app: AppHandle) -> Result<CacheInfo, String> {
+    let dir = get_cache_dir(&app)?;
+    let blobs = list_blobs(&dir);
+    let (partial_count, partial_bytes) = partials_usage(&dir);
+    let limit_mb = load_state(app)?
+        .cache_size_limit_mb
+        .unwrap_or(DEFAULT_CACHE_SIZE_LIMIT_MB);
+
+    Ok(CacheInfo {
+        path: dir.to_string_lossy().to_string(),
+        size_bytes: blobs.iter().map(|(_, size, _)| size).sum::<u64>() + partial_bytes,
+        file_count: blobs.len() + partial_count,
+        limit_mb,
+    })
+}
+
+/// Set the cache size limit in MB (0 disables caching) and evict to fit
+#[tauri::command]
+pub fn set_cache_size_limit(app: AppHandle, limit_mb: u64) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.cache_size_limit_mb = Some(limit_mb);
+        Ok(())
+    })?;
+
+    BlobCache::open(&app)?.enforce_limit();
+    Ok(())
+}
+
+/// Delete all cached blobs and interrupted downloads
+/// Returns the number of bytes freed
+#[tauri::command]
+pub fn clear_cache(app: AppHandle) -> Result<u64, String> {
+    let dir = get_cache_dir(&app)?;
+    let freed = list_blobs(&dir).iter().map(|(_, size, _)| size).sum::<u64>() + partials_usage(&dir).1;
+
+    for dir in [partials_dir(&dir), dir] {
+        if dir.exists() {
+            fs::remove_dir_all(&dir)
+                .map_err(|e| format!("Failed to clear cache: {}", e))?;
+        }
+    }
+
+    Ok(freed)
+}
@@ -0,0 +1,124 @@
+use crate::commands::app_info::{compare_versions, get_app_version, optional_string_or_number};
+use crate::commands::network::{describe_request_error, http_client, TracedSend};
+use crate::commands::profiles::{add_profile, PackProfile};
+use crate::config::{Forge, RepoConfig, DEFAULT_API_BASE_URL, DEFAULT_RAW_BASE_URL, PACK_CATALOG_URL};
+use serde::{Deserialize, Serialize};
+
+fn default_branch() -> String {
+    "main".to_string()
+}
+
+/// Pack catalog listing all supported texture packs
+#[derive(Debug, Clone, Deserialize)]
+struct PackCatalog {
+    packs: Vec<CatalogPack>,
+}
+
+/// A texture pack entry from the remote catalog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatalogPack {
+    /// Unique id of the pack
+    pub id: String,
+    /// Display name (e.g. "NCAA NEXT 26")
+    pub name: String,
+    pub repo_owner: String,
+    pub repo_name: String,
+    /// Git URL (defaults to the GitHub URL built from owner/name)
+    #[serde(default)]
+    pub repo_url: Option<String>,
+    #[serde(default = "default_branch")]
+    pub branch: String,
+    /// Extra servers hosting the repository's files (see RepoConfig::mirrors)
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+    pub slus_folder: String,
+    pub sparse_path: String,
+    /// Places sparse_path may move to (see RepoConfig::sparse_path_aliases)
+    #[serde(default)]
+    pub sparse_path_aliases: Vec<String>,
+    /// Total size of the pack (e.g., "8.5 GB" or just "22.5")
+    #[serde(default, deserialize_with = "optional_string_or_number")]
+    pub total_size: Option<String>,
+    /// Minimum version of this app required to install the pack
+    #[serde(default, deserialize_with = "optional_string_or_number")]
+    pub min_app_version: Option<String>,
+    /// Whether this app version satisfies min_app_version (computed locally)
+    #[serde(default, skip_deserializing)]
+    pub supported: bool,
+}
+
+impl CatalogPack {
+    /// Build the repository configuration for this pack (e.g. to create a profile)
+    pub fn to_repo_config(&self) -> RepoConfig {
+        RepoConfig {
+            repo_owner: self.repo_owner.clone(),
+            repo_name: self.repo_name.clone(),
+            repo_url: self.repo_url.clone().unwrap_or_else(|| {
+                format!("https://github.com/{}/{}.git", self.repo_owner, self.repo_name)
+            }),
+            branch: self.branch.clone(),
+            slus_folder: self.slus_folder.clone(),
+            sparse_path: self.sparse_path.clone(),
+            temp_dir_name: format!("_temp_{}_repo", self.id),
+            api_base_url: DEFAULT_API_BASE_URL.to_string(),
+            raw_base_url: DEFAULT_RAW_BASE_URL.to_string(),
+            forge: Forge::GitHub,
+            mirrors: self.mirrors.clone(),
+            sparse_path_aliases: self.sparse_path_aliases.clone(),
+        }
+    }
+}
+
+/// Fetch the list of supported texture packs from the central catalog
+#[tauri::command]
+pub async fn fetch_pack_catalog(app_handle: tauri::AppHandle) -> Result<Vec<CatalogPack>, String> {
+    let app_version = get_app_version(&app_handle);
+    let client = http_client();
+
+    let response = client
+        .get(PACK_CATALOG_URL)
+        .header("User-Agent", "PS2-Textures-Downloader")
+        .send_traced()
+        .await
+        .map_err(|e| format!("Network error: {}", describe_request_error(&e)))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to fetch pack catalog: HTTP {}", response.status()));
+    }
+
+    let catalog: PackCatalog = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse pack catalog: {}", e))?;
+
+    Ok(catalog
+        .packs
+        .into_iter()
+        .map(|mut pack| {
+            pack.supported = is_supported(&pack, &app_version);
+            pack
+        })
+        .collect())
+}
+
+/// Check whether the given app version satisfies the pack's minimum version
+fn is_supported(pack: &CatalogPack, app_version: &str) -> bool {
+    match &pack.min_app_version {
+        Some(min) => compare_versions(app_version.to_string(), min.clone()) >= 0,
+        None => true,
+    }
+}
+
+/// Create a pack profile from a catalog entry
+#[tauri::command]
+pub fn add_profile_from_catalog(app_handle: tauri::AppHandle, pack: CatalogPack) -> Result<PackProfile, String> {
+    let app_version = get_app_version(&app_handle);
+    if !is_supported(&pack, &app_version) {
+        return Err(format!(
+            "{} requires app version {} or newer",
+            pack.name,
+            pack.min_app_version.as_deref().unwrap_or("?")
+        ));
+    }
+    add_profile(app_handle, pack.name.clone(), pack.to_repo_config())
+}
@@ -0,0 +1,209 @@
+//! Housekeeping of the SLUS folder: junk files and empty directories left behind by syncs
+
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync::{emit_log, is_protected_path, load_protected_paths, LogLevel};
+use crate::commands::watcher::suppress_drift_detection;
+use crate::config::resolve_repo_config;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Window};
+
+/// Which files cleanup and verification treat as junk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JunkRules {
+    /// Treat every hidden (dot) file as junk, not just known OS metadata
+    #[serde(default)]
+    pub remove_all_hidden: bool,
+    /// Exact file names (case-insensitive)
+    #[serde(default)]
+    pub file_names: Vec<String>,
+    /// File name prefixes (e.g. "._" for macOS resource forks)
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    /// File name suffixes (e.g. ".download-tmp" left by interrupted downloads)
+    #[serde(default)]
+    pub suffixes: Vec<String>,
+}
+
+impl Default for JunkRules {
+    fn default() -> Self {
+        JunkRules {
+            remove_all_hidden: false,
+            file_names: [".DS_Store", "Thumbs.db", "ehthumbs.db", "desktop.ini"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            prefixes: vec!["._".to_string()],
+            suffixes: vec![".download-tmp".to_string(), ".dedup-tmp".to_string()],
+        }
+    }
+}
+
+impl JunkRules {
+    /// Check if a file name matches any junk rule
+    pub fn matches(&self, name: &str) -> bool {
+        if self.remove_all_hidden && name.starts_with('.') {
+            return true;
+        }
+
+        let lower = name.to_ascii_lowercase();
+        self.file_names.iter().any(|n| n.eq_ignore_ascii_case(name))
+            || self.prefixes.iter().any(|p| lower.starts_with(&p.to_ascii_lowercase()))
+            || self.suffixes.iter().any(|s| lower.ends_with(&s.to_ascii_lowercase()))
+    }
+}
+
+/// What a cleanup pass removed (or would remove, in a dry run)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct CleanupReport {
+    /// Empty directories, relative to the SLUS folder
+    pub removed_dirs: Vec<String>,
+    /// Junk files, relative to the SLUS folder
+    pub removed_files: Vec<String>,
+    pub dry_run: bool,
+}
+
+/// Junk rules from state (defaults if the state can't be read)
+pub(crate) fn load_junk_rules(app: &AppHandle) -> JunkRules {
+    load_state(app.clone())
+        .map(|state| state.junk_rules)
+        .unwrap_or_default()
+}
+
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Recursively remove junk files and empty directories
+/// The root itself is left alone, including junk files in it (our manifest and index live there),
+/// and so is anything under a user-protected path
+pub(crate) fn cleanup_empty_directories(root: &Path, dry_run: bool, window: &Window) -> CleanupReport {
+    let rules = load_junk_rules(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    let mut report = CleanupReport {
+        dry_run,
+        ..Default::default()
+    };
+
+    if root.is_dir() {
+        cleanup_directory(root, root, &rules, &protected_paths, &mut report, window);
+    }
+
+    report
+}
+
+/// Returns whether dir was removed (or would be, in a dry run)
+fn cleanup_directory(
+    root: &Path,
+    dir: &Path,
+    rules: &JunkRules,
+    protected_paths: &[String],
+    report: &mut CleanupReport,
+    window: &Window,
+) -> bool {
+    let is_root = dir == root;
+    if !is_root && is_protected_path(&relative_path(root, dir), protected_paths) {
+        return false;
+    }
+
+    let entries: Vec<_> = match fs::read_dir(dir) {
+        Ok(rd) => rd.filter_map(|e| e.ok()).collect(),
+        Err(e) => {
+            emit_log(window, LogLevel::Warn, "cleanup", None, format!("Error reading dir {:?}: {}", dir, e));
+            return false;
+        }
+    };
+
+    // Count what stays behind - subdirectories first, so their removal can empty this one
+    let mut remaining = 0;
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            if !cleanup_directory(root, &path, rules, protected_paths, report, window) {
+                remaining += 1;
+            }
+            continue;
+        }
+
+        let is_junk = !is_root && entry.file_name().to_str().is_some_and(|name| rules.matches(name));
+        if is_junk && (report.dry_run || fs::remove_file(&path).is_ok()) {
+            report.removed_files.push(relative_path(root, &path));
+        } else {
+            remaining += 1;
+        }
+    }
+
+    if is_root || remaining > 0 {
+        return false;
+    }
+
+    if !report.dry_run {
+        if let Err(e) = fs::remove_dir(dir) {
+            emit_log(window, LogLevel::Warn, "cleanup", None, format!("Failed to remove {:?}: {}", dir, e));
+            return false;
+        }
+    }
+
+    report.removed_dirs.push(relative_path(root, dir));
+    true
+}
+
+/// Get the junk file rules
+#[tauri::command]
+pub fn get_junk_rules(app: AppHandle) -> Result<JunkRules, String> {
+    Ok(load_state(app)?.junk_rules)
+}
+
+/// Set the junk file rules used by cleanup and verification
+#[tauri::command]
+pub fn set_junk_rules(app: AppHandle, rules: JunkRules) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.junk_rules = rules;
+        Ok(())
+    })
+}
+
+/// List the junk files and empty directories cleanup would remove, without removing anything
+#[tauri::command]
+pub fn preview_cleanup(textures_dir: String, window: Window) -> Result<CleanupReport, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    Ok(cleanup_empty_directories(&slus_path, true, &window))
+}
+
+/// Remove junk files and empty directories from the SLUS folder on its own, without a sync or
+/// verification. With `dry_run` nothing is removed and the report lists what would be
+#[tauri::command]
+pub async fn run_cleanup(textures_dir: String, dry_run: Option<bool>, window: Window) -> Result<CleanupReport, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    if !slus_path.is_dir() {
+        return Err(format!("Folder {} does not exist", config.slus_folder));
+    }
+    let dry_run = dry_run.unwrap_or(false);
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let _drift_guard = suppress_drift_detection();
+        let report = cleanup_empty_directories(&slus_path, dry_run, &window);
+        if !dry_run {
+            emit_log(
+                &window,
+                LogLevel::Info,
+                "cleanup",
+                None,
+                format!(
+                    "Removed {} junk files and {} empty folders",
+                    report.removed_files.len(),
+                    report.removed_dirs.len()
+                ),
+            );
+        }
+        report
+    })
+    .await
+    .map_err(|e| format!("Cleanup failed: {}", e))
+}
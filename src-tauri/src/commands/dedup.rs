@@ -0,0 +1,228 @@
+//! Hardlink deduplication between the live install, its backups and other profiles' installs
+
+use crate::commands::progress::{MessageParams, Stage};
+use crate::commands::state::load_state;
+use crate::commands::sync::{compute_git_blob_sha, SyncProgressPayload};
+use crate::commands::watcher::suppress_drift_detection;
+use crate::config::{resolve_repo_config, RepoConfig};
+use same_file::is_same_file;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Window};
+
+/// Result of a dedup pass
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DedupReport {
+    /// Folders that were scanned (installs and backups)
+    pub folders: Vec<String>,
+    pub files_scanned: usize,
+    /// Duplicate files replaced with hardlinks (or that would be, in a dry run)
+    pub files_linked: usize,
+    /// Space saved (or that would be saved, in a dry run)
+    pub bytes_saved: u64,
+    /// Duplicates that couldn't be linked (e.g. on different filesystems)
+    pub files_failed: usize,
+    pub dry_run: bool,
+}
+
+/// SLUS folder names of every known pack (bundled, overrides and profiles)
+fn known_slus_folders(app: &AppHandle) -> Result<HashSet<String>, String> {
+    let state = load_state(app.clone())?;
+    let mut folders: HashSet<String> = state
+        .profiles
+        .iter()
+        .map(|p| p.config.slus_folder.clone())
+        .collect();
+    folders.insert(RepoConfig::bundled().slus_folder.clone());
+    folders.insert(resolve_repo_config(app)?.slus_folder);
+    Ok(folders)
+}
+
+/// Installs and backups in the textures directory (live installs first, so they keep the original copy)
+fn find_dedup_roots(textures_path: &Path, slus_folders: &HashSet<String>) -> Vec<PathBuf> {
+    let mut installs = Vec::new();
+    let mut backups = Vec::new();
+
+    let Ok(entries) = fs::read_dir(textures_path) else {
+        return installs;
+    };
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if slus_folders.contains(&name) {
+            installs.push(path);
+        } else if slus_folders
+            .iter()
+            .any(|folder| name.starts_with(&format!("{}_backup_", folder)))
+        {
+            backups.push(path);
+        }
+    }
+
+    installs.sort();
+    backups.sort();
+    installs.extend(backups);
+    installs
+}
+
+/// Collect all regular files under a folder
+/// Hidden files (index database, manifest) are skipped - they are modified in place
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(|e| e.ok()) {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            collect_files(&entry.path(), files);
+        } else if file_type.is_file() {
+            files.push(entry.path());
+        }
+    }
+}
+
+/// Replace duplicate with a hardlink to original (via a temp link so the duplicate is never lost)
+fn replace_with_hardlink(original: &Path, duplicate: &Path) -> Result<(), String> {
+    let temp_path = duplicate.with_extension("dedup-tmp");
+    let _ = fs::remove_file(&temp_path);
+
+    fs::hard_link(original, &temp_path)
+        .map_err(|e| format!("Failed to create hardlink: {}", e))?;
+
+    fs::rename(&temp_path, duplicate).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to replace duplicate: {}", e)
+    })
+}
+
+/// Find identical files across installs and backups and replace duplicates with hardlinks
+/// With dry_run set, only reports how much space would be saved
+#[tauri::command]
+pub async fn dedup_installs(
+    app: AppHandle,
+    textures_dir: String,
+    dry_run: bool,
+    window: Window,
+) -> Result<DedupReport, String> {
+    let textures_path = PathBuf::from(&textures_dir);
+    let _drift_guard = suppress_drift_detection();
+    let roots = find_dedup_roots(&textures_path, &known_slus_folders(&app)?);
+
+    let mut report = DedupReport {
+        folders: roots
+            .iter()
+            .filter_map(|r| r.file_name().map(|n| n.to_string_lossy().to_string()))
+            .collect(),
+        dry_run,
+        ..Default::default()
+    };
+
+    if roots.len() < 2 {
+        return Ok(report);
+    }
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Dedup,
+        key: "dedup.scanning",
+        params: MessageParams::from([("folders", roots.len().to_string())]),
+        message: format!("Scanning {} folders for duplicate files...", roots.len()),
+        current: None,
+        total: None,
+    });
+
+    let mut files = Vec::new();
+    for root in &roots {
+        collect_files(root, &mut files);
+    }
+    report.files_scanned = files.len();
+
+    // Only files of equal size can be identical - skip hashing everything else
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for file in files {
+        if let Ok(metadata) = fs::metadata(&file) {
+            if metadata.len() > 0 {
+                by_size.entry(metadata.len()).or_default().push(file);
+            }
+        }
+    }
+    by_size.retain(|_, paths| paths.len() > 1);
+
+    let candidate_count: usize = by_size.values().map(|paths| paths.len()).sum();
+    let mut hashed = 0;
+
+    for (size, paths) in by_size {
+        let mut by_sha: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in paths {
+            hashed += 1;
+            if hashed % 1000 == 0 {
+                let _ = window.emit("sync-progress", SyncProgressPayload {
+                    stage: Stage::Dedup,
+                    key: "dedup.hashing",
+                    params: MessageParams::from([
+                        ("current", hashed.to_string()),
+                        ("total", candidate_count.to_string()),
+                    ]),
+                    message: format!("Hashing candidates ({}/{})...", hashed, candidate_count),
+                    current: Some(hashed as u32),
+                    total: Some(candidate_count as u32),
+                });
+            }
+            if let Ok(sha) = compute_git_blob_sha(&path) {
+                by_sha.entry(sha).or_default().push(path);
+            }
+        }
+
+        for (_, mut duplicates) in by_sha.into_iter().filter(|(_, p)| p.len() > 1) {
+            // Keep the copy from the first root (live installs come before backups)
+            duplicates.sort_by_key(|p| roots.iter().position(|r| p.starts_with(r)));
+            let original = duplicates.remove(0);
+
+            for duplicate in duplicates {
+                // Skip copies already linked to the original (same device and inode, or volume
+                // serial and file index on Windows) and files that can't be opened
+                if is_same_file(&original, &duplicate).unwrap_or(true) {
+                    continue;
+                }
+
+                if dry_run || replace_with_hardlink(&original, &duplicate).is_ok() {
+                    report.files_linked += 1;
+                    report.bytes_saved += size;
+                } else {
+                    report.files_failed += 1;
+                }
+            }
+        }
+    }
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Dedup,
+        key: if dry_run { "dedup.found" } else { "dedup.linked" },
+        params: MessageParams::from([
+            ("count", report.files_linked.to_string()),
+            ("bytes", report.bytes_saved.to_string()),
+        ]),
+        message: format!(
+            "{} {} duplicate files ({:.1} MB)",
+            if dry_run { "Found" } else { "Linked" },
+            report.files_linked,
+            report.bytes_saved as f64 / (1024.0 * 1024.0)
+        ),
+        current: None,
+        total: None,
+    });
+
+    Ok(report)
+}
@@ -0,0 +1,235 @@
+//! Disabled textures: how a disabled file is named on disk, and which textures the user has disabled
+
+use crate::commands::repo_paths::{check_repo_path, check_repo_paths};
+use crate::commands::state::{load_state, with_state_mut};
+use crate::config::resolve_repo_config;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+/// Name of the per-folder subfolder used by DisableConvention::Subfolder
+const DISABLED_FOLDER: &str = "disabled";
+
+/// Suffix used by DisableConvention::Suffix
+const DISABLED_SUFFIX: &str = ".disabled";
+
+/// How a disabled texture is stored next to (or instead of) its enabled path
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisableConvention {
+    /// "dir/-file.png"
+    #[default]
+    DashPrefix,
+    /// "dir/file.png.disabled"
+    Suffix,
+    /// "dir/disabled/file.png"
+    Subfolder,
+}
+
+/// Split a relative path into ("dir/", "file")
+fn split_path(path: &str) -> (&str, &str) {
+    match path.rfind('/') {
+        Some(pos) => (&path[..pos + 1], &path[pos + 1..]),
+        None => ("", path),
+    }
+}
+
+impl DisableConvention {
+    /// Check if a relative path is a disabled file under this convention
+    pub fn is_disabled(self, path: &str) -> bool {
+        let (dir, file) = split_path(path);
+        match self {
+            DisableConvention::DashPrefix => file.starts_with('-'),
+            DisableConvention::Suffix => file.len() > DISABLED_SUFFIX.len() && file.ends_with(DISABLED_SUFFIX),
+            DisableConvention::Subfolder => {
+                dir.trim_end_matches('/').rsplit('/').next() == Some(DISABLED_FOLDER)
+            }
+        }
+    }
+
+    /// Get the disabled version path for a file
+    pub fn disabled_path(self, path: &str) -> String {
+        let (dir, file) = split_path(path);
+        match self {
+            DisableConvention::DashPrefix => format!("{}-{}", dir, file),
+            DisableConvention::Suffix => format!("{}{}", path, DISABLED_SUFFIX),
+            DisableConvention::Subfolder => format!("{}{}/{}", dir, DISABLED_FOLDER, file),
+        }
+    }
+
+    /// Get the enabled version path for a disabled file
+    pub fn enabled_path(self, path: &str) -> Option<String> {
+        if !self.is_disabled(path) {
+            return None;
+        }
+
+        let (dir, file) = split_path(path);
+        match self {
+            DisableConvention::DashPrefix => Some(format!("{}{}", dir, &file[1..])),
+            DisableConvention::Suffix => path.strip_suffix(DISABLED_SUFFIX).map(|p| p.to_string()),
+            DisableConvention::Subfolder => {
+                let parent = &dir[..dir.len() - DISABLED_FOLDER.len() - 1];
+                Some(format!("{}{}", parent, file))
+            }
+        }
+    }
+}
+
+/// Repo paths (relative to the SLUS folder) the user has disabled, with the blob SHA last seen for each
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DisabledRegistry(BTreeMap<String, Option<String>>);
+
+impl DisabledRegistry {
+    pub fn contains(&self, path: &str) -> bool {
+        self.0.contains_key(path)
+    }
+
+    pub fn insert(&mut self, path: String, sha: Option<String>) {
+        self.0.insert(path, sha);
+    }
+
+    /// Move an entry to a new path (upstream rename)
+    pub fn rename(&mut self, from: &str, to: &str) {
+        if let Some(sha) = self.0.remove(from) {
+            self.0.insert(to.to_string(), sha);
+        }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    /// Refuse a registry holding a path that's unsafe to rename (e.g. from an imported file)
+    pub fn check_paths(&self) -> Result<(), String> {
+        check_repo_paths(self.0.keys().map(String::as_str))
+    }
+
+    /// Update the registry from a full view of local files and the repo (path -> SHA)
+    pub fn observe(
+        &mut self,
+        local_files: &HashMap<String, String>,
+        remote_files: &HashMap<String, String>,
+        convention: DisableConvention,
+    ) {
+        // Disabled files on disk are the user's current choices
+        for (local_path, sha) in local_files {
+            // A repo file that merely looks disabled (e.g. "-.png")
+            if remote_files.contains_key(local_path) {
+                continue;
+            }
+            if let Some(enabled_path) = convention.enabled_path(local_path) {
+                if remote_files.contains_key(&enabled_path) && !local_files.contains_key(&enabled_path) {
+                    self.0.insert(enabled_path, Some(sha.clone()));
+                }
+            }
+        }
+
+        // Follow upstream renames: a registered path gone from the repo whose content
+        // now lives at exactly one new path that isn't on disk yet
+        let renames: Vec<(String, String)> = self
+            .0
+            .iter()
+            .filter(|(path, _)| !remote_files.contains_key(*path))
+            .filter_map(|(path, sha)| {
+                let sha = sha.as_deref()?;
+                let mut candidates = remote_files.iter().filter(|(candidate, remote_sha)| {
+                    remote_sha.as_str() == sha
+                        && !self.0.contains_key(*candidate)
+                        && !local_files.contains_key(*candidate)
+                        && !local_files.contains_key(&convention.disabled_path(candidate))
+                });
+                match (candidates.next(), candidates.next()) {
+                    (Some((candidate, _)), None) => Some((path.clone(), candidate.clone())),
+                    _ => None,
+                }
+            })
+            .collect();
+        for (from, to) in renames {
+            self.rename(&from, &to);
+        }
+
+        // Only the enabled file on disk means the user re-enabled it
+        self.0.retain(|path, _| {
+            !local_files.contains_key(path) || local_files.contains_key(&convention.disabled_path(path))
+        });
+    }
+}
+
+/// Disable convention from state (the default if the state can't be read)
+pub(crate) fn load_disable_convention(app: &AppHandle) -> DisableConvention {
+    load_state(app.clone())
+        .map(|state| state.disable_convention)
+        .unwrap_or_default()
+}
+
+/// Disabled registry from state (empty if the state can't be read)
+pub(crate) fn load_disabled_registry(app: &AppHandle) -> DisabledRegistry {
+    load_state(app.clone())
+        .map(|state| state.disabled_textures)
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_disabled_registry(app: &AppHandle, registry: &DisabledRegistry) -> Result<(), String> {
+    with_state_mut(app, |state| {
+        state.disabled_textures = registry.clone();
+        Ok(())
+    })
+}
+
+/// Disable every registered texture that is currently enabled on disk (e.g. after a fresh install)
+/// Returns the number of files renamed
+pub(crate) fn apply_disabled_registry(slus_path: &Path, convention: DisableConvention, registry: &DisabledRegistry) -> u32 {
+    let mut renamed = 0;
+    for path in registry.paths() {
+        let disabled_rel_path = convention.disabled_path(path);
+        if check_repo_path(path).is_err() || check_repo_path(&disabled_rel_path).is_err() {
+            continue;
+        }
+        let enabled_path = slus_path.join(path);
+        let disabled_path = slus_path.join(disabled_rel_path);
+        if !enabled_path.is_file() || disabled_path.exists() {
+            continue;
+        }
+        if let Some(parent) = disabled_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if fs::rename(&enabled_path, &disabled_path).is_ok() {
+            renamed += 1;
+        }
+    }
+    renamed
+}
+
+/// Get how disabled textures are named on disk
+#[tauri::command]
+pub fn get_disable_convention(app: AppHandle) -> Result<DisableConvention, String> {
+    Ok(load_state(app)?.disable_convention)
+}
+
+/// Set how disabled textures are named on disk (existing disabled files are not renamed)
+#[tauri::command]
+pub fn set_disable_convention(app: AppHandle, convention: DisableConvention) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.disable_convention = convention;
+        Ok(())
+    })
+}
+
+/// List the repo paths the user has disabled
+#[tauri::command]
+pub fn get_disabled_textures(app: AppHandle) -> Result<Vec<String>, String> {
+    Ok(load_state(app)?.disabled_textures.paths().cloned().collect())
+}
+
+/// Re-apply the registered disable choices to the install (e.g. after importing settings from another PC)
+/// Returns the number of textures disabled
+#[tauri::command]
+pub fn restore_disabled_textures(app: AppHandle, textures_dir: String) -> Result<u32, String> {
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let registry = load_disabled_registry(&app);
+    Ok(apply_disabled_registry(&slus_path, load_disable_convention(&app), &registry))
+}
@@ -0,0 +1,413 @@
+//! SQLite index of the local SLUS folder (path, size, mtime, sha, enabled flag, last verified)
+
+use crate::commands::disabled::{load_disable_convention, DisableConvention};
+use crate::commands::sync::{compute_git_blob_sha, should_skip_path};
+use crate::config::resolve_repo_config;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tauri::AppHandle;
+
+/// Index database file name, stored in the SLUS folder (hidden, so sync and verification ignore it)
+pub(crate) const INDEX_FILE_NAME: &str = ".textures-index.sqlite";
+
+/// Schema version (bump to rebuild the index after schema changes)
+const INDEX_SCHEMA_VERSION: i64 = 1;
+
+/// Default number of search results
+const DEFAULT_SEARCH_LIMIT: usize = 200;
+
+/// A file in the local index
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexedFile {
+    /// Path relative to the SLUS folder, as stored on disk (disabled files keep their disabled name)
+    pub path: String,
+    pub size: u64,
+    pub enabled: bool,
+    pub sha: Option<String>,
+    pub last_verified: Option<String>,
+}
+
+/// Summary of the local index
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalFileStats {
+    pub total_files: usize,
+    pub enabled_files: usize,
+    pub disabled_files: usize,
+    pub total_bytes: u64,
+    pub last_indexed: Option<String>,
+}
+
+/// What changed during an index refresh
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IndexRefreshStats {
+    pub added: usize,
+    pub changed: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
+
+/// Open connection to the index of one SLUS folder
+pub struct FileIndex {
+    conn: Connection,
+    slus_path: PathBuf,
+}
+
+fn db_err(e: rusqlite::Error) -> String {
+    format!("File index error: {}", e)
+}
+
+/// Modification time in nanoseconds since the epoch (0 if unavailable)
+fn mtime_nanos(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos() as i64)
+        .unwrap_or(0)
+}
+
+/// Walk the SLUS folder collecting (relative path, size, mtime) with the same skip rules as sync
+fn walk_files(base_path: &Path, current_path: &Path, files: &mut Vec<(String, u64, i64)>) -> Result<(), String> {
+    let entries = fs::read_dir(current_path)
+        .map_err(|e| format!("Failed to read directory: {}", e))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
+        let path = entry.path();
+
+        // Skip hidden files
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            if name.starts_with('.') {
+                continue;
+            }
+        }
+
+        if path.is_dir() {
+            walk_files(base_path, &path, files)?;
+        } else if path.is_file() {
+            let relative_path = path
+                .strip_prefix(base_path)
+                .map_err(|e| format!("Failed to get relative path: {}", e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            // Protected paths are indexed too; sync and verification filter them out themselves
+            if should_skip_path(&relative_path, &[]) {
+                continue;
+            }
+
+            let metadata = entry.metadata().map_err(|e| format!("Failed to read metadata: {}", e))?;
+            files.push((relative_path, metadata.len(), mtime_nanos(&metadata)));
+        }
+    }
+
+    Ok(())
+}
+
+impl FileIndex {
+    /// Open (or create) the index for a SLUS folder
+    pub fn open(slus_path: &Path) -> Result<Self, String> {
+        if !slus_path.exists() {
+            return Err(format!("{} folder not found", slus_path.display()));
+        }
+
+        let conn = Connection::open(slus_path.join(INDEX_FILE_NAME)).map_err(db_err)?;
+
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(db_err)?;
+        if version != INDEX_SCHEMA_VERSION {
+            conn.execute_batch(&format!(
+                "DROP TABLE IF EXISTS files;
+                 DROP TABLE IF EXISTS meta;
+                 CREATE TABLE files (
+                     path TEXT PRIMARY KEY,
+                     size INTEGER NOT NULL,
+                     mtime INTEGER NOT NULL,
+                     sha TEXT,
+                     enabled INTEGER NOT NULL,
+                     last_verified TEXT
+                 );
+                 CREATE TABLE meta (key TEXT PRIMARY KEY, value TEXT NOT NULL);
+                 PRAGMA user_version = {};",
+                INDEX_SCHEMA_VERSION
+            ))
+            .map_err(db_err)?;
+        }
+
+        Ok(FileIndex {
+            conn,
+            slus_path: slus_path.to_path_buf(),
+        })
+    }
+
+    /// Bring the index in line with the disk (stat only - hashes of changed files are cleared)
+    pub fn refresh(&mut self, convention: DisableConvention) -> Result<IndexRefreshStats, String> {
+        let mut on_disk = Vec::new();
+        walk_files(&self.slus_path, &self.slus_path, &mut on_disk)?;
+
+        let mut stats = IndexRefreshStats::default();
+        let tx = self.conn.transaction().map_err(db_err)?;
+
+        let mut indexed: HashMap<String, (u64, i64, bool)> = HashMap::new();
+        {
+            let mut stmt = tx.prepare("SELECT path, size, mtime, enabled FROM files").map_err(db_err)?;
+            let rows = stmt
+                .query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64, row.get::<_, i64>(2)?, row.get::<_, bool>(3)?))
+                })
+                .map_err(db_err)?;
+            for row in rows {
+                let (path, size, mtime, enabled) = row.map_err(db_err)?;
+                indexed.insert(path, (size, mtime, enabled));
+            }
+        }
+
+        for (path, size, mtime) in &on_disk {
+            let enabled = !convention.is_disabled(path);
+            match indexed.remove(path) {
+                Some((old_size, old_mtime, old_enabled)) if old_size == *size && old_mtime == *mtime => {
+                    // The disable convention may have changed since the file was indexed
+                    if old_enabled != enabled {
+                        tx.execute("UPDATE files SET enabled = ?2 WHERE path = ?1", params![path, enabled])
+                            .map_err(db_err)?;
+                    }
+                    stats.unchanged += 1;
+                }
+                Some(_) => {
+                    tx.execute(
+                        "UPDATE files SET size = ?2, mtime = ?3, sha = NULL, enabled = ?4, last_verified = NULL WHERE path = ?1",
+                        params![path, *size as i64, mtime, enabled],
+                    )
+                    .map_err(db_err)?;
+                    stats.changed += 1;
+                }
+                None => {
+                    tx.execute(
+                        "INSERT INTO files (path, size, mtime, sha, enabled, last_verified) VALUES (?1, ?2, ?3, NULL, ?4, NULL)",
+                        params![path, *size as i64, mtime, enabled],
+                    )
+                    .map_err(db_err)?;
+                    stats.added += 1;
+                }
+            }
+        }
+
+        // Anything left in the index no longer exists on disk
+        for path in indexed.keys() {
+            tx.execute("DELETE FROM files WHERE path = ?1", params![path]).map_err(db_err)?;
+            stats.removed += 1;
+        }
+
+        tx.execute(
+            "INSERT OR REPLACE INTO meta (key, value) VALUES ('last_indexed', ?1)",
+            params![Utc::now().to_rfc3339()],
+        )
+        .map_err(db_err)?;
+        tx.commit().map_err(db_err)?;
+
+        Ok(stats)
+    }
+
+    /// Mark a file as changed so the next refresh re-stats and re-hashes it
+    pub fn invalidate(&self, path: &str) -> Result<(), String> {
+        self.conn
+            .execute("UPDATE files SET mtime = -1, sha = NULL, last_verified = NULL WHERE path = ?1", params![path])
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    /// Whether the index has never been populated
+    pub fn is_empty(&self) -> Result<bool, String> {
+        let count: i64 = self
+            .conn
+            .query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))
+            .map_err(db_err)?;
+        Ok(count == 0)
+    }
+
+    /// Map of path -> git blob sha, hashing only files without a cached sha
+    pub fn sha_map(&mut self) -> Result<HashMap<String, String>, String> {
+        self.sha_map_under("")
+    }
+
+    /// sha_map for the files whose path starts with `prefix` (e.g. "uniforms/")
+    pub fn sha_map_under(&mut self, prefix: &str) -> Result<HashMap<String, String>, String> {
+        let mut file_map = HashMap::new();
+        let mut missing = Vec::new();
+
+        {
+            let mut stmt = self
+                .conn
+                .prepare("SELECT path, sha FROM files WHERE substr(path, 1, length(?1)) = ?1")
+                .map_err(db_err)?;
+            let rows = stmt
+                .query_map([prefix], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?)))
+                .map_err(db_err)?;
+            for row in rows {
+                match row.map_err(db_err)? {
+                    (path, Some(sha)) => {
+                        file_map.insert(path, sha);
+                    }
+                    (path, None) => missing.push(path),
+                }
+            }
+        }
+
+        let tx = self.conn.transaction().map_err(db_err)?;
+        for path in missing {
+            let sha = compute_git_blob_sha(&self.slus_path.join(&path))?;
+            tx.execute("UPDATE files SET sha = ?2 WHERE path = ?1", params![path, sha])
+                .map_err(db_err)?;
+            file_map.insert(path, sha);
+        }
+        tx.commit().map_err(db_err)?;
+
+        Ok(file_map)
+    }
+
+    /// Map of git blob sha -> one path with that content, from the hashes already in the index
+    pub fn paths_by_sha(&self) -> Result<HashMap<String, String>, String> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT sha, path FROM files WHERE sha IS NOT NULL")
+            .map_err(db_err)?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(db_err)?;
+        rows.collect::<Result<HashMap<_, _>, _>>().map_err(db_err)
+    }
+
+    /// Map of path -> size in bytes, as of the last refresh
+    pub fn size_map(&self) -> Result<HashMap<String, u64>, String> {
+        let mut stmt = self.conn.prepare("SELECT path, size FROM files").map_err(db_err)?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)? as u64)))
+            .map_err(db_err)?;
+        rows.collect::<Result<HashMap<_, _>, _>>().map_err(db_err)
+    }
+
+    /// Record that these files were verified against the repository
+    pub fn mark_verified<'a>(&mut self, paths: impl Iterator<Item = &'a String>) -> Result<(), String> {
+        let now = Utc::now().to_rfc3339();
+        let tx = self.conn.transaction().map_err(db_err)?;
+        for path in paths {
+            tx.execute("UPDATE files SET last_verified = ?2 WHERE path = ?1", params![path, now])
+                .map_err(db_err)?;
+        }
+        tx.commit().map_err(db_err)
+    }
+
+    fn query_files(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<IndexedFile>, String> {
+        let mut stmt = self.conn.prepare(sql).map_err(db_err)?;
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok(IndexedFile {
+                    path: row.get(0)?,
+                    size: row.get::<_, i64>(1)? as u64,
+                    enabled: row.get(2)?,
+                    sha: row.get(3)?,
+                    last_verified: row.get(4)?,
+                })
+            })
+            .map_err(db_err)?;
+        rows.collect::<Result<Vec<_>, _>>().map_err(db_err)
+    }
+
+    /// Largest enabled files no bigger than max_size, biggest first
+    pub fn largest_files(&self, max_size: u64, limit: usize) -> Result<Vec<IndexedFile>, String> {
+        self.query_files(
+            "SELECT path, size, enabled, sha, last_verified FROM files WHERE enabled = 1 AND size <= ?1 ORDER BY size DESC LIMIT ?2",
+            params![max_size as i64, limit as i64],
+        )
+    }
+
+    /// Files whose path contains the query (case-insensitive)
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<IndexedFile>, String> {
+        self.query_files(
+            "SELECT path, size, enabled, sha, last_verified FROM files WHERE path LIKE '%' || ?1 || '%' ORDER BY path LIMIT ?2",
+            params![query, limit as i64],
+        )
+    }
+
+    /// All user-disabled files
+    pub fn disabled_files(&self) -> Result<Vec<IndexedFile>, String> {
+        self.query_files(
+            "SELECT path, size, enabled, sha, last_verified FROM files WHERE enabled = 0 ORDER BY path",
+            [],
+        )
+    }
+
+    /// Counts and sizes of indexed files
+    pub fn stats(&self) -> Result<LocalFileStats, String> {
+        let (total_files, enabled_files, total_bytes): (i64, i64, i64) = self
+            .conn
+            .query_row(
+                "SELECT COUNT(*), COALESCE(SUM(enabled), 0), COALESCE(SUM(size), 0) FROM files",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .map_err(db_err)?;
+
+        let last_indexed: Option<String> = self
+            .conn
+            .query_row("SELECT value FROM meta WHERE key = 'last_indexed'", [], |row| row.get(0))
+            .optional()
+            .map_err(db_err)?;
+
+        Ok(LocalFileStats {
+            total_files: total_files as usize,
+            enabled_files: enabled_files as usize,
+            disabled_files: (total_files - enabled_files) as usize,
+            total_bytes: total_bytes as u64,
+            last_indexed,
+        })
+    }
+}
+
+/// Open the index for the active pack, building it on first use
+fn open_index(app: &AppHandle, textures_dir: &str) -> Result<FileIndex, String> {
+    let config = resolve_repo_config(app)?;
+    let slus_path = PathBuf::from(textures_dir).join(&config.slus_folder);
+    let mut index = FileIndex::open(&slus_path)?;
+    if index.is_empty()? {
+        index.refresh(load_disable_convention(app))?;
+    }
+    Ok(index)
+}
+
+/// Search local files by path
+#[tauri::command]
+pub fn search_local_files(
+    app: AppHandle,
+    textures_dir: String,
+    query: String,
+    limit: Option<usize>,
+) -> Result<Vec<IndexedFile>, String> {
+    open_index(&app, &textures_dir)?.search(&query, limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+}
+
+/// Get counts and sizes of local files
+#[tauri::command]
+pub fn get_local_file_stats(app: AppHandle, textures_dir: String) -> Result<LocalFileStats, String> {
+    open_index(&app, &textures_dir)?.stats()
+}
+
+/// List files the user has disabled
+#[tauri::command]
+pub fn list_disabled_files(app: AppHandle, textures_dir: String) -> Result<Vec<IndexedFile>, String> {
+    open_index(&app, &textures_dir)?.disabled_files()
+}
+
+/// Re-scan the SLUS folder and update the index
+#[tauri::command]
+pub fn refresh_file_index(app: AppHandle, textures_dir: String) -> Result<IndexRefreshStats, String> {
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    FileIndex::open(&slus_path)?.refresh(load_disable_convention(&app))
+}
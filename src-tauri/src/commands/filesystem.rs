@@ -1,71 +1,747 @@
-use crate::config::SLUS_FOLDER;
-use chrono::Local;
+use crate::commands::install::{temp_clone_in_use, ProgressPayload};
+use crate::commands::manifest::read_manifest;
+use crate::commands::progress::{MessageParams, Stage};
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync_journal::clear_sync_journal;
+use crate::commands::sync_transaction::STAGING_DIR_NAME;
+use crate::commands::watcher::{install_writes_in_progress, stop_folder_watch};
+use crate::config::resolve_repo_config;
+use chrono::{DateTime, Local, Utc};
+use serde::Serialize;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Instant, SystemTime};
+use tauri::{AppHandle, Emitter, Manager, Window};
+
+/// Whether deletions skip the Recycle Bin/Trash (false if the state can't be read)
+pub(crate) fn load_delete_permanently(app: &AppHandle) -> bool {
+    load_state(app.clone())
+        .map(|state| state.delete_permanently)
+        .unwrap_or_default()
+}
+
+/// Delete a file or folder, moving it to the Recycle Bin/Trash unless `permanently`
+/// Falls back to deleting permanently only where there is no trash (e.g. network drives, or Linux
+/// desktops without a trash folder); other failures (a file in use, missing permissions) are
+/// returned so the user doesn't lose something they expected to be able to restore
+pub(crate) fn delete_path(path: &Path, permanently: bool) -> std::io::Result<()> {
+    if !permanently {
+        match trash::delete(path) {
+            Ok(()) => return Ok(()),
+            // What the trash crate reports on Linux and macOS when it finds no usable trash for the
+            // path (Windows always has a Recycle Bin and deletes permanently by itself where not)
+            Err(trash::Error::Unknown { .. }) if !cfg!(target_os = "windows") => {}
+            Err(e) => {
+                return Err(std::io::Error::other(format!("Could not move to the Recycle Bin/Trash: {:?}", e)));
+            }
+        }
+    }
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
 
 /// Check if the SLUS folder already exists in the textures directory
 #[tauri::command]
-pub fn check_existing_folder(textures_dir: String) -> Result<bool, String> {
-    let path = PathBuf::from(&textures_dir).join(SLUS_FOLDER);
+pub fn check_existing_folder(app: AppHandle, textures_dir: String) -> Result<bool, String> {
+    let config = resolve_repo_config(&app)?;
+    let path = PathBuf::from(&textures_dir).join(&config.slus_folder);
     Ok(path.exists())
 }
 
-/// Backup the existing SLUS folder by renaming it with a timestamp
+/// What's in an existing SLUS folder, so setup can offer more than backup or delete
+#[derive(Debug, Clone, Serialize)]
+pub struct ExistingFolderInfo {
+    pub file_count: u64,
+    pub total_bytes: u64,
+    /// Most recent modification of any file (RFC 3339)
+    pub last_modified: Option<String>,
+    /// The folder is a git working copy (e.g. cloned by hand)
+    pub is_git_clone: bool,
+    /// The folder has the layout the app installs (a replacements folder)
+    pub matches_managed_layout: bool,
+    /// Best guess at the installed pack version (commit SHA)
+    pub pack_version: Option<String>,
+    /// Where pack_version comes from: "manifest" (integrity manifest in the folder) or
+    /// "last_sync" (the app's own record for this textures directory)
+    pub pack_version_source: Option<String>,
+}
+
+/// File count, total size and newest modification time below a folder
+fn folder_stats(dir: &Path, stats: &mut (u64, u64, Option<SystemTime>)) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            // git's object store would count as pack content otherwise
+            if entry.file_name() != ".git" {
+                folder_stats(&entry.path(), stats);
+            }
+            continue;
+        }
+        stats.0 += 1;
+        stats.1 += metadata.len();
+        if let Ok(modified) = metadata.modified() {
+            stats.2 = Some(stats.2.map_or(modified, |newest| newest.max(modified)));
+        }
+    }
+}
+
+/// Inspect the existing SLUS folder: size, age, whether it's a git clone or an install made by
+/// this app, and which pack version it probably holds
 #[tauri::command]
-pub fn backup_existing_folder(textures_dir: String) -> Result<String, String> {
-    let source = PathBuf::from(&textures_dir).join(SLUS_FOLDER);
+pub async fn inspect_existing_folder(app: AppHandle, textures_dir: String) -> Result<ExistingFolderInfo, String> {
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    if !slus_path.is_dir() {
+        return Err(format!("Folder {} does not exist", config.slus_folder));
+    }
+
+    let walk_path = slus_path.clone();
+    let (file_count, total_bytes, newest) = tauri::async_runtime::spawn_blocking(move || {
+        let mut stats = (0, 0, None);
+        folder_stats(&walk_path, &mut stats);
+        stats
+    })
+    .await
+    .map_err(|e| format!("Failed to inspect folder: {}", e))?;
+
+    let (pack_version, pack_version_source) = match read_manifest(&slus_path) {
+        Ok(manifest) => (Some(manifest.commit_sha), Some("manifest".to_string())),
+        Err(_) => {
+            let state = load_state(app.clone()).unwrap_or_default();
+            match state.last_sync_commit {
+                Some(commit) if state.textures_path.as_deref() == Some(textures_dir.as_str()) => {
+                    (Some(commit), Some("last_sync".to_string()))
+                }
+                _ => (None, None),
+            }
+        }
+    };
+
+    Ok(ExistingFolderInfo {
+        file_count,
+        total_bytes,
+        last_modified: newest.map(|time| DateTime::<Utc>::from(time).to_rfc3339()),
+        is_git_clone: slus_path.join(".git").exists(),
+        matches_managed_layout: slus_path.join("replacements").is_dir(),
+        pack_version,
+        pack_version_source,
+    })
+}
+
+/// Set by cancel_backup, checked between files while a backup copies or compresses
+static BACKUP_CANCELLED: AtomicBool = AtomicBool::new(false);
+
+/// Emit a backup progress event every this many files (and for the last one)
+const BACKUP_PROGRESS_INTERVAL: usize = 50;
+
+/// Files below a folder with their sizes, relative path first
+fn list_files(root: &Path, dir: &Path, files: &mut Vec<(PathBuf, u64)>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            list_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push((relative.to_path_buf(), entry.metadata()?.len()));
+        }
+    }
+    Ok(())
+}
+
+/// Where a backup is written file by file
+enum BackupWriter {
+    Folder(PathBuf),
+    Zip(zip::ZipWriter<fs::File>),
+}
+
+impl BackupWriter {
+    fn add(&mut self, source: &Path, relative: &Path) -> Result<(), String> {
+        match self {
+            BackupWriter::Folder(dest) => {
+                let target = dest.join(relative);
+                if let Some(parent) = target.parent() {
+                    fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+                }
+                fs::copy(source, &target).map_err(|e| format!("Failed to copy {}: {}", relative.display(), e))?;
+            }
+            BackupWriter::Zip(writer) => {
+                // Zip entries always use forward slashes
+                let name = relative.to_string_lossy().replace('\\', "/");
+                let options = zip::write::SimpleFileOptions::default()
+                    .compression_method(zip::CompressionMethod::Deflated);
+                writer
+                    .start_file(name, options)
+                    .map_err(|e| format!("Failed to add {} to the archive: {}", relative.display(), e))?;
+                let mut file = fs::File::open(source).map_err(|e| format!("Failed to read {}: {}", relative.display(), e))?;
+                std::io::copy(&mut file, writer)
+                    .map_err(|e| format!("Failed to compress {}: {}", relative.display(), e))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<(), String> {
+        if let BackupWriter::Zip(writer) = self {
+            writer.finish().map_err(|e| format!("Failed to finish the archive: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Copy or compress `source` into `dest` file by file, emitting progress and stopping on cancel
+/// A cancelled or failed backup removes what it wrote; the source is left untouched either way
+fn write_backup(source: &Path, dest: &Path, compress: bool, window: &Window) -> Result<(), String> {
+    let mut files = Vec::new();
+    list_files(source, source, &mut files).map_err(|e| format!("Failed to read folder: {}", e))?;
+    let total_files = files.len();
+    let total_bytes: u64 = files.iter().map(|(_, size)| size).sum();
+
+    let mut writer = if compress {
+        let file = fs::File::create(dest).map_err(|e| format!("Failed to create the archive: {}", e))?;
+        BackupWriter::Zip(zip::ZipWriter::new(file))
+    } else {
+        BackupWriter::Folder(dest.to_path_buf())
+    };
+    let (stage, key, verb) = if compress {
+        (Stage::Compressing, "backup.compressing", "Compressing")
+    } else {
+        (Stage::Moving, "backup.copying", "Copying")
+    };
+
+    let started = Instant::now();
+    let mut bytes_done: u64 = 0;
+    let result = files.iter().enumerate().try_for_each(|(i, (relative, size))| {
+        if BACKUP_CANCELLED.load(Ordering::SeqCst) {
+            return Err("Backup cancelled".to_string());
+        }
+        writer.add(&source.join(relative), relative)?;
+        bytes_done += size;
+
+        let done = i + 1;
+        if done % BACKUP_PROGRESS_INTERVAL == 0 || done == total_files {
+            let elapsed = started.elapsed().as_secs_f64();
+            let _ = window.emit("backup-progress", ProgressPayload {
+                stage,
+                key,
+                params: MessageParams::from([("files", done.to_string()), ("total", total_files.to_string())]),
+                message: format!("{} backup: {} of {} files", verb, done, total_files),
+                percent: Some((bytes_done * 100 / total_bytes.max(1)) as u32),
+                bytes_received: Some(bytes_done),
+                speed: (elapsed > 0.0).then(|| (bytes_done as f64 / elapsed) as u64),
+            });
+        }
+        Ok(())
+    });
+    let result = result.and_then(|()| writer.finish());
+
+    if result.is_err() {
+        let _ = if compress { fs::remove_file(dest) } else { fs::remove_dir_all(dest) };
+    }
+    result
+}
+
+/// Back up the existing SLUS folder as {folder}_backup_{timestamp}, moving it out of the way
+/// A plain backup next to the folder is a rename; one in another folder (e.g. another drive) or
+/// compressed into a zip is written file by file with "backup-progress" events and can be
+/// cancelled with cancel_backup. Returns the backup's path
+#[tauri::command]
+pub async fn backup_existing_folder(
+    app: AppHandle,
+    window: Window,
+    textures_dir: String,
+    destination_dir: Option<String>,
+    compress: Option<bool>,
+) -> Result<String, String> {
+    let config = resolve_repo_config(&app)?;
+    let source = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let compress = compress.unwrap_or(false);
 
     if !source.exists() {
-        return Err(format!("Folder {} does not exist", SLUS_FOLDER));
+        return Err(format!("Folder {} does not exist", config.slus_folder));
     }
 
     let timestamp = Local::now().format("%Y%m%d_%H%M%S");
-    let backup_name = format!("{}_backup_{}", SLUS_FOLDER, timestamp);
-    let dest = PathBuf::from(&textures_dir).join(&backup_name);
+    let mut backup_name = format!("{}_backup_{}", config.slus_folder, timestamp);
+    if compress {
+        backup_name.push_str(".zip");
+    }
+    let dest_dir = PathBuf::from(destination_dir.unwrap_or_else(|| textures_dir.clone()));
+    let dest = dest_dir.join(&backup_name);
+    if dest.exists() {
+        return Err(format!("{} already exists", dest.display()));
+    }
+
+    // A rename is instant; it fails across drives, where the backup is copied instead
+    if compress || fs::rename(&source, &dest).is_err() {
+        fs::create_dir_all(&dest_dir).map_err(|e| format!("Failed to create backup folder: {}", e))?;
+        BACKUP_CANCELLED.store(false, Ordering::SeqCst);
+
+        let (copy_source, copy_dest, copy_window) = (source.clone(), dest.clone(), window.clone());
+        tauri::async_runtime::spawn_blocking(move || write_backup(&copy_source, &copy_dest, compress, &copy_window))
+            .await
+            .map_err(|e| format!("Failed to backup folder: {}", e))??;
+
+        // The backup holds everything now, clear the way for the new install
+        fs::remove_dir_all(&source).map_err(|e| format!("Backed up, but failed to remove the original folder: {}", e))?;
+    }
+
+    let _ = window.emit("backup-progress", ProgressPayload {
+        stage: Stage::Complete,
+        key: "backup.complete",
+        params: MessageParams::from([("path", dest.to_string_lossy().to_string())]),
+        message: format!("Backed up existing folder to: {}", dest.display()),
+        percent: Some(100),
+        bytes_received: None,
+        speed: None,
+    });
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+/// Size of a folder, as counted by estimate_folder_size
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FolderSize {
+    pub file_count: u64,
+    pub total_bytes: u64,
+}
+
+/// Files counted between "folder-size-progress" events
+const SIZE_PROGRESS_INTERVAL: u64 = 500;
+
+/// Add up every file below a folder, calling `progress` every SIZE_PROGRESS_INTERVAL files
+/// Symlinks are counted as links, not followed
+fn walk_folder_size(dir: &Path, size: &mut FolderSize, progress: &mut dyn FnMut(&FolderSize)) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            walk_folder_size(&entry.path(), size, progress);
+            continue;
+        }
+        size.file_count += 1;
+        size.total_bytes += metadata.len();
+        if size.file_count % SIZE_PROGRESS_INTERVAL == 0 {
+            progress(size);
+        }
+    }
+}
+
+/// Count the files below `path` and their total size, without blocking the UI
+/// Emits "folder-size-progress" with the running totals while it walks
+#[tauri::command]
+pub async fn estimate_folder_size(path: String, window: Window) -> Result<FolderSize, String> {
+    let root = PathBuf::from(&path);
+    if !root.is_dir() {
+        return Err(format!("{} is not a folder", path));
+    }
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut size = FolderSize::default();
+        walk_folder_size(&root, &mut size, &mut |size| {
+            let _ = window.emit("folder-size-progress", ProgressPayload {
+                stage: Stage::Counting,
+                key: "size.counting",
+                params: MessageParams::from([("files", size.file_count.to_string())]),
+                message: format!("Counting files: {} so far", size.file_count),
+                percent: None,
+                bytes_received: Some(size.total_bytes),
+                speed: None,
+            });
+        });
+        size
+    })
+    .await
+    .map_err(|e| format!("Failed to measure folder: {}", e))
+}
 
-    fs::rename(&source, &dest)
-        .map_err(|e| format!("Failed to backup folder: {}", e))?;
+/// What a DiskUsageEntry is: the live install, a backup of it, downloaded data the app
+/// caches, or leftovers of an interrupted install or sync
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiskUsageCategory {
+    Install,
+    Backup,
+    Cache,
+    Temp,
+}
 
-    Ok(backup_name)
+/// One folder (or backup zip) the app's data takes up space in
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskUsageEntry {
+    pub category: DiskUsageCategory,
+    pub path: String,
+    pub file_count: u64,
+    pub total_bytes: u64,
+    /// Whether remove_disk_usage_entry may delete it (everything but the live install, and
+    /// temp folders an installation is writing right now)
+    pub removable: bool,
 }
 
-/// Delete the existing SLUS folder
+/// Where the app's data takes up space, with a total per category
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskUsage {
+    pub install_bytes: u64,
+    pub backup_bytes: u64,
+    pub cache_bytes: u64,
+    pub temp_bytes: u64,
+    pub entries: Vec<DiskUsageEntry>,
+}
+
+/// Every folder the app keeps data in for this textures directory, with its category
+fn disk_usage_locations(app: &AppHandle, textures_dir: &Path) -> Result<Vec<(DiskUsageCategory, PathBuf)>, String> {
+    let config = resolve_repo_config(app)?;
+    let mut locations = vec![(DiskUsageCategory::Install, textures_dir.join(&config.slus_folder))];
+
+    // Backups made next to the folder by backup_existing_folder
+    let backup_prefix = format!("{}_backup_", config.slus_folder);
+    if let Ok(entries) = fs::read_dir(textures_dir) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with(&backup_prefix) {
+                locations.push((DiskUsageCategory::Backup, entry.path()));
+            }
+        }
+    }
+
+    if let Ok(cache_dir) = app.path().app_cache_dir() {
+        locations.push((DiskUsageCategory::Cache, cache_dir.join("blobs")));
+        locations.push((DiskUsageCategory::Cache, cache_dir.join("archives")));
+        locations.push((DiskUsageCategory::Cache, cache_dir.join("partials")));
+    }
+
+    locations.push((DiskUsageCategory::Temp, textures_dir.join(&config.temp_dir_name)));
+    locations.push((DiskUsageCategory::Temp, textures_dir.join(STAGING_DIR_NAME)));
+
+    Ok(locations.into_iter().filter(|(_, path)| path.exists()).collect())
+}
+
+/// Files and bytes in a folder, or in a single file (a backup zip)
+fn location_size(path: &Path) -> FolderSize {
+    if path.is_dir() {
+        let mut size = FolderSize::default();
+        walk_folder_size(path, &mut size, &mut |_| {});
+        size
+    } else {
+        FolderSize {
+            file_count: 1,
+            total_bytes: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        }
+    }
+}
+
+/// Report how much space the live install, its backups, the download cache and temp folders
+/// take up
 #[tauri::command]
-pub fn delete_existing_folder(textures_dir: String) -> Result<(), String> {
-    let path = PathBuf::from(&textures_dir).join(SLUS_FOLDER);
+pub async fn get_disk_usage(app: AppHandle, textures_dir: String) -> Result<DiskUsage, String> {
+    let locations = disk_usage_locations(&app, Path::new(&textures_dir))?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let mut usage = DiskUsage {
+            install_bytes: 0,
+            backup_bytes: 0,
+            cache_bytes: 0,
+            temp_bytes: 0,
+            entries: Vec::new(),
+        };
+        for (category, path) in locations {
+            let size = location_size(&path);
+            *match category {
+                DiskUsageCategory::Install => &mut usage.install_bytes,
+                DiskUsageCategory::Backup => &mut usage.backup_bytes,
+                DiskUsageCategory::Cache => &mut usage.cache_bytes,
+                DiskUsageCategory::Temp => &mut usage.temp_bytes,
+            } += size.total_bytes;
+            usage.entries.push(DiskUsageEntry {
+                category,
+                removable: category != DiskUsageCategory::Install && !temp_clone_in_use(&path),
+                path: path.to_string_lossy().to_string(),
+                file_count: size.file_count,
+                total_bytes: size.total_bytes,
+            });
+        }
+        usage
+    })
+    .await
+    .map_err(|e| format!("Failed to measure disk usage: {}", e))
+}
+
+/// Delete a backup, cache or temp folder listed by get_disk_usage
+/// Only paths get_disk_usage reports as removable are accepted. They're deleted permanently,
+/// the point is to get the space back
+#[tauri::command]
+pub fn remove_disk_usage_entry(app: AppHandle, textures_dir: String, path: String) -> Result<(), String> {
+    let target = PathBuf::from(&path);
+    let removable = disk_usage_locations(&app, Path::new(&textures_dir))?
+        .into_iter()
+        .any(|(category, location)| {
+            location == target && category != DiskUsageCategory::Install && !temp_clone_in_use(&location)
+        });
+    if !removable {
+        return Err(format!("{} can't be removed from here", path));
+    }
+
+    delete_path(&target, true).map_err(|e| format!("Failed to remove {}: {}", path, e))
+}
+
+/// Everything uninstall_pack removed (or would remove, in a dry run)
+#[derive(Debug, Clone, Serialize)]
+pub struct UninstallReport {
+    pub dry_run: bool,
+    pub entries: Vec<DiskUsageEntry>,
+    pub total_bytes: u64,
+    /// Paths that could not be removed, each with the reason (the rest were removed)
+    pub failed: Vec<String>,
+}
+
+/// Remove the installed pack, its temp folders and optionally its backups and the download
+/// cache, then forget the install so the app offers a fresh one
+/// With `dry_run` nothing is removed, the report lists what would be
+/// The pack and its backups go to the Recycle Bin/Trash unless deletions are set to be permanent
+#[tauri::command]
+pub async fn uninstall_pack(
+    app: AppHandle,
+    textures_dir: String,
+    include_backups: bool,
+    include_cache: bool,
+    dry_run: bool,
+) -> Result<UninstallReport, String> {
+    let locations: Vec<(DiskUsageCategory, PathBuf)> = disk_usage_locations(&app, Path::new(&textures_dir))?
+        .into_iter()
+        .filter(|(category, _)| match category {
+            DiskUsageCategory::Install | DiskUsageCategory::Temp => true,
+            DiskUsageCategory::Backup => include_backups,
+            DiskUsageCategory::Cache => include_cache,
+        })
+        .collect();
+    if !dry_run {
+        if install_writes_in_progress() {
+            return Err("A sync or installation is running. Wait for it to finish or cancel it before uninstalling".to_string());
+        }
+        if locations.iter().any(|(_, path)| temp_clone_in_use(path)) {
+            return Err("An installation is still running. Cancel it before uninstalling".to_string());
+        }
+        // Nothing may keep watching or writing into the folders being removed
+        let _ = stop_folder_watch();
+    }
+
+    let delete_permanently = load_delete_permanently(&app);
+    let mut report = tauri::async_runtime::spawn_blocking(move || {
+        let mut report = UninstallReport { dry_run, entries: Vec::new(), total_bytes: 0, failed: Vec::new() };
+        for (category, path) in locations {
+            let size = location_size(&path);
+            if !dry_run {
+                let permanently = delete_permanently
+                    || matches!(category, DiskUsageCategory::Cache | DiskUsageCategory::Temp);
+                if let Err(e) = delete_path(&path, permanently) {
+                    report.failed.push(format!("{}: {}", path.display(), e));
+                    continue;
+                }
+            }
+            report.total_bytes += size.total_bytes;
+            report.entries.push(DiskUsageEntry {
+                category,
+                removable: true,
+                path: path.to_string_lossy().to_string(),
+                file_count: size.file_count,
+                total_bytes: size.total_bytes,
+            });
+        }
+        report
+    })
+    .await
+    .map_err(|e| format!("Failed to uninstall: {}", e))?;
+
+    let config = resolve_repo_config(&app)?;
+    if dry_run || Path::new(&textures_dir).join(&config.slus_folder).exists() {
+        return Ok(report);
+    }
+
+    // The pack is gone, so is everything the app knew about it (disabled textures are kept for
+    // a reinstall)
+    if let Err(e) = clear_sync_journal(&app) {
+        report.failed.push(e);
+    }
+    with_state_mut(&app, |state| {
+        state.initial_setup_done = false;
+        state.last_sync_commit = None;
+        state.last_sync_timestamp = None;
+        state.last_verification = None;
+        state.incremental_syncs_since_full = 0;
+        state.last_full_sync_timestamp = None;
+        Ok(())
+    })?;
+    Ok(report)
+}
+
+/// Open Explorer/Finder (or the Linux file manager) with a file selected
+/// With `textures_dir`, `path` is relative to the SLUS folder (as in sync and verification
+/// results). A path that doesn't exist (yet) shows its closest existing parent folder instead
+#[tauri::command]
+pub fn reveal_in_file_manager(app: AppHandle, path: String, textures_dir: Option<String>) -> Result<(), String> {
+    let mut target = match textures_dir {
+        Some(textures_dir) => {
+            let config = resolve_repo_config(&app)?;
+            PathBuf::from(textures_dir).join(&config.slus_folder).join(&path)
+        }
+        None => PathBuf::from(&path),
+    };
+    while !target.exists() {
+        if !target.pop() {
+            return Err(format!("{} does not exist", path));
+        }
+    }
+    tauri_plugin_opener::reveal_item_in_dir(&target)
+        .map_err(|e| format!("Failed to open the file manager: {}", e))
+}
+
+/// Stop a backup that is being copied or compressed
+#[tauri::command]
+pub fn cancel_backup() {
+    BACKUP_CANCELLED.store(true, Ordering::SeqCst);
+}
+
+/// Delete the existing SLUS folder (to the Recycle Bin/Trash unless deletions are permanent)
+#[tauri::command]
+pub fn delete_existing_folder(app: AppHandle, textures_dir: String) -> Result<(), String> {
+    let config = resolve_repo_config(&app)?;
+    let path = PathBuf::from(&textures_dir).join(&config.slus_folder);
 
     if !path.exists() {
         return Ok(());
     }
 
-    fs::remove_dir_all(&path)
+    delete_path(&path, load_delete_permanently(&app))
         .map_err(|e| format!("Failed to delete folder: {}", e))?;
 
     Ok(())
 }
 
-/// Check if a directory exists and is writable
-#[tauri::command]
-pub fn validate_directory(path: String) -> Result<bool, String> {
-    let path = PathBuf::from(&path);
+/// Windows' classic path length limit, still enforced by PCSX2 and many tools
+const WINDOWS_MAX_PATH: usize = 260;
 
-    if !path.exists() {
-        return Ok(false);
-    }
+/// Room left for the deepest texture path below the textures directory (SLUS folder included)
+const PACK_PATH_BUDGET: usize = 150;
 
-    if !path.is_dir() {
-        return Err("Path is not a directory".to_string());
+/// Result of checking a textures directory before installing into it
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationReport {
+    pub exists: bool,
+    pub is_directory: bool,
+    /// A test file could be created and deleted
+    pub writable: bool,
+    /// Bytes available to the current user on the directory's drive
+    pub free_bytes: Option<u64>,
+    pub path_length: usize,
+    /// Texture paths below this directory may exceed the Windows path limit
+    pub long_path_risk: bool,
+    /// What makes the directory unusable, or is worth a warning, in plain words
+    pub problems: Vec<String>,
+    /// Exists, is a directory and is writable
+    pub valid: bool,
+}
+
+/// Create and delete a file, which catches ACL-denied folders and read-only shares that the
+/// readonly bit doesn't show
+fn write_test(dir: &Path) -> Result<(), String> {
+    let test_path = dir.join(format!(".ncaa_write_test_{}", std::process::id()));
+    let result = fs::write(&test_path, b"write test").map_err(|e| e.to_string());
+    let removed = fs::remove_file(&test_path).map_err(|e| e.to_string());
+    result.and(removed)
+}
+
+/// Bytes available to the current user on the drive holding `path`
+#[cfg(unix)]
+fn free_space(path: &Path) -> Option<u64> {
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: c_path is NUL-terminated and stat is a valid out-pointer
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) } != 0 {
+        return None;
     }
+    // The field types differ between platforms (u32 on macOS, u64 on Linux)
+    #[allow(clippy::unnecessary_cast)]
+    Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+}
+
+/// Bytes available to the current user on the drive holding `path`
+#[cfg(windows)]
+fn free_space(path: &Path) -> Option<u64> {
+    use windows::core::HSTRING;
+    use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+
+    let mut available: u64 = 0;
+    // SAFETY: the out-pointer is valid for the duration of the call
+    unsafe { GetDiskFreeSpaceExW(&HSTRING::from(path.as_os_str()), Some(&mut available), None, None) }.ok()?;
+    Some(available)
+}
 
-    // Try to check write permission by checking metadata
-    match fs::metadata(&path) {
-        Ok(metadata) => {
-            if metadata.permissions().readonly() {
-                Err("Directory is read-only".to_string())
-            } else {
-                Ok(true)
+/// Check that a directory can take an install: it exists, is writable for real, has free space
+/// and is short enough for the pack's paths to fit the Windows path limit
+#[tauri::command]
+pub fn validate_directory(path: String) -> ValidationReport {
+    let dir = PathBuf::from(&path);
+    let exists = dir.exists();
+    let is_directory = dir.is_dir();
+    let mut problems = Vec::new();
+
+    let writable = if !exists {
+        problems.push("The directory does not exist".to_string());
+        false
+    } else if !is_directory {
+        problems.push("The path is not a directory".to_string());
+        false
+    } else {
+        match write_test(&dir) {
+            Ok(()) => true,
+            Err(e) => {
+                problems.push(format!("The directory is not writable: {}", e));
+                false
             }
         }
-        Err(e) => Err(format!("Cannot access directory: {}", e)),
+    };
+
+    let free_bytes = if is_directory { free_space(&dir) } else { None };
+
+    let path_length = path.chars().count();
+    let long_path_risk = cfg!(windows) && path_length + PACK_PATH_BUDGET > WINDOWS_MAX_PATH;
+    if long_path_risk {
+        problems.push(format!(
+            "The path is {} characters long, so some texture paths may exceed the Windows limit of {}. Choose a shorter folder",
+            path_length, WINDOWS_MAX_PATH
+        ));
     }
+
+    ValidationReport {
+        exists,
+        is_directory,
+        writable,
+        free_bytes,
+        path_length,
+        long_path_risk,
+        problems,
+        valid: exists && is_directory && writable,
+    }
+}
+
+/// Choose whether deletions skip the Recycle Bin/Trash
+#[tauri::command]
+pub fn set_delete_permanently(app: AppHandle, enabled: bool) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.delete_permanently = enabled;
+        Ok(())
+    })
 }
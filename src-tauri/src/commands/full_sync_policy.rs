@@ -0,0 +1,129 @@
+//! Periodic full syncs
+
+use crate::commands::progress::MessageParams;
+use crate::commands::state::{load_state, with_state_mut};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// When to force a full sync (both off by default)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FullSyncPolicy {
+    /// Run a full sync after this many incremental syncs in a row
+    #[serde(default)]
+    pub every_syncs: Option<u32>,
+    /// Run a full sync once the last one is this many days ago
+    #[serde(default)]
+    pub every_days: Option<u32>,
+}
+
+/// Why a full sync was forced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FullSyncReason {
+    /// This many incremental syncs ran since the last full sync
+    SyncCount(u32),
+    /// The last full sync was this many days ago
+    DaysSince(i64),
+    /// No full sync has been recorded yet
+    NeverRan,
+}
+
+impl FullSyncReason {
+    /// Message key, parameters and English text for the progress log
+    pub(crate) fn describe(&self) -> (&'static str, MessageParams, String) {
+        match self {
+            FullSyncReason::SyncCount(count) => (
+                "sync.forced_full_sync_count",
+                MessageParams::from([("count", count.to_string())]),
+                format!("{} incremental syncs since the last full sync, running a full sync to catch drift...", count),
+            ),
+            FullSyncReason::DaysSince(days) => (
+                "sync.forced_full_sync_days",
+                MessageParams::from([("days", days.to_string())]),
+                format!("Last full sync was {} days ago, running a full sync to catch drift...", days),
+            ),
+            FullSyncReason::NeverRan => (
+                "sync.forced_full_sync_never",
+                MessageParams::new(),
+                "No full sync recorded yet, running a full sync to catch drift...".to_string(),
+            ),
+        }
+    }
+}
+
+/// Whether the policy calls for a full sync now, and why
+pub(crate) fn full_sync_reason(
+    policy: &FullSyncPolicy,
+    incremental_syncs: u32,
+    last_full_sync: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<FullSyncReason> {
+    if let Some(every_syncs) = policy.every_syncs.filter(|n| *n > 0) {
+        if incremental_syncs >= every_syncs {
+            return Some(FullSyncReason::SyncCount(incremental_syncs));
+        }
+    }
+    let every_days = policy.every_days.filter(|n| *n > 0)?;
+    let Some(last) = last_full_sync.and_then(|t| DateTime::parse_from_rfc3339(t).ok()) else {
+        return Some(FullSyncReason::NeverRan);
+    };
+    let days = (now - last.with_timezone(&Utc)).num_days();
+    (days >= i64::from(every_days)).then_some(FullSyncReason::DaysSince(days))
+}
+
+/// Whether the active pack is due a full sync
+pub(crate) fn full_sync_due(app: &AppHandle) -> Option<FullSyncReason> {
+    let state = load_state(app.clone()).ok()?;
+    full_sync_reason(
+        &state.full_sync_policy,
+        state.incremental_syncs_since_full,
+        state.last_full_sync_timestamp.as_deref(),
+        Utc::now(),
+    )
+}
+
+/// Count a finished sync towards the policy
+/// Bookkeeping only, so failing to save the state doesn't fail the sync
+pub(crate) fn record_sync_mode(app: &AppHandle, full: bool) {
+    let _ = with_state_mut(app, |state| {
+        if full {
+            state.incremental_syncs_since_full = 0;
+            state.last_full_sync_timestamp = Some(Utc::now().to_rfc3339());
+        } else {
+            state.incremental_syncs_since_full = state.incremental_syncs_since_full.saturating_add(1);
+        }
+        Ok(())
+    });
+}
+
+/// Get the periodic full sync policy
+#[tauri::command]
+pub fn get_full_sync_policy(app: AppHandle) -> Result<FullSyncPolicy, String> {
+    Ok(load_state(app)?.full_sync_policy)
+}
+
+/// Save the periodic full sync policy
+#[tauri::command]
+pub fn set_full_sync_policy(app: AppHandle, policy: FullSyncPolicy) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.full_sync_policy = policy;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_sync_policy_forces_full_syncs() {
+        let now = DateTime::parse_from_rfc3339("2024-03-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let policy = FullSyncPolicy { every_syncs: Some(10), every_days: Some(30) };
+
+        assert_eq!(full_sync_reason(&FullSyncPolicy::default(), 50, None, now), None);
+        assert_eq!(full_sync_reason(&policy, 3, Some("2024-02-20T00:00:00Z"), now), None);
+        assert_eq!(full_sync_reason(&policy, 10, Some("2024-02-20T00:00:00Z"), now), Some(FullSyncReason::SyncCount(10)));
+        assert_eq!(full_sync_reason(&policy, 3, Some("2024-01-01T00:00:00Z"), now), Some(FullSyncReason::DaysSince(60)));
+        assert_eq!(full_sync_reason(&policy, 3, None, now), Some(FullSyncReason::NeverRan));
+    }
+}
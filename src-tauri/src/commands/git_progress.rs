@@ -0,0 +1,117 @@
+//! Structured progress parsed from git's progress lines
+
+use regex::Regex;
+use serde::Serialize;
+use std::sync::OnceLock;
+
+/// Environment that makes git print untranslated progress
+pub const GIT_LOCALE_ENV: [(&str, &str); 3] = [("LC_ALL", "C"), ("LANG", "C"), ("LANGUAGE", "C")];
+
+/// Phase of a clone or checkout reported by git
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitPhase {
+    Enumerating,
+    Counting,
+    Compressing,
+    Receiving,
+    Resolving,
+    Updating,
+}
+
+/// One parsed git progress line
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GitProgress {
+    pub phase: GitPhase,
+    /// Whether the line came from the server ("remote: ...")
+    pub remote: bool,
+    pub percent: Option<u32>,
+    /// Objects, deltas or files done so far
+    pub current: Option<u64>,
+    pub total: Option<u64>,
+    /// Bytes received so far (Receiving objects only)
+    pub bytes: Option<u64>,
+    /// Transfer speed in bytes per second (Receiving objects only)
+    pub bytes_per_second: Option<u64>,
+}
+
+fn counts_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(\d+)% \((\d+)/(\d+)\)").unwrap())
+}
+
+fn plain_count_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"^(\d+)(?:,|$)").unwrap())
+}
+
+fn bytes_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r", (\d+(?:\.\d+)?) (bytes?|KiB|MiB|GiB)(?: \||,|$)").unwrap())
+}
+
+fn speed_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\| (\d+(?:\.\d+)?) (bytes?|KiB|MiB|GiB)/s").unwrap())
+}
+
+/// Bytes from a value and one of git's human-readable units
+fn to_bytes(value: &str, unit: &str) -> Option<u64> {
+    let value: f64 = value.parse().ok()?;
+    let multiplier = match unit {
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "KiB" => 1024.0,
+        _ => 1.0,
+    };
+    Some((value * multiplier) as u64)
+}
+
+/// Parse a git progress line, None for anything that isn't one
+pub fn parse_git_progress(line: &str) -> Option<GitProgress> {
+    let line = line.trim();
+    let (remote, line) = match line.strip_prefix("remote:") {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, line),
+    };
+
+    let (label, rest) = line.split_once(':')?;
+    let phase = match label {
+        "Enumerating objects" => GitPhase::Enumerating,
+        "Counting objects" => GitPhase::Counting,
+        "Compressing objects" => GitPhase::Compressing,
+        "Receiving objects" => GitPhase::Receiving,
+        "Resolving deltas" => GitPhase::Resolving,
+        "Updating files" => GitPhase::Updating,
+        _ => return None,
+    };
+    let rest = rest.trim();
+
+    let mut progress = GitProgress {
+        phase,
+        remote,
+        percent: None,
+        current: None,
+        total: None,
+        bytes: None,
+        bytes_per_second: None,
+    };
+
+    if let Some(caps) = counts_re().captures(rest) {
+        progress.percent = caps[1].parse().ok();
+        progress.current = caps[2].parse().ok();
+        progress.total = caps[3].parse().ok();
+    } else if let Some(caps) = plain_count_re().captures(rest) {
+        // "Enumerating objects: 5, done." only knows the running count
+        progress.current = caps[1].parse().ok();
+    }
+
+    if let Some(caps) = bytes_re().captures(rest) {
+        progress.bytes = to_bytes(&caps[1], &caps[2]);
+    }
+    if let Some(caps) = speed_re().captures(rest) {
+        progress.bytes_per_second = to_bytes(&caps[1], &caps[2]);
+    }
+
+    Some(progress)
+}
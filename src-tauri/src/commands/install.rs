@@ -1,114 +1,373 @@
-use crate::config::{REPO_URL, SLUS_FOLDER, SPARSE_PATH, TEMP_DIR_NAME};
+use crate::commands::cleanup::load_junk_rules;
+use crate::commands::disabled::{apply_disabled_registry, load_disable_convention, load_disabled_registry, save_disabled_registry};
+use crate::commands::git_progress::{parse_git_progress, GitPhase, GitProgress, GIT_LOCALE_ENV};
+use crate::commands::network::http_client;
+use crate::commands::plan_confirm::issue_plan;
+#[cfg(target_os = "windows")]
+use crate::commands::power::{prevent_sleep, release_all_sleep_guards};
+use crate::commands::progress::{next_event_stamp, record_event, start_install_session, with_heartbeat, MessageParams, Stage};
+use crate::commands::state::with_state_mut;
+use crate::commands::sync::{fetch_github_tree_at_commit, load_protected_paths, scan_for_discrepancies, VerificationResult};
+use crate::commands::watcher::suppress_drift_detection;
+use crate::config::{resolve_repo_config, RepoConfig};
+use chrono::Utc;
 use regex::Regex;
 use serde::Serialize;
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
 use std::io::{BufReader, Read as IoRead};
-use std::path::PathBuf;
-#[cfg(not(target_os = "windows"))]
-use std::process::{Command, Stdio};
-#[cfg(target_os = "windows")]
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::fs;
 use std::sync::{Arc, Mutex};
-use tauri::{Emitter, Window};
+use tauri::{AppHandle, Emitter, Manager, Window};
 
 // Track running process PIDs so we can kill them on app exit
+// On Unix each one also leads its own process group (portable-pty starts children in a new
+// session), so signalling the group reaches git's helpers (git-remote-https, index-pack) too
 static RUNNING_PIDS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
 
-/// Kill all tracked processes (called on app exit)
+// Temp clones an installation is currently writing, removed on app exit so a clone cut off
+// mid-transfer doesn't stay behind in the textures folder
+static ACTIVE_TEMP_CLONES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+/// How long tracked processes get to exit after being asked to stop before they're killed
+const TERMINATE_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Whether an installation is writing the temp clone at `path` right now
+pub(crate) fn temp_clone_in_use(path: &Path) -> bool {
+    ACTIVE_TEMP_CLONES
+        .lock()
+        .map(|clones| clones.iter().any(|clone| clone == path))
+        .unwrap_or(false)
+}
+
+/// Untracks a temp clone when the installation writing it returns
+struct TempCloneGuard(PathBuf);
+
+impl TempCloneGuard {
+    fn new(path: &Path) -> Self {
+        if let Ok(mut clones) = ACTIVE_TEMP_CLONES.lock() {
+            clones.push(path.to_path_buf());
+        }
+        TempCloneGuard(path.to_path_buf())
+    }
+}
+
+impl Drop for TempCloneGuard {
+    fn drop(&mut self) {
+        if let Ok(mut clones) = ACTIVE_TEMP_CLONES.lock() {
+            clones.retain(|path| path != &self.0);
+        }
+    }
+}
+
+/// Whether a process is still running, per tasklist
+#[cfg(target_os = "windows")]
+fn process_running(pid: u32) -> bool {
+    Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+        .output()
+        .is_ok_and(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .split_whitespace()
+                .any(|field| field == pid.to_string())
+        })
+}
+
+/// Send a signal to a process group, returning whether it exists
+#[cfg(not(target_os = "windows"))]
+fn signal_process_group(pgid: u32, signal: libc::c_int) -> bool {
+    // SAFETY: kill only sends a signal; a negative PID addresses the process group
+    unsafe { libc::kill(-(pgid as libc::pid_t), signal) == 0 }
+}
+
+/// Stop all tracked processes
+/// They're asked to stop first (SIGTERM on Unix, taskkill without /F on Windows) and killed if
+/// still running after a grace period
 pub fn cleanup_processes() {
-    if let Ok(pids) = RUNNING_PIDS.lock() {
-        for pid in pids.iter() {
-            #[cfg(target_os = "windows")]
-            {
-                // Use taskkill to kill the process tree
-                let _ = Command::new("taskkill")
-                    .args(["/F", "/T", "/PID", &pid.to_string()])
-                    .output();
-            }
-            #[cfg(not(target_os = "windows"))]
-            {
-                // On Unix, kill the process group
-                let _ = Command::new("kill")
-                    .args(["-9", &pid.to_string()])
-                    .output();
-            }
+    let pids = match RUNNING_PIDS.lock() {
+        Ok(pids) => pids.clone(),
+        Err(_) => return,
+    };
+    if pids.is_empty() {
+        return;
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        for pid in &pids {
+            let _ = Command::new("taskkill")
+                .args(["/T", "/PID", &pid.to_string()])
+                .output();
+        }
+
+        let deadline = std::time::Instant::now() + TERMINATE_GRACE_PERIOD;
+        while pids.iter().any(|&pid| process_running(pid)) && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(250));
+        }
+
+        for pid in &pids {
+            // Force-kill whatever is left of the process tree
+            let _ = Command::new("taskkill")
+                .args(["/F", "/T", "/PID", &pid.to_string()])
+                .output();
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        for &pid in &pids {
+            signal_process_group(pid, libc::SIGTERM);
+        }
+
+        // Signal 0 only checks that the group still exists
+        let deadline = std::time::Instant::now() + TERMINATE_GRACE_PERIOD;
+        while pids.iter().any(|&pid| signal_process_group(pid, 0)) && std::time::Instant::now() < deadline {
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+
+        for &pid in &pids {
+            signal_process_group(pid, libc::SIGKILL);
         }
     }
 }
 
-#[derive(Clone, Serialize)]
+/// Orderly shutdown on app exit: stop git, remove the temp clone it was writing and let the
+/// computer sleep again
+pub fn shutdown_git_operations() {
+    cleanup_processes();
+
+    let clones = ACTIVE_TEMP_CLONES
+        .lock()
+        .map(|mut clones| std::mem::take(&mut *clones))
+        .unwrap_or_default();
+    for temp_path in clones {
+        let _ = fs::remove_dir_all(&temp_path);
+    }
+
+    release_all_sleep_guards();
+}
+
+/// Progress payload for install events
+/// Serialized with the operation id and sequence number (see progress::next_event_stamp)
+#[derive(Clone)]
 pub struct ProgressPayload {
-    pub stage: String,
+    pub stage: Stage,
+    /// Stable message identifier for localization (e.g. "install.git_output")
+    pub key: &'static str,
+    pub params: MessageParams,
+    /// English message, used when the frontend has no translation for the key
     pub message: String,
     pub percent: Option<u32>,
+    /// Bytes transferred so far, while a download reports them
+    pub bytes_received: Option<u64>,
+    /// Transfer speed in bytes per second, while a download reports it
+    pub speed: Option<u64>,
 }
 
-/// Get the path to git executable
-/// On Windows x64, use bundled MinGit if available
-/// On Windows ARM, require system git
-/// On macOS, use system git
-fn get_git_path() -> Result<String, String> {
+impl Serialize for ProgressPayload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Payload<'a> {
+            stage: Stage,
+            key: &'a str,
+            params: &'a MessageParams,
+            message: &'a str,
+            percent: Option<u32>,
+            bytes_received: Option<u64>,
+            speed: Option<u64>,
+            operation_id: u64,
+            seq: u64,
+        }
+
+        let (operation_id, seq) = next_event_stamp();
+        let payload = Payload {
+            stage: self.stage,
+            key: self.key,
+            params: &self.params,
+            message: &self.message,
+            percent: self.percent,
+            bytes_received: self.bytes_received,
+            speed: self.speed,
+            operation_id,
+            seq,
+        };
+        record_event(operation_id, &payload);
+        payload.serialize(serializer)
+    }
+}
+
+/// Find the bundled MinGit executable (Windows x64 only)
+pub fn find_bundled_mingit() -> Option<PathBuf> {
     #[cfg(target_os = "windows")]
     {
-        let is_arm = cfg!(target_arch = "aarch64");
+        let exe_path = std::env::current_exe().ok()?;
+        let exe_dir = exe_path.parent()?;
+
+        // Try multiple possible resource paths
+        let paths_to_try = [
+            // Full nested path
+            exe_dir.join("resources").join("mingit").join("x64").join("cmd").join("git.exe"),
+            // Flattened cmd folder
+            exe_dir.join("resources").join("cmd").join("git.exe"),
+            // Direct in resources
+            exe_dir.join("resources").join("git.exe"),
+        ];
 
-        // On x64, check for bundled MinGit first
-        if !is_arm {
-            if let Ok(exe_path) = std::env::current_exe() {
-                if let Some(exe_dir) = exe_path.parent() {
-                    // Try multiple possible resource paths
-                    let paths_to_try = [
-                        // Full nested path
-                        exe_dir.join("resources").join("mingit").join("x64").join("cmd").join("git.exe"),
-                        // Flattened cmd folder
-                        exe_dir.join("resources").join("cmd").join("git.exe"),
-                        // Direct in resources
-                        exe_dir.join("resources").join("git.exe"),
-                    ];
-
-                    for mingit_path in &paths_to_try {
-                        if mingit_path.exists() {
-                            return Ok(mingit_path.to_string_lossy().to_string());
-                        }
-                    }
-                }
-            }
-        }
+        paths_to_try.into_iter().find(|p| p.exists())
+    }
 
-        // Fall back to system git
-        if Command::new("git").arg("--version").output().is_ok() {
-            return Ok("git".to_string());
-        }
+    #[cfg(not(target_os = "windows"))]
+    {
+        None
+    }
+}
 
-        // Build error message based on architecture
-        if is_arm {
-            Err("Git not found. On Windows ARM, please install Git manually from https://git-scm.com/download/win".to_string())
-        } else {
-            let mut err_msg = String::from("Git not found. Searched locations:\n");
+/// Where the resolved git executable comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+#[serde(rename_all = "snake_case")]
+pub enum GitSource {
+    /// MinGit shipped with the app (Windows x64)
+    Bundled,
+    /// git on the system PATH
+    System,
+}
+
+/// What the user can do to get a usable git
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitRemediation {
+    /// Install Git for Windows
+    InstallGitForWindows,
+    /// Install the Xcode Command Line Tools (macOS)
+    InstallXcodeTools,
+    /// Install git with the distribution's package manager (Linux)
+    InstallPackage,
+    /// The git found is too old for sparse, partial clones
+    UpdateGit,
+}
+
+/// Whether git is usable, where it was found, and how to fix it if not
+#[derive(Debug, Clone, Serialize)]
+pub struct GitStatus {
+    pub found: bool,
+    pub path: Option<String>,
+    /// Version number (e.g. "2.52.0")
+    pub version: Option<String>,
+    pub source: Option<GitSource>,
+    /// Places that were checked, in order
+    pub searched_locations: Vec<String>,
+    /// None when git is found and recent enough
+    pub remediation: Option<GitRemediation>,
+    /// English description of the problem (empty when git is usable)
+    pub message: String,
+}
+
+/// Remediation when no git is found on this platform
+fn missing_git_remediation() -> GitRemediation {
+    if cfg!(target_os = "windows") {
+        GitRemediation::InstallGitForWindows
+    } else if cfg!(target_os = "macos") {
+        GitRemediation::InstallXcodeTools
+    } else {
+        GitRemediation::InstallPackage
+    }
+}
+
+/// Message shown when no git is found on this platform
+fn missing_git_message() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "Git not found. Please install Git from https://git-scm.com/download/win"
+    } else if cfg!(target_os = "macos") {
+        "Git not found. Please install Xcode Command Line Tools by running: xcode-select --install"
+    } else {
+        "Git not found. Please install git with your distribution's package manager (e.g. sudo apt install git)"
+    }
+}
+
+/// Whether a git executable starts and reports its version
+#[cfg(target_os = "windows")]
+fn git_runs(git_path: &Path) -> bool {
+    Command::new(git_path)
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// Find the git executable to use
+/// On Windows x64, use bundled MinGit if it works
+/// Then system git (the only option on Windows ARM, macOS and Linux)
+fn resolve_git_status() -> GitStatus {
+    let mut searched_locations = Vec::new();
+    let mut found = None;
+
+    #[cfg(target_os = "windows")]
+    {
+        // On x64, check for bundled MinGit first (skipping a copy too damaged to start)
+        if !cfg!(target_arch = "aarch64") {
             if let Ok(exe_path) = std::env::current_exe() {
                 if let Some(exe_dir) = exe_path.parent() {
-                    err_msg.push_str(&format!("  - {}\\resources\\mingit\\x64\\cmd\\git.exe\n", exe_dir.display()));
-                    err_msg.push_str(&format!("  - {}\\resources\\cmd\\git.exe\n", exe_dir.display()));
-                    err_msg.push_str(&format!("  - {}\\resources\\git.exe\n", exe_dir.display()));
+                    searched_locations.push(format!("{}\\resources\\mingit\\x64\\cmd\\git.exe", exe_dir.display()));
+                    searched_locations.push(format!("{}\\resources\\cmd\\git.exe", exe_dir.display()));
+                    searched_locations.push(format!("{}\\resources\\git.exe", exe_dir.display()));
                 }
             }
-            err_msg.push_str("  - System PATH\n");
-            err_msg.push_str("\nPlease reinstall the app or install Git from https://git-scm.com/download/win");
-            Err(err_msg)
+            found = find_bundled_mingit()
+                .filter(|path| git_runs(path))
+                .map(|path| (path.to_string_lossy().to_string(), GitSource::Bundled));
         }
     }
 
-    #[cfg(not(target_os = "windows"))]
-    {
-        // On macOS/Linux, check for system git
-        if Command::new("git").arg("--version").output().is_ok() {
-            return Ok("git".to_string());
-        }
+    // Fall back to system git
+    searched_locations.push("System PATH".to_string());
+    if found.is_none() && Command::new("git").arg("--version").output().is_ok() {
+        found = Some(("git".to_string(), GitSource::System));
+    }
+
+    let Some((path, source)) = found else {
+        return GitStatus {
+            found: false,
+            path: None,
+            version: None,
+            source: None,
+            searched_locations,
+            remediation: Some(missing_git_remediation()),
+            message: missing_git_message().to_string(),
+        };
+    };
+
+    let git_info = inspect_git(&path);
+    let (remediation, message) = if git_info.meets_requirements {
+        (None, String::new())
+    } else {
+        (
+            Some(GitRemediation::UpdateGit),
+            format!(
+                "Git {} is too old to install the textures (sparse checkout and partial clone need Git {} or newer). Please update Git from https://git-scm.com/downloads",
+                git_info.version.as_deref().unwrap_or("(unknown version)"),
+                git_info.minimum_version
+            ),
+        )
+    };
 
-        Err("Git not found. Please install Xcode Command Line Tools by running: xcode-select --install".to_string())
+    GitStatus {
+        found: true,
+        path: Some(path),
+        version: git_info.version,
+        source: Some(source),
+        searched_locations,
+        remediation,
+        message,
     }
 }
 
+/// Get the path to git executable (see resolve_git_status for the search order)
+fn get_git_path() -> Result<String, String> {
+    let status = resolve_git_status();
+    status.path.ok_or(status.message)
+}
+
 /// Check if git is available
 #[tauri::command]
 pub fn check_git_installed() -> Result<bool, String> {
@@ -118,50 +377,148 @@ pub fn check_git_installed() -> Result<bool, String> {
     }
 }
 
-/// Get the git installation error message (for display to user)
+/// Get whether git is usable, where it was found, and how to fix it if not (for the setup screen)
 #[tauri::command]
-pub fn get_git_error() -> String {
-    match get_git_path() {
-        Ok(_) => String::new(),
-        Err(e) => e,
+pub fn get_git_status() -> GitStatus {
+    resolve_git_status()
+}
+
+/// First git version with `clone --sparse` and `git sparse-checkout`
+const SPARSE_CHECKOUT_MIN_VERSION: (u32, u32, u32) = (2, 25, 0);
+
+/// First git version with partial clone (`clone --filter=blob:none`)
+const PARTIAL_CLONE_MIN_VERSION: (u32, u32, u32) = (2, 19, 0);
+
+/// Error prefix for a git too old to install with (the frontend shows an upgrade hint for it)
+pub const GIT_TOO_OLD_ERROR: &str = "GIT_TOO_OLD";
+
+/// Resolved git executable and the features the installer needs from it
+#[derive(Debug, Clone, Serialize)]
+pub struct GitInfo {
+    pub path: String,
+    /// Version number (e.g. "2.52.0"), None if `git --version` couldn't be parsed
+    pub version: Option<String>,
+    pub supports_sparse_checkout: bool,
+    pub supports_partial_clone: bool,
+    /// Whether the installer can use this git
+    pub meets_requirements: bool,
+    /// Oldest version the installer supports
+    pub minimum_version: String,
+}
+
+/// Parse the version from `git --version` output
+/// e.g. "git version 2.39.3 (Apple Git-146)" or "git version 2.52.0.windows.1"
+fn parse_git_version(output: &str) -> Option<(u32, u32, u32)> {
+    let version = output.trim().strip_prefix("git version ")?.split_whitespace().next()?;
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+fn format_version((major, minor, patch): (u32, u32, u32)) -> String {
+    format!("{}.{}.{}", major, minor, patch)
+}
+
+/// Query the version of a git executable and the features it supports
+/// An unparseable version is given the benefit of the doubt (git itself reports a clear error then)
+fn inspect_git(git_path: &str) -> GitInfo {
+    let version = Command::new(git_path)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| parse_git_version(&String::from_utf8_lossy(&output.stdout)));
+
+    let supports_sparse_checkout = version.is_none_or(|v| v >= SPARSE_CHECKOUT_MIN_VERSION);
+    let supports_partial_clone = version.is_none_or(|v| v >= PARTIAL_CLONE_MIN_VERSION);
+
+    GitInfo {
+        path: git_path.to_string(),
+        version: version.map(format_version),
+        supports_sparse_checkout,
+        supports_partial_clone,
+        meets_requirements: supports_sparse_checkout && supports_partial_clone,
+        minimum_version: format_version(SPARSE_CHECKOUT_MIN_VERSION.max(PARTIAL_CLONE_MIN_VERSION)),
     }
 }
 
+/// Get the resolved git executable, its version and supported features
+#[tauri::command]
+pub fn get_git_info() -> Result<GitInfo, String> {
+    Ok(inspect_git(&get_git_path()?))
+}
+
 /// Strip ANSI escape codes from a string
+/// Covers CSI sequences (colors, cursor moves, ConPTY's "?25l") and OSC ones (window titles)
 fn strip_ansi_codes(s: &str) -> String {
-    let ansi_re = Regex::new(r"\x1b\[[0-9;]*[a-zA-Z]").unwrap();
+    let ansi_re = Regex::new(r"\x1b\[[0-9;?]*[a-zA-Z]|\x1b\][^\x07]*\x07").unwrap();
     ansi_re.replace_all(s, "").to_string()
 }
 
 /// Detect the stage and percentage from git output
-fn detect_git_stage(line: &str) -> (Option<&'static str>, Option<u32>) {
-    let percent_re = Regex::new(r"(\d+)%").ok();
-    let percent = percent_re
-        .as_ref()
-        .and_then(|re| re.captures(line))
-        .and_then(|caps| caps.get(1))
-        .and_then(|m| m.as_str().parse().ok());
+fn detect_git_stage(line: &str, progress: Option<&GitProgress>) -> (Option<Stage>, Option<u32>) {
+    let Some(progress) = progress else {
+        // Other server messages come while it prepares the pack
+        let stage = line.starts_with("remote:").then_some(Stage::Compressing);
+        return (stage, None);
+    };
 
-    if line.contains("Receiving objects:") {
-        return (Some("downloading"), percent);
-    }
-    if line.contains("Updating files:") {
-        return (Some("extracting"), percent);
-    }
-    if line.contains("Resolving deltas:") {
-        return (Some("downloading"), percent);
-    }
-    if line.contains("Compressing objects:") {
-        return (Some("compressing"), percent);
-    }
-    if line.contains("Enumerating objects:") || line.contains("Counting objects:") {
-        return (Some("compressing"), percent);
-    }
-    if line.contains("remote:") {
-        return (Some("compressing"), percent);
+    let stage = match progress.phase {
+        GitPhase::Enumerating | GitPhase::Counting | GitPhase::Compressing => Stage::Compressing,
+        GitPhase::Receiving | GitPhase::Resolving => Stage::Downloading,
+        GitPhase::Updating => Stage::Extracting,
+    };
+    (Some(stage), progress.percent)
+}
+
+/// Record a line of git output and report it as install progress
+/// (plus a structured "install-git-progress" event for progress lines)
+/// When detect_stages is false, always uses default_stage
+fn emit_git_output(
+    window: &Window,
+    line: String,
+    default_stage: Stage,
+    detect_stages: bool,
+    recent_lines: Option<&Mutex<Vec<String>>>,
+) {
+    // Store recent lines for error reporting
+    if let Some(lines) = recent_lines {
+        if let Ok(mut lines) = lines.lock() {
+            lines.push(line.clone());
+            // Keep only the last 10 lines
+            if lines.len() > 10 {
+                lines.remove(0);
+            }
+        }
     }
 
-    (None, percent)
+    let progress = parse_git_progress(&line);
+    let (detected_stage, percent) = detect_git_stage(&line, progress.as_ref());
+    let stage = if detect_stages {
+        detected_stage.unwrap_or(default_stage)
+    } else {
+        default_stage
+    };
+
+    let bytes_received = progress.as_ref().and_then(|p| p.bytes);
+    let speed = progress.as_ref().and_then(|p| p.bytes_per_second);
+    if let Some(progress) = progress {
+        let _ = window.emit("install-git-progress", progress);
+    }
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage,
+            key: "install.git_output",
+            params: MessageParams::from([("line", line.clone())]),
+            message: line,
+            percent,
+            bytes_received,
+            speed,
+        },
+    );
 }
 
 /// Read output handling both \r and \n as line terminators
@@ -171,7 +528,7 @@ fn detect_git_stage(line: &str) -> (Option<&'static str>, Option<u32>) {
 fn read_output_with_progress<R: IoRead>(
     reader: R,
     window: &Window,
-    default_stage: &str,
+    default_stage: Stage,
     detect_stages: bool,
     recent_lines: Option<Arc<Mutex<Vec<String>>>>
 ) {
@@ -188,32 +545,7 @@ fn read_output_with_progress<R: IoRead>(
                         if let Ok(line) = String::from_utf8(buffer.clone()) {
                             let line = strip_ansi_codes(line.trim());
                             if !line.is_empty() {
-                                // Store recent lines for error reporting
-                                if let Some(ref lines) = recent_lines {
-                                    if let Ok(mut lines) = lines.lock() {
-                                        lines.push(line.clone());
-                                        // Keep only the last 10 lines
-                                        if lines.len() > 10 {
-                                            lines.remove(0);
-                                        }
-                                    }
-                                }
-
-                                let (detected_stage, percent) = detect_git_stage(&line);
-                                let stage = if detect_stages {
-                                    detected_stage.unwrap_or(default_stage)
-                                } else {
-                                    default_stage
-                                };
-
-                                let _ = window.emit(
-                                    "install-progress",
-                                    ProgressPayload {
-                                        stage: stage.to_string(),
-                                        message: line,
-                                        percent,
-                                    },
-                                );
+                                emit_git_output(window, line, default_stage, detect_stages, recent_lines.as_deref());
                             }
                         }
                         buffer.clear();
@@ -231,240 +563,76 @@ fn read_output_with_progress<R: IoRead>(
         if let Ok(line) = String::from_utf8(buffer) {
             let line = strip_ansi_codes(line.trim());
             if !line.is_empty() {
-                // Store recent lines for error reporting
-                if let Some(ref lines) = recent_lines {
-                    if let Ok(mut lines) = lines.lock() {
-                        lines.push(line.clone());
-                        if lines.len() > 10 {
-                            lines.remove(0);
-                        }
-                    }
-                }
-
-                let (detected_stage, percent) = detect_git_stage(&line);
-                let stage = if detect_stages {
-                    detected_stage.unwrap_or(default_stage)
-                } else {
-                    default_stage
-                };
-
-                let _ = window.emit(
-                    "install-progress",
-                    ProgressPayload {
-                        stage: stage.to_string(),
-                        message: line,
-                        percent,
-                    },
-                );
+                emit_git_output(window, line, default_stage, detect_stages, recent_lines.as_deref());
             }
         }
     }
 }
 
-/// Run a git command with PTY support (using script command on macOS/Linux)
-/// This ensures git outputs progress even when not connected to a real terminal
-/// Uses caffeinate to prevent system sleep during long operations
-/// When detect_stages is false, always uses default_stage instead of detecting from output
-/// Returns Ok(true) on success, Ok(false) on failure with error details, or Err on spawn failure
-#[cfg(not(target_os = "windows"))]
-fn run_git_with_pty(
-    git_path: &str,
-    args: &[&str],
-    working_dir: &PathBuf,
-    window: &Window,
-    default_stage: &str,
-    detect_stages: bool,
-) -> Result<(bool, String), String> {
-    // Use 'caffeinate' to prevent sleep, 'script' to create a PTY for git
-    // caffeinate -d: prevent display sleep (also prevents screensaver)
-    // script -q /dev/null: create PTY without saving typescript
-    let mut cmd_args: Vec<&str> = vec!["-d", "script", "-q", "/dev/null", git_path];
-    cmd_args.extend(args);
-
-    let mut cmd = Command::new("caffeinate")
-        .args(&cmd_args)
-        .current_dir(working_dir)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("Failed to start command: {}", e))?;
-
-    // Collect recent output for error reporting
-    let recent_lines = Arc::new(Mutex::new(Vec::<String>::new()));
-
-    // script command outputs everything to stdout (including what would normally be stderr)
-    if let Some(stdout) = cmd.stdout.take() {
-        read_output_with_progress(stdout, window, default_stage, detect_stages, Some(recent_lines.clone()));
-    }
-
-    let status = cmd
-        .wait()
-        .map_err(|e| format!("Command failed: {}", e))?;
-
-    // Get recent output for error message
-    let error_context = recent_lines.lock()
-        .map(|lines| lines.join("\n"))
-        .unwrap_or_default();
-
-    Ok((status.success(), error_context))
-}
-
-/// Run a git command on Windows using ConPTY for proper progress output
-/// Uses SetThreadExecutionState to prevent system sleep during long operations
+/// Run a git command in a pseudo-terminal, so it prints progress as it would in a terminal
+/// (a Unix PTY on macOS/Linux, ConPTY on Windows, both through portable-pty)
 /// When detect_stages is false, always uses default_stage instead of detecting from output
 /// Returns Ok((true, _)) on success, Ok((false, error_context)) on failure, or Err on spawn failure
-#[cfg(target_os = "windows")]
 fn run_git_with_pty(
     git_path: &str,
     args: &[&str],
-    working_dir: &PathBuf,
+    working_dir: &Path,
     window: &Window,
-    default_stage: &str,
+    default_stage: Stage,
     detect_stages: bool,
 ) -> Result<(bool, String), String> {
-    use conpty::spawn;
-    use std::io::Read as _;
-    use windows::Win32::System::Power::{SetThreadExecutionState, ES_CONTINUOUS, ES_SYSTEM_REQUIRED, ES_DISPLAY_REQUIRED};
-
-    // Prevent system sleep during the operation
-    unsafe {
-        SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED | ES_DISPLAY_REQUIRED);
-    }
-
-    let working_dir_str = working_dir.to_string_lossy().to_string();
-
-    // Build command arguments
-    // For clone command, replace "." destination with full path
-    // For other commands, use -C flag to set working directory
-    let is_clone = args.first() == Some(&"clone");
-
-    let full_args: Vec<String> = if is_clone {
-        // For clone, replace "." with the full path
-        args.iter().map(|arg| {
-            if *arg == "." {
-                format!("\"{}\"", working_dir_str)
-            } else if arg.contains(' ') {
-                format!("\"{}\"", arg)
-            } else {
-                arg.to_string()
-            }
-        }).collect()
-    } else {
-        // For other commands, use -C flag
-        let mut v: Vec<String> = vec![
-            "-C".to_string(),
-            format!("\"{}\"", working_dir_str),
-        ];
-        for arg in args {
-            if arg.contains(' ') {
-                v.push(format!("\"{}\"", arg));
-            } else {
-                v.push(arg.to_string());
-            }
-        }
-        v
-    };
-
-    // Build command line - use cmd.exe /c wrapper when path has spaces
-    // ConPTY doesn't handle quoted executable paths correctly
-    let command_line = if git_path.contains(' ') {
-        format!("cmd.exe /c \"\"{}\" {}\"", git_path, full_args.join(" "))
-    } else {
-        format!("{} {}", git_path, full_args.join(" "))
-    };
+    let pair = native_pty_system()
+        .openpty(PtySize {
+            rows: 24,
+            cols: 120,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .map_err(|e| format!("Failed to open a pseudo-terminal: {}", e))?;
+    let reader = pair
+        .master
+        .try_clone_reader()
+        .map_err(|e| format!("Failed to read from the pseudo-terminal: {}", e))?;
+
+    let mut cmd = CommandBuilder::new(git_path);
+    cmd.args(args);
+    cmd.cwd(working_dir);
+    for (key, value) in GIT_LOCALE_ENV {
+        cmd.env(key, value);
+    }
 
-    // Spawn process using ConPTY (Windows Pseudo Console)
-    // This makes git think it's connected to a real terminal
-    let mut proc = spawn(&command_line)
-        .map_err(|e| {
-            unsafe { SetThreadExecutionState(ES_CONTINUOUS); }
-            format!("Failed to spawn process with ConPTY: {}", e)
-        })?;
+    let mut child = pair
+        .slave
+        .spawn_command(cmd)
+        .map_err(|e| format!("Failed to start command: {}", e))?;
+    // Only the child should hold the terminal's other end, or the reader never sees EOF
+    drop(pair.slave);
 
     // Track the PID so we can kill it if the app closes
-    let pid = proc.pid();
-    if let Ok(mut pids) = RUNNING_PIDS.lock() {
+    let pid = child.process_id();
+    if let (Some(pid), Ok(mut pids)) = (pid, RUNNING_PIDS.lock()) {
         pids.push(pid);
     }
 
-    // Read output from the PTY in a separate thread
-    // This prevents blocking if the PTY doesn't send EOF properly
-    let output = proc.output().map_err(|e| {
-        unsafe { SetThreadExecutionState(ES_CONTINUOUS); }
-        format!("Failed to get process output: {}", e)
-    })?;
-
-    let window_clone = window.clone();
-    let default_stage_owned = default_stage.to_string();
-
-    // Collect recent output for error reporting
+    // Read output in a separate thread, as the PTY doesn't always send EOF when git exits
     let recent_lines = Arc::new(Mutex::new(Vec::<String>::new()));
-    let recent_lines_clone = recent_lines.clone();
-
-    let reader_handle = std::thread::spawn(move || {
-        let mut output = output;
-        let mut buffer = [0u8; 1];
-        let mut line_buffer = Vec::new();
-
-        loop {
-            match output.read(&mut buffer) {
-                Ok(0) => break, // EOF
-                Ok(_) => {
-                    let byte = buffer[0];
-                    if byte == b'\r' || byte == b'\n' {
-                        if !line_buffer.is_empty() {
-                            if let Ok(line) = String::from_utf8(line_buffer.clone()) {
-                                let line = line.trim().to_string();
-                                if !line.is_empty() {
-                                    // Store recent lines for error reporting
-                                    if let Ok(mut lines) = recent_lines_clone.lock() {
-                                        lines.push(line.clone());
-                                        if lines.len() > 10 {
-                                            lines.remove(0);
-                                        }
-                                    }
-
-                                    let (detected_stage, percent) = detect_git_stage(&line);
-                                    let stage = if detect_stages {
-                                        detected_stage.unwrap_or(&default_stage_owned)
-                                    } else {
-                                        &default_stage_owned
-                                    };
-
-                                    let _ = window_clone.emit(
-                                        "install-progress",
-                                        ProgressPayload {
-                                            stage: stage.to_string(),
-                                            message: line,
-                                            percent,
-                                        },
-                                    );
-                                }
-                            }
-                            line_buffer.clear();
-                        }
-                    } else {
-                        line_buffer.push(byte);
-                    }
-                }
-                Err(_) => break,
-            }
-        }
-    });
+    let reader_handle = {
+        let window = window.clone();
+        let recent_lines = recent_lines.clone();
+        std::thread::spawn(move || {
+            read_output_with_progress(reader, &window, default_stage, detect_stages, Some(recent_lines));
+        })
+    };
 
-    // Wait for process to exit (this returns even if reader is still running)
-    let exit_code = proc.wait(None).map_err(|e| {
-        unsafe { SetThreadExecutionState(ES_CONTINUOUS); }
-        format!("Failed to wait for process: {}", e)
-    })?;
+    let status = child.wait().map_err(|e| format!("Command failed: {}", e));
 
     // Remove PID from tracking list
-    if let Ok(mut pids) = RUNNING_PIDS.lock() {
+    if let (Some(pid), Ok(mut pids)) = (pid, RUNNING_PIDS.lock()) {
         pids.retain(|&p| p != pid);
     }
 
-    // Drop proc to close the PTY, which should cause the reader to get EOF
-    drop(proc);
+    // Close the PTY, which should cause the reader to get EOF
+    drop(pair.master);
 
     // Give the reader thread a short time to finish reading any buffered output
     // Don't block forever - if it's stuck, just move on
@@ -475,10 +643,7 @@ fn run_git_with_pty(
     }
     // Don't call join() - if thread is stuck, let it be orphaned
 
-    // Restore normal sleep behavior
-    unsafe {
-        SetThreadExecutionState(ES_CONTINUOUS);
-    }
+    let status = status?;
 
     // Get recent output for error message
     let mut error_context = recent_lines.lock()
@@ -486,72 +651,270 @@ fn run_git_with_pty(
         .unwrap_or_default();
 
     // If command failed, include the command line for debugging
-    if exit_code != 0 {
-        error_context.push_str(&format!("\n\n[Debug] Command: {}", command_line));
+    if !status.success() {
+        error_context.push_str(&format!("\n\n[Debug] Command: {} {}", git_path, args.join(" ")));
     }
 
-    // Exit code 0 means success
-    Ok((exit_code == 0, error_context))
+    Ok((status.success(), error_context))
 }
 
 /// Run the git sparse checkout installation
 #[tauri::command]
 pub async fn start_installation(textures_dir: String, window: Window) -> Result<(), String> {
-    let git_path = get_git_path()?;
-    let textures_path = PathBuf::from(&textures_dir);
-    let temp_path = textures_path.join(TEMP_DIR_NAME);
-    let final_path = textures_path.join(SLUS_FOLDER);
+    let config = resolve_repo_config(window.app_handle())?;
+    let final_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    start_install_session();
 
-    // Emit initial progress
+    run_installation(&config, &textures_dir, &window)?;
+
+    // Done!
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "preparing".to_string(),
-            message: "Preparing installation...".to_string(),
-            percent: Some(0),
+            stage: Stage::Complete,
+            key: "install.complete",
+            params: MessageParams::from([("path", final_path.display().to_string())]),
+            message: format!(
+                "Installation complete! Textures installed to: {}",
+                final_path.display()
+            ),
+            percent: Some(100),
+            bytes_received: None,
+            speed: None,
+        },
+    );
+
+    Ok(())
+}
+
+/// Result of the one-shot install + verify pipeline
+#[derive(Clone, Serialize)]
+pub struct InstallVerifyResult {
+    /// Commit that was cloned (recorded as last_sync_commit)
+    pub commit_sha: String,
+    pub verification: VerificationResult,
+}
+
+/// Install textures, record the cloned commit as the last sync, then verify the install
+/// All progress is reported on the "install-progress" event
+#[tauri::command]
+pub async fn install_and_verify(
+    textures_dir: String,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<InstallVerifyResult, String> {
+    let app = window.app_handle().clone();
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    start_install_session();
+
+    let commit_sha = run_installation(&config, &textures_dir, &window)?;
+
+    // Seed sync bookkeeping from the commit that was actually cloned
+    with_state_mut(&app, |state| {
+        state.initial_setup_done = true;
+        state.last_sync_commit = Some(commit_sha.clone());
+        state.last_sync_timestamp = Some(Utc::now().to_rfc3339());
+        Ok(())
+    })?;
+
+    // Verify the install against the same commit
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage: Stage::Verifying,
+            key: "verify.fetching_file_list",
+            params: MessageParams::new(),
+            message: "Fetching repository file list...".to_string(),
+            percent: None,
+            bytes_received: None,
+            speed: None,
+        },
+    );
+
+    let client = http_client();
+    let (remote_files, _) = with_heartbeat(
+        &window,
+        Stage::Verifying,
+        fetch_github_tree_at_commit(&config, &client, &commit_sha, &github_token),
+    )
+    .await?;
+
+    let junk_rules = load_junk_rules(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    let convention = load_disable_convention(window.app_handle());
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    // A fresh clone has every texture enabled; restore the user's disable choices first
+    apply_disabled_registry(&slus_path, convention, &disabled_registry);
+    let mut verification = scan_for_discrepancies(
+        &slus_path,
+        &remote_files,
+        &junk_rules,
+        &protected_paths,
+        convention,
+        &mut disabled_registry,
+        |key, params, message| {
+            let _ = window.emit(
+                "install-progress",
+                ProgressPayload {
+                    stage: Stage::Verifying,
+                    key,
+                    params,
+                    message,
+                    percent: None,
+                    bytes_received: None,
+                    speed: None,
+                },
+            );
+        },
+    )?;
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
+
+    // The frontend offers to apply the fixes, which may be a large delete plan
+    let downloads: Vec<&str> = verification.files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = verification.files_to_delete.iter().map(String::as_str).collect();
+    verification.plan_token = issue_plan(&commit_sha, &downloads, &deletes);
+
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage: Stage::Complete,
+            key: "install.complete",
+            params: MessageParams::from([("path", slus_path.display().to_string())]),
+            message: format!(
+                "Installation complete! Textures installed to: {}",
+                slus_path.display()
+            ),
+            percent: Some(100),
+            bytes_received: None,
+            speed: None,
         },
     );
 
-    // Clean up any existing temp directory
-    if temp_path.exists() {
-        fs::remove_dir_all(&temp_path)
-            .map_err(|e| format!("Failed to clean temp directory: {}", e))?;
+    Ok(InstallVerifyResult {
+        commit_sha,
+        verification,
+    })
+}
+
+/// What to do with a temp clone left behind by an interrupted installation
+#[derive(Debug)]
+enum TempCloneAction {
+    /// No temp clone, start from scratch
+    Fresh,
+    /// The clone is intact: skip cloning and continue with the checkout
+    Resume,
+    /// Stale lock files were removed and the clone is intact again
+    Repaired(Vec<String>),
+    /// The clone can't be trusted and has to be removed, with the reason
+    Wipe(String),
+}
+
+/// Lock files git leaves behind when it's killed mid-write
+const STALE_GIT_LOCKS: [&str; 5] = ["index.lock", "HEAD.lock", "config.lock", "shallow.lock", "packed-refs.lock"];
+
+/// Run a git command in the temp clone, returning its trimmed stdout when it succeeds
+fn git_in(git_path: &str, dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new(git_path)
+        .args(args)
+        .current_dir(dir)
+        .envs(GIT_LOCALE_ENV)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Check the clone's metadata and objects (the missing blobs of a partial clone are expected)
+fn check_temp_clone(git_path: &str, temp_path: &Path, config: &RepoConfig) -> Result<(), String> {
+    let git_dir = temp_path.join(".git");
+    if !git_dir.join("HEAD").is_file() || !git_dir.join("objects").is_dir() {
+        return Err("not a git repository".to_string());
     }
 
-    // Create temp directory (only on macOS - on Windows, git clone will create it)
-    #[cfg(not(target_os = "windows"))]
-    fs::create_dir_all(&temp_path)
-        .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    let remote = git_in(git_path, temp_path, &["config", "--get", "remote.origin.url"])
+        .ok_or("no origin remote")?;
+    if remote.trim_end_matches(".git") != config.repo_url.trim_end_matches(".git") {
+        return Err(format!("cloned from {} instead of {}", remote, config.repo_url));
+    }
+
+    let branch = git_in(git_path, temp_path, &["rev-parse", "--abbrev-ref", "HEAD"])
+        .ok_or("HEAD doesn't resolve to a commit")?;
+    if branch != config.branch {
+        return Err(format!("on branch {} instead of {}", branch, config.branch));
+    }
+
+    git_in(git_path, temp_path, &["fsck", "--connectivity-only", "--no-dangling", "--no-progress"])
+        .ok_or("git fsck found corrupt or missing objects")?;
 
+    Ok(())
+}
+
+/// Decide whether a temp clone left by an earlier installation can be resumed, repaired or
+/// has to be wiped
+fn inspect_temp_clone(git_path: &str, temp_path: &Path, config: &RepoConfig) -> TempCloneAction {
+    if !temp_path.exists() {
+        return TempCloneAction::Fresh;
+    }
+
+    let git_dir = temp_path.join(".git");
+    let stale_locks: Vec<String> = STALE_GIT_LOCKS
+        .iter()
+        .filter(|name| git_dir.join(name).is_file())
+        .map(|name| name.to_string())
+        .collect();
+    for name in &stale_locks {
+        if fs::remove_file(git_dir.join(name)).is_err() {
+            return TempCloneAction::Wipe(format!("stale {} can't be removed", name));
+        }
+    }
+
+    match check_temp_clone(git_path, temp_path, config) {
+        Ok(()) if stale_locks.is_empty() => TempCloneAction::Resume,
+        Ok(()) => TempCloneAction::Repaired(stale_locks),
+        Err(reason) => TempCloneAction::Wipe(reason),
+    }
+}
+
+/// Stage 1 of an installation: a sparse, partial clone of the pack (just metadata)
+fn clone_sparse(config: &RepoConfig, git_path: &str, temp_path: &Path, window: &Window) -> Result<(), String> {
     // Stage 1: Clone with sparse checkout (this is quick - just metadata)
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "cloning".to_string(),
+            stage: Stage::Cloning,
+            key: "install.initializing_repo",
+            params: MessageParams::new(),
             message: "Initializing repository...".to_string(),
             percent: Some(0),
+            bytes_received: None,
+            speed: None,
         },
     );
 
     let (clone_success, clone_output) = run_git_with_pty(
-        &git_path,
+        git_path,
         &[
             "clone",
             "--depth=1",
             "--filter=blob:none",
             "--sparse",
             "--progress",
-            REPO_URL,
+            "--branch",
+            &config.branch,
+            &config.repo_url,
             ".",
         ],
-        &temp_path,
-        &window,
-        "cloning",
+        temp_path,
+        window,
+        Stage::Cloning,
         false, // Don't detect stages - keep showing "Initializing repository..."
     )?;
 
     if !clone_success {
-        let _ = fs::remove_dir_all(&temp_path);
+        let _ = fs::remove_dir_all(temp_path);
         let error_msg = if clone_output.is_empty() {
             "Git clone has failed. Please check your internet connection.".to_string()
         } else {
@@ -560,22 +923,112 @@ pub async fn start_installation(textures_dir: String, window: Window) -> Result<
         return Err(error_msg);
     }
 
+    Ok(())
+}
+
+/// Clone the pack and move it into place
+/// Returns the HEAD commit SHA of the clone
+fn run_installation(config: &RepoConfig, textures_dir: &str, window: &Window) -> Result<String, String> {
+    let _drift_guard = suppress_drift_detection();
+    let _sleep_guard = prevent_sleep("Installing NCAA NEXT textures");
+    // Refuse before touching the folder if git is missing or can't do a sparse, partial clone
+    let git_status = resolve_git_status();
+    let git_path = match (git_status.path, git_status.remediation) {
+        (Some(path), None) => path,
+        (_, Some(GitRemediation::UpdateGit)) => return Err(format!("{}: {}", GIT_TOO_OLD_ERROR, git_status.message)),
+        _ => return Err(git_status.message),
+    };
+    let textures_path = PathBuf::from(&textures_dir);
+    let temp_path = textures_path.join(&config.temp_dir_name);
+    let final_path = textures_path.join(&config.slus_folder);
+
+    // Emit initial progress
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage: Stage::Preparing,
+            key: "install.preparing",
+            params: MessageParams::new(),
+            message: "Preparing installation...".to_string(),
+            percent: Some(0),
+            bytes_received: None,
+            speed: None,
+        },
+    );
+
+    // A temp clone left by an interrupted installation is resumed when intact, repaired when
+    // git only left stale locks behind, and wiped otherwise
+    let temp_action = inspect_temp_clone(&git_path, &temp_path, config);
+    let decision = match &temp_action {
+        TempCloneAction::Fresh => None,
+        TempCloneAction::Resume => Some((
+            "install.temp_resume",
+            MessageParams::new(),
+            "Resuming the interrupted download...".to_string(),
+        )),
+        TempCloneAction::Repaired(locks) => Some((
+            "install.temp_repaired",
+            MessageParams::from([("files", locks.join(", "))]),
+            format!("Repaired the interrupted download (removed {}), resuming...", locks.join(", ")),
+        )),
+        TempCloneAction::Wipe(reason) => Some((
+            "install.temp_wipe",
+            MessageParams::from([("reason", reason.clone())]),
+            format!("Discarding the interrupted download ({}), starting over...", reason),
+        )),
+    };
+    if let Some((key, params, message)) = decision {
+        let _ = window.emit(
+            "install-progress",
+            ProgressPayload {
+                stage: Stage::Preparing,
+                key,
+                params,
+                message,
+                percent: Some(0),
+                bytes_received: None,
+                speed: None,
+            },
+        );
+    }
+
+    let resume = matches!(temp_action, TempCloneAction::Resume | TempCloneAction::Repaired(_));
+    if !resume {
+        if temp_path.exists() {
+            fs::remove_dir_all(&temp_path)
+                .map_err(|e| format!("Failed to clean temp directory: {}", e))?;
+        }
+
+        // Create temp directory (git clones into it as the working directory)
+        fs::create_dir_all(&temp_path)
+            .map_err(|e| format!("Failed to create temp directory: {}", e))?;
+    }
+    let _temp_clone_guard = TempCloneGuard::new(&temp_path);
+
+    if !resume {
+        clone_sparse(config, &git_path, &temp_path, window)?;
+    }
+
     // Stage 2: Set sparse checkout path - THIS IS THE MAIN DOWNLOAD
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "downloading".to_string(),
-            message: format!("Starting download of {}...", SPARSE_PATH),
+            stage: Stage::Downloading,
+            key: "install.starting_download",
+            params: MessageParams::from([("path", config.sparse_path.to_string())]),
+            message: format!("Starting download of {}...", config.sparse_path),
             percent: Some(0),
+            bytes_received: None,
+            speed: None,
         },
     );
 
     let (checkout_success, checkout_output) = run_git_with_pty(
         &git_path,
-        &["sparse-checkout", "set", SPARSE_PATH],
+        &["sparse-checkout", "set", &config.sparse_path],
         &temp_path,
-        &window,
-        "downloading",
+        window,
+        Stage::Downloading,
         true, // Detect stages - show compressing/downloading/extracting
     )?;
 
@@ -589,23 +1042,39 @@ pub async fn start_installation(textures_dir: String, window: Window) -> Result<
         return Err(error_msg);
     }
 
+    // Record the cloned commit before the temp clone is removed
+    let head_output = Command::new(&git_path)
+        .args(["rev-parse", "HEAD"])
+        .current_dir(&temp_path)
+        .output()
+        .map_err(|e| format!("Failed to read cloned commit: {}", e))?;
+    if !head_output.status.success() {
+        let _ = fs::remove_dir_all(&temp_path);
+        return Err("Failed to read cloned commit".to_string());
+    }
+    let head_sha = String::from_utf8_lossy(&head_output.stdout).trim().to_string();
+
     // Stage 3: Move folder to final location
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "moving".to_string(),
-            message: format!("Moving {} to final location...", SLUS_FOLDER),
+            stage: Stage::Moving,
+            key: "install.moving",
+            params: MessageParams::from([("folder", config.slus_folder.to_string())]),
+            message: format!("Moving {} to final location...", config.slus_folder),
             percent: Some(0),
+            bytes_received: None,
+            speed: None,
         },
     );
 
-    let source_path = temp_path.join("textures").join(SLUS_FOLDER);
+    let source_path = temp_path.join(&config.sparse_path);
 
     if !source_path.exists() {
         let _ = fs::remove_dir_all(&temp_path);
         return Err(format!(
             "Expected folder {} not found in repository",
-            SPARSE_PATH
+            config.sparse_path
         ));
     }
 
@@ -617,27 +1086,182 @@ pub async fn start_installation(textures_dir: String, window: Window) -> Result<
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "cleanup".to_string(),
+            stage: Stage::Cleanup,
+            key: "install.cleanup",
+            params: MessageParams::new(),
             message: "Cleaning up temporary files...".to_string(),
             percent: Some(0),
+            bytes_received: None,
+            speed: None,
         },
     );
 
     fs::remove_dir_all(&temp_path)
         .map_err(|e| format!("Failed to clean up temp directory: {}", e))?;
 
-    // Done!
+    Ok(head_sha)
+}
+
+/// What an installation that was cut off left behind in the textures folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartialInstall {
+    /// Nothing left over (or an installation is running right now)
+    None,
+    /// The download stopped before the checkout finished, it has to be resumed
+    InterruptedDownload,
+    /// The checkout finished but the SLUS folder was never moved into place
+    NotMoved,
+    /// Part of the SLUS folder was moved into place, the rest is still in the temp clone
+    PartiallyMoved,
+    /// The SLUS folder is in place, only the temp clone wasn't removed
+    CleanupPending,
+}
+
+/// Whether every file of the sparse path is checked out in the temp clone
+/// Files outside the sparse checkout are skipped by ls-files, so this only passes once
+/// "sparse-checkout set" got the folder fully on disk
+fn checkout_complete(git_path: &str, temp_path: &Path, config: &RepoConfig) -> bool {
+    temp_path.join(&config.sparse_path).is_dir()
+        && git_in(git_path, temp_path, &["ls-files", "--deleted", "--", &config.sparse_path])
+            .is_some_and(|deleted| deleted.is_empty())
+}
+
+fn detect_partial(git_path: Option<&str>, temp_path: &Path, final_path: &Path, config: &RepoConfig) -> PartialInstall {
+    if temp_clone_in_use(temp_path) || !temp_path.is_dir() {
+        return PartialInstall::None;
+    }
+
+    let source_path = temp_path.join(&config.sparse_path);
+    if final_path.exists() {
+        // rename() moves the folder in one step, leftovers on both sides mean it was copied
+        return if source_path.is_dir() {
+            PartialInstall::PartiallyMoved
+        } else {
+            PartialInstall::CleanupPending
+        };
+    }
+
+    match git_path {
+        Some(git_path) if checkout_complete(git_path, temp_path, config) => PartialInstall::NotMoved,
+        _ => PartialInstall::InterruptedDownload,
+    }
+}
+
+/// Check the textures folder for an installation that was cut off
+#[tauri::command]
+pub fn detect_partial_install(app: AppHandle, textures_dir: String) -> Result<PartialInstall, String> {
+    let config = resolve_repo_config(&app)?;
+    let textures_path = PathBuf::from(&textures_dir);
+    let git_path = resolve_git_status().path;
+    Ok(detect_partial(
+        git_path.as_deref(),
+        &textures_path.join(&config.temp_dir_name),
+        &textures_path.join(&config.slus_folder),
+        &config,
+    ))
+}
+
+/// Move every file still in `source` to the same place in `dest`, replacing what's there
+/// (the files in the temp clone are the freshly checked out ones)
+fn move_remaining_files(source: &Path, dest: &Path) -> Result<usize, String> {
+    let mut moved = 0;
+    let entries = fs::read_dir(source).map_err(|e| format!("Failed to read {}: {}", source.display(), e))?;
+    fs::create_dir_all(dest).map_err(|e| format!("Failed to create {}: {}", dest.display(), e))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let target = dest.join(entry.file_name());
+        if path.is_dir() {
+            moved += move_remaining_files(&path, &target)?;
+        } else {
+            if target.exists() {
+                fs::remove_file(&target).map_err(|e| format!("Failed to replace {}: {}", target.display(), e))?;
+            }
+            fs::rename(&path, &target).map_err(|e| format!("Failed to move {}: {}", path.display(), e))?;
+            moved += 1;
+        }
+    }
+    Ok(moved)
+}
+
+/// Finish an installation that was cut off, redoing only the steps that didn't complete
+/// Returns the commit SHA of the installed pack
+#[tauri::command]
+pub async fn repair_install(textures_dir: String, window: Window) -> Result<String, String> {
+    let app = window.app_handle().clone();
+    let config = resolve_repo_config(&app)?;
+    let git_path = resolve_git_status().path;
+    let textures_path = PathBuf::from(&textures_dir);
+    let temp_path = textures_path.join(&config.temp_dir_name);
+    let final_path = textures_path.join(&config.slus_folder);
+    start_install_session();
+
+    let state = detect_partial(git_path.as_deref(), &temp_path, &final_path, &config);
+    let head_sha = match state {
+        PartialInstall::None => return Err("There is no interrupted installation to repair".to_string()),
+        // Resumes (or restarts) the clone, then moves and cleans up as usual
+        PartialInstall::InterruptedDownload => run_installation(&config, &textures_dir, &window)?,
+        PartialInstall::NotMoved | PartialInstall::PartiallyMoved | PartialInstall::CleanupPending => {
+            let git_path = git_path.ok_or_else(missing_git_message)?;
+            let head_sha = git_in(&git_path, &temp_path, &["rev-parse", "HEAD"])
+                .ok_or("Failed to read the commit of the interrupted installation")?;
+            let _drift_guard = suppress_drift_detection();
+
+            if state != PartialInstall::CleanupPending {
+                let _ = window.emit(
+                    "install-progress",
+                    ProgressPayload {
+                        stage: Stage::Moving,
+                        key: "install.moving",
+                        params: MessageParams::from([("folder", config.slus_folder.to_string())]),
+                        message: format!("Moving {} to final location...", config.slus_folder),
+                        percent: Some(0),
+                        bytes_received: None,
+                        speed: None,
+                    },
+                );
+                let source_path = temp_path.join(&config.sparse_path);
+                if state == PartialInstall::NotMoved {
+                    fs::rename(&source_path, &final_path)
+                        .map_err(|e| format!("Failed to move folder to final location: {}", e))?;
+                } else {
+                    move_remaining_files(&source_path, &final_path)?;
+                }
+            }
+
+            let _ = window.emit(
+                "install-progress",
+                ProgressPayload {
+                    stage: Stage::Cleanup,
+                    key: "install.cleanup",
+                    params: MessageParams::new(),
+                    message: "Cleaning up temporary files...".to_string(),
+                    percent: Some(0),
+                    bytes_received: None,
+                    speed: None,
+                },
+            );
+            fs::remove_dir_all(&temp_path)
+                .map_err(|e| format!("Failed to clean up temp directory: {}", e))?;
+            head_sha
+        }
+    };
+
     let _ = window.emit(
         "install-progress",
         ProgressPayload {
-            stage: "complete".to_string(),
+            stage: Stage::Complete,
+            key: "install.complete",
+            params: MessageParams::from([("path", final_path.display().to_string())]),
             message: format!(
                 "Installation complete! Textures installed to: {}",
                 final_path.display()
             ),
             percent: Some(100),
+            bytes_received: None,
+            speed: None,
         },
     );
 
-    Ok(())
+    Ok(head_sha)
 }
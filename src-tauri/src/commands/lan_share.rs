@@ -0,0 +1,532 @@
+// LAN sharing: serve an installed pack to other PCs on the local network
+// A peer downloads the manifest, then fetches only the files it is missing
+
+use crate::commands::cleanup::{cleanup_empty_directories, load_junk_rules};
+use crate::commands::disabled::{load_disable_convention, load_disabled_registry, save_disabled_registry, DisableConvention};
+use crate::commands::filesystem::load_delete_permanently;
+use crate::commands::install::ProgressPayload;
+use crate::commands::manifest::{get_manifest_path, manifest_from_tree, read_manifest, write_manifest, IntegrityManifest};
+use crate::commands::network::http_client;
+use crate::commands::pack_source::{SourceProgress, PackSource, PackTree};
+use crate::commands::plan_confirm::{check_plan, issue_plan};
+use crate::commands::progress::{start_install_session, MessageParams, Stage};
+use crate::commands::repo_paths::{check_blob_sha, check_repo_paths};
+use crate::commands::state::with_state_mut;
+use crate::commands::sync::{
+    content_matches_sha, fetch_github_tree_at_commit, load_protected_paths, scan_for_discrepancies, CompareFile,
+    VerificationResult,
+};
+use crate::commands::sync_transaction::SyncTransaction;
+use crate::commands::watcher::suppress_drift_detection;
+use crate::config::{resolve_repo_config, RepoConfig};
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use rand::distributions::{Alphanumeric, DistString};
+use reqwest::Client;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::net::UdpSocket;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager, Window};
+use tiny_http::{Header, Method, Response, Server};
+
+/// Default port for the LAN share server
+const DEFAULT_LAN_SHARE_PORT: u16 = 47800;
+
+/// Header carrying the pairing code shown by the sharing PC
+const PAIRING_CODE_HEADER: &str = "X-Pairing-Code";
+
+/// Length of a pairing code
+const PAIRING_CODE_LENGTH: usize = 10;
+
+/// Characters escaped in file paths (keeps "/" so paths stay readable)
+const PATH_ENCODE_SET: &AsciiSet = &CONTROLS.add(b' ').add(b'"').add(b'#').add(b'%').add(b'?').add(b'<').add(b'>');
+
+/// Running share server (only one at a time)
+static LAN_SERVER: Mutex<Option<LanServer>> = Mutex::new(None);
+
+struct LanServer {
+    server: Arc<Server>,
+    thread: JoinHandle<()>,
+    info: LanShareInfo,
+}
+
+/// Details other PCs need to connect to this share
+#[derive(Debug, Clone, Serialize)]
+pub struct LanShareInfo {
+    /// Address to enter on the other PC ("ip:port")
+    pub address: String,
+    pub port: u16,
+    /// Code to enter on the other PC along with the address; requests without it are refused
+    pub pairing_code: String,
+    pub commit_sha: String,
+    pub file_count: usize,
+}
+
+/// Best-effort local network IP (no packets are sent, the OS just picks the outbound interface)
+fn local_ip() -> Option<String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    socket.local_addr().ok().map(|addr| addr.ip().to_string())
+}
+
+/// Whether a request carries the share's pairing code
+fn is_paired(request: &tiny_http::Request, pairing_code: &str) -> bool {
+    request.headers().iter().any(|header| {
+        header.field.equiv(PAIRING_CODE_HEADER) && header.value.as_str().as_bytes() == pairing_code.as_bytes()
+    })
+}
+
+/// Handle a single request: GET /manifest or GET /files/<path>
+fn handle_request(
+    request: tiny_http::Request,
+    slus_path: &Path,
+    shared_files: &HashSet<String>,
+    convention: DisableConvention,
+    pairing_code: &str,
+) {
+    let url = request.url().to_string();
+
+    if *request.method() != Method::Get {
+        let _ = request.respond(Response::empty(405));
+        return;
+    }
+
+    if !is_paired(&request, pairing_code) {
+        let _ = request.respond(Response::empty(401));
+        return;
+    }
+
+    if url == "/manifest" {
+        let manifest_path = get_manifest_path(slus_path);
+        match fs::File::open(&manifest_path) {
+            Ok(file) => {
+                let header = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                    .expect("static header is valid");
+                let _ = request.respond(Response::from_file(file).with_header(header));
+            }
+            Err(_) => {
+                let _ = request.respond(Response::empty(404));
+            }
+        }
+        return;
+    }
+
+    let Some(encoded_path) = url.strip_prefix("/files/") else {
+        let _ = request.respond(Response::empty(404));
+        return;
+    };
+
+    // Only serve files listed in the manifest (also rules out path traversal)
+    let relative_path = percent_decode_str(encoded_path).decode_utf8_lossy().to_string();
+    if !shared_files.contains(&relative_path) {
+        let _ = request.respond(Response::empty(404));
+        return;
+    }
+
+    // Serve the enabled file, or the user-disabled copy if that's what exists
+    let enabled_path = slus_path.join(&relative_path);
+    let file_path = if enabled_path.is_file() {
+        enabled_path
+    } else {
+        slus_path.join(convention.disabled_path(&relative_path))
+    };
+
+    match fs::File::open(&file_path) {
+        Ok(file) => {
+            let _ = request.respond(Response::from_file(file));
+        }
+        Err(_) => {
+            let _ = request.respond(Response::empty(404));
+        }
+    }
+}
+
+/// Start serving the installed pack to other PCs on the local network
+/// Requires an integrity manifest (see generate_manifest)
+#[tauri::command]
+pub fn start_lan_share(textures_dir: String, port: Option<u16>, window: Window) -> Result<LanShareInfo, String> {
+    let mut guard = LAN_SERVER.lock().map_err(|_| "LAN share state is poisoned".to_string())?;
+    if let Some(running) = guard.as_ref() {
+        return Ok(running.info.clone());
+    }
+
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let manifest = read_manifest(&slus_path)?;
+    let convention = load_disable_convention(window.app_handle());
+
+    let port = port.unwrap_or(DEFAULT_LAN_SHARE_PORT);
+    let server = Server::http(("0.0.0.0", port))
+        .map_err(|e| format!("Failed to start LAN share on port {}: {}", port, e))?;
+    let server = Arc::new(server);
+
+    let pairing_code = Alphanumeric.sample_string(&mut rand::thread_rng(), PAIRING_CODE_LENGTH);
+    let info = LanShareInfo {
+        address: format!("{}:{}", local_ip().unwrap_or_else(|| "localhost".to_string()), port),
+        port,
+        pairing_code: pairing_code.clone(),
+        commit_sha: manifest.commit_sha.clone(),
+        file_count: manifest.files.len(),
+    };
+
+    let shared_files: HashSet<String> = manifest.files.into_keys().collect();
+    let thread_server = server.clone();
+    let thread = std::thread::spawn(move || {
+        for request in thread_server.incoming_requests() {
+            handle_request(request, &slus_path, &shared_files, convention, &pairing_code);
+        }
+    });
+
+    *guard = Some(LanServer {
+        server,
+        thread,
+        info: info.clone(),
+    });
+
+    Ok(info)
+}
+
+/// Stop the LAN share server (no-op if it isn't running)
+#[tauri::command]
+pub fn stop_lan_share() -> Result<(), String> {
+    let running = LAN_SERVER
+        .lock()
+        .map_err(|_| "LAN share state is poisoned".to_string())?
+        .take();
+
+    if let Some(running) = running {
+        running.server.unblock();
+        let _ = running.thread.join();
+    }
+
+    Ok(())
+}
+
+/// Get the running LAN share, if any
+#[tauri::command]
+pub fn get_lan_share_status() -> Result<Option<LanShareInfo>, String> {
+    let guard = LAN_SERVER.lock().map_err(|_| "LAN share state is poisoned".to_string())?;
+    Ok(guard.as_ref().map(|running| running.info.clone()))
+}
+
+/// Another PC running a LAN share, as a pack source
+/// The peer's manifest holds a single version, so there is no change history. The share is
+/// unauthenticated beyond its pairing code, so the manifest is only used once it matches the
+/// repository's file list for the commit it names, and every file is checked against that list
+pub(crate) struct LanPeerSource {
+    peer: String,
+    pairing_code: String,
+    config: RepoConfig,
+    token: Option<String>,
+    client: Client,
+    manifest: Mutex<Option<IntegrityManifest>>,
+}
+
+impl LanPeerSource {
+    pub fn new(config: &RepoConfig, token: &Option<String>, peer_address: &str, pairing_code: &str) -> Self {
+        LanPeerSource {
+            peer: peer_address.trim().trim_start_matches("http://").trim_end_matches('/').to_string(),
+            pairing_code: pairing_code.trim().to_string(),
+            config: config.clone(),
+            token: token.clone(),
+            client: http_client(),
+            manifest: Mutex::new(None),
+        }
+    }
+
+    /// The peer's pack, checked against the repository (cached for the lifetime of the source)
+    /// Returns a manifest built from the repository's file list, not the one the peer sent
+    pub async fn manifest(&self) -> Result<IntegrityManifest, String> {
+        if let Ok(guard) = self.manifest.lock() {
+            if let Some(manifest) = guard.as_ref() {
+                return Ok(manifest.clone());
+            }
+        }
+
+        let response = self
+            .client
+            .get(format!("http://{}/manifest", self.peer))
+            .header(PAIRING_CODE_HEADER, &self.pairing_code)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to connect to peer: {}", e))?;
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err("The peer refused the pairing code. Check the code shown on the sharing PC.".to_string());
+        }
+        let peer_manifest: IntegrityManifest = response
+            .error_for_status()
+            .map_err(|e| format!("Peer returned an error: {}", e))?
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse peer manifest: {}", e))?;
+        check_blob_sha(&peer_manifest.commit_sha).map_err(|_| "The peer's manifest names an invalid commit".to_string())?;
+        check_repo_paths(peer_manifest.files.keys().map(String::as_str))?;
+
+        let (files, sizes) =
+            fetch_github_tree_at_commit(&self.config, &self.client, &peer_manifest.commit_sha, &self.token).await?;
+        let differing = peer_manifest
+            .files
+            .iter()
+            .filter(|(path, entry)| files.get(*path) != Some(&entry.sha))
+            .count()
+            + files.keys().filter(|path| !peer_manifest.files.contains_key(*path)).count();
+        if differing > 0 {
+            return Err(format!(
+                "The peer's pack doesn't match version {} of the pack ({} files differ), so nothing was copied from it",
+                &peer_manifest.commit_sha[..7],
+                differing
+            ));
+        }
+
+        let manifest = manifest_from_tree(&peer_manifest.commit_sha, files, &sizes);
+        if let Ok(mut guard) = self.manifest.lock() {
+            *guard = Some(manifest.clone());
+        }
+        Ok(manifest)
+    }
+}
+
+impl PackSource for LanPeerSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        Ok(self.manifest().await?.commit_sha)
+    }
+
+    async fn list_tree(&self, _reference: &str) -> Result<PackTree, String> {
+        let manifest = self.manifest().await?;
+        let files = manifest
+            .files
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.sha.clone()))
+            .collect();
+        let sizes = manifest
+            .files
+            .iter()
+            .map(|(path, entry)| (path.clone(), entry.size))
+            .collect();
+        Ok((files, sizes))
+    }
+
+    async fn get_changes(
+        &self,
+        _base_ref: &str,
+        _head_ref: &str,
+        _progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        Err("TRUNCATED: LAN peers have no change history".to_string())
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        let url = format!("http://{}/files/{}", self.peer, utf8_percent_encode(path, PATH_ENCODE_SET));
+
+        let response = self
+            .client
+            .get(&url)
+            .header(PAIRING_CODE_HEADER, &self.pairing_code)
+            .send()
+            .await
+            .map_err(|e| format!("Failed to download {} from peer: {}", path, e))?;
+
+        if !response.status().is_success() {
+            return Err(format!("Failed to download {} from peer: HTTP {}", path, response.status()));
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| format!("Failed to read file content: {}", e))
+    }
+}
+
+/// Compare the install with a peer's pack (checked against the repository)
+/// Returns the manifest and the files to copy and delete
+async fn plan_from_peer(
+    app: &AppHandle,
+    window: &Window,
+    source: &LanPeerSource,
+    slus_path: &Path,
+) -> Result<(IntegrityManifest, VerificationResult), String> {
+    let manifest = source.manifest().await?;
+    let expected_files: HashMap<String, String> = manifest
+        .files
+        .iter()
+        .map(|(path, entry)| (path.clone(), entry.sha.clone()))
+        .collect();
+
+    let junk_rules = load_junk_rules(app);
+    let protected_paths = load_protected_paths(app);
+    let convention = load_disable_convention(app);
+    let mut disabled_registry = load_disabled_registry(app);
+    let plan = scan_for_discrepancies(
+        slus_path,
+        &expected_files,
+        &junk_rules,
+        &protected_paths,
+        convention,
+        &mut disabled_registry,
+        |key, params, message| {
+            let _ = window.emit(
+                "install-progress",
+                ProgressPayload {
+                    stage: Stage::Preparing,
+                    key,
+                    params,
+                    message,
+                    percent: None,
+                    bytes_received: None,
+                    speed: None,
+                },
+            );
+        },
+    )?;
+    save_disabled_registry(app, &disabled_registry)?;
+    Ok((manifest, plan))
+}
+
+fn emit_connecting(window: &Window, peer: &str) {
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage: Stage::Preparing,
+            key: "lan.connecting",
+            params: MessageParams::from([("peer", peer.to_string())]),
+            message: format!("Connecting to {}...", peer),
+            percent: Some(0),
+            bytes_received: None,
+            speed: None,
+        },
+    );
+}
+
+/// Show what installing from a peer would change, without changing anything
+/// Pass the result's plan_token to install_from_lan_peer (needed when many files would be deleted)
+#[tauri::command]
+pub async fn analyze_lan_peer(
+    textures_dir: String,
+    peer_address: String,
+    pairing_code: String,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<VerificationResult, String> {
+    let app = window.app_handle().clone();
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let source = LanPeerSource::new(&config, &github_token, &peer_address, &pairing_code);
+    emit_connecting(&window, &source.peer);
+
+    let (manifest, mut plan) = plan_from_peer(&app, &window, &source, &slus_path).await?;
+    let downloads: Vec<&str> = plan.files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = plan.files_to_delete.iter().map(String::as_str).collect();
+    plan.plan_token = issue_plan(&manifest.commit_sha, &downloads, &deletes);
+    Ok(plan)
+}
+
+/// Install (or update) the pack from another PC running a LAN share
+/// Only files that are missing or differ locally are transferred. Everything is copied into a
+/// staging folder first and swapped in once all files arrived (see sync_transaction), and files to
+/// delete go to the Recycle Bin/Trash unless deletions are permanent
+/// plan_token comes from analyze_lan_peer; it's required when many files would be deleted
+/// Returns the commit of the installed pack
+#[tauri::command]
+pub async fn install_from_lan_peer(
+    textures_dir: String,
+    peer_address: String,
+    pairing_code: String,
+    plan_token: Option<String>,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<String, String> {
+    let app = window.app_handle().clone();
+    let config = resolve_repo_config(&app)?;
+    let textures_path = PathBuf::from(&textures_dir);
+    let slus_path = textures_path.join(&config.slus_folder);
+    let source = LanPeerSource::new(&config, &github_token, &peer_address, &pairing_code);
+    let peer = source.peer.clone();
+    let _drift_guard = suppress_drift_detection();
+    start_install_session();
+    emit_connecting(&window, &peer);
+
+    fs::create_dir_all(&slus_path)
+        .map_err(|e| format!("Failed to create {}: {}", config.slus_folder, e))?;
+    let (manifest, plan) = plan_from_peer(&app, &window, &source, &slus_path).await?;
+
+    let downloads: Vec<&str> = plan.files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = plan.files_to_delete.iter().map(String::as_str).collect();
+    if let Some(reference) = check_plan(plan_token.as_deref(), &downloads, &deletes)? {
+        if reference != manifest.commit_sha {
+            return Err("The peer's pack changed after it was analyzed. Analyze it again before installing.".to_string());
+        }
+    }
+
+    // Copy into the staging folder; nothing in the install changes until every file is there
+    let convention = load_disable_convention(&app);
+    let transaction = SyncTransaction::begin(&textures_path, &slus_path)?;
+    let mut staged = Vec::with_capacity(plan.files_to_download.len());
+    let download_count = plan.files_to_download.len();
+    for (i, file) in plan.files_to_download.iter().enumerate() {
+        let _ = window.emit(
+            "install-progress",
+            ProgressPayload {
+                stage: Stage::Downloading,
+                key: "lan.copying_file",
+                params: MessageParams::from([("path", file.path.to_string())]),
+                message: format!("Copying from peer: {}", file.path),
+                percent: Some(((i * 100) / download_count) as u32),
+                bytes_received: None,
+                speed: None,
+            },
+        );
+
+        let relative_path = if file.to_disabled { convention.disabled_path(&file.path) } else { file.path.clone() };
+        let content = source.fetch_file(&file.path).await?;
+        let expected_sha = manifest.files.get(&file.path).map(|entry| entry.sha.as_str());
+        if !expected_sha.is_some_and(|sha| content_matches_sha(&content, sha)) {
+            return Err(format!(
+                "{} from {} doesn't match the pack, so nothing was installed",
+                file.path, peer
+            ));
+        }
+        let staged_path = transaction.staged_path(&relative_path);
+        if let Some(parent) = staged_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        fs::write(&staged_path, &content).map_err(|e| format!("Failed to write {}: {}", file.path, e))?;
+        staged.push(relative_path);
+    }
+
+    transaction.commit(&staged, &plan.files_to_delete, load_delete_permanently(&app))?;
+    cleanup_empty_directories(&slus_path, false, &window);
+
+    // Built from the repository's file list, so this PC can verify offline and share in turn
+    write_manifest(&slus_path, &manifest)?;
+
+    // The commit isn't recorded: the next sync runs in full and establishes it from the
+    // repository itself rather than from what the peer said
+    with_state_mut(&app, |state| {
+        state.initial_setup_done = true;
+        state.last_sync_commit = None;
+        Ok(())
+    })?;
+
+    let _ = window.emit(
+        "install-progress",
+        ProgressPayload {
+            stage: Stage::Complete,
+            key: "lan.complete",
+            params: MessageParams::from([("count", download_count.to_string()), ("peer", peer.to_string())]),
+            message: format!(
+                "Installation complete! Copied {} files from {}",
+                download_count, peer
+            ),
+            percent: Some(100),
+            bytes_received: None,
+            speed: None,
+        },
+    );
+
+    Ok(manifest.commit_sha)
+}
@@ -0,0 +1,204 @@
+use crate::commands::cleanup::load_junk_rules;
+use crate::commands::disabled::{load_disable_convention, load_disabled_registry, save_disabled_registry};
+use crate::commands::network::http_client;
+use crate::commands::progress::{with_heartbeat, MessageParams, Stage};
+use crate::commands::state::load_state;
+use crate::commands::sync::{
+    fetch_github_tree_at_commit, get_latest_commit_with_token, load_protected_paths, scan_for_discrepancies, SyncProgressPayload,
+    VerificationResult,
+};
+use crate::config::resolve_repo_config;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{Emitter, Manager, Window};
+
+/// Manifest file name, written into the SLUS folder (hidden, so sync and verification ignore it)
+const MANIFEST_FILE_NAME: &str = ".textures-manifest.json";
+
+/// Current manifest format version
+const MANIFEST_VERSION: u32 = 1;
+
+/// Expected content of a single file in the pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Git blob SHA
+    pub sha: String,
+    pub size: u64,
+}
+
+/// Integrity manifest for an installed pack (path -> sha/size)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityManifest {
+    pub version: u32,
+    /// Commit the manifest was generated from
+    pub commit_sha: String,
+    pub generated_at: String,
+    pub files: BTreeMap<String, ManifestEntry>,
+}
+
+/// Summary returned after generating a manifest
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestInfo {
+    pub path: String,
+    pub commit_sha: String,
+    pub file_count: usize,
+}
+
+pub(crate) fn get_manifest_path(slus_path: &Path) -> PathBuf {
+    slus_path.join(MANIFEST_FILE_NAME)
+}
+
+/// Read the integrity manifest from an install
+pub(crate) fn read_manifest(slus_path: &Path) -> Result<IntegrityManifest, String> {
+    let manifest_path = get_manifest_path(slus_path);
+
+    if !manifest_path.exists() {
+        return Err("No integrity manifest found. Generate one first.".to_string());
+    }
+
+    let contents = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest: {}", e))?;
+    let manifest: IntegrityManifest = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse manifest: {}", e))?;
+
+    if manifest.version > MANIFEST_VERSION {
+        return Err(format!(
+            "Manifest version {} is newer than supported version {}. Please update the app.",
+            manifest.version, MANIFEST_VERSION
+        ));
+    }
+
+    Ok(manifest)
+}
+
+/// Write an integrity manifest into an install
+pub(crate) fn write_manifest(slus_path: &Path, manifest: &IntegrityManifest) -> Result<PathBuf, String> {
+    let manifest_path = get_manifest_path(slus_path);
+    let contents = serde_json::to_string_pretty(manifest)
+        .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+    fs::write(&manifest_path, contents)
+        .map_err(|e| format!("Failed to write manifest: {}", e))?;
+
+    Ok(manifest_path)
+}
+
+/// Build a manifest from the repository's file list at a commit (path -> sha, path -> size)
+pub(crate) fn manifest_from_tree(commit_sha: &str, files: HashMap<String, String>, sizes: &HashMap<String, u64>) -> IntegrityManifest {
+    let files: BTreeMap<String, ManifestEntry> = files
+        .into_iter()
+        .map(|(path, sha)| {
+            let size = sizes.get(&path).copied().unwrap_or(0);
+            (path, ManifestEntry { sha, size })
+        })
+        .collect();
+
+    IntegrityManifest {
+        version: MANIFEST_VERSION,
+        commit_sha: commit_sha.to_string(),
+        generated_at: Utc::now().to_rfc3339(),
+        files,
+    }
+}
+
+/// Generate an integrity manifest for the last synced commit and write it into the install
+/// Falls back to the latest commit if the install has never been synced
+#[tauri::command]
+pub async fn generate_manifest(
+    textures_dir: String,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<ManifestInfo, String> {
+    let app = window.app_handle();
+    let config = resolve_repo_config(app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+
+    if !slus_path.is_dir() {
+        return Err(format!("{} folder not found in {}", config.slus_folder, textures_dir));
+    }
+
+    let commit_sha = match load_state(app.clone())?.last_sync_commit {
+        Some(sha) => sha,
+        None => with_heartbeat(&window, Stage::Manifest, get_latest_commit_with_token(&config, &github_token)).await?,
+    };
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Manifest,
+        key: "manifest.fetching_file_list",
+        params: MessageParams::from([("commit", commit_sha[..7.min(commit_sha.len())].to_string())]),
+        message: format!("Fetching file list for commit {}...", &commit_sha[..7.min(commit_sha.len())]),
+        current: None,
+        total: None,
+    });
+
+    let client = http_client();
+    let (remote_files, remote_sizes) = with_heartbeat(
+        &window,
+        Stage::Manifest,
+        fetch_github_tree_at_commit(&config, &client, &commit_sha, &github_token),
+    )
+    .await?;
+
+    let manifest = manifest_from_tree(&commit_sha, remote_files, &remote_sizes);
+    let manifest_path = write_manifest(&slus_path, &manifest)?;
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Manifest,
+        key: "manifest.written",
+        params: MessageParams::from([("count", manifest.files.len().to_string())]),
+        message: format!("Manifest written with {} files", manifest.files.len()),
+        current: None,
+        total: None,
+    });
+
+    Ok(ManifestInfo {
+        path: manifest_path.to_string_lossy().to_string(),
+        commit_sha,
+        file_count: manifest.files.len(),
+    })
+}
+
+/// Verify the install against its integrity manifest (no network access)
+#[tauri::command]
+pub async fn verify_against_manifest(
+    textures_dir: String,
+    window: Window,
+) -> Result<VerificationResult, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let manifest = read_manifest(&slus_path)?;
+
+    let expected_files = manifest
+        .files
+        .into_iter()
+        .map(|(path, entry)| (path, entry.sha))
+        .collect();
+
+    let junk_rules = load_junk_rules(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    let convention = load_disable_convention(window.app_handle());
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    let verification = scan_for_discrepancies(
+        &slus_path,
+        &expected_files,
+        &junk_rules,
+        &protected_paths,
+        convention,
+        &mut disabled_registry,
+        |key, params, message| {
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Verifying,
+                key,
+                params,
+                message,
+                current: None,
+                total: None,
+            });
+        },
+    )?;
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
+
+    Ok(verification)
+}
@@ -0,0 +1,140 @@
+//! Download mirrors for pack files
+
+use crate::commands::network::{describe_request_error, TracedSend};
+use reqwest::Client;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Time a mirror has to answer the probe before it's ranked as unreachable
+const MIRROR_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Probe result of a single mirror
+#[derive(Debug, Clone, Serialize)]
+pub struct MirrorHealth {
+    pub url: String,
+    /// Time until the response headers arrived (None = unreachable)
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Files a mirror served since the last report
+#[derive(Debug, Clone, Serialize)]
+pub struct MirrorUsage {
+    pub url: String,
+    pub files: u32,
+    /// Files it served after a better-ranked mirror failed
+    pub failovers: u32,
+}
+
+/// Mirrors of the pack in order of preference, with per-mirror usage counts
+/// A mirror URL is the base the repository's files are served under (a file at
+/// "<sparse_path>/<path>" in the repository is fetched from "<mirror>/<sparse_path>/<path>")
+pub(crate) struct MirrorPool {
+    ranked: Mutex<Vec<String>>,
+    usage: Mutex<Vec<MirrorUsage>>,
+}
+
+impl MirrorPool {
+    /// The origin comes first until the pool has been probed; duplicates are dropped
+    pub fn new(origin: String, mirrors: &[String]) -> Self {
+        let mut ranked: Vec<String> = Vec::with_capacity(mirrors.len() + 1);
+        for url in std::iter::once(&origin).chain(mirrors) {
+            let url = url.trim_end_matches('/').to_string();
+            if !url.is_empty() && !ranked.contains(&url) {
+                ranked.push(url);
+            }
+        }
+
+        MirrorPool {
+            ranked: Mutex::new(ranked),
+            usage: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Whether there is more than one place to download from
+    pub fn has_mirrors(&self) -> bool {
+        self.ranked().len() > 1
+    }
+
+    /// Base URLs, best first
+    pub fn ranked(&self) -> Vec<String> {
+        self.ranked.lock().map(|ranked| ranked.clone()).unwrap_or_default()
+    }
+
+    /// Probe every mirror in parallel and rank them by latency (unreachable mirrors last)
+    /// `probe_path` is requested relative to each mirror, so the probe sees the same routing as downloads
+    pub async fn probe(&self, client: &Client, probe_path: &str) -> Vec<MirrorHealth> {
+        let handles: Vec<_> = self
+            .ranked()
+            .into_iter()
+            .map(|url| {
+                let request = client
+                    .head(format!("{}/{}", url, probe_path))
+                    .header("User-Agent", "NCAA-NEXT-Textures-Downloader")
+                    .timeout(MIRROR_PROBE_TIMEOUT);
+                tauri::async_runtime::spawn(async move {
+                    let started = Instant::now();
+                    // Any HTTP answer counts - some mirrors don't serve HEAD or folder paths
+                    match request.send_traced().await {
+                        Ok(_) => MirrorHealth {
+                            url,
+                            latency_ms: Some(started.elapsed().as_millis() as u64),
+                            error: None,
+                        },
+                        Err(e) => MirrorHealth {
+                            url,
+                            latency_ms: None,
+                            error: Some(describe_request_error(&e)),
+                        },
+                    }
+                })
+            })
+            .collect();
+
+        let mut health = Vec::with_capacity(handles.len());
+        for handle in handles {
+            if let Ok(result) = handle.await {
+                health.push(result);
+            }
+        }
+
+        // Stable sort keeps the declared order for ties and among unreachable mirrors
+        health.sort_by_key(|mirror| mirror.latency_ms.unwrap_or(u64::MAX));
+        if let Ok(mut ranked) = self.ranked.lock() {
+            *ranked = health.iter().map(|mirror| mirror.url.clone()).collect();
+        }
+
+        health
+    }
+
+    /// Record a file served by a mirror
+    pub fn record(&self, url: &str, failover: bool) {
+        let Ok(mut usage) = self.usage.lock() else {
+            return;
+        };
+        let index = match usage.iter().position(|entry| entry.url == url) {
+            Some(index) => index,
+            None => {
+                usage.push(MirrorUsage {
+                    url: url.to_string(),
+                    files: 0,
+                    failovers: 0,
+                });
+                usage.len() - 1
+            }
+        };
+        usage[index].files += 1;
+        if failover {
+            usage[index].failovers += 1;
+        }
+    }
+
+    /// Usage since the last call
+    pub fn take_usage(&self) -> Vec<MirrorUsage> {
+        self.usage
+            .lock()
+            .map(|mut usage| std::mem::take(&mut *usage))
+            .unwrap_or_default()
+    }
+}
@@ -1,11 +1,61 @@
 pub mod app_info;
+pub mod auto_sync;
+pub mod auto_verify;
+pub mod bandwidth;
+pub mod blob_cache;
+pub mod catalog;
+pub mod cleanup;
+pub mod dedup;
+pub mod disabled;
+pub mod file_index;
 pub mod filesystem;
+pub mod full_sync_policy;
+pub mod git_progress;
 pub mod install;
+pub mod lan_share;
+pub mod manifest;
+pub mod mirrors;
+pub mod network;
+pub mod pack_health;
+pub mod pack_source;
+pub mod plan_confirm;
+pub mod power;
+pub mod profiles;
+pub mod progress;
+pub mod relocation;
+pub mod repo_paths;
+pub mod reset;
 pub mod state;
 pub mod sync;
+pub mod sync_journal;
+pub mod sync_transaction;
+pub mod verification_history;
+pub mod watcher;
 
 pub use app_info::*;
+pub use auto_sync::*;
+pub use auto_verify::*;
+pub use bandwidth::*;
+pub use blob_cache::*;
+pub use catalog::*;
+pub use cleanup::*;
+pub use dedup::*;
+pub use disabled::*;
+pub use file_index::*;
 pub use filesystem::*;
+pub use full_sync_policy::*;
 pub use install::*;
+pub use lan_share::*;
+pub use manifest::*;
+pub use network::*;
+pub use pack_health::*;
+pub use pack_source::*;
+pub use profiles::*;
+pub use progress::*;
+pub use relocation::*;
+pub use reset::*;
 pub use state::*;
 pub use sync::*;
+pub use sync_journal::*;
+pub use verification_history::*;
+pub use watcher::*;
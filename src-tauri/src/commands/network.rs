@@ -0,0 +1,411 @@
+//! Shared HTTP client and connectivity checks
+
+use crate::commands::state::{load_state, with_state_mut, AppState};
+use chrono::Utc;
+use reqwest::header::AUTHORIZATION;
+use reqwest::{Certificate, Client, RequestBuilder, Response};
+use serde::Serialize;
+use std::error::Error as StdError;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::Write;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Manager};
+
+/// Hosts the app downloads from (api.github.com has no IPv6 addresses, so it isn't probed)
+const PROBE_HOSTS: [&str; 2] = ["github.com", "raw.githubusercontent.com"];
+
+/// Connect timeout of each probe connection
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Addresses tried per host and address family
+const PROBE_ADDRESSES_PER_FAMILY: usize = 2;
+
+/// Connect timeout of the shared client, so a dead route fails instead of hanging
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Time a request may go without receiving anything before it counts as stalled
+pub(crate) const STALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// URL requested to check that TLS connections succeed
+const TLS_CHECK_URL: &str = "https://raw.githubusercontent.com/";
+
+/// File name of the request trace log (in the app log directory)
+const TRACE_LOG_FILE: &str = "http-trace.log";
+
+/// Size at which the trace log is rotated to "<name>.1"
+const TRACE_LOG_MAX_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Query parameters whose values are never written to the trace log
+const REDACTED_QUERY_PARAMS: [&str; 3] = ["token", "access_token", "client_secret"];
+
+/// Whether the shared client only connects over IPv4
+static PREFER_IPV4: AtomicBool = AtomicBool::new(false);
+
+/// Extra CA certificates trusted by the shared client (from the user's CA bundle)
+static EXTRA_CERTIFICATES: Mutex<Vec<Certificate>> = Mutex::new(Vec::new());
+
+/// Shared client, rebuilt after the network settings change
+static HTTP_CLIENT: Mutex<Option<Client>> = Mutex::new(None);
+
+/// Trace log file while request tracing is enabled
+static TRACE_LOG: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// A TLS handshake failure, usually a certificate the client doesn't trust
+#[derive(Debug, Clone, Serialize)]
+pub struct TlsError {
+    pub url: String,
+    pub message: String,
+}
+
+/// Result of the connectivity probe
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectivityReport {
+    /// Whether the probed hosts have IPv6 addresses at all
+    pub ipv6_resolved: bool,
+    pub ipv6_reachable: bool,
+    pub ipv4_reachable: bool,
+    /// Whether requests now go over IPv4 only (setting or detected broken IPv6)
+    pub prefer_ipv4: bool,
+    /// Set when HTTPS requests fail certificate validation (e.g. TLS interception)
+    pub tls_error: Option<TlsError>,
+}
+
+/// An operation that stopped making progress
+/// Commands return it as its message, which always starts with "Operation stalled"
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationStalled {
+    /// What was being done, e.g. "downloading" or "fetching the file list"
+    pub phase: String,
+    /// File being transferred, if any
+    pub path: Option<String>,
+    pub stalled_secs: u64,
+}
+
+impl fmt::Display for OperationStalled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Operation stalled while {}", self.phase)?;
+        if let Some(path) = &self.path {
+            write!(f, " {}", path)?;
+        }
+        write!(f, ": no progress for {} seconds", self.stalled_secs)
+    }
+}
+
+/// Await one step of a request (sending it, or reading the next part of the response),
+/// failing with an OperationStalled message if it takes longer than STALL_TIMEOUT
+pub(crate) async fn watchdog<F: Future>(phase: &str, path: Option<&str>, step: F) -> Result<F::Output, String> {
+    tokio::time::timeout(STALL_TIMEOUT, step).await.map_err(|_| {
+        OperationStalled {
+            phase: phase.to_string(),
+            path: path.map(str::to_string),
+            stalled_secs: STALL_TIMEOUT.as_secs(),
+        }
+        .to_string()
+    })
+}
+
+/// Get the shared HTTP client
+pub(crate) fn http_client() -> Client {
+    let Ok(mut cached) = HTTP_CLIENT.lock() else {
+        return build_client().unwrap_or_default();
+    };
+    if let Some(client) = cached.as_ref() {
+        return client.clone();
+    }
+
+    let client = build_client().unwrap_or_default();
+    *cached = Some(client.clone());
+    client
+}
+
+fn build_client() -> reqwest::Result<Client> {
+    let mut builder = Client::builder().connect_timeout(CONNECT_TIMEOUT);
+    if let Ok(certificates) = EXTRA_CERTIFICATES.lock() {
+        for certificate in certificates.iter() {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+    }
+    if PREFER_IPV4.load(Ordering::SeqCst) {
+        // Binding to the IPv4 wildcard address rules out IPv6 destinations
+        builder = builder.local_address(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+    }
+    builder.build()
+}
+
+/// Drop the cached client so the next request picks up changed settings
+fn reset_http_client() {
+    if let Ok(mut cached) = HTTP_CLIENT.lock() {
+        *cached = None;
+    }
+}
+
+fn set_client_prefer_ipv4(prefer_ipv4: bool) {
+    if PREFER_IPV4.swap(prefer_ipv4, Ordering::SeqCst) != prefer_ipv4 {
+        reset_http_client();
+    }
+}
+
+/// Read every certificate of a PEM bundle
+fn load_ca_bundle(path: &str) -> Result<Vec<Certificate>, String> {
+    let pem = fs::read(path).map_err(|e| format!("Failed to read CA bundle: {}", e))?;
+    let certificates =
+        Certificate::from_pem_bundle(&pem).map_err(|e| format!("Failed to parse CA bundle: {}", e))?;
+    if certificates.is_empty() {
+        return Err("CA bundle contains no PEM certificates".to_string());
+    }
+    Ok(certificates)
+}
+
+fn set_client_certificates(certificates: Vec<Certificate>) {
+    if let Ok(mut extra) = EXTRA_CERTIFICATES.lock() {
+        *extra = certificates;
+    }
+    reset_http_client();
+}
+
+/// Find the TLS failure behind a request error, if that's what it is
+fn tls_failure(e: &reqwest::Error) -> Option<String> {
+    if !e.is_connect() {
+        return None;
+    }
+    let mut source = e.source();
+    while let Some(err) = source {
+        let message = err.to_string();
+        let lower = message.to_ascii_lowercase();
+        if lower.contains("certificate") || lower.contains("ssl") || lower.contains("tls") {
+            return Some(message);
+        }
+        source = err.source();
+    }
+    None
+}
+
+/// Describe a request error, spelling out certificate failures and how to fix them
+pub(crate) fn describe_request_error(e: &reqwest::Error) -> String {
+    match tls_failure(e) {
+        Some(detail) => format!(
+            "TLS certificate error ({}). If your network inspects HTTPS traffic, add its CA certificate bundle in the network settings",
+            detail
+        ),
+        None => e.to_string(),
+    }
+}
+
+fn trace_log_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let log_dir = app
+        .path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get app log directory: {}", e))?;
+    fs::create_dir_all(&log_dir).map_err(|e| format!("Failed to create app log directory: {}", e))?;
+    Ok(log_dir.join(TRACE_LOG_FILE))
+}
+
+fn set_tracing(app: &AppHandle, enabled: bool) -> Result<(), String> {
+    let path = if enabled { Some(trace_log_path(app)?) } else { None };
+    if let Ok(mut trace_log) = TRACE_LOG.lock() {
+        *trace_log = path;
+    }
+    Ok(())
+}
+
+/// Apply the persisted network settings to the shared client and tracing
+fn apply_network_settings(app: &AppHandle, state: &AppState) {
+    if let Some(path) = &state.ca_bundle_path {
+        // An unreadable bundle shows up as the TLS error of the connectivity check
+        set_client_certificates(load_ca_bundle(path).unwrap_or_default());
+    }
+    let _ = set_tracing(app, state.http_tracing);
+}
+
+/// Go back to the default network settings (after the state was reset)
+pub(crate) fn reset_network_settings(app: &AppHandle) {
+    set_client_prefer_ipv4(false);
+    set_client_certificates(Vec::new());
+    let _ = set_tracing(app, false);
+}
+
+/// URL with the values of credential-like query parameters replaced
+fn redact_url(url: &reqwest::Url) -> String {
+    let mut redacted = url.clone();
+    if url.query().is_some() {
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let hidden = REDACTED_QUERY_PARAMS.iter().any(|p| key.eq_ignore_ascii_case(p));
+                (key.into_owned(), if hidden { "REDACTED".to_string() } else { value.into_owned() })
+            })
+            .collect();
+        redacted.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    redacted.to_string()
+}
+
+/// Append a line to the trace log, rotating it once it gets large
+fn write_trace_line(path: &PathBuf, line: &str) {
+    if fs::metadata(path).map(|m| m.len() >= TRACE_LOG_MAX_BYTES).unwrap_or(false) {
+        let _ = fs::rename(path, path.with_extension("log.1"));
+    }
+    if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+        let _ = writeln!(file, "{}", line);
+    }
+}
+
+/// Sending a request with an entry in the trace log when tracing is enabled
+pub(crate) trait TracedSend {
+    async fn send_traced(self) -> reqwest::Result<Response>;
+}
+
+impl TracedSend for RequestBuilder {
+    async fn send_traced(self) -> reqwest::Result<Response> {
+        let trace_log = TRACE_LOG.lock().ok().and_then(|trace_log| trace_log.clone());
+        let Some(trace_log) = trace_log else {
+            return self.send().await;
+        };
+
+        let (client, request) = self.build_split();
+        let request = request?;
+        let method = request.method().clone();
+        let url = redact_url(request.url());
+        // Only whether a token was sent is logged, never the token itself
+        let auth = if request.headers().contains_key(AUTHORIZATION) { "token" } else { "anonymous" };
+
+        let started = Instant::now();
+        let result = client.execute(request).await;
+        let duration_ms = started.elapsed().as_millis();
+
+        let outcome = match &result {
+            Ok(response) => {
+                let header = |name: &str| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .unwrap_or("-")
+                        .to_string()
+                };
+                format!(
+                    "{} rate-limit remaining={} limit={} reset={}",
+                    response.status(),
+                    header("x-ratelimit-remaining"),
+                    header("x-ratelimit-limit"),
+                    header("x-ratelimit-reset")
+                )
+            }
+            Err(e) => format!("error: {}", describe_request_error(e)),
+        };
+
+        write_trace_line(
+            &trace_log,
+            &format!("{} {} {} ({}) -> {} in {} ms", Utc::now().to_rfc3339(), method, url, auth, outcome, duration_ms),
+        );
+        result
+    }
+}
+
+/// Try to open a TCP connection to any of the addresses
+fn any_reachable(addresses: &[SocketAddr]) -> bool {
+    addresses
+        .iter()
+        .take(PROBE_ADDRESSES_PER_FAMILY)
+        .any(|addr| TcpStream::connect_timeout(addr, PROBE_TIMEOUT).is_ok())
+}
+
+/// Probe IPv4 and IPv6 reachability of the download hosts (blocking)
+/// Returns (ipv6_resolved, ipv6_reachable, ipv4_reachable)
+fn probe_hosts() -> (bool, bool, bool) {
+    let mut ipv6_resolved = false;
+    let mut ipv6_reachable = false;
+    let mut ipv4_reachable = false;
+
+    for host in PROBE_HOSTS {
+        let Ok(addresses) = (host, 443).to_socket_addrs() else {
+            continue;
+        };
+        let (v6, v4): (Vec<SocketAddr>, Vec<SocketAddr>) = addresses.partition(|addr| addr.is_ipv6());
+
+        ipv6_resolved |= !v6.is_empty();
+        ipv6_reachable = ipv6_reachable || any_reachable(&v6);
+        ipv4_reachable = ipv4_reachable || any_reachable(&v4);
+    }
+
+    (ipv6_resolved, ipv6_reachable, ipv4_reachable)
+}
+
+/// Check IPv4/IPv6 connectivity to GitHub and switch the shared client to IPv4 when
+/// IPv6 is advertised but unreachable (or when the user forced IPv4), then check TLS
+/// The detection is not persisted, since it depends on the network the PC is on
+#[tauri::command]
+pub async fn check_connectivity(app: AppHandle) -> Result<ConnectivityReport, String> {
+    let state = load_state(app.clone())?;
+    let forced = state.prefer_ipv4;
+    apply_network_settings(&app, &state);
+    let (ipv6_resolved, ipv6_reachable, ipv4_reachable) = tauri::async_runtime::spawn_blocking(probe_hosts)
+        .await
+        .map_err(|e| format!("Failed to probe connectivity: {}", e))?;
+
+    let prefer_ipv4 = forced || (ipv6_resolved && !ipv6_reachable && ipv4_reachable);
+    set_client_prefer_ipv4(prefer_ipv4);
+
+    let tls_error = match http_client().head(TLS_CHECK_URL).send_traced().await {
+        Err(e) => tls_failure(&e).map(|message| TlsError {
+            url: TLS_CHECK_URL.to_string(),
+            message,
+        }),
+        Ok(_) => None,
+    };
+
+    Ok(ConnectivityReport {
+        ipv6_resolved,
+        ipv6_reachable,
+        ipv4_reachable,
+        prefer_ipv4,
+        tls_error,
+    })
+}
+
+/// Force all requests over IPv4 (false = decide by the connectivity probe)
+#[tauri::command]
+pub fn set_prefer_ipv4(app: AppHandle, enabled: bool) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.prefer_ipv4 = enabled;
+        Ok(())
+    })?;
+    if enabled {
+        set_client_prefer_ipv4(true);
+    }
+    Ok(())
+}
+
+/// Trust the CA certificates of a PEM bundle in addition to the system store (None = system store only)
+#[tauri::command]
+pub fn set_ca_bundle(app: AppHandle, path: Option<String>) -> Result<(), String> {
+    let certificates = match &path {
+        Some(path) => load_ca_bundle(path)?,
+        None => Vec::new(),
+    };
+
+    with_state_mut(&app, |state| {
+        state.ca_bundle_path = path;
+        Ok(())
+    })?;
+    set_client_certificates(certificates);
+    Ok(())
+}
+
+/// Log every GitHub request (URL, status, duration, rate-limit headers; never the token) to the trace log
+/// Returns the path of the log file
+#[tauri::command]
+pub fn set_http_tracing(app: AppHandle, enabled: bool) -> Result<String, String> {
+    with_state_mut(&app, |state| {
+        state.http_tracing = enabled;
+        Ok(())
+    })?;
+    set_tracing(&app, enabled)?;
+    Ok(trace_log_path(&app)?.to_string_lossy().to_string())
+}
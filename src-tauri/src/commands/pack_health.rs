@@ -0,0 +1,92 @@
+//! Health summary of the installed pack for the main screen
+
+use crate::commands::filesystem::{get_disk_usage, DiskUsage};
+use crate::commands::install::{detect_partial_install, PartialInstall};
+use crate::commands::state::load_state;
+use crate::commands::sync::SyncFailure;
+use crate::commands::sync_journal::load_sync_journal;
+use crate::commands::verification_history::{verification_status, VerificationStatus};
+use crate::commands::watcher::{detected_local_changes, LocalChanges};
+use crate::config::resolve_repo_config;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::path::Path;
+use tauri::AppHandle;
+
+/// Overall state of the pack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthLevel {
+    /// Installed, verified and nothing pending
+    Good,
+    /// Usable, but worth a look (local changes, verification due)
+    Attention,
+    /// Something has to be fixed (missing or broken install, failed files)
+    Problem,
+}
+
+/// Everything the health card shows
+#[derive(Debug, Clone, Serialize)]
+pub struct PackHealth {
+    pub level: HealthLevel,
+    /// Setup was completed and the SLUS folder exists
+    pub installed: bool,
+    pub partial_install: PartialInstall,
+    pub last_sync_commit: Option<String>,
+    pub last_sync_timestamp: Option<String>,
+    pub days_since_sync: Option<i64>,
+    pub verification: VerificationStatus,
+    /// Changes made to the install by other programs since the last sync
+    pub local_changes: Option<LocalChanges>,
+    pub disabled_count: usize,
+    /// An analyzed sync that was cut off and can be resumed
+    pub sync_interrupted: bool,
+    /// Files that failed in the interrupted sync
+    pub known_failures: Vec<SyncFailure>,
+    /// None if it couldn't be measured
+    pub disk_usage: Option<DiskUsage>,
+}
+
+fn health_level(health: &PackHealth) -> HealthLevel {
+    let broken = !health.installed
+        || health.partial_install != PartialInstall::None
+        || health.sync_interrupted
+        || !health.known_failures.is_empty()
+        || (health.verification.last.is_some() && !health.verification.all_good);
+    if broken {
+        HealthLevel::Problem
+    } else if health.local_changes.is_some() || health.verification.reverify_suggested {
+        HealthLevel::Attention
+    } else {
+        HealthLevel::Good
+    }
+}
+
+/// Get the health of the active pack in one call
+#[tauri::command]
+pub async fn get_pack_health(app: AppHandle, textures_dir: String) -> Result<PackHealth, String> {
+    let state = load_state(app.clone())?;
+    let config = resolve_repo_config(&app)?;
+    let journal = load_sync_journal(&app)?.filter(|journal| journal.repo_url == config.repo_url);
+
+    let mut health = PackHealth {
+        level: HealthLevel::Good,
+        installed: state.initial_setup_done && Path::new(&textures_dir).join(&config.slus_folder).is_dir(),
+        partial_install: detect_partial_install(app.clone(), textures_dir.clone())?,
+        days_since_sync: state
+            .last_sync_timestamp
+            .as_deref()
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|timestamp| (Utc::now() - timestamp.with_timezone(&Utc)).num_days()),
+        last_sync_commit: state.last_sync_commit,
+        last_sync_timestamp: state.last_sync_timestamp,
+        verification: verification_status(state.last_verification, Utc::now()),
+        local_changes: detected_local_changes(),
+        disabled_count: state.disabled_textures.paths().count(),
+        sync_interrupted: journal.is_some(),
+        known_failures: journal.map(|journal| journal.failed_files).unwrap_or_default(),
+        disk_usage: get_disk_usage(app, textures_dir).await.ok(),
+    };
+    health.level = health_level(&health);
+    Ok(health)
+}
@@ -0,0 +1,1087 @@
+//! Where a sync gets the pack from
+
+use crate::commands::bandwidth::{download_connections, throttle_download};
+use crate::commands::lan_share::LanPeerSource;
+use crate::commands::mirrors::{MirrorHealth, MirrorPool, MirrorUsage};
+use crate::commands::network::{describe_request_error, http_client, watchdog, TracedSend};
+use crate::commands::progress::{ByteProgress, MessageParams};
+use crate::commands::repo_paths::safe_file_name;
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync::{
+    compute_sha_for_content, fetch_changed_files, fetch_changed_files_chained, fetch_github_tree_at_commit,
+    get_latest_commit_with_token, write_file_replacing, CompareFile,
+};
+use crate::config::RepoConfig;
+use flate2::read::GzDecoder;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+use tauri::{AppHandle, Manager};
+use tokio::task::JoinSet;
+use zip::ZipArchive;
+
+/// Files of the pack, relative to the SLUS folder: (path -> git blob SHA, path -> size in bytes)
+pub(crate) type PackTree = (HashMap<String, String>, HashMap<String, u64>);
+
+/// Receives progress messages from a source (key, params, English message)
+pub(crate) type SourceProgress<'a> = dyn Fn(&'static str, MessageParams, String) + Sync + 'a;
+
+/// A place the pack can be synced from
+pub(crate) trait PackSource {
+    /// Identifier of the newest pack version (a commit SHA for git-backed sources)
+    async fn latest_ref(&self) -> Result<String, String>;
+
+    /// Files of the pack at a version returned by latest_ref
+    async fn list_tree(&self, reference: &str) -> Result<PackTree, String>;
+
+    /// Files changed between two versions, with paths relative to the SLUS folder
+    /// Errors containing "TRUNCATED" make the sync fall back to comparing every file
+    async fn get_changes(
+        &self,
+        base_ref: &str,
+        head_ref: &str,
+        progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String>;
+
+    /// Content of a file (relative to the SLUS folder) at the newest version
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String>;
+
+    /// fetch_file that keeps what arrived of a large file in `partial_path` when the transfer
+    /// breaks, and continues from there on the next call. The caller checks the content and
+    /// removes the partial file; sources that can't resume fetch the file whole
+    async fn fetch_file_resumable(&self, path: &str, partial_path: &Path) -> Result<Vec<u8>, String> {
+        let _ = partial_path;
+        self.fetch_file(path).await
+    }
+
+    /// Whether get_changes can work out changes, rather than every sync comparing all files
+    fn has_change_history(&self) -> bool {
+        false
+    }
+
+    /// Probe and rank the download mirrors (empty when the source has none)
+    async fn probe_mirrors(&self) -> Vec<MirrorHealth> {
+        Vec::new()
+    }
+
+    /// Files each mirror served since the last call (empty when the source has no mirrors)
+    fn take_mirror_usage(&self) -> Vec<MirrorUsage> {
+        Vec::new()
+    }
+
+    /// Whether extract_archive can download many files in one request
+    fn supports_archive(&self) -> bool {
+        false
+    }
+
+    /// Download an archive of the pack at a version into `work_dir` and extract the `targets` from it
+    /// Returns the paths that were extracted with the expected content; the rest are left to fetch_file
+    async fn extract_archive(
+        &self,
+        _reference: &str,
+        _targets: HashMap<String, ArchiveTarget>,
+        _work_dir: &Path,
+        _progress: &SourceProgress<'_>,
+    ) -> Result<HashSet<String>, String> {
+        Err("This pack source has no archive download".to_string())
+    }
+}
+
+/// Where to write a file extracted from an archive, and the content it must have
+pub(crate) struct ArchiveTarget {
+    pub dest_path: PathBuf,
+    /// Expected git blob SHA (entries with other content are skipped)
+    pub sha: Option<String>,
+}
+
+/// Bytes between archive download progress messages
+const ARCHIVE_PROGRESS_INTERVAL: u64 = 10 * 1024 * 1024;
+
+/// Files at least this large are written to a partial file as they download, so an interrupted
+/// download can resume with a Range request
+const RESUMABLE_MIN_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Files at least this large are split across several connections, when that's enabled
+const CHUNKED_MIN_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Download bytes start..=end of a file with a request of its own, into the same bytes of the partial file
+#[allow(clippy::too_many_arguments)]
+async fn fetch_range(
+    client: Client,
+    url: String,
+    path: String,
+    authorization: Option<String>,
+    partial_path: PathBuf,
+    start: u64,
+    end: u64,
+    progress: Option<Arc<ByteProgress>>,
+) -> Result<(), String> {
+    let mut req = client
+        .get(&url)
+        .header("User-Agent", "NCAA-NEXT-Textures-Downloader")
+        .header("Range", format!("bytes={}-{}", start, end));
+    if let Some(value) = authorization {
+        req = req.header("Authorization", value);
+    }
+
+    let mut response = watchdog("downloading", Some(path.as_str()), req.send_traced())
+        .await?
+        .map_err(|e| format!("Failed to download file: {}", describe_request_error(&e)))?;
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        return Err(format!("Failed to download bytes {}-{}: HTTP {}", start, end, response.status()));
+    }
+
+    let mut file = File::options()
+        .write(true)
+        .open(&partial_path)
+        .map_err(|e| format!("Failed to open partial download: {}", e))?;
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to write partial download: {}", e))?;
+
+    let expected = end - start + 1;
+    let mut received = 0;
+    while let Some(chunk) = watchdog("downloading", Some(path.as_str()), response.chunk())
+        .await?
+        .map_err(|e| format!("Failed to read file content: {}", e))?
+    {
+        received += chunk.len() as u64;
+        if received > expected {
+            break;
+        }
+        file.write_all(&chunk).map_err(|e| format!("Failed to write partial download: {}", e))?;
+        if let Some(progress) = &progress {
+            progress.add(chunk.len());
+        }
+        throttle_download(chunk.len()).await;
+    }
+    if received != expected {
+        return Err(format!("Got {} of {} bytes for {}-{}", received, expected, start, end));
+    }
+
+    Ok(())
+}
+
+/// Extract the targets from a gzipped repository tarball
+/// Entries sit under a single top-level folder ("owner-repo-sha/" on GitHub), then the sparse path
+fn extract_tarball(
+    archive_path: &Path,
+    sparse_path: &str,
+    targets: &HashMap<String, ArchiveTarget>,
+) -> Result<HashSet<String>, String> {
+    let file = File::open(archive_path).map_err(|e| format!("Failed to open archive: {}", e))?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+    let prefix = format!("{}/", sparse_path);
+    let mut extracted = HashSet::new();
+
+    let entries = archive.entries().map_err(|e| format!("Failed to read archive: {}", e))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Failed to read archive: {}", e))?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+
+        let entry_path = entry
+            .path()
+            .map_err(|e| format!("Failed to read archive: {}", e))?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let Some(repo_path) = entry_path.split_once('/').map(|(_, rest)| rest) else {
+            continue;
+        };
+        let Some(relative_path) = repo_path.strip_prefix(&prefix) else {
+            continue;
+        };
+        let Some(target) = targets.get(relative_path) else {
+            continue;
+        };
+
+        let mut content = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {} from archive: {}", relative_path, e))?;
+        if target.sha.as_deref().is_some_and(|sha| compute_sha_for_content(&content) != sha) {
+            continue;
+        }
+
+        if let Some(parent) = target.dest_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        write_file_replacing(&target.dest_path, &content)?;
+        extracted.insert(relative_path.to_string());
+    }
+
+    Ok(extracted)
+}
+
+/// Where syncs get the pack from (stored in state)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PackSourceSetting {
+    /// The pack repository, through the forge's REST API
+    #[default]
+    Repository,
+    /// A zip asset of the repository's latest release (the first .zip unless named)
+    ReleaseArchive { asset_name: Option<String> },
+    /// A folder holding the pack, e.g. a copy on a USB drive
+    LocalFolder { path: String },
+    /// A zip file holding the pack
+    LocalZip { path: String },
+    /// Another PC sharing its install (see start_lan_share), as "ip:port" plus its pairing code
+    LanPeer {
+        address: String,
+        #[serde(default)]
+        pairing_code: String,
+    },
+}
+
+/// The repository through the forge's REST API, with files from raw content or its mirrors
+pub(crate) struct GitHubSource {
+    config: RepoConfig,
+    client: Client,
+    token: Option<String>,
+    /// Raw content of the repository (the origin) followed by its mirrors
+    mirrors: MirrorPool,
+}
+
+impl GitHubSource {
+    pub fn new(config: &RepoConfig, token: &Option<String>, mirrors: &[String]) -> Self {
+        GitHubSource {
+            config: config.clone(),
+            client: http_client(),
+            token: token.clone(),
+            mirrors: MirrorPool::new(config.raw_url(""), mirrors),
+        }
+    }
+
+    /// Download a file (relative to the SLUS folder) from one mirror
+    /// With a partial path, large files go through it and a previous partial download is resumed
+    async fn fetch_from(&self, base_url: &str, path: &str, partial_path: Option<&Path>) -> Result<Vec<u8>, String> {
+        let url = format!("{}/{}/{}", base_url, self.config.sparse_path, path);
+        let mut req = self
+            .client
+            .get(&url)
+            .header("User-Agent", "NCAA-NEXT-Textures-Downloader");
+
+        // The token is only ever sent to the repository's own host
+        let authorization = self
+            .token
+            .as_ref()
+            .filter(|_| base_url == self.config.raw_url("").trim_end_matches('/'))
+            .map(|t| self.config.forge.authorization(t));
+        if let Some(value) = &authorization {
+            req = req.header("Authorization", value);
+        }
+
+        let offset = partial_path
+            .and_then(|partial| fs::metadata(partial).ok())
+            .map_or(0, |metadata| metadata.len());
+        if offset > 0 {
+            req = req.header("Range", format!("bytes={}-", offset));
+        }
+
+        let mut response = watchdog("downloading", Some(path), req.send_traced())
+            .await?
+            .map_err(|e| format!("Failed to download file: {}", describe_request_error(&e)))?;
+
+        // Nothing left after the offset: the partial file already holds the whole file
+        if let (Some(partial), StatusCode::RANGE_NOT_SATISFIABLE) = (partial_path, response.status()) {
+            return fs::read(partial).map_err(|e| format!("Failed to read partial download: {}", e));
+        }
+        if !response.status().is_success() {
+            return Err(format!("Failed to download {}: HTTP {}", path, response.status()));
+        }
+
+        // Very large files over several connections, when enabled and the mirror serves ranges
+        let connections = download_connections();
+        let accepts_ranges = response
+            .headers()
+            .get(reqwest::header::ACCEPT_RANGES)
+            .is_some_and(|value| value == "bytes");
+        if let (Some(partial), Some(len)) = (partial_path, response.content_length()) {
+            if offset == 0 && connections > 1 && accepts_ranges && len >= CHUNKED_MIN_BYTES {
+                drop(response);
+                return self.fetch_chunked(&url, path, authorization, partial, len, connections).await;
+            }
+        }
+
+        // A server without range support answers 200 with the whole file, so start over
+        let resumed = offset > 0 && response.status() == StatusCode::PARTIAL_CONTENT;
+        let partial_path = partial_path.filter(|_| {
+            resumed || response.content_length().is_some_and(|len| len >= RESUMABLE_MIN_BYTES)
+        });
+
+        // Read in chunks so the rate cap can pace the stream
+        let already = if resumed { offset } else { 0 };
+        let progress = ByteProgress::start(path, response.content_length().map(|len| already + len), already);
+        let Some(partial_path) = partial_path else {
+            let mut bytes = Vec::with_capacity(response.content_length().unwrap_or(0).min(RESUMABLE_MIN_BYTES) as usize);
+            while let Some(chunk) = watchdog("downloading", Some(path), response.chunk())
+                .await?
+                .map_err(|e| format!("Failed to read file content: {}", e))?
+            {
+                bytes.extend_from_slice(&chunk);
+                if let Some(progress) = &progress {
+                    progress.add(chunk.len());
+                }
+                throttle_download(chunk.len()).await;
+            }
+            return Ok(bytes);
+        };
+
+        if let Some(parent) = partial_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        let mut file = if resumed {
+            File::options().append(true).open(partial_path)
+        } else {
+            File::create(partial_path)
+        }
+        .map_err(|e| format!("Failed to open partial download: {}", e))?;
+        while let Some(chunk) = watchdog("downloading", Some(path), response.chunk())
+            .await?
+            .map_err(|e| format!("Failed to read file content: {}", e))?
+        {
+            file.write_all(&chunk).map_err(|e| format!("Failed to write partial download: {}", e))?;
+            if let Some(progress) = &progress {
+                progress.add(chunk.len());
+            }
+            throttle_download(chunk.len()).await;
+        }
+        drop(file);
+
+        fs::read(partial_path).map_err(|e| format!("Failed to read partial download: {}", e))
+    }
+
+    /// Download a file of `len` bytes as that many byte ranges at once, each written into the partial file
+    async fn fetch_chunked(
+        &self,
+        url: &str,
+        path: &str,
+        authorization: Option<String>,
+        partial_path: &Path,
+        len: u64,
+        connections: u64,
+    ) -> Result<Vec<u8>, String> {
+        if let Some(parent) = partial_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+        }
+        File::create(partial_path).map_err(|e| format!("Failed to open partial download: {}", e))?;
+
+        let chunk_len = len.div_ceil(connections);
+        let progress = ByteProgress::start(path, Some(len), 0).map(Arc::new);
+        let mut tasks = JoinSet::new();
+        let mut start = 0;
+        while start < len {
+            let end = (start + chunk_len).min(len) - 1;
+            tasks.spawn(fetch_range(
+                self.client.clone(),
+                url.to_string(),
+                path.to_string(),
+                authorization.clone(),
+                partial_path.to_path_buf(),
+                start,
+                end,
+                progress.clone(),
+            ));
+            start = end + 1;
+        }
+
+        // Dropping the set on an error cancels the other ranges
+        // The partial file has holes then, so it can't be resumed from its length
+        while let Some(result) = tasks.join_next().await {
+            if let Err(e) = result.map_err(|e| format!("Download task failed: {}", e)).and_then(|r| r) {
+                let _ = fs::remove_file(partial_path);
+                return Err(e);
+            }
+        }
+
+        fs::read(partial_path).map_err(|e| format!("Failed to read partial download: {}", e))
+    }
+
+    /// Download a file from the best mirror that has it
+    async fn fetch_mirrored(&self, path: &str, partial_path: Option<&Path>) -> Result<Vec<u8>, String> {
+        let mut last_error = None;
+        for (rank, base_url) in self.mirrors.ranked().iter().enumerate() {
+            match self.fetch_from(base_url, path, partial_path).await {
+                Ok(bytes) => {
+                    self.mirrors.record(base_url, rank > 0);
+                    return Ok(bytes);
+                }
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| format!("No download mirror for {}", path)))
+    }
+}
+
+impl PackSource for GitHubSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        get_latest_commit_with_token(&self.config, &self.token).await
+    }
+
+    async fn list_tree(&self, reference: &str) -> Result<PackTree, String> {
+        fetch_github_tree_at_commit(&self.config, &self.client, reference, &self.token).await
+    }
+
+    async fn get_changes(
+        &self,
+        base_ref: &str,
+        head_ref: &str,
+        progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        let (mut changed_files, is_truncated) = fetch_changed_files(&self.config, base_ref, head_ref, &self.token).await?;
+
+        // If the response is truncated (300+ files), walk the commits one by one instead
+        // Errors from the chained walk are "TRUNCATED" too, which falls back to full sync
+        if is_truncated {
+            progress(
+                "sync.chaining_commits",
+                MessageParams::new(),
+                "Too many changes for one comparison, fetching changes commit by commit...".to_string(),
+            );
+            changed_files = fetch_changed_files_chained(&self.config, base_ref, head_ref, &self.token).await?;
+        }
+
+        // Keep only files in the sparse path; a previous path outside it has no local source
+        let prefix = format!("{}/", self.config.sparse_path);
+        Ok(changed_files
+            .into_iter()
+            .filter_map(|mut file| {
+                file.filename = file.filename.strip_prefix(&prefix)?.to_string();
+                file.previous_filename = file
+                    .previous_filename
+                    .and_then(|p| p.strip_prefix(&prefix).map(|p| p.to_string()));
+                Some(file)
+            })
+            .collect())
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        self.fetch_mirrored(path, None).await
+    }
+
+    async fn fetch_file_resumable(&self, path: &str, partial_path: &Path) -> Result<Vec<u8>, String> {
+        self.fetch_mirrored(path, Some(partial_path)).await
+    }
+
+    fn has_change_history(&self) -> bool {
+        true
+    }
+
+    async fn probe_mirrors(&self) -> Vec<MirrorHealth> {
+        if !self.mirrors.has_mirrors() {
+            return Vec::new();
+        }
+        self.mirrors
+            .probe(&self.client, &format!("{}/", self.config.sparse_path))
+            .await
+    }
+
+    fn take_mirror_usage(&self) -> Vec<MirrorUsage> {
+        if !self.mirrors.has_mirrors() {
+            return Vec::new();
+        }
+        self.mirrors.take_usage()
+    }
+
+    fn supports_archive(&self) -> bool {
+        true
+    }
+
+    async fn extract_archive(
+        &self,
+        reference: &str,
+        targets: HashMap<String, ArchiveTarget>,
+        work_dir: &Path,
+        progress: &SourceProgress<'_>,
+    ) -> Result<HashSet<String>, String> {
+        let url = format!("{}/{}", self.config.api_repo_url(), self.config.forge.archive_endpoint(reference));
+        let mut req = self
+            .client
+            .get(&url)
+            .header("User-Agent", "NCAA-NEXT-Textures-Downloader");
+        if let Some(t) = &self.token {
+            req = req.header("Authorization", self.config.forge.authorization(t));
+        }
+
+        let mut response = watchdog("downloading the pack archive", None, req.send_traced())
+            .await?
+            .map_err(|e| format!("Failed to download archive: {}", describe_request_error(&e)))?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to download archive: HTTP {}", response.status()));
+        }
+
+        fs::create_dir_all(work_dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+        let archive_path = work_dir.join(format!("{}.tar.gz", reference));
+        let mut file = File::create(&archive_path).map_err(|e| format!("Failed to create archive: {}", e))?;
+        let mut received: u64 = 0;
+        let mut next_report = 0;
+        while let Some(chunk) = watchdog("downloading the pack archive", None, response.chunk())
+            .await?
+            .map_err(|e| format!("Failed to download archive: {}", e))?
+        {
+            file.write_all(&chunk).map_err(|e| format!("Failed to write archive: {}", e))?;
+            received += chunk.len() as u64;
+            throttle_download(chunk.len()).await;
+
+            if received >= next_report {
+                let mb = received / (1024 * 1024);
+                progress(
+                    "sync.downloading_archive",
+                    MessageParams::from([("mb", mb.to_string())]),
+                    format!("Downloading pack archive: {} MB...", mb),
+                );
+                next_report = received + ARCHIVE_PROGRESS_INTERVAL;
+            }
+        }
+        drop(file);
+
+        progress(
+            "sync.extracting_archive",
+            MessageParams::from([("count", targets.len().to_string())]),
+            format!("Extracting {} files from the pack archive...", targets.len()),
+        );
+
+        let sparse_path = self.config.sparse_path.clone();
+        let extract_path = archive_path.clone();
+        let result = tauri::async_runtime::spawn_blocking(move || extract_tarball(&extract_path, &sparse_path, &targets))
+            .await
+            .map_err(|e| format!("Archive extraction failed: {}", e))?;
+        let _ = fs::remove_file(&archive_path);
+        result
+    }
+}
+
+/// An opened zip archive holding the pack
+struct ZipPack {
+    archive: ZipArchive<File>,
+    /// Path of the SLUS folder inside the archive ("" when the archive root is the SLUS folder)
+    prefix: String,
+}
+
+impl ZipPack {
+    fn open(path: &Path, slus_folder: &str) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| format!("Failed to open archive: {}", e))?;
+        let archive = ZipArchive::new(file).map_err(|e| format!("Failed to read archive: {}", e))?;
+
+        // The SLUS folder may sit anywhere in the archive (e.g. "textures/SLUS-21214/")
+        let prefix = archive
+            .file_names()
+            .find_map(|name| {
+                let segments: Vec<&str> = name.split('/').collect();
+                let position = segments.iter().position(|segment| *segment == slus_folder)?;
+                Some(format!("{}/", segments[..=position].join("/")))
+            })
+            .unwrap_or_default();
+
+        Ok(ZipPack { archive, prefix })
+    }
+
+    /// List the pack's files, hashing each one
+    fn list(&mut self) -> Result<PackTree, String> {
+        let mut files = HashMap::new();
+        let mut sizes = HashMap::new();
+
+        for i in 0..self.archive.len() {
+            let mut entry = self
+                .archive
+                .by_index(i)
+                .map_err(|e| format!("Failed to read archive: {}", e))?;
+            if !entry.is_file() {
+                continue;
+            }
+            let Some(path) = entry.name().strip_prefix(&self.prefix).map(|p| p.to_string()) else {
+                continue;
+            };
+            // Archiver metadata and hidden files are never part of the pack
+            if path.starts_with("__MACOSX/") || path.split('/').any(|segment| segment.starts_with('.')) {
+                continue;
+            }
+
+            let mut content = Vec::with_capacity(entry.size() as usize);
+            entry
+                .read_to_end(&mut content)
+                .map_err(|e| format!("Failed to read {} from archive: {}", path, e))?;
+            sizes.insert(path.clone(), content.len() as u64);
+            files.insert(path, compute_sha_for_content(&content));
+        }
+
+        Ok((files, sizes))
+    }
+
+    fn read(&mut self, path: &str) -> Result<Vec<u8>, String> {
+        let mut entry = self
+            .archive
+            .by_name(&format!("{}{}", self.prefix, path))
+            .map_err(|e| format!("Failed to find {} in archive: {}", path, e))?;
+        let mut content = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {} from archive: {}", path, e))?;
+        Ok(content)
+    }
+}
+
+/// Version of a local file or folder, from its size and modification time
+fn local_version(kind: &str, path: &Path) -> Result<String, String> {
+    let metadata = fs::metadata(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    Ok(format!("{}:{}-{}", kind, metadata.len(), modified))
+}
+
+/// A zip file holding the pack
+pub(crate) struct LocalZipSource {
+    path: PathBuf,
+    slus_folder: String,
+    archive: Mutex<Option<ZipPack>>,
+}
+
+impl LocalZipSource {
+    pub fn new(path: &str, slus_folder: &str) -> Self {
+        LocalZipSource {
+            path: PathBuf::from(path),
+            slus_folder: slus_folder.to_string(),
+            archive: Mutex::new(None),
+        }
+    }
+
+    /// Run f on the opened archive (opened on first use)
+    fn with_archive<T>(&self, f: impl FnOnce(&mut ZipPack) -> Result<T, String>) -> Result<T, String> {
+        let mut guard = self.archive.lock().map_err(|_| "Archive state is poisoned".to_string())?;
+        if guard.is_none() {
+            *guard = Some(ZipPack::open(&self.path, &self.slus_folder)?);
+        }
+        f(guard.as_mut().expect("archive was just opened"))
+    }
+}
+
+impl PackSource for LocalZipSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        local_version("zip", &self.path)
+    }
+
+    async fn list_tree(&self, _reference: &str) -> Result<PackTree, String> {
+        self.with_archive(|archive| archive.list())
+    }
+
+    async fn get_changes(
+        &self,
+        _base_ref: &str,
+        _head_ref: &str,
+        _progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        Err("TRUNCATED: Zip files have no change history".to_string())
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        self.with_archive(|archive| archive.read(path))
+    }
+}
+
+/// Release entry from the releases API
+#[derive(Debug, Deserialize)]
+struct ReleaseResponse {
+    tag_name: String,
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+/// A zip asset of the repository's latest release, downloaded once into the app cache
+pub(crate) struct ReleaseArchiveSource {
+    config: RepoConfig,
+    client: Client,
+    token: Option<String>,
+    asset_name: Option<String>,
+    download_dir: PathBuf,
+    /// Release tag and archive of the opened release
+    archive: Mutex<Option<(String, ZipPack)>>,
+}
+
+impl ReleaseArchiveSource {
+    pub fn new(config: &RepoConfig, token: &Option<String>, asset_name: Option<String>, download_dir: PathBuf) -> Self {
+        ReleaseArchiveSource {
+            config: config.clone(),
+            client: http_client(),
+            token: token.clone(),
+            asset_name,
+            download_dir,
+            archive: Mutex::new(None),
+        }
+    }
+
+    async fn fetch_release(&self, endpoint: &str) -> Result<ReleaseResponse, String> {
+        let mut req = self
+            .client
+            .get(format!("{}/{}", self.config.api_repo_url(), endpoint))
+            .header("User-Agent", "NCAA-NEXT-Textures-Downloader");
+        if let Some(t) = &self.token {
+            req = req.header("Authorization", self.config.forge.authorization(t));
+        }
+
+        let response = req
+            .send_traced()
+            .await
+            .map_err(|e| format!("Failed to fetch release: {}", describe_request_error(&e)))?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to fetch release: HTTP {}", response.status()));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse release: {}", e))
+    }
+
+    /// Download the release's pack archive unless it's already in the app cache
+    async fn download_archive(&self, tag: &str) -> Result<PathBuf, String> {
+        let release = self.fetch_release(&format!("releases/tags/{}", tag)).await?;
+        let asset = release
+            .assets
+            .iter()
+            .find(|asset| match &self.asset_name {
+                Some(name) => &asset.name == name,
+                None => asset.name.to_ascii_lowercase().ends_with(".zip"),
+            })
+            .ok_or_else(|| format!("Release {} has no pack archive", tag))?;
+
+        // The asset name comes from the server
+        let file_name = safe_file_name(&asset.name)?;
+        let archive_path = self.download_dir.join(format!("{}-{}", tag.replace(['/', '\\'], "_"), file_name));
+        if archive_path.is_file() {
+            return Ok(archive_path);
+        }
+
+        let mut req = self
+            .client
+            .get(&asset.browser_download_url)
+            .header("User-Agent", "NCAA-NEXT-Textures-Downloader");
+        if let Some(t) = &self.token {
+            req = req.header("Authorization", self.config.forge.authorization(t));
+        }
+        let mut response = watchdog("downloading", Some(asset.name.as_str()), req.send_traced())
+            .await?
+            .map_err(|e| format!("Failed to download {}: {}", asset.name, describe_request_error(&e)))?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to download {}: HTTP {}", asset.name, response.status()));
+        }
+
+        fs::create_dir_all(&self.download_dir)
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        let partial_path = archive_path.with_extension("download-tmp");
+        let mut file = File::create(&partial_path).map_err(|e| format!("Failed to create archive: {}", e))?;
+        while let Some(chunk) = watchdog("downloading", Some(asset.name.as_str()), response.chunk())
+            .await?
+            .map_err(|e| format!("Failed to download {}: {}", asset.name, e))?
+        {
+            file.write_all(&chunk).map_err(|e| format!("Failed to write archive: {}", e))?;
+            throttle_download(chunk.len()).await;
+        }
+        drop(file);
+
+        fs::rename(&partial_path, &archive_path).map_err(|e| format!("Failed to save archive: {}", e))?;
+        Ok(archive_path)
+    }
+
+    /// Open the archive of a release (downloading it if needed) unless it's already open
+    async fn open_release(&self, tag: &str) -> Result<(), String> {
+        let is_open = self
+            .archive
+            .lock()
+            .map_err(|_| "Archive state is poisoned".to_string())?
+            .as_ref()
+            .is_some_and(|(open_tag, _)| open_tag == tag);
+        if is_open {
+            return Ok(());
+        }
+
+        let archive_path = self.download_archive(tag).await?;
+        let pack = ZipPack::open(&archive_path, &self.config.slus_folder)?;
+        *self.archive.lock().map_err(|_| "Archive state is poisoned".to_string())? = Some((tag.to_string(), pack));
+        Ok(())
+    }
+
+    fn with_archive<T>(&self, f: impl FnOnce(&mut ZipPack) -> Result<T, String>) -> Result<T, String> {
+        let mut guard = self.archive.lock().map_err(|_| "Archive state is poisoned".to_string())?;
+        match guard.as_mut() {
+            Some((_, pack)) => f(pack),
+            None => Err("Release archive is not open".to_string()),
+        }
+    }
+}
+
+impl PackSource for ReleaseArchiveSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        Ok(self.fetch_release("releases/latest").await?.tag_name)
+    }
+
+    async fn list_tree(&self, reference: &str) -> Result<PackTree, String> {
+        self.open_release(reference).await?;
+        self.with_archive(|archive| archive.list())
+    }
+
+    async fn get_changes(
+        &self,
+        _base_ref: &str,
+        _head_ref: &str,
+        _progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        Err("TRUNCATED: Release archives have no change history".to_string())
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        let is_open = self
+            .archive
+            .lock()
+            .map_err(|_| "Archive state is poisoned".to_string())?
+            .is_some();
+        if !is_open {
+            let tag = self.latest_ref().await?;
+            self.open_release(&tag).await?;
+        }
+        self.with_archive(|archive| archive.read(path))
+    }
+}
+
+/// A folder holding the pack (either the SLUS folder itself or a folder containing it)
+pub(crate) struct LocalFolderSource {
+    root: PathBuf,
+}
+
+impl LocalFolderSource {
+    pub fn new(path: &str, slus_folder: &str) -> Self {
+        let path = PathBuf::from(path);
+        let nested = path.join(slus_folder);
+        LocalFolderSource {
+            root: if nested.is_dir() { nested } else { path },
+        }
+    }
+}
+
+/// Recursively hash the files of a folder (hidden files are skipped)
+fn list_folder(root: &Path, dir: &Path, files: &mut HashMap<String, String>, sizes: &mut HashMap<String, u64>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+    for entry in entries.filter_map(|e| e.ok()) {
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        if path.is_dir() {
+            list_folder(root, &path, files, sizes)?;
+        } else if path.is_file() {
+            let relative_path = path
+                .strip_prefix(root)
+                .map_err(|e| format!("Failed to get relative path: {}", e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let content = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", relative_path, e))?;
+            sizes.insert(relative_path.clone(), content.len() as u64);
+            files.insert(relative_path, compute_sha_for_content(&content));
+        }
+    }
+    Ok(())
+}
+
+impl PackSource for LocalFolderSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        local_version("folder", &self.root)
+    }
+
+    async fn list_tree(&self, _reference: &str) -> Result<PackTree, String> {
+        let mut files = HashMap::new();
+        let mut sizes = HashMap::new();
+        list_folder(&self.root, &self.root, &mut files, &mut sizes)?;
+        Ok((files, sizes))
+    }
+
+    async fn get_changes(
+        &self,
+        _base_ref: &str,
+        _head_ref: &str,
+        _progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        Err("TRUNCATED: Local folders have no change history".to_string())
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        fs::read(self.root.join(path)).map_err(|e| format!("Failed to read {}: {}", path, e))
+    }
+}
+
+/// The source selected in state
+pub(crate) enum ConfiguredSource {
+    Repository(GitHubSource),
+    ReleaseArchive(ReleaseArchiveSource),
+    LocalFolder(LocalFolderSource),
+    LocalZip(LocalZipSource),
+    LanPeer(LanPeerSource),
+}
+
+impl PackSource for ConfiguredSource {
+    async fn latest_ref(&self) -> Result<String, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.latest_ref().await,
+            ConfiguredSource::ReleaseArchive(source) => source.latest_ref().await,
+            ConfiguredSource::LocalFolder(source) => source.latest_ref().await,
+            ConfiguredSource::LocalZip(source) => source.latest_ref().await,
+            ConfiguredSource::LanPeer(source) => source.latest_ref().await,
+        }
+    }
+
+    async fn list_tree(&self, reference: &str) -> Result<PackTree, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.list_tree(reference).await,
+            ConfiguredSource::ReleaseArchive(source) => source.list_tree(reference).await,
+            ConfiguredSource::LocalFolder(source) => source.list_tree(reference).await,
+            ConfiguredSource::LocalZip(source) => source.list_tree(reference).await,
+            ConfiguredSource::LanPeer(source) => source.list_tree(reference).await,
+        }
+    }
+
+    async fn get_changes(
+        &self,
+        base_ref: &str,
+        head_ref: &str,
+        progress: &SourceProgress<'_>,
+    ) -> Result<Vec<CompareFile>, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.get_changes(base_ref, head_ref, progress).await,
+            ConfiguredSource::ReleaseArchive(source) => source.get_changes(base_ref, head_ref, progress).await,
+            ConfiguredSource::LocalFolder(source) => source.get_changes(base_ref, head_ref, progress).await,
+            ConfiguredSource::LocalZip(source) => source.get_changes(base_ref, head_ref, progress).await,
+            ConfiguredSource::LanPeer(source) => source.get_changes(base_ref, head_ref, progress).await,
+        }
+    }
+
+    async fn fetch_file(&self, path: &str) -> Result<Vec<u8>, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.fetch_file(path).await,
+            ConfiguredSource::ReleaseArchive(source) => source.fetch_file(path).await,
+            ConfiguredSource::LocalFolder(source) => source.fetch_file(path).await,
+            ConfiguredSource::LocalZip(source) => source.fetch_file(path).await,
+            ConfiguredSource::LanPeer(source) => source.fetch_file(path).await,
+        }
+    }
+
+    async fn fetch_file_resumable(&self, path: &str, partial_path: &Path) -> Result<Vec<u8>, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.fetch_file_resumable(path, partial_path).await,
+            _ => self.fetch_file(path).await,
+        }
+    }
+
+    fn has_change_history(&self) -> bool {
+        match self {
+            ConfiguredSource::Repository(source) => source.has_change_history(),
+            ConfiguredSource::ReleaseArchive(source) => source.has_change_history(),
+            ConfiguredSource::LocalFolder(source) => source.has_change_history(),
+            ConfiguredSource::LocalZip(source) => source.has_change_history(),
+            ConfiguredSource::LanPeer(source) => source.has_change_history(),
+        }
+    }
+
+    async fn probe_mirrors(&self) -> Vec<MirrorHealth> {
+        match self {
+            ConfiguredSource::Repository(source) => source.probe_mirrors().await,
+            _ => Vec::new(),
+        }
+    }
+
+    fn take_mirror_usage(&self) -> Vec<MirrorUsage> {
+        match self {
+            ConfiguredSource::Repository(source) => source.take_mirror_usage(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn supports_archive(&self) -> bool {
+        match self {
+            ConfiguredSource::Repository(source) => source.supports_archive(),
+            _ => false,
+        }
+    }
+
+    async fn extract_archive(
+        &self,
+        reference: &str,
+        targets: HashMap<String, ArchiveTarget>,
+        work_dir: &Path,
+        progress: &SourceProgress<'_>,
+    ) -> Result<HashSet<String>, String> {
+        match self {
+            ConfiguredSource::Repository(source) => source.extract_archive(reference, targets, work_dir, progress).await,
+            _ => Err("This pack source has no archive download".to_string()),
+        }
+    }
+}
+
+/// Open the pack source selected in state (the repository if the state can't be read)
+pub(crate) fn open_pack_source(app: &AppHandle, config: &RepoConfig, token: &Option<String>) -> Result<ConfiguredSource, String> {
+    let state = load_state(app.clone()).unwrap_or_default();
+
+    Ok(match state.pack_source {
+        PackSourceSetting::Repository => {
+            // Mirrors from the pack catalog plus those announced in installer-data.json
+            let installer_data_url = config.raw_url("installer-data.json");
+            let mut mirrors = config.mirrors.clone();
+            if let Some(cache) = state.installer_data_cache.filter(|cache| cache.url == installer_data_url) {
+                mirrors.extend(cache.data.download_mirrors);
+            }
+            ConfiguredSource::Repository(GitHubSource::new(config, token, &mirrors))
+        }
+        PackSourceSetting::ReleaseArchive { asset_name } => {
+            let download_dir = app
+                .path()
+                .app_cache_dir()
+                .map_err(|e| format!("Failed to get app cache directory: {}", e))?
+                .join("archives");
+            ConfiguredSource::ReleaseArchive(ReleaseArchiveSource::new(config, token, asset_name, download_dir))
+        }
+        PackSourceSetting::LocalFolder { path } => {
+            ConfiguredSource::LocalFolder(LocalFolderSource::new(&path, &config.slus_folder))
+        }
+        PackSourceSetting::LocalZip { path } => ConfiguredSource::LocalZip(LocalZipSource::new(&path, &config.slus_folder)),
+        PackSourceSetting::LanPeer { address, pairing_code } => {
+            ConfiguredSource::LanPeer(LanPeerSource::new(config, token, &address, &pairing_code))
+        }
+    })
+}
+
+/// Get where syncs get the pack from
+#[tauri::command]
+pub fn get_pack_source(app: AppHandle) -> Result<PackSourceSetting, String> {
+    Ok(load_state(app)?.pack_source)
+}
+
+/// Set where syncs get the pack from
+#[tauri::command]
+pub fn set_pack_source(app: AppHandle, source: PackSourceSetting) -> Result<(), String> {
+    match &source {
+        PackSourceSetting::LocalFolder { path } if !Path::new(path).is_dir() => {
+            return Err(format!("Folder not found: {}", path));
+        }
+        PackSourceSetting::LocalZip { path } if !Path::new(path).is_file() => {
+            return Err(format!("Zip file not found: {}", path));
+        }
+        _ => {}
+    }
+
+    with_state_mut(&app, |state| {
+        state.pack_source = source;
+        Ok(())
+    })
+}
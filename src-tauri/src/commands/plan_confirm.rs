@@ -0,0 +1,130 @@
+//! Confirmation of large delete plans
+
+use sha2::{Digest, Sha256};
+use std::sync::Mutex;
+
+/// Plans deleting at least this many files need their token to run
+pub const PLAN_CONFIRM_MIN_DELETES: usize = 25;
+
+/// Plans kept waiting for confirmation (the oldest is dropped first)
+const MAX_PENDING_PLANS: usize = 16;
+
+/// Token -> version the plan was made for
+static PENDING_PLANS: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+fn plan_token(reference: &str, downloads: &[&str], deletes: &[&str]) -> String {
+    let mut lines: Vec<String> = downloads
+        .iter()
+        .map(|path| format!("A {}", path))
+        .chain(deletes.iter().map(|path| format!("D {}", path)))
+        .collect();
+    lines.sort();
+
+    let mut hasher = Sha256::new();
+    hasher.update(reference.as_bytes());
+    for line in &lines {
+        hasher.update(b"\n");
+        hasher.update(line.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Register a plan shown to the user and return its token
+pub(crate) fn issue_plan(reference: &str, downloads: &[&str], deletes: &[&str]) -> String {
+    let token = plan_token(reference, downloads, deletes);
+    if let Ok(mut plans) = PENDING_PLANS.lock() {
+        plans.retain(|(pending, _)| pending != &token);
+        if plans.len() >= MAX_PENDING_PLANS {
+            plans.remove(0);
+        }
+        plans.push((token.clone(), reference.to_string()));
+    }
+    token
+}
+
+/// Check a plan about to run against the one the user confirmed
+/// Returns the version the plan was made for, or None when the plan is small enough to run
+/// without a token. A token can only be used once
+pub(crate) fn check_plan(token: Option<&str>, downloads: &[&str], deletes: &[&str]) -> Result<Option<String>, String> {
+    let Some(token) = token else {
+        if deletes.len() >= PLAN_CONFIRM_MIN_DELETES {
+            return Err(format!(
+                "Deleting {} files needs a confirmed plan. Review the changes again before applying them.",
+                deletes.len()
+            ));
+        }
+        return Ok(None);
+    };
+
+    let mut plans = PENDING_PLANS.lock().map_err(|_| "Plan registry is unavailable".to_string())?;
+    let position = plans
+        .iter()
+        .position(|(pending, _)| pending == token)
+        .ok_or_else(|| "This plan has expired or was already applied. Review the changes again.".to_string())?;
+    let reference = plans[position].1.clone();
+    if plan_token(&reference, downloads, deletes) != token {
+        return Err("The files to change differ from the plan that was reviewed. Review the changes again.".to_string());
+    }
+    plans.remove(position);
+    Ok(Some(reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The registry is shared, so tests that fill it mustn't evict each other's plans
+    static REGISTRY_LOCK: Mutex<()> = Mutex::new(());
+
+    fn delete_paths(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("uniforms/{}.png", i)).collect()
+    }
+
+    #[test]
+    fn large_delete_plans_need_their_token() {
+        let _lock = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let paths = delete_paths(PLAN_CONFIRM_MIN_DELETES);
+        let deletes: Vec<&str> = paths.iter().map(String::as_str).collect();
+
+        // Small plans run without a token, large ones don't
+        assert_eq!(check_plan(None, &[], &deletes[1..]), Ok(None));
+        assert!(check_plan(None, &[], &deletes).is_err());
+
+        // A changed plan is refused; the reviewed one runs
+        let token = issue_plan("abc123", &["stadiums/a.png"], &deletes);
+        assert!(check_plan(Some(&token), &[], &deletes).is_err());
+        assert_eq!(check_plan(Some(&token), &["stadiums/a.png"], &deletes), Ok(Some("abc123".to_string())));
+    }
+
+    #[test]
+    fn plan_tokens_are_single_use() {
+        let _lock = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let paths = delete_paths(PLAN_CONFIRM_MIN_DELETES);
+        let deletes: Vec<&str> = paths.iter().map(String::as_str).collect();
+
+        let token = issue_plan("def456", &[], &deletes);
+        assert_eq!(check_plan(Some(&token), &[], &deletes), Ok(Some("def456".to_string())));
+        assert!(check_plan(Some(&token), &[], &deletes).is_err());
+
+        // Showing the same plan again issues it again
+        assert_eq!(issue_plan("def456", &[], &deletes), token);
+        assert_eq!(check_plan(Some(&token), &[], &deletes), Ok(Some("def456".to_string())));
+    }
+
+    #[test]
+    fn oldest_pending_plan_is_dropped() {
+        let _lock = REGISTRY_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let paths = delete_paths(PLAN_CONFIRM_MIN_DELETES);
+        let deletes: Vec<&str> = paths.iter().map(String::as_str).collect();
+
+        let oldest = issue_plan("version-0", &[], &deletes);
+        let newer: Vec<String> = (1..=MAX_PENDING_PLANS)
+            .map(|i| issue_plan(&format!("version-{}", i), &[], &deletes))
+            .collect();
+
+        assert!(check_plan(Some(&oldest), &[], &deletes).is_err());
+        for (i, token) in newer.iter().enumerate() {
+            assert_eq!(check_plan(Some(token), &[], &deletes), Ok(Some(format!("version-{}", i + 1))));
+        }
+    }
+}
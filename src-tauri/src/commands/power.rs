@@ -0,0 +1,137 @@
+//! Keeps the computer awake during long installs, syncs and verifications
+
+#[cfg(not(target_os = "windows"))]
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(target_os = "windows")]
+use std::sync::mpsc::{channel, Sender};
+use std::sync::Mutex;
+
+/// What keeps the computer awake for one guard
+#[cfg(target_os = "windows")]
+type Inhibitor = Sender<()>;
+#[cfg(not(target_os = "windows"))]
+type Inhibitor = Child;
+
+/// Inhibitors of the guards that haven't been dropped yet, by guard id
+static ACTIVE_INHIBITORS: Mutex<Vec<(u64, Inhibitor)>> = Mutex::new(Vec::new());
+static NEXT_GUARD_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Prevents system sleep until dropped (also on early returns and errors)
+pub struct SleepGuard {
+    id: u64,
+}
+
+impl Drop for SleepGuard {
+    fn drop(&mut self) {
+        let inhibitor = ACTIVE_INHIBITORS.lock().ok().and_then(|mut inhibitors| {
+            let index = inhibitors.iter().position(|(id, _)| *id == self.id)?;
+            Some(inhibitors.remove(index).1)
+        });
+        // Already gone if release_all_sleep_guards ran first
+        if let Some(inhibitor) = inhibitor {
+            release(inhibitor);
+        }
+    }
+}
+
+/// Restore normal sleep for one inhibitor
+fn release(inhibitor: Inhibitor) {
+    #[cfg(target_os = "windows")]
+    {
+        // Dropping the sender wakes the holding thread, which resets SetThreadExecutionState
+        drop(inhibitor);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let mut child = inhibitor;
+        // Closing stdin ends the inhibited command on Linux, kill covers caffeinate
+        drop(child.stdin.take());
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+/// Register an inhibitor and return the guard that releases it
+fn track(inhibitor: Option<Inhibitor>) -> SleepGuard {
+    let id = NEXT_GUARD_ID.fetch_add(1, Ordering::Relaxed);
+    if let (Some(inhibitor), Ok(mut inhibitors)) = (inhibitor, ACTIVE_INHIBITORS.lock()) {
+        inhibitors.push((id, inhibitor));
+    }
+    SleepGuard { id }
+}
+
+/// Restore normal sleep now, whatever guards are still alive (called on app exit)
+pub fn release_all_sleep_guards() {
+    let inhibitors = match ACTIVE_INHIBITORS.lock() {
+        Ok(mut inhibitors) => std::mem::take(&mut *inhibitors),
+        Err(_) => return,
+    };
+    for (_, inhibitor) in inhibitors {
+        release(inhibitor);
+    }
+}
+
+/// Prevent system sleep until the returned guard is dropped
+/// `reason` is shown by the OS where it lists what is blocking sleep (Linux)
+pub fn prevent_sleep(reason: &str) -> SleepGuard {
+    #[cfg(target_os = "windows")]
+    {
+        let _ = reason;
+
+        // Execution state is per thread, so hold it on a thread that outlives any async task hops
+        let (sender, released) = channel::<()>();
+        let spawned = std::thread::Builder::new()
+            .name("sleep-guard".to_string())
+            .spawn(move || {
+                use windows::Win32::System::Power::{SetThreadExecutionState, ES_CONTINUOUS, ES_SYSTEM_REQUIRED};
+
+                unsafe {
+                    SetThreadExecutionState(ES_CONTINUOUS | ES_SYSTEM_REQUIRED);
+                }
+                // Returns once the sender is dropped (guard dropped or all guards released)
+                let _ = released.recv();
+                unsafe {
+                    SetThreadExecutionState(ES_CONTINUOUS);
+                }
+            });
+
+        track(spawned.ok().map(|_| sender))
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let _ = reason;
+
+        // -i: prevent idle sleep, -w: exit with us even if the guard is never dropped
+        let inhibitor = Command::new("caffeinate")
+            .args(["-i", "-w", &std::process::id().to_string()])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok();
+        track(inhibitor)
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    {
+        // The lock is held while the inhibited command runs: cat, which exits once the guard
+        // closes its stdin (or the app exits and the pipe closes with it)
+        let inhibitor = Command::new("systemd-inhibit")
+            .args([
+                "--what=idle:sleep",
+                "--who=NCAA NEXT Textures Downloader",
+                &format!("--why={}", reason),
+                "--mode=block",
+                "cat",
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok();
+        track(inhibitor)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::commands::{load_state, with_state_mut, AppState, DisabledRegistry, LastVerification};
+use crate::config::RepoConfig;
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// Profile id used for sync bookkeeping of the bundled pack
+pub const DEFAULT_PROFILE_ID: &str = "default";
+
+/// A texture pack profile pointing at its own repository / SLUS folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackProfile {
+    /// Unique id (derived from the name)
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Repository configuration for this pack
+    pub config: RepoConfig,
+}
+
+/// Per-profile sync bookkeeping, swapped in and out of AppState when switching profiles
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProfileSyncState {
+    pub initial_setup_done: bool,
+    pub last_sync_commit: Option<String>,
+    pub last_sync_timestamp: Option<String>,
+    #[serde(default)]
+    pub last_verification: Option<LastVerification>,
+    #[serde(default)]
+    pub incremental_syncs_since_full: u32,
+    #[serde(default)]
+    pub last_full_sync_timestamp: Option<String>,
+    #[serde(default)]
+    pub disabled_textures: DisabledRegistry,
+}
+
+/// Find the active profile in the state (None when the bundled pack is active)
+pub fn active_profile(state: &AppState) -> Option<&PackProfile> {
+    let id = state.active_profile.as_ref()?;
+    state.profiles.iter().find(|p| &p.id == id)
+}
+
+/// Build a profile id from a display name (lowercase, dash-separated)
+fn profile_id_from_name(name: &str) -> String {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    slug.split('-').filter(|s| !s.is_empty()).collect::<Vec<_>>().join("-")
+}
+
+/// List all configured profiles
+#[tauri::command]
+pub fn list_profiles(app: AppHandle) -> Result<Vec<PackProfile>, String> {
+    Ok(load_state(app)?.profiles)
+}
+
+/// Add a new pack profile
+#[tauri::command]
+pub fn add_profile(app: AppHandle, name: String, config: RepoConfig) -> Result<PackProfile, String> {
+    let id = profile_id_from_name(&name);
+    if id.is_empty() {
+        return Err("Profile name must contain letters or numbers".to_string());
+    }
+    if id == DEFAULT_PROFILE_ID {
+        return Err(format!("\"{}\" is reserved for the bundled pack", DEFAULT_PROFILE_ID));
+    }
+    // Also covers packs added from the catalog
+    config.check_paths()?;
+
+    with_state_mut(&app, |state| {
+        if state.profiles.iter().any(|p| p.id == id) {
+            return Err(format!("A profile named \"{}\" already exists", name));
+        }
+
+        let profile = PackProfile { id, name, config };
+        state.profiles.push(profile.clone());
+        Ok(profile)
+    })
+}
+
+/// Remove a pack profile (switches back to the bundled pack if it was active)
+#[tauri::command]
+pub fn remove_profile(app: AppHandle, id: String) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        if state.active_profile.as_deref() == Some(id.as_str()) {
+            switch_profile_in_state(state, None);
+        }
+        state.profiles.retain(|p| p.id != id);
+        state.profile_sync_states.remove(&id);
+        Ok(())
+    })
+}
+
+/// Switch the active profile (None = bundled pack)
+/// Returns the updated state with the new profile's sync bookkeeping swapped in
+#[tauri::command]
+pub fn switch_profile(app: AppHandle, id: Option<String>) -> Result<AppState, String> {
+    with_state_mut(&app, |state| {
+        if let Some(id) = &id {
+            if !state.profiles.iter().any(|p| &p.id == id) {
+                return Err(format!("Profile \"{}\" not found", id));
+            }
+        }
+
+        switch_profile_in_state(state, id);
+        Ok(())
+    })?;
+    load_state(app)
+}
+
+/// Stash the current sync bookkeeping under the outgoing profile and restore the incoming one's
+/// textures_path is shared since all packs live in the same PCSX2 textures directory
+fn switch_profile_in_state(state: &mut AppState, id: Option<String>) {
+    if state.active_profile == id {
+        return;
+    }
+
+    let outgoing = state.active_profile.clone().unwrap_or_else(|| DEFAULT_PROFILE_ID.to_string());
+    state.profile_sync_states.insert(
+        outgoing,
+        ProfileSyncState {
+            initial_setup_done: state.initial_setup_done,
+            last_sync_commit: state.last_sync_commit.take(),
+            last_sync_timestamp: state.last_sync_timestamp.take(),
+            last_verification: state.last_verification.take(),
+            incremental_syncs_since_full: std::mem::take(&mut state.incremental_syncs_since_full),
+            last_full_sync_timestamp: state.last_full_sync_timestamp.take(),
+            disabled_textures: std::mem::take(&mut state.disabled_textures),
+        },
+    );
+
+    let incoming = id.clone().unwrap_or_else(|| DEFAULT_PROFILE_ID.to_string());
+    let restored = state.profile_sync_states.remove(&incoming).unwrap_or_default();
+    state.initial_setup_done = restored.initial_setup_done;
+    state.last_sync_commit = restored.last_sync_commit;
+    state.last_sync_timestamp = restored.last_sync_timestamp;
+    state.last_verification = restored.last_verification;
+    state.incremental_syncs_since_full = restored.incremental_syncs_since_full;
+    state.last_full_sync_timestamp = restored.last_full_sync_timestamp;
+    state.disabled_textures = restored.disabled_textures;
+    state.active_profile = id;
+}
@@ -0,0 +1,321 @@
+//! Stable identifiers for progress events
+
+use crate::commands::network::OperationStalled;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{Emitter, Window};
+
+/// Time between heartbeat events while an operation waits on something
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Longest a single heartbeat-watched step may take before it's abandoned as stalled
+const PHASE_STALL_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Files at least this large report their bytes while downloading
+const BYTE_PROGRESS_MIN_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Time between byte progress events of one file
+const BYTE_PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Stage of an install, sync or maintenance operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Preparing,
+    Cloning,
+    Compressing,
+    Downloading,
+    Extracting,
+    Moving,
+    Cleanup,
+    Fetching,
+    Scanning,
+    Comparing,
+    Syncing,
+    Deleting,
+    Counting,
+    Verifying,
+    SyncComplete,
+    AnalysisComplete,
+    Dedup,
+    Manifest,
+    Complete,
+}
+
+/// Values substituted into a localized message (e.g. "path", "count")
+pub type MessageParams = BTreeMap<&'static str, String>;
+
+/// What the overall progress bar of a session covers
+#[derive(Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionKind {
+    /// Fetch, compare, download/delete and cleanup, then the verification the frontend runs after it
+    Sync,
+    /// A verification (or repair) on its own
+    Verification,
+    /// An installation (its events report their own percentage)
+    Install,
+}
+
+/// Overall progress of the current session, shared by every progress event
+struct ProgressSession {
+    /// Changes with every new session; a verification continuing a sync keeps the sync's
+    operation_id: u64,
+    kind: SessionKind,
+    /// Highest overall percentage reported, so the bar never moves backwards between phases
+    percent: u32,
+    /// The sync part is done, so a verification continues this session instead of starting one
+    awaiting_verification: bool,
+}
+
+static SESSION: Mutex<ProgressSession> = Mutex::new(ProgressSession {
+    operation_id: 0,
+    kind: SessionKind::Sync,
+    percent: 0,
+    awaiting_verification: false,
+});
+
+/// Sequence number of the next progress event (across all operations)
+static NEXT_SEQ: AtomicU64 = AtomicU64::new(1);
+
+/// Latest progress event, see get_operation_status
+static LAST_STATUS: Mutex<Option<OperationStatus>> = Mutex::new(None);
+
+/// Window of the running operation, which byte progress of large downloads goes to
+/// (downloads happen deep in the pack sources, which have no window of their own)
+static BYTE_PROGRESS_WINDOW: Mutex<Option<Window>> = Mutex::new(None);
+
+/// Latest progress event of the current operation
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationStatus {
+    pub operation_id: u64,
+    pub kind: SessionKind,
+    /// The event's payload as it was emitted (including its seq)
+    pub payload: serde_json::Value,
+}
+
+fn start_session(kind: SessionKind) {
+    if let Ok(mut session) = SESSION.lock() {
+        *session = ProgressSession {
+            operation_id: session.operation_id + 1,
+            kind,
+            percent: 0,
+            awaiting_verification: false,
+        };
+    }
+}
+
+/// Start the overall progress of a sync (before its first progress event)
+pub(crate) fn start_sync_session() {
+    start_session(SessionKind::Sync);
+}
+
+/// Start an installation (before its first progress event)
+pub(crate) fn start_install_session() {
+    start_session(SessionKind::Install);
+}
+
+/// Continue a sync session whose sync part just finished, or start a verification session
+pub(crate) fn start_verification_session() {
+    if let Ok(mut session) = SESSION.lock() {
+        if session.awaiting_verification {
+            session.awaiting_verification = false;
+            return;
+        }
+    }
+    start_session(SessionKind::Verification);
+}
+
+/// Part of the overall bar (start, end percent) a stage covers; None for stages that don't move it
+fn stage_span(kind: SessionKind, stage: Stage) -> Option<(u32, u32)> {
+    match kind {
+        SessionKind::Sync => match stage {
+            Stage::Fetching => Some((0, 5)),
+            Stage::Scanning => Some((5, 20)),
+            Stage::Comparing | Stage::AnalysisComplete => Some((20, 25)),
+            Stage::Syncing | Stage::Downloading => Some((25, 75)),
+            Stage::Deleting => Some((75, 85)),
+            Stage::Complete => Some((85, 85)),
+            Stage::SyncComplete | Stage::Cleanup => Some((85, 90)),
+            Stage::Counting | Stage::Verifying => Some((90, 100)),
+            _ => None,
+        },
+        SessionKind::Verification => match stage {
+            Stage::Fetching
+            | Stage::Scanning
+            | Stage::Comparing
+            | Stage::Counting
+            | Stage::Verifying
+            | Stage::Downloading
+            | Stage::Deleting => Some((0, 100)),
+            Stage::Complete => Some((100, 100)),
+            _ => None,
+        },
+        SessionKind::Install => None,
+    }
+}
+
+/// Overall percentage of the session after an event of `stage` with optional current/total counts
+pub(crate) fn overall_percent(stage: Stage, current: Option<u32>, total: Option<u32>) -> u32 {
+    let Ok(mut session) = SESSION.lock() else {
+        return 0;
+    };
+
+    if let Some((start, end)) = stage_span(session.kind, stage) {
+        let within = match (current, total) {
+            (Some(current), Some(total)) if total > 0 => (end - start) * current.min(total) / total,
+            _ => 0,
+        };
+        session.percent = session.percent.max(start + within);
+    }
+    if session.kind == SessionKind::Sync && matches!(stage, Stage::Complete | Stage::SyncComplete) {
+        session.awaiting_verification = true;
+    }
+
+    session.percent
+}
+
+/// Operation id and sequence number for the next progress event
+pub(crate) fn next_event_stamp() -> (u64, u64) {
+    let operation_id = SESSION.lock().map_or(0, |session| session.operation_id);
+    (operation_id, NEXT_SEQ.fetch_add(1, Ordering::Relaxed))
+}
+
+/// Remember an event as the latest status of its operation
+pub(crate) fn record_event(operation_id: u64, payload: &impl Serialize) {
+    let Ok(payload) = serde_json::to_value(payload) else {
+        return;
+    };
+    let kind = SESSION.lock().map_or(SessionKind::Sync, |session| session.kind);
+    if let Ok(mut last) = LAST_STATUS.lock() {
+        *last = Some(OperationStatus { operation_id, kind, payload });
+    }
+}
+
+/// The latest progress event, for a frontend that missed events (or was reloaded) to catch up
+/// None before the first event
+#[tauri::command]
+pub fn get_operation_status() -> Option<OperationStatus> {
+    LAST_STATUS.lock().ok().and_then(|last| last.clone())
+}
+
+/// Sent every few seconds while an operation waits on a single long step
+#[derive(Clone, Serialize)]
+struct HeartbeatPayload {
+    operation_id: u64,
+    stage: Stage,
+    /// Seconds since the step started
+    elapsed_secs: u64,
+}
+
+/// Await a step that reports no progress itself, emitting a "progress-heartbeat" event
+/// every few seconds until it finishes, or failing once it has taken PHASE_STALL_TIMEOUT
+pub(crate) async fn with_heartbeat<T, F>(window: &Window, stage: Stage, step: F) -> Result<T, String>
+where
+    F: Future<Output = Result<T, String>>,
+{
+    let started = Instant::now();
+    let mut step = std::pin::pin!(step);
+    loop {
+        match tokio::time::timeout(HEARTBEAT_INTERVAL, step.as_mut()).await {
+            Ok(output) => return output,
+            Err(_) if started.elapsed() >= PHASE_STALL_TIMEOUT => {
+                let phase = serde_json::to_value(stage)
+                    .ok()
+                    .and_then(|value| value.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                return Err(OperationStalled {
+                    phase: phase.replace('_', " "),
+                    path: None,
+                    stalled_secs: started.elapsed().as_secs(),
+                }
+                .to_string());
+            }
+            Err(_) => {
+                let operation_id = SESSION.lock().map_or(0, |session| session.operation_id);
+                let _ = window.emit("progress-heartbeat", HeartbeatPayload {
+                    operation_id,
+                    stage,
+                    elapsed_secs: started.elapsed().as_secs(),
+                });
+            }
+        }
+    }
+}
+
+/// Sent while a large file downloads ("download-progress")
+#[derive(Clone, Serialize)]
+struct DownloadProgressPayload {
+    operation_id: u64,
+    /// Path relative to the SLUS folder
+    path: String,
+    received_bytes: u64,
+    total_bytes: u64,
+}
+
+/// Sends byte progress of large downloads to a window while alive
+pub(crate) struct ByteProgressGuard;
+
+impl Drop for ByteProgressGuard {
+    fn drop(&mut self) {
+        if let Ok(mut target) = BYTE_PROGRESS_WINDOW.lock() {
+            *target = None;
+        }
+    }
+}
+
+/// Report byte progress of large downloads to `window` until the guard is dropped
+pub(crate) fn report_byte_progress(window: &Window) -> ByteProgressGuard {
+    if let Ok(mut target) = BYTE_PROGRESS_WINDOW.lock() {
+        *target = Some(window.clone());
+    }
+    ByteProgressGuard
+}
+
+/// Bytes received of one download, emitted every BYTE_PROGRESS_INTERVAL
+/// Shared by the connections of a download split into byte ranges
+pub(crate) struct ByteProgress {
+    window: Window,
+    path: String,
+    total: u64,
+    received: AtomicU64,
+    last_emit: Mutex<Instant>,
+}
+
+impl ByteProgress {
+    /// Track a download of `total` bytes, `already` of them from an earlier attempt
+    /// None when the size is unknown, the file is small or no operation listens
+    pub(crate) fn start(path: &str, total: Option<u64>, already: u64) -> Option<Self> {
+        let total = total.filter(|total| *total >= BYTE_PROGRESS_MIN_BYTES)?;
+        let window = BYTE_PROGRESS_WINDOW.lock().ok()?.clone()?;
+        Some(ByteProgress {
+            window,
+            path: path.to_string(),
+            total,
+            received: AtomicU64::new(already),
+            last_emit: Mutex::new(Instant::now()),
+        })
+    }
+
+    pub(crate) fn add(&self, bytes: usize) {
+        let received = self.received.fetch_add(bytes as u64, Ordering::Relaxed) + bytes as u64;
+        let Ok(mut last_emit) = self.last_emit.lock() else {
+            return;
+        };
+        if received < self.total && last_emit.elapsed() < BYTE_PROGRESS_INTERVAL {
+            return;
+        }
+        *last_emit = Instant::now();
+        let operation_id = SESSION.lock().map_or(0, |session| session.operation_id);
+        let _ = self.window.emit("download-progress", DownloadProgressPayload {
+            operation_id,
+            path: self.path.clone(),
+            received_bytes: received.min(self.total),
+            total_bytes: self.total,
+        });
+    }
+}
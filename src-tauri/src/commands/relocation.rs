@@ -0,0 +1,93 @@
+//! Detection and confirmation of the pack folder moving upstream
+
+use crate::commands::network::http_client;
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync::{get_latest_commit_with_token, list_repo_folders};
+use crate::config::resolve_repo_config;
+use serde::Serialize;
+use tauri::AppHandle;
+
+/// Where the pack folder went
+#[derive(Debug, Clone, Serialize)]
+pub struct SparsePathRelocation {
+    pub old_path: String,
+    pub new_path: String,
+    /// "alias" (announced by the pack) or "detected" (a folder with the SLUS folder's name)
+    pub source: String,
+    /// Other folders with the SLUS folder's name, when the detected location is a guess
+    pub other_candidates: Vec<String>,
+}
+
+/// Check whether the pack folder is still at sparse_path in the newest commit
+/// Ok(None) when it is; the new location when it moved; an error when it's gone and can't be found
+#[tauri::command]
+pub async fn detect_sparse_path_relocation(
+    app: AppHandle,
+    github_token: Option<String>,
+) -> Result<Option<SparsePathRelocation>, String> {
+    let config = resolve_repo_config(&app)?;
+    let client = http_client();
+    let commit_sha = get_latest_commit_with_token(&config, &github_token).await?;
+    let (folders, truncated) = list_repo_folders(&config, &client, &commit_sha, &github_token).await?;
+    if folders.contains(&config.sparse_path) {
+        return Ok(None);
+    }
+
+    // Aliases announced by the pack, in the order given
+    let mut aliases = config.sparse_path_aliases.clone();
+    let installer_data_url = config.raw_url("installer-data.json");
+    if let Some(cache) = load_state(app)?.installer_data_cache.filter(|cache| cache.url == installer_data_url) {
+        aliases.extend(cache.data.sparse_path_aliases);
+    }
+    if let Some(alias) = aliases.into_iter().find(|alias| folders.contains(alias)) {
+        return Ok(Some(SparsePathRelocation {
+            old_path: config.sparse_path,
+            new_path: alias,
+            source: "alias".to_string(),
+            other_candidates: Vec::new(),
+        }));
+    }
+
+    // A folder with the same name somewhere else, the shallowest first
+    let suffix = format!("/{}", config.slus_folder);
+    let mut candidates: Vec<String> = folders
+        .into_iter()
+        .filter(|folder| folder.ends_with(&suffix) || *folder == config.slus_folder)
+        .collect();
+    candidates.sort_by_key(|folder| folder.matches('/').count());
+    if candidates.is_empty() {
+        return Err(format!(
+            "The texture pack folder {} is no longer in the repository and its new location couldn't be found{}",
+            config.sparse_path,
+            if truncated { " (the repository listing was incomplete)" } else { "" }
+        ));
+    }
+
+    let new_path = candidates.remove(0);
+    Ok(Some(SparsePathRelocation {
+        old_path: config.sparse_path,
+        new_path,
+        source: "detected".to_string(),
+        other_candidates: candidates,
+    }))
+}
+
+/// Use the pack folder's new location from now on, in the active profile or (without one) the
+/// repository overrides
+/// Syncs across the move should be full syncs: an incremental one sees every file as new
+#[tauri::command]
+pub fn apply_sparse_path_relocation(app: AppHandle, new_path: String) -> Result<(), String> {
+    let new_path = new_path.trim().trim_matches('/').to_string();
+    if new_path.is_empty() {
+        return Err("The new location is empty".to_string());
+    }
+
+    with_state_mut(&app, |state| {
+        let active_profile = state.active_profile.clone();
+        match active_profile.and_then(|id| state.profiles.iter_mut().find(|profile| profile.id == id)) {
+            Some(profile) => profile.config.sparse_path = new_path,
+            None => state.repo_config_overrides.get_or_insert_with(Default::default).sparse_path = Some(new_path),
+        }
+        Ok(())
+    })
+}
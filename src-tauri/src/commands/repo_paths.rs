@@ -0,0 +1,127 @@
+//! Validation of file paths that come from the pack source
+
+use std::collections::HashMap;
+
+/// Names Windows reserves for devices, with or without an extension ("con", "nul.png")
+const RESERVED_NAMES: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8", "com9", "lpt1",
+    "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Characters that aren't allowed in Windows file names (backslash included, as it would be read
+/// as a separator there)
+const INVALID_CHARS: &[char] = &['<', '>', ':', '"', '|', '?', '*', '\\'];
+
+fn check_component(component: &str) -> Result<(), String> {
+    match component {
+        "" => return Err("empty path component".to_string()),
+        "." | ".." => return Err(format!("'{}' path component", component)),
+        _ => {}
+    }
+    if let Some(c) = component.chars().find(|c| INVALID_CHARS.contains(c) || c.is_control()) {
+        return Err(format!("invalid character {:?}", c));
+    }
+    if component.ends_with('.') || component.ends_with(' ') {
+        return Err("name ends with a dot or space".to_string());
+    }
+    let stem = component.split('.').next().unwrap_or(component).trim_end();
+    if RESERVED_NAMES.contains(&stem.to_ascii_lowercase().as_str()) {
+        return Err(format!("reserved name '{}'", stem));
+    }
+    Ok(())
+}
+
+/// Check a path relative to the SLUS folder ("uniforms/home.png")
+/// Returns why the path is unsafe to write to
+pub(crate) fn check_repo_path(path: &str) -> Result<(), String> {
+    if path.starts_with('/') {
+        return Err("absolute path".to_string());
+    }
+    path.split('/').try_for_each(check_component)
+}
+
+/// Check a git blob SHA before it's used in a path (the blob cache names files after them)
+pub(crate) fn check_blob_sha(sha: &str) -> Result<(), String> {
+    if sha.len() == 40 && sha.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)) {
+        Ok(())
+    } else {
+        Err(format!("Refusing invalid file hash {:?}", sha))
+    }
+}
+
+/// The last component of a name that's used as a file name (release asset names come from
+/// the server), refused if it's still unsafe
+pub(crate) fn safe_file_name(name: &str) -> Result<&str, String> {
+    let file_name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+    check_component(file_name).map_err(|reason| format!("Refusing unsafe file name {:?} ({})", name, reason))?;
+    Ok(file_name)
+}
+
+/// check_repo_path for a whole file list, failing on the first unsafe path
+pub(crate) fn check_repo_paths<'a>(paths: impl IntoIterator<Item = &'a str>) -> Result<(), String> {
+    for path in paths {
+        check_repo_path(path).map_err(|reason| format!("Refusing unsafe file path {:?} ({})", path, reason))?;
+    }
+    Ok(())
+}
+
+/// Remove unsafe entries from a pack listing (path -> sha, path -> size)
+/// Returns each removed path with the reason
+pub(crate) fn drop_unsafe_paths(
+    files: &mut HashMap<String, String>,
+    sizes: &mut HashMap<String, u64>,
+) -> Vec<(String, String)> {
+    let unsafe_paths: Vec<(String, String)> = files
+        .keys()
+        .filter_map(|path| check_repo_path(path).err().map(|reason| (path.clone(), reason)))
+        .collect();
+    for (path, _) in &unsafe_paths {
+        files.remove(path);
+        sizes.remove(path);
+    }
+    unsafe_paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsafe_repo_paths_are_rejected() {
+        assert!(check_repo_path("uniforms/home/jersey.png").is_ok());
+        assert!(check_repo_path("stadiums/console.png").is_ok());
+        for path in [
+            "../outside.png",
+            "uniforms/../../outside.png",
+            "/etc/passwd",
+            "C:/Windows/evil.dll",
+            "uniforms\\..\\evil.png",
+            "uniforms//home.png",
+            "uniforms/CON",
+            "uniforms/nul.png",
+            "uniforms/Lpt1.tga",
+            "uniforms/home.",
+            "uniforms/what?.png",
+            "uniforms/tab\there.png",
+        ] {
+            assert!(check_repo_path(path).is_err(), "{} should be rejected", path);
+        }
+    }
+
+    #[test]
+    fn blob_shas_must_be_lowercase_hex() {
+        assert!(check_blob_sha("e69de29bb2d1d6434b8b29ae775ad8c2e48c5391").is_ok());
+        assert!(check_blob_sha("E69DE29BB2D1D6434B8B29AE775AD8C2E48C5391").is_err());
+        assert!(check_blob_sha("../../../../../../../../../../etc/passwd").is_err());
+        assert!(check_blob_sha("e69de29b").is_err());
+    }
+
+    #[test]
+    fn asset_names_are_reduced_to_a_file_name() {
+        assert_eq!(safe_file_name("pack.zip"), Ok("pack.zip"));
+        assert_eq!(safe_file_name("../../pack.zip"), Ok("pack.zip"));
+        assert_eq!(safe_file_name("..\\pack.zip"), Ok("pack.zip"));
+        assert!(safe_file_name("..").is_err());
+        assert!(safe_file_name("dir/").is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Factory reset
+
+use crate::commands::bandwidth::configure_download_throttle;
+use crate::commands::file_index::INDEX_FILE_NAME;
+use crate::commands::filesystem::{delete_path, load_delete_permanently};
+use crate::commands::install::temp_clone_in_use;
+use crate::commands::lan_share::stop_lan_share;
+use crate::commands::network::reset_network_settings;
+use crate::commands::state::{delete_state_files, load_state};
+use crate::commands::sync::clear_tree_cache;
+use crate::commands::sync_transaction::{BACKUP_DIR_NAME, STAGING_DIR_NAME};
+use crate::commands::watcher::{install_writes_in_progress, stop_folder_watch};
+use crate::config::resolve_repo_config;
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// What the user types to confirm a reset
+const RESET_CONFIRM_TOKEN: &str = "RESET";
+
+/// What a reset removed
+#[derive(Debug, Clone, Serialize)]
+pub struct ResetReport {
+    pub removed: Vec<String>,
+    /// Paths that could not be removed, each with the reason (e.g. a file still in use)
+    pub failed: Vec<String>,
+    pub pack_removed: bool,
+}
+
+/// Files and folders the app created outside the textures directory
+fn app_data_locations(app: &AppHandle) -> Vec<PathBuf> {
+    let mut locations = Vec::new();
+    if let Ok(data_dir) = app.path().app_data_dir() {
+        locations.push(data_dir.join("sync-journal.json"));
+        locations.push(data_dir.join("sync-reports"));
+    }
+    if let Ok(cache_dir) = app.path().app_cache_dir() {
+        locations.push(cache_dir.join("blobs"));
+        locations.push(cache_dir.join("partials"));
+        locations.push(cache_dir.join("archives"));
+    }
+    if let Ok(log_dir) = app.path().app_log_dir() {
+        locations.push(log_dir);
+    }
+    locations
+}
+
+/// Temp folders and the index the app keeps in the textures directory, plus the installed pack
+/// Nothing is returned when the pack's folder names don't pass resolve_repo_config's checks (an
+/// empty or absolute temp_dir_name would otherwise point at the textures folder or anywhere)
+fn textures_dir_locations(app: &AppHandle) -> (Vec<PathBuf>, Option<PathBuf>) {
+    let (Ok(state), Ok(config)) = (load_state(app.clone()), resolve_repo_config(app)) else {
+        return (Vec::new(), None);
+    };
+    if config.check_paths().is_err() {
+        return (Vec::new(), None);
+    }
+    let Some(textures_dir) = state.textures_path.map(PathBuf::from) else {
+        return (Vec::new(), None);
+    };
+    let slus_path = textures_dir.join(&config.slus_folder);
+    let locations = vec![
+        textures_dir.join(&config.temp_dir_name),
+        textures_dir.join(STAGING_DIR_NAME),
+        textures_dir.join(BACKUP_DIR_NAME),
+        slus_path.join(INDEX_FILE_NAME),
+    ];
+    (locations, Some(slus_path))
+}
+
+fn remove_location(path: &Path) -> std::io::Result<()> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    }
+}
+
+/// Reset the app to a fresh install
+/// confirm_token must be "RESET" (typed by the user), so the reset can't happen by accident
+#[tauri::command]
+pub async fn reset_app(app: AppHandle, confirm_token: String, keep_installed_pack: bool) -> Result<ResetReport, String> {
+    if confirm_token.trim() != RESET_CONFIRM_TOKEN {
+        return Err(format!("Type {} to confirm the reset", RESET_CONFIRM_TOKEN));
+    }
+    if install_writes_in_progress() {
+        return Err("A sync or installation is running. Wait for it to finish or cancel it before resetting".to_string());
+    }
+
+    // Read before the state (which holds the textures path and pack overrides) is deleted
+    let (mut locations, pack_path) = textures_dir_locations(&app);
+    if locations.iter().any(|path| temp_clone_in_use(path)) {
+        return Err("An installation is still running. Cancel it before resetting".to_string());
+    }
+
+    // Nothing may keep writing into the folders being removed
+    let _ = stop_lan_share();
+    let _ = stop_folder_watch();
+    reset_network_settings(&app);
+    clear_tree_cache();
+
+    let delete_permanently = load_delete_permanently(&app);
+    locations.extend(app_data_locations(&app));
+
+    let mut report = ResetReport { removed: Vec::new(), failed: Vec::new(), pack_removed: false };
+    for path in locations.into_iter().filter(|path| path.exists()) {
+        match remove_location(&path) {
+            Ok(()) => report.removed.push(path.display().to_string()),
+            Err(e) => report.failed.push(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    // Only the installed pack is worth a trip to the Recycle Bin/Trash
+    if let Some(pack_path) = pack_path.filter(|path| !keep_installed_pack && path.exists()) {
+        match delete_path(&pack_path, delete_permanently) {
+            Ok(()) => {
+                report.removed.push(pack_path.display().to_string());
+                report.pack_removed = true;
+            }
+            Err(e) => report.failed.push(format!("{}: {}", pack_path.display(), e)),
+        }
+    }
+
+    delete_state_files(&app)?;
+    configure_download_throttle(&app);
+    Ok(report)
+}
@@ -1,7 +1,23 @@
+use crate::commands::app_info::{CachedContributors, CachedInstallerData};
+use crate::commands::auto_sync::AutoSyncSettings;
+use crate::commands::auto_verify::AutoVerifySettings;
+use crate::commands::cleanup::JunkRules;
+use crate::commands::disabled::{DisableConvention, DisabledRegistry};
+use crate::commands::filesystem::validate_directory;
+use crate::commands::full_sync_policy::FullSyncPolicy;
+use crate::commands::pack_source::PackSourceSetting;
+use crate::commands::profiles::{PackProfile, ProfileSyncState};
+use crate::commands::repo_paths::check_repo_paths;
+use crate::commands::verification_history::LastVerification;
+use crate::commands::watcher::set_watched_protected_paths;
+use crate::config::{forget_resolved_repo_config, resolve_repo_config, RepoConfig, RepoConfigOverrides};
+use crate::secrets::{self, GITHUB_TOKEN_KEY};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Manager};
 
 /// Persistent app state
@@ -15,11 +31,93 @@ pub struct AppState {
     pub last_sync_commit: Option<String>,
     /// Timestamp of when the last sync was performed (ISO 8601 UTC)
     pub last_sync_timestamp: Option<String>,
+    /// Time, mode and findings of the last verification
+    #[serde(default)]
+    pub last_verification: Option<LastVerification>,
+    /// Incremental syncs since the last full sync (for the full sync policy)
+    #[serde(default)]
+    pub incremental_syncs_since_full: u32,
+    /// Timestamp of the last full sync (ISO 8601 UTC)
+    #[serde(default)]
+    pub last_full_sync_timestamp: Option<String>,
     /// GitHub API token for higher rate limits
+    /// Only set here when no protection is available (get_github_token resolves the protected forms)
     pub github_token: Option<String>,
+    /// Keychain entry holding the GitHub token (set once the token has been moved into the keychain)
+    #[serde(default)]
+    pub github_token_ref: Option<String>,
+    /// GitHub token encrypted with a machine-bound key (fallback when no keychain is available)
+    #[serde(default)]
+    pub github_token_encrypted: Option<String>,
     /// Whether the user has acknowledged the sync disclaimer
     #[serde(default)]
     pub sync_disclaimer_acknowledged: bool,
+    /// Overrides of the bundled repository configuration (for forks / testing)
+    #[serde(default)]
+    pub repo_config_overrides: Option<RepoConfigOverrides>,
+    /// Additional texture pack profiles (other games / repositories)
+    #[serde(default)]
+    pub profiles: Vec<PackProfile>,
+    /// Id of the active profile (None = the bundled pack)
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// Saved sync bookkeeping of inactive profiles, keyed by profile id
+    #[serde(default)]
+    pub profile_sync_states: HashMap<String, ProfileSyncState>,
+    /// Last successfully fetched installer data (used when offline)
+    #[serde(default)]
+    pub installer_data_cache: Option<CachedInstallerData>,
+    /// Last fetched contributor list of the texture repository (for the credits screen)
+    #[serde(default)]
+    pub contributors_cache: Option<CachedContributors>,
+    /// Size limit of the downloaded blob cache in MB (None = default limit)
+    #[serde(default)]
+    pub cache_size_limit_mb: Option<u64>,
+    /// Download rate cap in KB/s (None = unlimited)
+    #[serde(default)]
+    pub download_rate_limit_kb: Option<u64>,
+    /// Connections used to download a very large file (None = one)
+    #[serde(default)]
+    pub download_connections: Option<u64>,
+    /// Send all requests over IPv4 (for networks with broken IPv6 routes)
+    #[serde(default)]
+    pub prefer_ipv4: bool,
+    /// PEM bundle of extra CA certificates to trust (for networks that intercept TLS)
+    #[serde(default)]
+    pub ca_bundle_path: Option<String>,
+    /// Log every GitHub request to the HTTP trace log (for diagnosing slow or failing syncs)
+    #[serde(default)]
+    pub http_tracing: bool,
+    /// Where syncs get the pack from
+    #[serde(default)]
+    pub pack_source: PackSourceSetting,
+    /// Which files cleanup and verification treat as junk
+    #[serde(default)]
+    pub junk_rules: JunkRules,
+    /// Folders and files (relative to the SLUS folder) that sync and verification never touch
+    #[serde(default)]
+    pub protected_paths: Vec<String>,
+    /// Stage analyzed syncs in a separate folder and swap them in only once every download succeeded
+    #[serde(default)]
+    pub transactional_sync: bool,
+    /// Sync automatically when the app is launched
+    #[serde(default)]
+    pub auto_sync: AutoSyncSettings,
+    /// Verify (and optionally fix) the pack at the end of every sync
+    #[serde(default)]
+    pub auto_verify: AutoVerifySettings,
+    /// Run a full sync instead of an incremental one every N syncs or days
+    #[serde(default)]
+    pub full_sync_policy: FullSyncPolicy,
+    /// Delete files and folders outright instead of moving them to the Recycle Bin/Trash
+    #[serde(default)]
+    pub delete_permanently: bool,
+    /// How disabled textures are named on disk
+    #[serde(default)]
+    pub disable_convention: DisableConvention,
+    /// Repo paths the user has disabled, so the choice survives renames, re-adds and reinstalls
+    #[serde(default)]
+    pub disabled_textures: DisabledRegistry,
 }
 
 /// Get the path to the state file
@@ -36,83 +134,530 @@ fn get_state_path(app: &AppHandle) -> Result<PathBuf, String> {
     Ok(app_data_dir.join("state.json"))
 }
 
+/// Read and parse a state file
+fn read_state_file(path: &Path) -> Result<AppState, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read state file: {}", e))?;
+
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse state file: {}", e))
+}
+
 /// Load the app state from disk
 #[tauri::command]
 pub fn load_state(app: AppHandle) -> Result<AppState, String> {
     let state_path = get_state_path(&app)?;
+    let _lock = lock_state_file(&state_path)?;
+    load_state_locked(&state_path)
+}
+
+/// load_state for a caller holding the state lock
+fn load_state_locked(state_path: &Path) -> Result<AppState, String> {
+    let backup_path = state_path.with_extension("json.bak");
 
-    if !state_path.exists() {
+    if !state_path.exists() && !backup_path.exists() {
         return Ok(AppState::default());
     }
 
-    let contents = fs::read_to_string(&state_path)
-        .map_err(|e| format!("Failed to read state file: {}", e))?;
+    let mut state = match read_state_file(state_path) {
+        Ok(state) => state,
+        Err(e) => {
+            // State file is missing, corrupt or truncated (e.g. crash mid-write) - fall back to the backup
+            let backup = read_state_file(&backup_path).map_err(|_| e)?;
+            write_state_file(state_path, &backup)?;
+            backup
+        }
+    };
 
-    serde_json::from_str(&contents)
-        .map_err(|e| format!("Failed to parse state file: {}", e))
+    // Migrate plain-text secrets written by older versions into the keychain (or encrypt them)
+    if state.github_token.is_some() {
+        let mut migrated = state.clone();
+        protect_secrets(&mut migrated);
+        if migrated.github_token.is_none() {
+            write_state_file(state_path, &migrated)?;
+            state.github_token_ref = migrated.github_token_ref;
+            state.github_token_encrypted = migrated.github_token_encrypted;
+        }
+    }
+
+    Ok(state)
+}
+
+/// Get the GitHub token from the keychain or its encrypted form (None when none is set)
+#[tauri::command]
+pub fn get_github_token(app: AppHandle) -> Result<Option<String>, String> {
+    let mut state = load_state(app)?;
+    resolve_secrets(&mut state);
+    Ok(state.github_token)
+}
+
+/// Move secrets out of the plain-text state before it is written to disk
+/// Secrets go to the OS keychain when available, otherwise they are encrypted with a machine-bound key
+/// As a last resort (no keychain and no machine id) the secret is kept as-is
+fn protect_secrets(state: &mut AppState) {
+    if let Some(token) = state.github_token.take() {
+        if secrets::store_secret(GITHUB_TOKEN_KEY, &token).is_ok() {
+            state.github_token_ref = Some(GITHUB_TOKEN_KEY.to_string());
+            state.github_token_encrypted = None;
+        } else if let Ok(encrypted) = secrets::encrypt_secret(&token) {
+            state.github_token_ref = None;
+            state.github_token_encrypted = Some(encrypted);
+        } else {
+            state.github_token = Some(token);
+            state.github_token_ref = None;
+            state.github_token_encrypted = None;
+        }
+    }
+}
+
+/// Fill in secret fields from the keychain or their encrypted form
+fn resolve_secrets(state: &mut AppState) {
+    if state.github_token.is_some() {
+        return;
+    }
+    if let Some(key) = &state.github_token_ref {
+        state.github_token = secrets::load_secret(key).ok().flatten();
+    } else if let Some(encrypted) = &state.github_token_encrypted {
+        state.github_token = secrets::decrypt_secret(encrypted).ok();
+    }
 }
 
 /// Save the app state to disk
+/// Overwrites changes saved since the state was loaded; use with_state_mut to update it
 #[tauri::command]
 pub fn save_state(app: AppHandle, state: AppState) -> Result<(), String> {
     let state_path = get_state_path(&app)?;
+    let _lock = lock_state_file(&state_path)?;
+    save_state_locked(&state_path, state)
+}
+
+/// Load, change and save the state while holding the state lock, so an update saved meanwhile
+/// by another thread or process isn't lost. The state isn't saved when `update` fails
+/// `update` must not load or save the state itself (the lock isn't reentrant)
+pub(crate) fn with_state_mut<T>(
+    app: &AppHandle,
+    update: impl FnOnce(&mut AppState) -> Result<T, String>,
+) -> Result<T, String> {
+    let state_path = get_state_path(app)?;
+    let _lock = lock_state_file(&state_path)?;
+    let mut state = load_state_locked(&state_path)?;
+    let result = update(&mut state)?;
+    save_state_locked(&state_path, state)?;
+    Ok(result)
+}
 
-    let contents = serde_json::to_string_pretty(&state)
+/// save_state for a caller holding the state lock
+fn save_state_locked(state_path: &Path, state: AppState) -> Result<(), String> {
+    let mut disk_state = state;
+    protect_secrets(&mut disk_state);
+    write_state_file(state_path, &disk_state)?;
+    forget_resolved_repo_config();
+    set_watched_protected_paths(Some(disk_state.protected_paths));
+    Ok(())
+}
+
+/// Take the exclusive lock on the state file, held until the returned file is dropped
+/// Serializes loads and saves across threads and processes, which would otherwise share
+/// state.json.tmp or save over each other's changes
+fn lock_state_file(state_path: &Path) -> Result<fs::File, String> {
+    let lock_file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(state_path.with_extension("json.lock"))
+        .map_err(|e| format!("Failed to open state lock file: {}", e))?;
+    lock_file
+        .lock()
+        .map_err(|e| format!("Failed to lock state file: {}", e))?;
+    Ok(lock_file)
+}
+
+/// Write the state as-is to the state file (the caller holds the state lock)
+/// Writes to a temp file and renames it into place so a crash mid-write never leaves a truncated file,
+/// and keeps the previous version as state.json.bak
+fn write_state_file(state_path: &Path, state: &AppState) -> Result<(), String> {
+    let temp_path = state_path.with_extension("json.tmp");
+    let backup_path = state_path.with_extension("json.bak");
+
+    let contents = serde_json::to_string_pretty(state)
         .map_err(|e| format!("Failed to serialize state: {}", e))?;
 
-    fs::write(&state_path, contents)
+    let mut file = fs::File::create(&temp_path)
+        .map_err(|e| format!("Failed to write state file: {}", e))?;
+    file.write_all(contents.as_bytes())
+        .and_then(|_| file.sync_all())
         .map_err(|e| format!("Failed to write state file: {}", e))?;
+    drop(file);
+
+    // Only back up a state file that is itself valid, so a corrupt file never replaces a good backup
+    if read_state_file(state_path).is_ok() {
+        fs::copy(state_path, &backup_path)
+            .map_err(|e| format!("Failed to back up state file: {}", e))?;
+    }
 
+    fs::rename(&temp_path, state_path)
+        .map_err(|e| format!("Failed to write state file: {}", e))?;
+
+    Ok(())
+}
+
+/// Delete the state file, its backup and the stored token, so the next load starts from the defaults
+pub(crate) fn delete_state_files(app: &AppHandle) -> Result<(), String> {
+    let state_path = get_state_path(app)?;
+    let _lock = lock_state_file(&state_path)?;
+    let state = read_state_file(&state_path).unwrap_or_default();
+    if state.github_token_ref.is_some() {
+        secrets::delete_secret(GITHUB_TOKEN_KEY)?;
+    }
+
+    for path in [
+        state_path.with_extension("json.tmp"),
+        state_path.with_extension("json.bak"),
+        state_path,
+    ] {
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+        }
+    }
+    forget_resolved_repo_config();
+    set_watched_protected_paths(None);
     Ok(())
 }
 
 /// Update just the textures_path in state
 #[tauri::command]
 pub fn set_textures_path(app: AppHandle, path: String) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.textures_path = Some(path);
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        state.textures_path = Some(path);
+        Ok(())
+    })
 }
 
 /// Mark initial setup as complete and save the commit SHA
 #[tauri::command]
 pub fn mark_setup_complete(app: AppHandle, commit_sha: String) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.initial_setup_done = true;
-    state.last_sync_commit = Some(commit_sha);
-    state.last_sync_timestamp = Some(Utc::now().to_rfc3339());
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        state.initial_setup_done = true;
+        state.last_sync_commit = Some(commit_sha);
+        state.last_sync_timestamp = Some(Utc::now().to_rfc3339());
+        Ok(())
+    })
 }
 
 /// Update the last sync commit SHA and timestamp
 #[tauri::command]
 pub fn update_last_sync_commit(app: AppHandle, commit_sha: String) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.last_sync_commit = Some(commit_sha);
-    state.last_sync_timestamp = Some(Utc::now().to_rfc3339());
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        state.last_sync_commit = Some(commit_sha);
+        state.last_sync_timestamp = Some(Utc::now().to_rfc3339());
+        Ok(())
+    })
 }
 
 /// Manually set initial_setup_done (for users who already have textures installed)
 #[tauri::command]
 pub fn set_initial_setup_done(app: AppHandle, done: bool) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.initial_setup_done = done;
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        state.initial_setup_done = done;
+        Ok(())
+    })
 }
 
-/// Set the GitHub API token
+/// Set the GitHub API token (stored in the OS keychain, or encrypted when no keychain is available)
 #[tauri::command]
 pub fn set_github_token(app: AppHandle, token: String) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.github_token = if token.is_empty() { None } else { Some(token) };
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        if token.is_empty() {
+            if state.github_token_ref.take().is_some() {
+                secrets::delete_secret(GITHUB_TOKEN_KEY)?;
+            }
+            state.github_token = None;
+            state.github_token_encrypted = None;
+        } else {
+            state.github_token = Some(token);
+        }
+        Ok(())
+    })
 }
 
 /// Set the sync disclaimer acknowledged flag
 #[tauri::command]
 pub fn set_sync_disclaimer_acknowledged(app: AppHandle, acknowledged: bool) -> Result<(), String> {
-    let mut state = load_state(app.clone())?;
-    state.sync_disclaimer_acknowledged = acknowledged;
-    save_state(app, state)
+    with_state_mut(&app, |state| {
+        state.sync_disclaimer_acknowledged = acknowledged;
+        Ok(())
+    })
+}
+
+/// Get the effective repository configuration
+#[tauri::command]
+pub fn get_repo_config(app: AppHandle) -> Result<RepoConfig, String> {
+    resolve_repo_config(&app)
+}
+
+/// Set (or clear with None) the repository configuration overrides
+#[tauri::command]
+pub fn set_repo_config_overrides(app: AppHandle, overrides: Option<RepoConfigOverrides>) -> Result<(), String> {
+    if let Some(overrides) = &overrides {
+        RepoConfig::bundled().with_overrides(overrides).check_paths()?;
+    }
+    with_state_mut(&app, |state| {
+        state.repo_config_overrides = overrides;
+        Ok(())
+    })
+}
+
+/// Version of the settings export format
+const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+/// Non-secret, machine-portable subset of the app state used for settings import/export
+/// Deliberately excludes secrets (GitHub token) and per-install sync bookkeeping
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableSettings {
+    /// Export format version
+    pub version: u32,
+    /// Path to the PCSX2 textures directory
+    #[serde(default)]
+    pub textures_path: Option<String>,
+    /// Whether the user has acknowledged the sync disclaimer
+    #[serde(default)]
+    pub sync_disclaimer_acknowledged: bool,
+    /// Size limit of the downloaded blob cache in MB
+    #[serde(default)]
+    pub cache_size_limit_mb: Option<u64>,
+    /// Download rate cap in KB/s (None = unlimited)
+    #[serde(default)]
+    pub download_rate_limit_kb: Option<u64>,
+    /// Connections used to download a very large file (None = one)
+    #[serde(default)]
+    pub download_connections: Option<u64>,
+    /// Send all requests over IPv4 (for networks with broken IPv6 routes)
+    #[serde(default)]
+    pub prefer_ipv4: bool,
+    /// PEM bundle of extra CA certificates to trust (for networks that intercept TLS)
+    #[serde(default)]
+    pub ca_bundle_path: Option<String>,
+    /// Where syncs get the pack from
+    #[serde(default)]
+    pub pack_source: PackSourceSetting,
+    /// Which files cleanup and verification treat as junk
+    #[serde(default)]
+    pub junk_rules: JunkRules,
+    /// Folders and files (relative to the SLUS folder) that sync and verification never touch
+    #[serde(default)]
+    pub protected_paths: Vec<String>,
+    /// Stage analyzed syncs in a separate folder and swap them in only once every download succeeded
+    #[serde(default)]
+    pub transactional_sync: bool,
+    /// Sync automatically when the app is launched
+    #[serde(default)]
+    pub auto_sync: AutoSyncSettings,
+    /// Verify (and optionally fix) the pack at the end of every sync
+    #[serde(default)]
+    pub auto_verify: AutoVerifySettings,
+    /// Run a full sync instead of an incremental one every N syncs or days
+    #[serde(default)]
+    pub full_sync_policy: FullSyncPolicy,
+    /// Delete files and folders outright instead of moving them to the Recycle Bin/Trash
+    #[serde(default)]
+    pub delete_permanently: bool,
+    /// How disabled textures are named on disk
+    #[serde(default)]
+    pub disable_convention: DisableConvention,
+    /// Repo paths the user has disabled, so the choice survives renames, re-adds and reinstalls
+    #[serde(default)]
+    pub disabled_textures: DisabledRegistry,
+}
+
+impl PortableSettings {
+    fn from_state(state: &AppState) -> Self {
+        PortableSettings {
+            version: SETTINGS_EXPORT_VERSION,
+            textures_path: state.textures_path.clone(),
+            sync_disclaimer_acknowledged: state.sync_disclaimer_acknowledged,
+            cache_size_limit_mb: state.cache_size_limit_mb,
+            download_rate_limit_kb: state.download_rate_limit_kb,
+            download_connections: state.download_connections,
+            prefer_ipv4: state.prefer_ipv4,
+            ca_bundle_path: state.ca_bundle_path.clone(),
+            pack_source: state.pack_source.clone(),
+            junk_rules: state.junk_rules.clone(),
+            protected_paths: state.protected_paths.clone(),
+            transactional_sync: state.transactional_sync,
+            auto_sync: state.auto_sync.clone(),
+            auto_verify: state.auto_verify.clone(),
+            full_sync_policy: state.full_sync_policy.clone(),
+            delete_permanently: state.delete_permanently,
+            disable_convention: state.disable_convention,
+            disabled_textures: state.disabled_textures.clone(),
+        }
+    }
+
+    /// Refuse repo paths that are unsafe to act on (the file may come from anywhere)
+    fn check_paths(&self) -> Result<(), String> {
+        check_repo_paths(self.protected_paths.iter().map(String::as_str))?;
+        self.disabled_textures.check_paths()
+    }
+
+    /// Paths from the exporting machine (textures folder, CA bundle, local pack source) are only
+    /// taken when they're usable here; otherwise this machine's values are kept
+    fn apply_to(self, state: &mut AppState) {
+        if let Some(path) = self.textures_path.filter(|path| validate_directory(path.clone()).valid) {
+            state.textures_path = Some(path);
+        }
+        state.sync_disclaimer_acknowledged = self.sync_disclaimer_acknowledged;
+        state.cache_size_limit_mb = self.cache_size_limit_mb;
+        state.download_rate_limit_kb = self.download_rate_limit_kb;
+        state.download_connections = self.download_connections;
+        state.prefer_ipv4 = self.prefer_ipv4;
+        if !matches!(&self.ca_bundle_path, Some(path) if !Path::new(path).is_file()) {
+            state.ca_bundle_path = self.ca_bundle_path;
+        }
+        let pack_source_usable = match &self.pack_source {
+            PackSourceSetting::LocalFolder { path } => Path::new(path).is_dir(),
+            PackSourceSetting::LocalZip { path } => Path::new(path).is_file(),
+            _ => true,
+        };
+        if pack_source_usable {
+            state.pack_source = self.pack_source;
+        }
+        state.junk_rules = self.junk_rules;
+        state.protected_paths = self.protected_paths;
+        state.transactional_sync = self.transactional_sync;
+        state.auto_sync = self.auto_sync;
+        state.auto_verify = self.auto_verify;
+        state.full_sync_policy = self.full_sync_policy;
+        state.delete_permanently = self.delete_permanently;
+        state.disable_convention = self.disable_convention;
+        state.disabled_textures = self.disabled_textures;
+    }
+}
+
+/// Export the non-secret settings to a JSON file
+#[tauri::command]
+pub fn export_settings(app: AppHandle, path: String) -> Result<(), String> {
+    let state = load_state(app)?;
+    let settings = PortableSettings::from_state(&state);
+
+    let contents = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+    fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write settings file: {}", e))
+}
+
+/// Import settings from a JSON file created by export_settings
+/// Returns the updated state
+#[tauri::command]
+pub fn import_settings(app: AppHandle, path: String) -> Result<AppState, String> {
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read settings file: {}", e))?;
+
+    let settings: PortableSettings = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse settings file: {}", e))?;
+
+    if settings.version > SETTINGS_EXPORT_VERSION {
+        return Err(format!(
+            "Settings file was created by a newer version of the app (format {}, supported {})",
+            settings.version, SETTINGS_EXPORT_VERSION
+        ));
+    }
+
+    settings.check_paths()?;
+
+    with_state_mut(&app, |state| {
+        settings.apply_to(state);
+        Ok(())
+    })?;
+    load_state(app)
+}
+
+/// Version of the state backup format
+const STATE_BACKUP_VERSION: u32 = 1;
+
+/// Full copy of the app state written by backup_state
+/// Unlike the settings export this keeps the sync bookkeeping and profiles, so it can bring an
+/// install back after an OS reinstall
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StateBackup {
+    version: u32,
+    created_at: String,
+    /// The state without any secrets (keychain references and machine-bound encryption don't
+    /// survive a move to another machine)
+    state: AppState,
+    /// GitHub token encrypted with the backup password (None when no password was given)
+    #[serde(default)]
+    github_token_encrypted: Option<String>,
+}
+
+/// Back up the full app state to a JSON file
+/// With a password the GitHub token is included, encrypted with it; without one it is left out
+#[tauri::command]
+pub fn backup_state(app: AppHandle, path: String, password: Option<String>) -> Result<(), String> {
+    let mut state = load_state(app)?;
+    resolve_secrets(&mut state);
+    let token = state.github_token.take();
+    state.github_token_ref = None;
+    state.github_token_encrypted = None;
+
+    let github_token_encrypted = match (token, password.filter(|p| !p.is_empty())) {
+        (Some(token), Some(password)) => Some(secrets::encrypt_with_password(&token, &password)?),
+        _ => None,
+    };
+    let backup = StateBackup {
+        version: STATE_BACKUP_VERSION,
+        created_at: Utc::now().to_rfc3339(),
+        state,
+        github_token_encrypted,
+    };
+
+    let contents = serde_json::to_string_pretty(&backup)
+        .map_err(|e| format!("Failed to serialize state backup: {}", e))?;
+    fs::write(&path, contents)
+        .map_err(|e| format!("Failed to write state backup: {}", e))
+}
+
+/// Replace the app state with a backup created by backup_state
+/// The backed-up token is restored when the password is given; otherwise the token already on
+/// this machine (if any) is kept
+/// Returns the restored state
+#[tauri::command]
+pub fn restore_state(app: AppHandle, path: String, password: Option<String>) -> Result<AppState, String> {
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read state backup: {}", e))?;
+
+    let backup: StateBackup = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse state backup: {}", e))?;
+
+    if backup.version > STATE_BACKUP_VERSION {
+        return Err(format!(
+            "State backup was created by a newer version of the app (format {}, supported {})",
+            backup.version, STATE_BACKUP_VERSION
+        ));
+    }
+
+    let restored_token = match (&backup.github_token_encrypted, password.filter(|p| !p.is_empty())) {
+        (Some(encrypted), Some(password)) => Some(secrets::decrypt_with_password(encrypted, &password)?),
+        _ => None,
+    };
+
+    // A backup is a file from anywhere; its pack settings get the same checks as when they're entered
+    for profile in &backup.state.profiles {
+        profile.config.check_paths()?;
+    }
+    if let Some(overrides) = &backup.state.repo_config_overrides {
+        RepoConfig::bundled().with_overrides(overrides).check_paths()?;
+    }
+    check_repo_paths(backup.state.protected_paths.iter().map(String::as_str))?;
+    backup.state.disabled_textures.check_paths()?;
+
+    let mut state = backup.state;
+    state.github_token = match restored_token {
+        Some(token) => Some(token),
+        None => get_github_token(app.clone())?,
+    };
+    state.github_token_ref = None;
+    state.github_token_encrypted = None;
+    save_state(app.clone(), state)?;
+    load_state(app)
 }
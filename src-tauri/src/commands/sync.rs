@@ -1,11 +1,40 @@
-use crate::config::{REPO_NAME, REPO_OWNER, SLUS_FOLDER, SPARSE_PATH};
+use crate::commands::auto_verify::{verify_after_sync, PostSyncVerification};
+use crate::commands::bandwidth::{configure_download_throttle, load_download_rate_limit};
+use crate::commands::blob_cache::BlobCache;
+use crate::commands::cleanup::{cleanup_empty_directories, load_junk_rules, CleanupReport, JunkRules};
+use crate::commands::disabled::{
+    load_disable_convention, load_disabled_registry, save_disabled_registry, DisableConvention, DisabledRegistry,
+};
+use crate::commands::file_index::FileIndex;
+use crate::commands::filesystem::{delete_path, load_delete_permanently};
+use crate::commands::full_sync_policy::{full_sync_due, record_sync_mode};
+use crate::commands::network::{describe_request_error, http_client, watchdog, TracedSend};
+use crate::commands::pack_source::{open_pack_source, ArchiveTarget, ConfiguredSource, PackSource};
+use crate::commands::plan_confirm::{check_plan, issue_plan};
+use crate::commands::power::prevent_sleep;
+use crate::commands::progress::{
+    next_event_stamp, overall_percent, record_event, report_byte_progress, start_sync_session, start_verification_session,
+    with_heartbeat, MessageParams, Stage,
+};
+use crate::commands::repo_paths::{check_blob_sha, check_repo_path, check_repo_paths, drop_unsafe_paths};
+use crate::commands::state::{load_state, with_state_mut};
+use crate::commands::sync_journal::{clear_sync_journal, load_sync_journal, save_sync_journal, SyncJournal, SYNC_JOURNAL_CHUNK_SIZE};
+use crate::commands::sync_transaction::SyncTransaction;
+use crate::commands::verification_history::{record_fixes_applied, record_verification, VerificationMode};
+use crate::commands::watcher::{detected_local_changes, suppress_drift_detection, LocalChanges};
+use crate::config::{resolve_repo_config, RepoConfig};
+use chrono::Utc;
+use futures_util::stream::{self, StreamExt};
+use rand::seq::SliceRandom;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use sha1::{Digest, Sha1};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use tauri::{Emitter, Window};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Window};
 
 /// GitHub tree entry from API response
 #[derive(Debug, Deserialize, Clone)]
@@ -14,10 +43,13 @@ struct TreeEntry {
     #[serde(rename = "type")]
     entry_type: String,
     sha: String,
+    /// Blob size in bytes (not present for tree entries)
+    #[serde(default)]
+    size: Option<u64>,
 }
 
 /// GitHub tree response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Clone)]
 struct TreeResponse {
     #[allow(dead_code)]
     sha: String,
@@ -50,23 +82,93 @@ struct CompareResponse {
 
 /// File entry in compare response
 #[derive(Debug, Deserialize, Clone)]
-struct CompareFile {
-    filename: String,
-    status: String, // "added", "modified", "removed", "renamed"
-    previous_filename: Option<String>,
-    #[allow(dead_code)]
-    sha: Option<String>,
+pub(crate) struct CompareFile {
+    pub filename: String,
+    pub status: String, // "added", "modified", "changed", "removed", "renamed", "copied", "unchanged"
+    pub previous_filename: Option<String>,
+    pub sha: Option<String>,
 }
 
 /// Progress payload for sync events
-#[derive(Clone, Serialize)]
+/// Serialized with the session's overall_percent (see progress::overall_percent), operation id
+/// and sequence number
+#[derive(Clone)]
 pub struct SyncProgressPayload {
-    pub stage: String,
+    pub stage: Stage,
+    /// Stable message identifier for localization (e.g. "sync.downloading_file")
+    pub key: &'static str,
+    pub params: MessageParams,
+    /// English message, used when the frontend has no translation for the key
     pub message: String,
     pub current: Option<u32>,
     pub total: Option<u32>,
 }
 
+impl Serialize for SyncProgressPayload {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Payload<'a> {
+            stage: Stage,
+            key: &'a str,
+            params: &'a MessageParams,
+            message: &'a str,
+            current: Option<u32>,
+            total: Option<u32>,
+            overall_percent: u32,
+            operation_id: u64,
+            seq: u64,
+        }
+
+        let (operation_id, seq) = next_event_stamp();
+        let payload = Payload {
+            stage: self.stage,
+            key: self.key,
+            params: &self.params,
+            message: &self.message,
+            current: self.current,
+            total: self.total,
+            overall_percent: overall_percent(self.stage, self.current, self.total),
+            operation_id,
+            seq,
+        };
+        record_event(operation_id, &payload);
+        payload.serialize(serializer)
+    }
+}
+
+/// Severity of a sync log entry
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// Structured operation log entry, emitted on the "sync-log" event
+/// Kept separate from sync-progress so the UI can build a clean per-file log
+#[derive(Debug, Clone, Serialize)]
+pub struct SyncLogEntry {
+    pub level: LogLevel,
+    /// Operation the entry belongs to ("download", "delete", "rename", "cleanup", ...)
+    pub category: String,
+    /// Path relative to the SLUS folder, when the entry concerns a single file
+    pub path: Option<String>,
+    pub detail: String,
+    pub timestamp: String,
+}
+
+/// Emit a structured log entry on the "sync-log" event
+pub(crate) fn emit_log(window: &Window, level: LogLevel, category: &str, path: Option<&str>, detail: impl Into<String>) {
+    let _ = window.emit("sync-log", SyncLogEntry {
+        level,
+        category: category.to_string(),
+        path: path.map(|p| p.to_string()),
+        detail: detail.into(),
+        timestamp: Utc::now().to_rfc3339(),
+    });
+}
+
 /// Sync result summary
 #[derive(Debug, Clone, Serialize)]
 pub struct SyncResult {
@@ -74,30 +176,273 @@ pub struct SyncResult {
     pub files_deleted: u32,
     pub files_renamed: u32,
     pub files_skipped: u32,
+    /// Commit the sync brought the pack to; only recorded as the last synced commit when
+    /// failed_files is empty, so the next sync plans the failed files again
     pub new_commit_sha: String,
+    /// Files that still failed after the retry pass (the rest of the sync completed)
+    pub failed_files: Vec<SyncFailure>,
+    /// What happened to each file (capped at MAX_INLINE_ACTIONS entries)
+    pub actions: Vec<FileActionResult>,
+    /// Report file with every action, written when the list above was capped
+    pub actions_report_path: Option<String>,
+    /// Junk files and empty directories removed after the sync
+    pub cleanup: Option<CleanupReport>,
+    /// Verification run at the end of the sync, when that's enabled
+    pub verification: Option<PostSyncVerification>,
+    /// Pass to apply_verification_fixes when retrying failed_files (empty when nothing failed)
+    pub retry_plan_token: String,
+}
+
+/// Maximum per-file results returned inline in SyncResult
+const MAX_INLINE_ACTIONS: usize = 500;
+
+/// Number of sync report files kept in the app data directory
+const MAX_SYNC_REPORTS: usize = 10;
+
+/// Outcome of one file operation during a sync
+#[derive(Debug, Clone, Serialize)]
+pub struct FileActionResult {
+    pub path: String,
+    /// "download", "delete", "rename", "copy" or "skip"
+    pub action: String,
+    /// "ok", "failed" or "skipped"
+    pub outcome: String,
+    pub error: Option<String>,
+}
+
+/// Sent for every file a sync or repair is done with ("file-done"), unthrottled, so the
+/// frontend can keep a live list of the work done
+#[derive(Clone, Serialize)]
+struct FileDonePayload<'a> {
+    operation_id: u64,
+    path: &'a str,
+    action: &'a str,
+    outcome: &'a str,
+}
+
+/// Collects per-file results while a sync runs
+#[derive(Default)]
+struct ActionLog {
+    actions: Vec<FileActionResult>,
+    /// Failures not yet retried (or that failed the retry)
+    failed: Vec<SyncFailure>,
+    /// Where each result is announced as a "file-done" event
+    window: Option<Window>,
+}
+
+impl ActionLog {
+    fn new(window: &Window) -> Self {
+        ActionLog {
+            window: Some(window.clone()),
+            ..ActionLog::default()
+        }
+    }
+
+    fn emit_file_done(&self, path: &str, action: &str, outcome: &str) {
+        if let Some(window) = &self.window {
+            let (operation_id, _) = next_event_stamp();
+            let _ = window.emit("file-done", FileDonePayload { operation_id, path, action, outcome });
+        }
+    }
+
+    fn record(&mut self, path: &str, action: &str, outcome: &str, error: Option<String>) {
+        self.emit_file_done(path, action, outcome);
+        self.actions.push(FileActionResult {
+            path: path.to_string(),
+            action: action.to_string(),
+            outcome: outcome.to_string(),
+            error,
+        });
+    }
+
+    fn ok(&mut self, path: &str, action: &str) {
+        self.record(path, action, "ok", None);
+    }
+
+    fn fail(&mut self, failure: SyncFailure) {
+        self.emit_file_done(&failure.path, &failure.action, "failed");
+        self.failed.push(failure);
+    }
+
+    /// Returns the files that still failed, the inline action list and,
+    /// if that list had to be capped, the path of a report with every action
+    fn finish(mut self, app: &AppHandle) -> (Vec<SyncFailure>, Vec<FileActionResult>, Option<String>) {
+        for failure in &self.failed {
+            self.actions.push(FileActionResult {
+                path: failure.path.clone(),
+                action: failure.action.clone(),
+                outcome: "failed".to_string(),
+                error: Some(failure.error.clone()),
+            });
+        }
+
+        if self.actions.len() <= MAX_INLINE_ACTIONS {
+            return (self.failed, self.actions, None);
+        }
+
+        // Failures first, so they survive the cap
+        self.actions.sort_by_key(|a| a.outcome != "failed");
+        let report_path = write_sync_report(app, &self.actions).ok();
+        self.actions.truncate(MAX_INLINE_ACTIONS);
+        (self.failed, self.actions, report_path)
+    }
+}
+
+/// Write every action of a sync to a JSON report, keeping only the most recent reports
+fn write_sync_report(app: &AppHandle, actions: &[FileActionResult]) -> Result<String, String> {
+    let reports_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?
+        .join("sync-reports");
+    fs::create_dir_all(&reports_dir)
+        .map_err(|e| format!("Failed to create reports directory: {}", e))?;
+
+    let report_path = reports_dir.join(format!("sync-{}.json", Utc::now().format("%Y%m%d-%H%M%S")));
+    let content = serde_json::to_string_pretty(actions)
+        .map_err(|e| format!("Failed to serialize sync report: {}", e))?;
+    fs::write(&report_path, content)
+        .map_err(|e| format!("Failed to write sync report: {}", e))?;
+
+    // Timestamped names sort chronologically
+    let mut reports: Vec<PathBuf> = fs::read_dir(&reports_dir)
+        .map(|entries| entries.filter_map(|e| e.ok()).map(|e| e.path()).collect())
+        .unwrap_or_default();
+    reports.sort();
+    let excess = reports.len().saturating_sub(MAX_SYNC_REPORTS);
+    for old_report in &reports[..excess] {
+        let _ = fs::remove_file(old_report);
+    }
+
+    Ok(report_path.to_string_lossy().to_string())
+}
+
+/// A file the sync couldn't download or delete
+/// Failed downloads can be retried with apply_verification_fixes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncFailure {
+    pub path: String,
+    /// "download", "delete" or "rename"
+    pub action: String,
+    /// Download to the disabled path (preserves disabled state)
+    #[serde(default)]
+    pub to_disabled: bool,
+    #[serde(default)]
+    pub sha: Option<String>,
+    /// Old path of a failed rename
+    #[serde(default)]
+    pub from: Option<String>,
+    pub error: String,
+}
+
+/// Register retrying the failed files (split the way the sync screen retries them) as a plan
+fn issue_retry_plan(reference: &str, failures: &[SyncFailure]) -> String {
+    if failures.is_empty() {
+        return String::new();
+    }
+    let downloads: Vec<&str> = failures
+        .iter()
+        .filter(|failure| failure.action == "download" || failure.action == "rename")
+        .map(|failure| failure.path.as_str())
+        .collect();
+    let deletes: Vec<&str> = failures
+        .iter()
+        .filter(|failure| failure.action == "delete")
+        .map(|failure| failure.path.as_str())
+        .collect();
+    issue_plan(reference, &downloads, &deletes)
 }
 
 /// Verification scan result (discrepancies found)
 #[derive(Debug, Clone, Serialize)]
 pub struct VerificationResult {
+    /// Every file to download (each tagged with why)
     pub files_to_download: Vec<VerificationFile>,
+    /// Every file to delete (orphaned_files plus junk_files)
     pub files_to_delete: Vec<String>,
+    /// Files that aren't in the repository (removed upstream or added by hand)
+    pub orphaned_files: Vec<String>,
+    /// OS metadata and leftover temp files
+    pub junk_files: Vec<String>,
     pub has_discrepancies: bool,
+    /// Pass to apply_verification_fixes to apply this result (see plan_confirm)
+    pub plan_token: String,
 }
 
-/// Quick count check result (fast, no SHA computation)
+/// Result of applying verification fixes
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationFixResult {
+    pub files_downloaded: u32,
+    pub files_deleted: u32,
+    /// Files that still failed after the retries, each with its reason (the other fixes were applied)
+    pub failed_files: Vec<SyncFailure>,
+    /// What happened to each file (capped at MAX_INLINE_ACTIONS entries)
+    pub actions: Vec<FileActionResult>,
+    /// Report file with every action, written when the list above was capped
+    pub actions_report_path: Option<String>,
+}
+
+/// Quick count check result (fast - counts plus hashes of a small random sample)
 #[derive(Debug, Clone, Serialize)]
 pub struct QuickCheckResult {
     pub local_count: usize,
     pub remote_count: usize,
     pub counts_match: bool,
+    /// Local and remote counts of every top-level folder, by name
+    pub folder_counts: Vec<FolderCount>,
+    /// Per-folder spot check results (only folders with discrepancies)
+    pub spot_check_discrepancies: Vec<FolderSpotCheck>,
+    /// Number of files hashed during the spot check
+    pub spot_check_sampled: usize,
+}
+
+/// File counts of one top-level folder in the quick count check
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderCount {
+    pub folder: String,
+    pub local_count: usize,
+    pub remote_count: usize,
+    /// Local minus remote (negative when files are missing)
+    pub delta: i64,
+}
+
+/// Spot check result for one top-level folder
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderSpotCheck {
+    pub folder: String,
+    pub sampled: usize,
+    /// Sampled files that are missing locally or whose hash doesn't match
+    pub mismatched: Vec<String>,
 }
 
+/// Default number of files hashed per top-level folder in the quick check
+const DEFAULT_SPOT_CHECK_SAMPLE_SIZE: usize = 5;
+
 /// File that needs to be downloaded during verification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationFile {
     pub path: String,
     pub to_disabled: bool,
+    /// Expected blob SHA (used to reuse cached downloads)
+    #[serde(default)]
+    pub sha: Option<String>,
+    #[serde(default)]
+    pub reason: DownloadReason,
+}
+
+/// Why verification wants to download a file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadReason {
+    /// Not present locally (neither enabled nor disabled)
+    #[default]
+    Missing,
+    /// Present but its content doesn't match the repository
+    Corrupted,
+    /// Disabled by the user, and the repository has a newer version
+    DisabledOutdated,
+    /// Empty or cut short, usually left behind by a failed download
+    Truncated,
 }
 
 /// Sync analysis result - what will happen if sync proceeds
@@ -111,6 +456,23 @@ pub struct SyncAnalysis {
     pub files_to_delete: Vec<String>,
     /// Latest commit SHA
     pub commit_sha: String,
+    /// Changes grouped by top-level folder (sorted by folder name)
+    pub folders: Vec<FolderChangeSummary>,
+    /// Pass to execute_analyzed_sync to run this plan (see plan_confirm)
+    pub plan_token: String,
+}
+
+/// Summary of pending changes within one top-level folder
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct FolderChangeSummary {
+    pub folder: String,
+    /// Files to add or replace
+    pub downloads: usize,
+    pub deletes: usize,
+    /// Total bytes to download
+    pub download_bytes: u64,
+    /// Total bytes of local files that will be deleted
+    pub delete_bytes: u64,
 }
 
 /// File info for sync operations
@@ -118,6 +480,116 @@ pub struct SyncAnalysis {
 pub struct SyncFile {
     pub path: String,
     pub to_disabled: bool,
+    /// Expected blob SHA (used to reuse cached downloads)
+    #[serde(default)]
+    pub sha: Option<String>,
+}
+
+/// Top-level folder of a path relative to the SLUS folder ("" for files at the root)
+fn get_top_level_folder(path: &str) -> &str {
+    match path.split_once('/') {
+        Some((folder, _)) => folder,
+        None => "",
+    }
+}
+
+/// Group analyzed changes by top-level folder
+fn summarize_changes_by_folder(
+    slus_path: &Path,
+    files_to_download: &[&SyncFile],
+    files_to_delete: &[String],
+    remote_sizes: &HashMap<String, u64>,
+) -> Vec<FolderChangeSummary> {
+    let mut folders: HashMap<&str, FolderChangeSummary> = HashMap::new();
+
+    for file in files_to_download {
+        let folder = get_top_level_folder(&file.path);
+        let summary = folders.entry(folder).or_insert_with(|| FolderChangeSummary {
+            folder: folder.to_string(),
+            ..Default::default()
+        });
+        summary.downloads += 1;
+        summary.download_bytes += remote_sizes.get(&file.path).copied().unwrap_or(0);
+    }
+
+    for path in files_to_delete {
+        let folder = get_top_level_folder(path);
+        let summary = folders.entry(folder).or_insert_with(|| FolderChangeSummary {
+            folder: folder.to_string(),
+            ..Default::default()
+        });
+        summary.deletes += 1;
+        summary.delete_bytes += fs::metadata(slus_path.join(path)).map(|m| m.len()).unwrap_or(0);
+    }
+
+    let mut summaries: Vec<FolderChangeSummary> = folders.into_values().collect();
+    summaries.sort_by(|a, b| a.folder.cmp(&b.folder));
+    summaries
+}
+
+/// Disk space a pending sync or verification fix will use and free
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct DiskDelta {
+    /// Bytes of the files that will be downloaded
+    pub bytes_added: u64,
+    /// Bytes of the local files that will be replaced or deleted
+    pub bytes_removed: u64,
+    /// bytes_added - bytes_removed (negative when the change frees space)
+    pub net_bytes: i64,
+    /// Downloads whose remote size is unknown (not counted in bytes_added)
+    pub unknown_sizes: usize,
+}
+
+/// Add up the disk delta of a plan; `local_size` is the size of a file relative to the SLUS folder (0 if missing)
+fn compute_disk_delta(
+    files_to_download: &[SyncFile],
+    files_to_delete: &[String],
+    remote_sizes: &HashMap<String, u64>,
+    convention: DisableConvention,
+    local_size: impl Fn(&str) -> u64,
+) -> DiskDelta {
+    let mut delta = DiskDelta::default();
+
+    for file in files_to_download {
+        match remote_sizes.get(&file.path) {
+            Some(size) => delta.bytes_added += size,
+            None => delta.unknown_sizes += 1,
+        }
+        // A replacement frees whatever is at the path it's written to
+        let dest = if file.to_disabled {
+            convention.disabled_path(&file.path)
+        } else {
+            file.path.clone()
+        };
+        delta.bytes_removed += local_size(&dest);
+    }
+
+    for path in files_to_delete {
+        delta.bytes_removed += local_size(path);
+    }
+
+    delta.net_bytes = delta.bytes_added as i64 - delta.bytes_removed as i64;
+    delta
+}
+
+/// Temp file a download is written to before it replaces dest_path
+/// The suffix is appended rather than swapped for the extension, so "home.png" and "home.dds"
+/// being written at the same time don't share a temp file
+pub(crate) fn download_temp_path(dest_path: &Path) -> PathBuf {
+    let mut name = dest_path.as_os_str().to_os_string();
+    name.push(".download-tmp");
+    PathBuf::from(name)
+}
+
+/// Write a file by replacing it rather than overwriting in place
+/// Keeps hardlinked copies (see dedup_installs) from being modified along with it
+pub(crate) fn write_file_replacing(dest_path: &Path, content: &[u8]) -> Result<(), String> {
+    let temp_path = download_temp_path(dest_path);
+    fs::write(&temp_path, content).map_err(|e| format!("Failed to write file: {}", e))?;
+    fs::rename(&temp_path, dest_path).map_err(|e| {
+        let _ = fs::remove_file(&temp_path);
+        format!("Failed to write file: {}", e)
+    })
 }
 
 /// Check if content is likely a text file (no null bytes in first 8KB)
@@ -151,7 +623,7 @@ fn normalize_line_endings(content: Vec<u8>) -> Vec<u8> {
 }
 
 /// Compute git blob SHA for raw content
-fn compute_sha_for_content(content: &[u8]) -> String {
+pub(crate) fn compute_sha_for_content(content: &[u8]) -> String {
     let header = format!("blob {}\0", content.len());
     let mut hasher = Sha1::new();
     hasher.update(header.as_bytes());
@@ -161,7 +633,7 @@ fn compute_sha_for_content(content: &[u8]) -> String {
 
 /// Compute git blob SHA for a file (same format git uses)
 /// Returns both the raw SHA and normalized SHA for text files
-fn compute_git_blob_sha(path: &Path) -> Result<String, String> {
+pub(crate) fn compute_git_blob_sha(path: &Path) -> Result<String, String> {
     let content = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
     Ok(compute_sha_for_content(&content))
 }
@@ -193,104 +665,22 @@ fn compute_git_blob_sha_with_normalization(path: &Path, expected_sha: Option<&st
     Ok(raw_sha)
 }
 
-/// Check if a filename is a junk file that can be safely deleted during cleanup
-fn is_junk_file(name: &str) -> bool {
-    // All hidden files (starting with .)
-    if name.starts_with('.') {
-        return true;
-    }
-    // Windows junk files
-    if name.eq_ignore_ascii_case("Thumbs.db") || name.eq_ignore_ascii_case("desktop.ini") || name.eq_ignore_ascii_case("ehthumbs.db") {
-        return true;
-    }
-    false
-}
-
-/// Recursively remove empty directories (and OS junk files)
-/// Does not remove the root directory itself, only empty subdirectories
-fn cleanup_empty_directories(root: &Path, window: &Window) -> u32 {
-    cleanup_empty_directories_recursive(root, true, window)
-}
-
-fn cleanup_empty_directories_recursive(dir: &Path, is_root: bool, window: &Window) -> u32 {
-    let mut removed = 0;
-
-    if !dir.is_dir() {
-        return 0;
-    }
-
-    // Get all entries in this directory
-    let entries: Vec<_> = match fs::read_dir(dir) {
-        Ok(rd) => rd.filter_map(|e| e.ok()).collect(),
-        Err(e) => {
-            let _ = window.emit("sync-progress", SyncProgressPayload {
-                stage: "cleanup".to_string(),
-                message: format!("Error reading dir {:?}: {}", dir, e),
-                current: None,
-                total: None,
-            });
-            return 0;
-        }
-    };
-
-    // First, recurse into subdirectories
-    for entry in &entries {
-        let path = entry.path();
-        if path.is_dir() {
-            removed += cleanup_empty_directories_recursive(&path, false, window);
-        }
-    }
-
-    // Don't remove the root directory itself
-    if is_root {
-        return removed;
-    }
-
-    // Remove hidden/junk files from this directory
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.filter_map(|e| e.ok()) {
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                    if is_junk_file(name) {
-                        let _ = fs::remove_file(&path);
-                    }
-                }
-            }
-        }
-    }
-
-    // Now check if this directory is empty (re-read after junk file removal)
-    let remaining: Vec<_> = match fs::read_dir(dir) {
-        Ok(rd) => rd.filter_map(|e| e.ok()).collect(),
-        Err(_) => return removed,
-    };
-
-    if remaining.is_empty() {
-        match fs::remove_dir(dir) {
-            Ok(_) => {
-                removed += 1;
-            }
-            Err(e) => {
-                let _ = window.emit("sync-progress", SyncProgressPayload {
-                    stage: "cleanup".to_string(),
-                    message: format!("Failed to remove {:?}: {}", dir, e),
-                    current: None,
-                    total: None,
-                });
-            }
-        }
-    }
-
-    removed
+/// Whether content hashes to the expected blob SHA, raw or (for text) with normalized line endings
+pub(crate) fn content_matches_sha(content: &[u8], expected_sha: &str) -> bool {
+    compute_sha_for_content(content) == expected_sha
+        || (is_text_content(content) && compute_sha_for_content(&normalize_line_endings(content.to_vec())) == expected_sha)
 }
 
-/// Check if a path should be skipped (user-customs folder or hidden files)
-fn should_skip_path(path: &str) -> bool {
+/// Check if a path should be skipped (user-customs folder, hidden files or a user-protected path)
+/// Protected paths are relative to the SLUS folder and cover everything below them
+pub(crate) fn should_skip_path(path: &str, protected_paths: &[String]) -> bool {
     // Skip user-customs folder
     if path.contains("user-customs") {
         return true;
     }
+    if is_protected_path(path, protected_paths) {
+        return true;
+    }
     // Skip hidden files/directories (starting with .)
     for component in path.split('/') {
         if component.starts_with('.') {
@@ -300,9 +690,18 @@ fn should_skip_path(path: &str) -> bool {
     false
 }
 
-/// Check if a filename is a disabled (dash-prefixed) version
-fn is_disabled_filename(filename: &str) -> bool {
-    filename.starts_with('-')
+/// Check if a path is, or is inside, one of the user-protected paths
+pub(crate) fn is_protected_path(path: &str, protected_paths: &[String]) -> bool {
+    protected_paths
+        .iter()
+        .any(|p| path == p || path.strip_prefix(p.as_str()).is_some_and(|rest| rest.starts_with('/')))
+}
+
+/// User-protected paths from state (none if the state can't be read)
+pub(crate) fn load_protected_paths(app: &AppHandle) -> Vec<String> {
+    load_state(app.clone())
+        .map(|state| state.protected_paths)
+        .unwrap_or_default()
 }
 
 /// Get just the filename from a path
@@ -310,44 +709,15 @@ fn get_filename(path: &str) -> &str {
     path.rsplit('/').next().unwrap_or(path)
 }
 
-/// Get the disabled version path for a file
-fn get_disabled_path(path: &str) -> String {
-    if let Some(pos) = path.rfind('/') {
-        let dir = &path[..pos + 1];
-        let file = &path[pos + 1..];
-        format!("{}-{}", dir, file)
-    } else {
-        format!("-{}", path)
-    }
-}
-
-/// Get the enabled version path for a disabled file
-fn get_enabled_path(path: &str) -> Option<String> {
-    let filename = get_filename(path);
-    if !is_disabled_filename(filename) {
-        return None;
-    }
-
-    if let Some(pos) = path.rfind("/-") {
-        let dir = &path[..pos + 1];
-        let file = &path[pos + 2..]; // Skip "/-"
-        Some(format!("{}{}", dir, file))
-    } else if path.starts_with('-') {
-        Some(path[1..].to_string())
-    } else {
-        None
-    }
-}
-
 /// Build request with optional auth token
-fn build_request(client: &Client, url: &str, token: &Option<String>) -> reqwest::RequestBuilder {
+fn build_request(config: &RepoConfig, client: &Client, url: &str, token: &Option<String>) -> reqwest::RequestBuilder {
     let mut req = client
         .get(url)
         .header("User-Agent", "NCAA-NEXT-Textures-Downloader")
         .header("Accept", "application/vnd.github.v3+json");
 
     if let Some(t) = token {
-        req = req.header("Authorization", format!("Bearer {}", t));
+        req = req.header("Authorization", config.forge.authorization(t));
     }
 
     req
@@ -355,27 +725,25 @@ fn build_request(client: &Client, url: &str, token: &Option<String>) -> reqwest:
 
 /// Get the latest commit SHA for the main branch
 #[tauri::command]
-pub async fn get_latest_commit() -> Result<String, String> {
-    get_latest_commit_with_token(&None).await
+pub async fn get_latest_commit(app: AppHandle) -> Result<String, String> {
+    let config = resolve_repo_config(&app)?;
+    get_latest_commit_with_token(&config, &None).await
 }
 
-async fn get_latest_commit_with_token(token: &Option<String>) -> Result<String, String> {
-    let (sha, _) = get_commit_details_with_token("main", token).await?;
+pub(crate) async fn get_latest_commit_with_token(config: &RepoConfig, token: &Option<String>) -> Result<String, String> {
+    let (sha, _) = get_commit_details_with_token(config, &config.branch, token).await?;
     Ok(sha)
 }
 
 /// Fetch commit details (sha and date) for a given commit reference
-async fn get_commit_details_with_token(commit_ref: &str, token: &Option<String>) -> Result<(String, String), String> {
-    let client = Client::new();
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/commits/{}",
-        REPO_OWNER, REPO_NAME, commit_ref
-    );
+async fn get_commit_details_with_token(config: &RepoConfig, commit_ref: &str, token: &Option<String>) -> Result<(String, String), String> {
+    let client = http_client();
+    let url = format!("{}/{}/{}", config.api_repo_url(), config.forge.commit_endpoint(), commit_ref);
 
-    let response = build_request(&client, &url, token)
-        .send()
+    let response = build_request(config, &client, &url, token)
+        .send_traced()
         .await
-        .map_err(|e| format!("Failed to fetch commit: {}", e))?;
+        .map_err(|e| format!("Failed to fetch commit: {}", describe_request_error(&e)))?;
 
     if !response.status().is_success() {
         return Err(format!(
@@ -393,46 +761,107 @@ async fn get_commit_details_with_token(commit_ref: &str, token: &Option<String>)
     Ok((commit.sha, commit.commit.committer.date))
 }
 
-/// Fetch a single tree from GitHub API
-async fn fetch_tree(client: &Client, tree_sha: &str, recursive: bool, token: &Option<String>) -> Result<TreeResponse, String> {
-    let url = if recursive {
-        format!(
-            "https://api.github.com/repos/{}/{}/git/trees/{}?recursive=1",
-            REPO_OWNER, REPO_NAME, tree_sha
-        )
-    } else {
-        format!(
-            "https://api.github.com/repos/{}/{}/git/trees/{}",
-            REPO_OWNER, REPO_NAME, tree_sha
-        )
-    };
+/// Attempts per tree request before its error is returned
+const TREE_FETCH_ATTEMPTS: u32 = 3;
 
-    let response = build_request(client, &url, token)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch tree: {}", e))?;
+/// Wait before the first retry of a tree request, doubled for each further one
+const TREE_RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
 
-    if !response.status().is_success() {
-        return Err(format!(
-            "GitHub API error: {} - {}",
-            response.status(),
-            response.text().await.unwrap_or_default()
+/// How long fetched trees are kept; a tree's content never changes for its SHA, this only
+/// bounds memory use in a long-running session
+const TREE_CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// Trees kept in the cache, the oldest is dropped beyond this
+const TREE_CACHE_MAX_ENTRIES: usize = 256;
+
+/// Trees fetched recently, by request URL (which holds the repository, the SHA and recursion)
+static TREE_CACHE: Mutex<Vec<(String, Instant, TreeResponse)>> = Mutex::new(Vec::new());
+
+fn cached_tree(url: &str) -> Option<TreeResponse> {
+    let mut cache = TREE_CACHE.lock().ok()?;
+    cache.retain(|(_, fetched, _)| fetched.elapsed() < TREE_CACHE_TTL);
+    cache.iter().find(|(key, _, _)| key == url).map(|(_, _, tree)| tree.clone())
+}
+
+fn cache_tree(url: String, tree: &TreeResponse) {
+    if let Ok(mut cache) = TREE_CACHE.lock() {
+        if cache.len() >= TREE_CACHE_MAX_ENTRIES {
+            cache.remove(0);
+        }
+        cache.push((url, Instant::now(), tree.clone()));
+    }
+}
+
+/// Forget every fetched tree
+pub(crate) fn clear_tree_cache() {
+    if let Ok(mut cache) = TREE_CACHE.lock() {
+        cache.clear();
+    }
+}
+
+/// One attempt at a tree request; the error says whether trying again could help
+async fn request_tree(config: &RepoConfig, client: &Client, url: &str, token: &Option<String>) -> Result<TreeResponse, (String, bool)> {
+    // A stalled request is worth another try, like a failed one
+    let response = watchdog("fetching the file list", None, build_request(config, client, url, token).send_traced())
+        .await
+        .map_err(|stalled| (stalled, true))?
+        .map_err(|e| (format!("Failed to fetch tree: {}", describe_request_error(&e)), true))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        // Server errors and throttling are usually gone a moment later, a missing tree or a
+        // rejected token isn't
+        let retryable = status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+        return Err((
+            format!("GitHub API error: {} - {}", status, response.text().await.unwrap_or_default()),
+            retryable,
         ));
     }
 
-    response
-        .json()
+    watchdog("fetching the file list", None, response.json())
         .await
-        .map_err(|e| format!("Failed to parse tree response: {}", e))
+        .map_err(|stalled| (stalled, true))?
+        .map_err(|e| (format!("Failed to parse tree response: {}", e), true))
+}
+
+/// Fetch a single tree from GitHub API
+/// Failed requests are retried with backoff, and trees are cached by SHA for a while, so the
+/// subtrees every scan resolves are only requested once
+async fn fetch_tree(config: &RepoConfig, client: &Client, tree_sha: &str, recursive: bool, token: &Option<String>) -> Result<TreeResponse, String> {
+    let url = if recursive {
+        format!("{}/git/trees/{}?recursive=1", config.api_repo_url(), tree_sha)
+    } else {
+        format!("{}/git/trees/{}", config.api_repo_url(), tree_sha)
+    };
+    if let Some(tree) = cached_tree(&url) {
+        return Ok(tree);
+    }
+
+    let mut attempt = 1;
+    loop {
+        match request_tree(config, client, &url, token).await {
+            Ok(tree) => {
+                cache_tree(url, &tree);
+                return Ok(tree);
+            }
+            Err((error, retryable)) => {
+                if !retryable || attempt == TREE_FETCH_ATTEMPTS {
+                    return Err(error);
+                }
+                tokio::time::sleep(TREE_RETRY_BASE_DELAY * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+        }
+    }
 }
 
 /// Navigate to a subtree by path (e.g., "textures/SLUS-21214")
-async fn get_subtree_sha(client: &Client, root_sha: &str, path: &str, token: &Option<String>) -> Result<String, String> {
+async fn get_subtree_sha(config: &RepoConfig, client: &Client, root_sha: &str, path: &str, token: &Option<String>) -> Result<String, String> {
     let parts: Vec<&str> = path.split('/').collect();
     let mut current_sha = root_sha.to_string();
 
     for part in parts {
-        let tree = fetch_tree(client, &current_sha, false, token).await?;
+        let tree = fetch_tree(config, client, &current_sha, false, token).await?;
 
         let entry = tree.tree.iter()
             .find(|e| e.path == part && e.entry_type == "tree")
@@ -444,66 +873,170 @@ async fn get_subtree_sha(client: &Client, root_sha: &str, path: &str, token: &Op
     Ok(current_sha)
 }
 
-/// Recursively fetch all files from a tree, handling truncation
-async fn fetch_tree_files_recursive(
+/// Subtrees fetched at the same time while a truncated tree is walked folder by folder
+const TREE_FETCH_CONCURRENCY: usize = 8;
+
+/// What one tree request found: files with their (sha, size), and subtrees (sha, path) still to
+/// fetch because the listing was truncated
+type SubtreeListing = (Vec<(String, String, u64)>, Vec<(String, String)>);
+
+fn join_tree_path(base_path: &str, path: &str) -> String {
+    if base_path.is_empty() {
+        path.to_string()
+    } else {
+        format!("{}/{}", base_path, path)
+    }
+}
+
+/// List one tree: recursively when GitHub returns it whole, otherwise its direct files plus the
+/// subtrees still to fetch
+async fn fetch_subtree_listing(
+    config: &RepoConfig,
     client: &Client,
     tree_sha: &str,
     base_path: &str,
-    file_map: &mut HashMap<String, String>,
     token: &Option<String>,
-) -> Result<(), String> {
-    let tree = fetch_tree(client, tree_sha, true, token).await?;
+) -> Result<SubtreeListing, String> {
+    let tree = fetch_tree(config, client, tree_sha, true, token).await?;
+    let mut files = Vec::new();
+    let mut subtrees = Vec::new();
 
     if tree.truncated {
         // Tree is truncated, need to fetch each subdirectory individually
-        let tree_non_recursive = fetch_tree(client, tree_sha, false, token).await?;
-
+        let tree_non_recursive = fetch_tree(config, client, tree_sha, false, token).await?;
         for entry in tree_non_recursive.tree {
-            let entry_path = if base_path.is_empty() {
-                entry.path.clone()
-            } else {
-                format!("{}/{}", base_path, entry.path)
-            };
-
+            let entry_path = join_tree_path(base_path, &entry.path);
             if entry.entry_type == "blob" {
-                file_map.insert(entry_path, entry.sha);
+                files.push((entry_path, entry.sha, entry.size.unwrap_or(0)));
             } else if entry.entry_type == "tree" {
-                // Recursively fetch this subdirectory
-                Box::pin(fetch_tree_files_recursive(client, &entry.sha, &entry_path, file_map, token)).await?;
+                subtrees.push((entry.sha, entry_path));
             }
         }
     } else {
         // Tree is complete, add all files
         for entry in tree.tree {
             if entry.entry_type == "blob" {
-                let entry_path = if base_path.is_empty() {
-                    entry.path
-                } else {
-                    format!("{}/{}", base_path, entry.path)
-                };
-                file_map.insert(entry_path, entry.sha);
+                files.push((join_tree_path(base_path, &entry.path), entry.sha, entry.size.unwrap_or(0)));
             }
         }
     }
 
+    Ok((files, subtrees))
+}
+
+/// Fetch all files from a tree, handling truncation
+/// Subtrees of a truncated tree are fetched concurrently (at most TREE_FETCH_CONCURRENCY at once)
+async fn fetch_tree_files_recursive(
+    config: &RepoConfig,
+    client: &Client,
+    tree_sha: &str,
+    base_path: &str,
+    file_map: &mut HashMap<String, String>,
+    size_map: &mut HashMap<String, u64>,
+    token: &Option<String>,
+) -> Result<(), String> {
+    let mut pending = vec![(tree_sha.to_string(), base_path.to_string())];
+    // Dropping the set (on an error) aborts the requests still running
+    let mut running = tokio::task::JoinSet::new();
+
+    while !pending.is_empty() || !running.is_empty() {
+        while running.len() < TREE_FETCH_CONCURRENCY {
+            let Some((sha, path)) = pending.pop() else {
+                break;
+            };
+            let (config, client, token) = (config.clone(), client.clone(), token.clone());
+            running.spawn(async move { fetch_subtree_listing(&config, &client, &sha, &path, &token).await });
+        }
+
+        let Some(joined) = running.join_next().await else {
+            break;
+        };
+        let (files, subtrees) = joined.map_err(|e| format!("Failed to fetch tree: {}", e))??;
+        for (path, sha, size) in files {
+            size_map.insert(path.clone(), size);
+            file_map.insert(path, sha);
+        }
+        pending.extend(subtrees);
+    }
+
     Ok(())
 }
 
-/// Fetch the GitHub tree for the sparse path (used for full sync)
-async fn fetch_github_tree(token: &Option<String>) -> Result<(HashMap<String, String>, String), String> {
-    let client = Client::new();
+/// Fetch the newest version of the pack from a source (used for full sync)
+/// Returns (path -> sha, path -> size in bytes, version ref)
+async fn fetch_latest_tree(
+    source: &impl PackSource,
+    window: &Window,
+) -> Result<(HashMap<String, String>, HashMap<String, u64>, String), String> {
+    let latest_ref = with_heartbeat(window, Stage::Fetching, source.latest_ref()).await?;
+    let (mut file_map, mut size_map) = with_heartbeat(window, Stage::Fetching, source.list_tree(&latest_ref)).await?;
+    for (path, reason) in drop_unsafe_paths(&mut file_map, &mut size_map) {
+        emit_log(window, LogLevel::Warn, "tree", Some(&path), format!("Skipped unsafe path ({})", reason));
+    }
+
+    Ok((file_map, size_map, latest_ref))
+}
 
-    // First get the latest commit SHA
-    let commit_sha = get_latest_commit_with_token(token).await?;
+/// Fetch the GitHub tree for the sparse path at a specific commit
+/// Returns (path -> sha, path -> size in bytes)
+pub(crate) async fn fetch_github_tree_at_commit(
+    config: &RepoConfig,
+    client: &Client,
+    commit_sha: &str,
+    token: &Option<String>,
+) -> Result<(HashMap<String, String>, HashMap<String, u64>), String> {
+    let mut file_map: HashMap<String, String> = HashMap::new();
+    let mut size_map: HashMap<String, u64> = HashMap::new();
+
+    // One recursive listing of the whole commit usually has everything: the sparse path's files
+    // when it's complete, and at least the sparse path's own entry (listed early, paths are
+    // sorted) when it's truncated
+    let root = fetch_tree(config, client, commit_sha, true, token).await?;
+    let subtree_entry = root
+        .tree
+        .iter()
+        .find(|e| e.path == config.sparse_path && e.entry_type == "tree")
+        .map(|e| e.sha.clone());
+
+    if !root.truncated {
+        subtree_entry.ok_or_else(|| format!("Path '{}' not found in repository", config.sparse_path))?;
+        let prefix = format!("{}/", config.sparse_path);
+        for entry in root.tree {
+            if entry.entry_type != "blob" {
+                continue;
+            }
+            if let Some(relative_path) = entry.path.strip_prefix(&prefix) {
+                size_map.insert(relative_path.to_string(), entry.size.unwrap_or(0));
+                file_map.insert(relative_path.to_string(), entry.sha);
+            }
+        }
+        return Ok((file_map, size_map));
+    }
 
-    // Navigate to the SPARSE_PATH subtree to avoid fetching the entire repo
-    let subtree_sha = get_subtree_sha(&client, &commit_sha, SPARSE_PATH, token).await?;
+    // Navigate to the sparse path subtree (one request per path component) if the truncated
+    // listing didn't reach it
+    let subtree_sha = match subtree_entry {
+        Some(sha) => sha,
+        None => get_subtree_sha(config, client, commit_sha, &config.sparse_path, token).await?,
+    };
 
     // Now fetch all files from this subtree
-    let mut file_map: HashMap<String, String> = HashMap::new();
-    fetch_tree_files_recursive(&client, &subtree_sha, "", &mut file_map, token).await?;
+    fetch_tree_files_recursive(config, client, &subtree_sha, "", &mut file_map, &mut size_map, token).await?;
+
+    Ok((file_map, size_map))
+}
 
-    Ok((file_map, commit_sha))
+/// Every folder in a commit (paths from the repository root), and whether the listing was
+/// truncated (then folders deep in the tree may be missing)
+pub(crate) async fn list_repo_folders(
+    config: &RepoConfig,
+    client: &Client,
+    commit_sha: &str,
+    token: &Option<String>,
+) -> Result<(Vec<String>, bool), String> {
+    let root = fetch_tree(config, client, commit_sha, true, token).await?;
+    let folders = root.tree.into_iter().filter(|e| e.entry_type == "tree").map(|e| e.path).collect();
+    Ok((folders, root.truncated))
 }
 
 /// GitHub Compare API file limit
@@ -511,21 +1044,24 @@ const GITHUB_COMPARE_FILE_LIMIT: usize = 300;
 
 /// Fetch changed files between two commits using compare API
 /// Returns (files, is_truncated) - truncated if exactly 300 files returned
-async fn fetch_changed_files(
+pub(crate) async fn fetch_changed_files(
+    config: &RepoConfig,
     base_sha: &str,
     head_sha: &str,
     token: &Option<String>,
 ) -> Result<(Vec<CompareFile>, bool), String> {
-    let client = Client::new();
-    let url = format!(
-        "https://api.github.com/repos/{}/{}/compare/{}...{}",
-        REPO_OWNER, REPO_NAME, base_sha, head_sha
-    );
+    // Treated like a truncated comparison, so the changes are walked commit by commit
+    if !config.forge.compare_lists_files() {
+        return Ok((Vec::new(), true));
+    }
 
-    let response = build_request(&client, &url, token)
-        .send()
+    let client = http_client();
+    let url = format!("{}/compare/{}...{}", config.api_repo_url(), base_sha, head_sha);
+
+    let response = build_request(config, &client, &url, token)
+        .send_traced()
         .await
-        .map_err(|e| format!("Failed to compare commits: {}", e))?;
+        .map_err(|e| format!("Failed to compare commits: {}", describe_request_error(&e)))?;
 
     if !response.status().is_success() {
         return Err(format!(
@@ -546,76 +1082,244 @@ async fn fetch_changed_files(
     Ok((files, is_truncated))
 }
 
-/// Build a map of local files (relative_path -> sha)
-fn build_local_file_map(textures_dir: &Path) -> Result<HashMap<String, String>, String> {
-    let slus_path = textures_dir.join(SLUS_FOLDER);
-    if !slus_path.exists() {
-        return Err(format!("{} folder not found", SLUS_FOLDER));
-    }
+/// Maximum commits to walk when chaining comparisons - beyond this a full tree sync is cheaper
+const MAX_CHAINED_COMMITS: usize = 200;
 
-    let mut file_map: HashMap<String, String> = HashMap::new();
-    build_local_file_map_recursive(&slus_path, &slus_path, &mut file_map)?;
-    Ok(file_map)
-}
+/// Page size for the commit list and commit files APIs
+const GITHUB_PAGE_SIZE: usize = 100;
 
-/// Count local files quickly (no SHA computation)
-fn count_local_files(textures_dir: &Path) -> Result<usize, String> {
-    let slus_path = textures_dir.join(SLUS_FOLDER);
-    if !slus_path.exists() {
-        return Err(format!("{} folder not found", SLUS_FOLDER));
-    }
+/// Entry in the commit list response
+#[derive(Debug, Deserialize)]
+struct CommitListEntry {
+    sha: String,
+}
 
-    let mut count = 0;
-    count_local_files_recursive(&slus_path, &slus_path, &mut count)?;
-    Ok(count)
+/// Single commit response (only the changed files)
+#[derive(Debug, Deserialize)]
+struct CommitFilesResponse {
+    files: Option<Vec<CompareFile>>,
 }
 
-fn count_local_files_recursive(
-    base_path: &Path,
-    current_path: &Path,
-    count: &mut usize,
-) -> Result<(), String> {
-    let entries = fs::read_dir(current_path)
-        .map_err(|e| format!("Failed to read directory: {}", e))?;
+/// List the commits after base_sha up to and including head_sha, oldest first
+async fn fetch_commits_between(
+    config: &RepoConfig,
+    client: &Client,
+    base_sha: &str,
+    head_sha: &str,
+    token: &Option<String>,
+) -> Result<Vec<String>, String> {
+    let mut commits = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let url = format!(
+            "{}/commits?sha={}&{}={}&page={}",
+            config.api_repo_url(),
+            head_sha,
+            config.forge.page_size_param(),
+            GITHUB_PAGE_SIZE,
+            page
+        );
+
+        let response = build_request(config, client, &url, token)
+            .send_traced()
+            .await
+            .map_err(|e| format!("Failed to list commits: {}", describe_request_error(&e)))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "GitHub API error: {} - {}",
+                response.status(),
+                response.text().await.unwrap_or_default()
+            ));
+        }
 
-    for entry in entries {
-        let entry = entry.map_err(|e| format!("Failed to read entry: {}", e))?;
-        let path = entry.path();
+        let entries: Vec<CommitListEntry> = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse commit list: {}", e))?;
 
-        // Skip hidden files
-        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-            if name.starts_with('.') {
-                continue;
+        if entries.is_empty() {
+            return Err("TRUNCATED: Previous sync commit not found in history".to_string());
+        }
+
+        for entry in entries {
+            if entry.sha == base_sha {
+                commits.reverse();
+                return Ok(commits);
+            }
+            commits.push(entry.sha);
+            if commits.len() > MAX_CHAINED_COMMITS {
+                return Err(format!("TRUNCATED: More than {} commits since last sync", MAX_CHAINED_COMMITS));
             }
         }
 
-        if path.is_dir() {
-            count_local_files_recursive(base_path, &path, count)?;
-        } else if path.is_file() {
-            let relative_path = path
-                .strip_prefix(base_path)
-                .map_err(|e| format!("Failed to get relative path: {}", e))?
-                .to_string_lossy()
-                .to_string();
+        page += 1;
+    }
+}
 
-            let relative_path = relative_path.replace('\\', "/");
+/// Fetch all files changed by a single commit (paginated, unlike the compare API)
+async fn fetch_commit_files(
+    config: &RepoConfig,
+    client: &Client,
+    commit_sha: &str,
+    token: &Option<String>,
+) -> Result<Vec<CompareFile>, String> {
+    let mut files = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let url = format!(
+            "{}/{}/{}?{}={}&page={}",
+            config.api_repo_url(),
+            config.forge.commit_endpoint(),
+            commit_sha,
+            config.forge.page_size_param(),
+            GITHUB_PAGE_SIZE,
+            page
+        );
+
+        let response = build_request(config, client, &url, token)
+            .send_traced()
+            .await
+            .map_err(|e| format!("Failed to fetch commit {}: {}", commit_sha, describe_request_error(&e)))?;
+
+        if !response.status().is_success() {
+            return Err(format!(
+                "GitHub API error: {} - {}",
+                response.status(),
+                response.text().await.unwrap_or_default()
+            ));
+        }
 
-            // Skip user-customs
-            if should_skip_path(&relative_path) {
-                continue;
+        let commit: CommitFilesResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse commit response: {}", e))?;
+
+        let page_files = commit.files.unwrap_or_default();
+        let is_last_page = page_files.len() < GITHUB_PAGE_SIZE;
+        files.extend(page_files);
+
+        if is_last_page {
+            return Ok(files);
+        }
+        page += 1;
+    }
+}
+
+/// Collapse per-commit changes (oldest commit first) into one change per path, by final state
+/// Renames are kept only when nothing else touched either path afterwards; otherwise they
+/// become a delete of the old path and a download of the new one
+fn aggregate_commit_changes(commits: Vec<Vec<CompareFile>>) -> Vec<CompareFile> {
+    let mut final_state: BTreeMap<String, CompareFile> = BTreeMap::new();
+    // Old path -> new path, for removals that only exist because of a rename
+    let mut rename_sources: HashMap<String, String> = HashMap::new();
+
+    for files in commits {
+        for file in files {
+            rename_sources.remove(&file.filename);
+
+            if file.status == "renamed" {
+                if let Some(previous) = &file.previous_filename {
+                    final_state.insert(previous.clone(), CompareFile {
+                        filename: previous.clone(),
+                        status: "removed".to_string(),
+                        previous_filename: None,
+                        sha: None,
+                    });
+                    rename_sources.insert(previous.clone(), file.filename.clone());
+                }
             }
 
-            *count += 1;
+            final_state.insert(file.filename.clone(), file);
         }
     }
 
-    Ok(())
+    let surviving_renames: HashSet<String> = rename_sources
+        .iter()
+        .filter(|(old, new)| {
+            matches!(final_state.get(*new), Some(f) if f.status == "renamed" && f.previous_filename.as_ref() == Some(*old))
+        })
+        .map(|(old, _)| old.clone())
+        .collect();
+
+    final_state
+        .into_values()
+        .filter(|file| !surviving_renames.contains(&file.filename))
+        .map(|mut file| {
+            let rename_survived = file
+                .previous_filename
+                .as_ref()
+                .is_some_and(|previous| surviving_renames.contains(previous));
+            if file.status == "renamed" && !rename_survived {
+                file.status = "added".to_string();
+                file.previous_filename = None;
+            }
+            file
+        })
+        .collect()
+}
+
+/// Fetch changed files between two commits by walking each commit in between
+/// Avoids the compare API's 300 file limit without downloading the full tree
+pub(crate) async fn fetch_changed_files_chained(
+    config: &RepoConfig,
+    base_sha: &str,
+    head_sha: &str,
+    token: &Option<String>,
+) -> Result<Vec<CompareFile>, String> {
+    // One request per commit would exhaust the unauthenticated rate limit (60/hour)
+    if token.is_none() {
+        return Err("TRUNCATED: Chained comparison requires a GitHub token".to_string());
+    }
+
+    let client = http_client();
+    let commits = fetch_commits_between(config, &client, base_sha, head_sha, token).await?;
+
+    let mut per_commit_files = Vec::with_capacity(commits.len());
+    for commit_sha in &commits {
+        per_commit_files.push(fetch_commit_files(config, &client, commit_sha, token).await?);
+    }
+
+    Ok(aggregate_commit_changes(per_commit_files))
+}
+
+/// Build a map of local files (relative_path -> sha)
+/// Backed by the file index, so only new or modified files are hashed
+fn build_local_file_map(slus_path: &Path, convention: DisableConvention) -> Result<HashMap<String, String>, String> {
+    build_local_file_map_under(slus_path, "", convention)
+}
+
+/// build_local_file_map for the files whose path starts with `prefix`
+/// The whole index is refreshed (stat only), but only files under the prefix are hashed
+fn build_local_file_map_under(slus_path: &Path, prefix: &str, convention: DisableConvention) -> Result<HashMap<String, String>, String> {
+    let mut index = FileIndex::open(slus_path)?;
+    index.refresh(convention)?;
+    index.sha_map_under(prefix)
+}
+
+/// Top-level folder of a path relative to the SLUS folder (files at the top are their own group)
+fn top_level_folder(path: &str) -> &str {
+    path.split('/').next().unwrap_or(path)
+}
+
+/// Count local files quickly (no SHA computation), per top-level folder
+fn count_local_files(slus_path: &Path, protected_paths: &[String]) -> Result<BTreeMap<String, usize>, String> {
+    if !slus_path.exists() {
+        return Err(format!("{} folder not found", slus_path.display()));
+    }
+
+    let mut counts = BTreeMap::new();
+    count_local_files_recursive(slus_path, slus_path, protected_paths, &mut counts)?;
+    Ok(counts)
 }
 
-fn build_local_file_map_recursive(
+fn count_local_files_recursive(
     base_path: &Path,
     current_path: &Path,
-    file_map: &mut HashMap<String, String>,
+    protected_paths: &[String],
+    counts: &mut BTreeMap<String, usize>,
 ) -> Result<(), String> {
     let entries = fs::read_dir(current_path)
         .map_err(|e| format!("Failed to read directory: {}", e))?;
@@ -632,7 +1336,7 @@ fn build_local_file_map_recursive(
         }
 
         if path.is_dir() {
-            build_local_file_map_recursive(base_path, &path, file_map)?;
+            count_local_files_recursive(base_path, &path, protected_paths, counts)?;
         } else if path.is_file() {
             let relative_path = path
                 .strip_prefix(base_path)
@@ -640,80 +1344,341 @@ fn build_local_file_map_recursive(
                 .to_string_lossy()
                 .to_string();
 
-            // Use forward slashes for consistency
             let relative_path = relative_path.replace('\\', "/");
 
-            // Skip user-customs
-            if should_skip_path(&relative_path) {
+            // Skip user-customs and protected paths
+            if should_skip_path(&relative_path, protected_paths) {
                 continue;
             }
 
-            let sha = compute_git_blob_sha(&path)?;
-            file_map.insert(relative_path, sha);
+            *counts.entry(top_level_folder(&relative_path).to_string()).or_default() += 1;
         }
     }
 
     Ok(())
 }
 
-/// Download a file from GitHub raw content
+/// Downloads of a file whose content doesn't match its expected SHA before giving up
+const DOWNLOAD_HASH_ATTEMPTS: u32 = 3;
+
+/// Download a file of the pack from its source
+/// With an expected SHA the content is checked against it, and fetched again on a mismatch
 async fn download_file(
-    client: &Client,
+    source: &impl PackSource,
+    cache: &BlobCache,
     relative_path: &str,
+    expected_sha: Option<&str>,
     dest_path: &Path,
-    token: &Option<String>,
 ) -> Result<(), String> {
-    let url = format!(
-        "https://raw.githubusercontent.com/{}/{}/main/{}/{}",
-        REPO_OWNER, REPO_NAME, SPARSE_PATH, relative_path
-    );
+    // Reuse a previously downloaded copy of this exact content, or a duplicate already on disk
+    // (the cache names its files after the SHA, so it's checked first)
+    if let Some(sha) = expected_sha {
+        check_blob_sha(sha)?;
+        if cache.restore(sha, dest_path) || cache.restore_local(sha, dest_path) {
+            return Ok(());
+        }
+    }
+
+    // A truncated response or a stale copy from a CDN would otherwise only surface at the next
+    // verification; fetch again when the content doesn't match the expected SHA
+    // Large files with a known SHA resume from what an interrupted attempt left behind
+    let partial_path = expected_sha.map(|sha| cache.partial_path(sha));
+    let fetch = || async {
+        match &partial_path {
+            Some(partial) => source.fetch_file_resumable(relative_path, partial).await,
+            None => source.fetch_file(relative_path).await,
+        }
+    };
+    let mut bytes = fetch().await?;
+    if let Some(sha) = expected_sha {
+        let mut attempt = 1;
+        while !content_matches_sha(&bytes, sha) {
+            // A resumed download that doesn't add up must start over
+            if let Some(partial) = &partial_path {
+                let _ = fs::remove_file(partial);
+            }
+            if attempt == DOWNLOAD_HASH_ATTEMPTS {
+                return Err(format!(
+                    "Downloaded {} doesn't match the expected hash {} ({} attempts)",
+                    relative_path, sha, DOWNLOAD_HASH_ATTEMPTS
+                ));
+            }
+            attempt += 1;
+            bytes = fetch().await?;
+        }
+    }
+    if let Some(partial) = &partial_path {
+        let _ = fs::remove_file(partial);
+    }
+
+    // Ensure parent directory exists
+    if let Some(parent) = dest_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+
+    write_file_replacing(dest_path, &bytes)?;
+
+    if let Some(sha) = expected_sha {
+        cache.store(sha, &bytes);
+    }
+
+    Ok(())
+}
+
+/// Probe the source's download mirrors and log their ranking (no-op without mirrors)
+async fn rank_mirrors(source: &impl PackSource, window: &Window) {
+    let health = source.probe_mirrors().await;
+    if health.is_empty() {
+        return;
+    }
+
+    let ranking: Vec<String> = health
+        .iter()
+        .map(|mirror| match mirror.latency_ms {
+            Some(ms) => format!("{} ({} ms)", mirror.url, ms),
+            None => format!("{} (unreachable)", mirror.url),
+        })
+        .collect();
+    emit_log(window, LogLevel::Info, "mirror", None, format!("Mirror ranking: {}", ranking.join(", ")));
+}
+
+/// Report which mirrors served the files downloaded since the last report (no-op without mirrors)
+fn emit_mirror_usage(source: &impl PackSource, window: &Window) {
+    let usage = source.take_mirror_usage();
+    if usage.is_empty() {
+        return;
+    }
+
+    for mirror in &usage {
+        let detail = if mirror.failovers > 0 {
+            format!("Served {} files ({} after failover)", mirror.files, mirror.failovers)
+        } else {
+            format!("Served {} files", mirror.files)
+        };
+        emit_log(window, LogLevel::Info, "mirror", Some(&mirror.url), detail);
+    }
+    let _ = window.emit("sync-mirror-usage", usage);
+}
+
+/// How the files of a sync are downloaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DownloadStrategy {
+    /// One raw content request per file
+    PerFile,
+    /// One archive of the whole repository, extracting the needed files
+    Archive,
+}
+
+/// Syncs downloading fewer files always use per-file requests
+const ARCHIVE_MIN_FILES: usize = 500;
+
+/// Estimated cost of a per-file request, as the bytes that could be streamed in the same time
+const PER_FILE_OVERHEAD_BYTES: u64 = 512 * 1024;
+
+/// Pick per-file downloads or an archive for a sync
+/// The archive holds the whole pack, so it wins once per-request overhead outweighs the unneeded bytes
+fn choose_download_strategy(download_count: usize, download_bytes: u64, pack_bytes: u64) -> DownloadStrategy {
+    if download_count < ARCHIVE_MIN_FILES {
+        return DownloadStrategy::PerFile;
+    }
 
+    let per_file_cost = download_bytes + download_count as u64 * PER_FILE_OVERHEAD_BYTES;
+    if per_file_cost > pack_bytes {
+        DownloadStrategy::Archive
+    } else {
+        DownloadStrategy::PerFile
+    }
+}
+
+/// Decide the download strategy, report it, and extract the files from an archive if that was picked
+/// Returns the extracted paths; everything else (including all files if the archive fails) is left
+/// for per-file downloads. Without the pack's file sizes, files are always downloaded one by one
+async fn download_via_archive(
+    source: &impl PackSource,
+    reference: &str,
+    targets: HashMap<String, ArchiveTarget>,
+    pack_sizes: Option<&HashMap<String, u64>>,
+    window: &Window,
+) -> HashSet<String> {
+    let download_count = targets.len();
+    let download_bytes: u64 = pack_sizes
+        .map(|sizes| targets.keys().filter_map(|path| sizes.get(path)).sum())
+        .unwrap_or(0);
+    let strategy = match pack_sizes {
+        Some(sizes) if source.supports_archive() => {
+            choose_download_strategy(download_count, download_bytes, sizes.values().sum())
+        }
+        _ => DownloadStrategy::PerFile,
+    };
+
+    let params = MessageParams::from([
+        ("count", download_count.to_string()),
+        ("mb", (download_bytes / (1024 * 1024)).to_string()),
+    ]);
+    if strategy == DownloadStrategy::PerFile {
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Downloading,
+            key: "sync.strategy_per_file",
+            params,
+            message: format!("Downloading {} files ({} MB) one by one", download_count, download_bytes / (1024 * 1024)),
+            current: None,
+            total: None,
+        });
+        return HashSet::new();
+    }
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Downloading,
+        key: "sync.strategy_archive",
+        params,
+        message: format!(
+            "Downloading {} files ({} MB) through a pack archive, faster than one by one",
+            download_count,
+            download_bytes / (1024 * 1024)
+        ),
+        current: None,
+        total: None,
+    });
+
+    let work_dir = match window.app_handle().path().app_cache_dir() {
+        Ok(dir) => dir.join("archives"),
+        Err(e) => {
+            emit_log(window, LogLevel::Warn, "archive", None, format!("Falling back to per-file downloads: {}", e));
+            return HashSet::new();
+        }
+    };
+
+    let progress = |key, params, message| {
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Downloading,
+            key,
+            params,
+            message,
+            current: None,
+            total: None,
+        });
+    };
+    match source.extract_archive(reference, targets, &work_dir, &progress).await {
+        Ok(extracted) => {
+            emit_log(window, LogLevel::Info, "archive", None, format!("Extracted {} files from the pack archive", extracted.len()));
+            extracted
+        }
+        Err(e) => {
+            emit_log(window, LogLevel::Warn, "archive", None, format!("Falling back to per-file downloads: {}", e));
+            HashSet::new()
+        }
+    }
+}
+
+/// Raw content URL of a file in the pack
+pub(crate) fn raw_file_url(config: &RepoConfig, relative_path: &str) -> String {
+    config.raw_url(&format!("{}/{}", config.sparse_path, relative_path))
+}
+
+/// Upper bound for the speed test download
+const SPEED_PROBE_MAX_BYTES: u64 = 8 * 1024 * 1024;
+
+/// Measured download speed and the time a pending download would take at that speed
+#[derive(Debug, Clone, Serialize)]
+pub struct SpeedTestResult {
+    /// File downloaded as the probe (relative to the SLUS folder)
+    pub probe_path: String,
+    pub probe_bytes: u64,
+    pub duration_ms: u64,
+    pub bytes_per_second: u64,
+    pub pending_bytes: u64,
+    /// Estimated time to download pending_bytes (at most the download rate cap)
+    pub estimated_seconds: u64,
+}
+
+/// Download one pack file to measure speed, and estimate how long pending_bytes would take
+/// The probe defaults to the largest local texture (up to 8 MB); timing includes the request
+/// round trip, like the per-file downloads of a sync
+#[tauri::command]
+pub async fn measure_download_speed(
+    textures_dir: String,
+    pending_bytes: u64,
+    probe_path: Option<String>,
+    github_token: Option<String>,
+    app: AppHandle,
+) -> Result<SpeedTestResult, String> {
+    let config = resolve_repo_config(&app)?;
+    let probe_path = match probe_path {
+        Some(path) => path,
+        None => {
+            let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+            let protected_paths = load_protected_paths(&app);
+            FileIndex::open(&slus_path)?
+                .largest_files(SPEED_PROBE_MAX_BYTES, 20)?
+                .into_iter()
+                .map(|file| file.path)
+                .find(|path| !should_skip_path(path, &protected_paths))
+                .ok_or_else(|| "No local texture to use as a speed probe".to_string())?
+        }
+    };
+
+    let client = http_client();
     let mut req = client
-        .get(&url)
+        .get(raw_file_url(&config, &probe_path))
         .header("User-Agent", "NCAA-NEXT-Textures-Downloader");
-
-    if let Some(t) = token {
-        req = req.header("Authorization", format!("Bearer {}", t));
+    if let Some(t) = &github_token {
+        req = req.header("Authorization", config.forge.authorization(t));
     }
 
-    let response = req
-        .send()
+    let started = Instant::now();
+    let mut response = req
+        .send_traced()
         .await
-        .map_err(|e| format!("Failed to download file: {}", e))?;
+        .map_err(|e| format!("Failed to download speed probe: {}", describe_request_error(&e)))?;
 
     if !response.status().is_success() {
-        return Err(format!(
-            "Failed to download {}: HTTP {}",
-            relative_path,
-            response.status()
-        ));
+        return Err(format!("Failed to download {}: HTTP {}", probe_path, response.status()));
     }
 
-    let bytes = response
-        .bytes()
+    let mut probe_bytes: u64 = 0;
+    while let Some(chunk) = response
+        .chunk()
         .await
-        .map_err(|e| format!("Failed to read file content: {}", e))?;
+        .map_err(|e| format!("Failed to read speed probe: {}", e))?
+    {
+        probe_bytes += chunk.len() as u64;
+        if probe_bytes >= SPEED_PROBE_MAX_BYTES {
+            break;
+        }
+    }
+    let elapsed = started.elapsed();
 
-    // Ensure parent directory exists
-    if let Some(parent) = dest_path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| format!("Failed to create directory: {}", e))?;
+    if probe_bytes == 0 {
+        return Err(format!("Speed probe {} was empty", probe_path));
     }
 
-    fs::write(dest_path, &bytes).map_err(|e| format!("Failed to write file: {}", e))?;
+    let bytes_per_second = ((probe_bytes as f64 / elapsed.as_secs_f64().max(0.001)) as u64).max(1);
+    // The sync itself won't go faster than the configured rate cap
+    let sync_bytes_per_second = match load_download_rate_limit(&app) {
+        Some(limit_kb) => bytes_per_second.min(limit_kb * 1024),
+        None => bytes_per_second,
+    };
 
-    Ok(())
+    Ok(SpeedTestResult {
+        probe_path,
+        probe_bytes,
+        duration_ms: elapsed.as_millis() as u64,
+        bytes_per_second,
+        pending_bytes,
+        estimated_seconds: pending_bytes.div_ceil(sync_bytes_per_second.max(1)),
+    })
 }
 
 /// Check if a local file exists (either normal or disabled version)
 /// Returns (exists, is_disabled, actual_path)
-fn find_local_file(slus_path: &Path, relative_path: &str) -> (bool, bool, PathBuf) {
+fn find_local_file(slus_path: &Path, relative_path: &str, convention: DisableConvention) -> (bool, bool, PathBuf) {
     let normal_path = slus_path.join(relative_path);
     if normal_path.exists() {
         return (true, false, normal_path);
     }
 
-    let disabled_path = slus_path.join(get_disabled_path(relative_path));
+    let disabled_path = slus_path.join(convention.disabled_path(relative_path));
     if disabled_path.exists() {
         return (true, true, disabled_path);
     }
@@ -721,30 +1686,82 @@ fn find_local_file(slus_path: &Path, relative_path: &str) -> (bool, bool, PathBu
     (false, false, normal_path)
 }
 
+/// How an incremental sync applies one entry from the compare API
+#[derive(Debug, PartialEq, Eq)]
+enum IncrementalAction {
+    /// Download the new content
+    Download,
+    /// Delete the local file (normal or disabled)
+    Delete,
+    /// Move the local file from its previous path (relative to the SLUS folder)
+    Rename { from: String },
+    /// Duplicate the local file at the source path (relative to the SLUS folder)
+    Copy { from: String },
+    /// Nothing to apply
+    Skip,
+}
+
+/// Decide how to apply a compare API entry
+/// Renames and copies from outside the sparse path (or from an unsafe or protected path) have no
+/// local source the sync may use, so they are downloaded and the source is left alone
+fn plan_incremental_action(file: &CompareFile, prefix: &str, protected_paths: &[String]) -> IncrementalAction {
+    let previous_rel_path = file
+        .previous_filename
+        .as_deref()
+        .and_then(|p| p.strip_prefix(prefix))
+        .filter(|p| check_repo_path(p).is_ok() && !should_skip_path(p, protected_paths))
+        .map(|p| p.to_string());
+
+    match file.status.as_str() {
+        // "changed" is a mode or type change - the content may differ too
+        "added" | "modified" | "changed" => IncrementalAction::Download,
+        "removed" => IncrementalAction::Delete,
+        "renamed" => match previous_rel_path {
+            Some(from) => IncrementalAction::Rename { from },
+            None => IncrementalAction::Download,
+        },
+        "copied" => match previous_rel_path {
+            Some(from) => IncrementalAction::Copy { from },
+            None => IncrementalAction::Download,
+        },
+        _ => IncrementalAction::Skip,
+    }
+}
+
 /// Run incremental sync (only changes since last sync)
 async fn run_incremental_sync(
+    config: &RepoConfig,
+    source: &impl PackSource,
     textures_dir: &str,
     last_commit: &str,
-    token: &Option<String>,
     window: &Window,
 ) -> Result<SyncResult, String> {
     let textures_path = PathBuf::from(textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
-    let client = Client::new();
+    let slus_path = textures_path.join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let delete_permanently = load_delete_permanently(window.app_handle());
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    let cache = BlobCache::open(window.app_handle())?.with_local_copies(&slus_path);
+    configure_download_throttle(window.app_handle());
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "fetching".to_string(),
+        stage: Stage::Fetching,
+        key: "sync.fetching_changes",
+        params: MessageParams::new(),
         message: "Fetching changes since last sync...".to_string(),
         current: None,
         total: None,
     });
 
-    // Get latest commit
-    let latest_sha = get_latest_commit_with_token(token).await?;
+    // Get latest version
+    let latest_sha = with_heartbeat(window, Stage::Fetching, source.latest_ref()).await?;
 
     if latest_sha == last_commit {
         let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "complete".to_string(),
+            stage: Stage::Complete,
+            key: "sync.up_to_date",
+            params: MessageParams::new(),
             message: "Already up to date!".to_string(),
             current: None,
             total: None,
@@ -754,70 +1771,134 @@ async fn run_incremental_sync(
             files_deleted: 0,
             files_renamed: 0,
             files_skipped: 0,
+            retry_plan_token: String::new(),
             new_commit_sha: latest_sha,
+            failed_files: Vec::new(),
+            actions: Vec::new(),
+            actions_report_path: None,
+            cleanup: None,
+            verification: None,
         });
     }
 
-    // Get changed files
-    let (changed_files, is_truncated) = fetch_changed_files(last_commit, &latest_sha, token).await?;
-
-    // If the response is truncated (300+ files), fall back to full sync
-    if is_truncated {
-        return Err("TRUNCATED: Too many changed files, falling back to full sync".to_string());
-    }
+    // Get changed files (already relative to the SLUS folder)
+    let changed_files = with_heartbeat(
+        window,
+        Stage::Fetching,
+        source.get_changes(last_commit, &latest_sha, &|key, params, message| {
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Fetching,
+                key,
+                params,
+                message,
+                current: None,
+                total: None,
+            });
+        }),
+    )
+    .await?;
 
-    // Filter to only files in our sparse path
-    let prefix = format!("{}/", SPARSE_PATH);
     let relevant_files: Vec<CompareFile> = changed_files
         .into_iter()
-        .filter(|f| f.filename.starts_with(&prefix) && !should_skip_path(&f.filename))
+        .filter(|f| match check_repo_path(&f.filename) {
+            Ok(()) => true,
+            Err(reason) => {
+                emit_log(window, LogLevel::Warn, "tree", Some(&f.filename), format!("Skipped unsafe path ({})", reason));
+                false
+            }
+        })
+        .filter(|f| !should_skip_path(&f.filename, &protected_paths))
         .collect();
 
     let total = relevant_files.len() as u32;
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "comparing".to_string(),
+        stage: Stage::Comparing,
+        key: "sync.changes_found_count",
+        params: MessageParams::from([("count", total.to_string())]),
         message: format!("Found {} changed files", total),
         current: None,
         total: None,
     });
 
+    rank_mirrors(source, window).await;
+
     let mut downloaded: u32 = 0;
     let mut deleted: u32 = 0;
     let mut renamed: u32 = 0;
     let mut skipped: u32 = 0;
+    let mut actions = ActionLog::new(window);
 
     for (i, file) in relevant_files.iter().enumerate() {
-        let relative_path = file.filename.strip_prefix(&prefix).unwrap().to_string();
+        let relative_path = file.filename.clone();
 
         let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "syncing".to_string(),
+            stage: Stage::Syncing,
+            key: "sync.processing_file",
+            params: MessageParams::from([
+                ("status", file.status.to_string()),
+                ("path", relative_path.to_string()),
+            ]),
             message: format!("[{}] {}", file.status, relative_path),
             current: Some(i as u32 + 1),
             total: Some(total),
         });
 
-        match file.status.as_str() {
-            "added" | "modified" => {
+        match plan_incremental_action(file, "", &protected_paths) {
+            IncrementalAction::Download => {
                 // Check if we have a disabled version locally
-                let (exists, is_disabled, local_path) = find_local_file(&slus_path, &relative_path);
-
+                let (exists, is_disabled, local_path) = find_local_file(&slus_path, &relative_path, convention);
                 if exists && is_disabled {
-                    // Download to the disabled path (preserve disabled state)
-                    let disabled_rel_path = get_disabled_path(&relative_path);
-                    let dest = slus_path.join(&disabled_rel_path);
-                    download_file(&client, &relative_path, &dest, token).await?;
+                    disabled_registry.insert(relative_path.clone(), file.sha.clone());
+                }
+
+                // Re-added files the user had disabled before stay disabled (download to the disabled path)
+                let to_disabled = is_disabled || (!exists && disabled_registry.contains(&relative_path));
+                let dest = if to_disabled {
+                    slus_path.join(convention.disabled_path(&relative_path))
                 } else {
-                    // Download to normal path
-                    download_file(&client, &relative_path, &local_path, token).await?;
+                    local_path
+                };
+                match download_file(source, &cache, &relative_path, file.sha.as_deref(), &dest).await {
+                    Ok(()) => {
+                        emit_log(window, LogLevel::Info, "download", Some(&relative_path), format!("Downloaded ({})", file.status));
+                        actions.ok(&relative_path, "download");
+                        downloaded += 1;
+                    }
+                    Err(e) => {
+                        emit_log(window, LogLevel::Warn, "download", Some(&relative_path), e.clone());
+                        actions.fail(SyncFailure {
+                            path: relative_path.clone(),
+                            action: "download".to_string(),
+                            to_disabled,
+                            sha: file.sha.clone(),
+                            from: None,
+                            error: e,
+                        });
+                    }
                 }
-                downloaded += 1;
             }
-            "removed" => {
+            IncrementalAction::Delete => {
                 // Delete the file (check both normal and disabled versions)
-                let (exists, _, local_path) = find_local_file(&slus_path, &relative_path);
+                let (exists, _, local_path) = find_local_file(&slus_path, &relative_path, convention);
                 if exists {
-                    fs::remove_file(&local_path)
-                        .map_err(|e| format!("Failed to delete {}: {}", relative_path, e))?;
+                    if let Err(e) = delete_path(&local_path, delete_permanently) {
+                        let error = format!("Failed to delete {}: {}", relative_path, e);
+                        emit_log(window, LogLevel::Warn, "delete", Some(&relative_path), error.clone());
+                        actions.fail(SyncFailure {
+                            path: local_path
+                                .strip_prefix(&slus_path)
+                                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                                .unwrap_or_else(|_| relative_path.clone()),
+                            action: "delete".to_string(),
+                            to_disabled: false,
+                            sha: None,
+                            from: None,
+                            error,
+                        });
+                        continue;
+                    }
+                    emit_log(window, LogLevel::Info, "delete", Some(&relative_path), "Deleted (removed from repository)");
+                    actions.ok(&relative_path, "delete");
                     deleted += 1;
 
                     // Try to remove empty parent directories
@@ -826,52 +1907,155 @@ async fn run_incremental_sync(
                     }
                 }
             }
-            "renamed" => {
-                if let Some(old_filename) = &file.previous_filename {
-                    if old_filename.starts_with(&prefix) {
-                        let old_rel_path = old_filename.strip_prefix(&prefix).unwrap();
-                        let (exists, is_disabled, old_local_path) = find_local_file(&slus_path, old_rel_path);
-
-                        if exists {
-                            // Determine new path (preserve disabled state)
-                            let new_local_path = if is_disabled {
-                                slus_path.join(get_disabled_path(&relative_path))
-                            } else {
-                                slus_path.join(&relative_path)
-                            };
-
-                            // Ensure parent directory exists
-                            if let Some(parent) = new_local_path.parent() {
-                                fs::create_dir_all(parent)
-                                    .map_err(|e| format!("Failed to create directory: {}", e))?;
-                            }
-
-                            // Move the file
-                            fs::rename(&old_local_path, &new_local_path)
-                                .map_err(|e| format!("Failed to rename {}: {}", old_rel_path, e))?;
-                            renamed += 1;
-
-                            // Try to remove empty old parent directories
-                            if let Some(parent) = old_local_path.parent() {
-                                let _ = fs::remove_dir(parent);
-                            }
-                        } else {
-                            // Old file doesn't exist locally, download the new one
-                            let dest = slus_path.join(&relative_path);
-                            download_file(&client, &relative_path, &dest, token).await?;
+            IncrementalAction::Rename { from: old_rel_path } => {
+                let (exists, is_disabled, old_local_path) = find_local_file(&slus_path, &old_rel_path, convention);
+                disabled_registry.rename(&old_rel_path, &relative_path);
+                if exists && is_disabled {
+                    disabled_registry.insert(relative_path.clone(), file.sha.clone());
+                }
+
+                if exists {
+                    // Determine new path (preserve disabled state)
+                    let new_local_path = if is_disabled {
+                        slus_path.join(convention.disabled_path(&relative_path))
+                    } else {
+                        slus_path.join(&relative_path)
+                    };
+
+                    // Ensure parent directory exists, then move the file
+                    let moved = new_local_path
+                        .parent()
+                        .map_or(Ok(()), fs::create_dir_all)
+                        .and_then(|_| fs::rename(&old_local_path, &new_local_path));
+                    if let Err(e) = moved {
+                        let error = format!("Failed to rename {}: {}", old_rel_path, e);
+                        emit_log(window, LogLevel::Warn, "rename", Some(&relative_path), error.clone());
+                        actions.fail(SyncFailure {
+                            path: relative_path.clone(),
+                            action: "rename".to_string(),
+                            to_disabled: is_disabled,
+                            sha: file.sha.clone(),
+                            from: Some(old_rel_path.clone()),
+                            error,
+                        });
+                        continue;
+                    }
+                    emit_log(window, LogLevel::Info, "rename", Some(&relative_path), format!("Renamed from {}", old_rel_path));
+                    actions.ok(&relative_path, "rename");
+                    renamed += 1;
+
+                    // Try to remove empty old parent directories
+                    if let Some(parent) = old_local_path.parent() {
+                        let _ = fs::remove_dir(parent);
+                    }
+                } else {
+                    // Old file doesn't exist locally, download the new one
+                    let to_disabled = disabled_registry.contains(&relative_path);
+                    let dest = if to_disabled {
+                        slus_path.join(convention.disabled_path(&relative_path))
+                    } else {
+                        slus_path.join(&relative_path)
+                    };
+                    match download_file(source, &cache, &relative_path, file.sha.as_deref(), &dest).await {
+                        Ok(()) => {
+                            emit_log(window, LogLevel::Info, "download", Some(&relative_path), format!("Downloaded (renamed from {}, not found locally)", old_rel_path));
+                            actions.ok(&relative_path, "download");
+                            downloaded += 1;
+                        }
+                        Err(e) => {
+                            emit_log(window, LogLevel::Warn, "download", Some(&relative_path), e.clone());
+                            actions.fail(SyncFailure {
+                                path: relative_path.clone(),
+                                action: "download".to_string(),
+                                to_disabled,
+                                sha: file.sha.clone(),
+                                from: None,
+                                error: e,
+                            });
+                        }
+                    }
+                }
+            }
+            IncrementalAction::Copy { from: source_rel_path } => {
+                // Copies may be "similar" rather than identical - only reuse the source if it matches the new SHA
+                let (source_exists, _, source_path) = find_local_file(&slus_path, &source_rel_path, convention);
+                let source_matches = source_exists
+                    && file.sha.is_some()
+                    && compute_git_blob_sha_with_normalization(&source_path, file.sha.as_deref()).ok() == file.sha;
+
+                // The copy keeps its own disabled state, not the source's
+                let (exists, is_disabled, local_path) = find_local_file(&slus_path, &relative_path, convention);
+                let to_disabled = is_disabled || (!exists && disabled_registry.contains(&relative_path));
+                let dest = if to_disabled {
+                    slus_path.join(convention.disabled_path(&relative_path))
+                } else {
+                    local_path
+                };
+
+                let copied = if source_matches {
+                    fs::read(&source_path)
+                        .map_err(|e| format!("Failed to read {}: {}", source_rel_path, e))
+                        .and_then(|content| write_file_replacing(&dest, &content))
+                        .is_ok()
+                } else {
+                    false
+                };
+
+                if copied {
+                    emit_log(window, LogLevel::Info, "copy", Some(&relative_path), format!("Copied from {}", source_rel_path));
+                    actions.ok(&relative_path, "copy");
+                    downloaded += 1;
+                } else {
+                    match download_file(source, &cache, &relative_path, file.sha.as_deref(), &dest).await {
+                        Ok(()) => {
+                            emit_log(window, LogLevel::Info, "download", Some(&relative_path), format!("Downloaded (copied from {}, no matching local source)", source_rel_path));
+                            actions.ok(&relative_path, "download");
                             downloaded += 1;
                         }
+                        Err(e) => {
+                            emit_log(window, LogLevel::Warn, "download", Some(&relative_path), e.clone());
+                            actions.fail(SyncFailure {
+                                path: relative_path.clone(),
+                                action: "download".to_string(),
+                                to_disabled,
+                                sha: file.sha.clone(),
+                                from: None,
+                                error: e,
+                            });
+                        }
                     }
                 }
             }
-            _ => {
+            IncrementalAction::Skip => {
+                let reason = format!("Skipped unsupported change status '{}'", file.status);
+                emit_log(window, LogLevel::Warn, "skip", Some(&relative_path), reason.clone());
+                actions.record(&relative_path, "skip", "skipped", Some(reason));
                 skipped += 1;
             }
         }
     }
 
+    emit_mirror_usage(source, window);
+
+    let (retried_downloads, retried_deletes) =
+        retry_failures(source, &cache, &slus_path, window, &mut actions).await;
+    downloaded += retried_downloads;
+    deleted += retried_deletes;
+    let (failures, inline_actions, actions_report_path) = actions.finish(window.app_handle());
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
+
+    // Keep the download cache within its size limit
+    cache.enforce_limit();
+
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "complete".to_string(),
+        stage: Stage::Complete,
+        key: "sync.complete_incremental",
+        params: MessageParams::from([
+            ("downloaded", downloaded.to_string()),
+            ("deleted", deleted.to_string()),
+            ("renamed", renamed.to_string()),
+            ("skipped", skipped.to_string()),
+        ]),
         message: format!(
             "Sync complete! Downloaded: {}, Deleted: {}, Renamed: {}, Skipped: {}",
             downloaded, deleted, renamed, skipped
@@ -885,57 +2069,259 @@ async fn run_incremental_sync(
         files_deleted: deleted,
         files_renamed: renamed,
         files_skipped: skipped,
+        retry_plan_token: issue_retry_plan(&latest_sha, &failures),
         new_commit_sha: latest_sha,
+        failed_files: failures,
+        actions: inline_actions,
+        actions_report_path,
+        cleanup: None,
+        verification: None,
     })
 }
 
-/// Run full sync (compare all files)
-async fn run_full_sync(
-    textures_dir: &str,
-    token: &Option<String>,
-    window: &Window,
-) -> Result<SyncResult, String> {
-    let textures_path = PathBuf::from(textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
-
-    let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "fetching".to_string(),
-        message: "Fetching repository tree (this may take a while)...".to_string(),
-        current: None,
-        total: None,
-    });
+/// Number of passes over the failed files at the end of a sync
+const RETRY_PASSES: u32 = 2;
 
-    // Fetch GitHub tree
-    let (remote_files, commit_sha) = fetch_github_tree(token).await?;
-    // Count excluding user-customs and hidden files for accurate comparison
-    let remote_count = remote_files.keys().filter(|p| !should_skip_path(p)).count();
+/// Wait before the first retry pass, doubled for each further pass
+const RETRY_PASS_BASE_DELAY: Duration = Duration::from_secs(2);
 
-    let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
-        message: format!("Found {} files in repository", remote_count),
-        current: None,
+/// Retry one failed download, delete or rename
+async fn retry_failure(
+    source: &impl PackSource,
+    cache: &BlobCache,
+    slus_path: &Path,
+    failure: &SyncFailure,
+    convention: DisableConvention,
+    delete_permanently: bool,
+) -> Result<(), String> {
+    match (failure.action.as_str(), &failure.from) {
+        ("delete", _) => match delete_path(&slus_path.join(&failure.path), delete_permanently) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(format!("Failed to delete {}: {}", failure.path, e)),
+            _ => Ok(()),
+        },
+        ("rename", Some(from)) => {
+            let (exists, is_disabled, old_path) = find_local_file(slus_path, from, convention);
+            let new_path = if is_disabled {
+                slus_path.join(convention.disabled_path(&failure.path))
+            } else {
+                slus_path.join(&failure.path)
+            };
+            if !exists {
+                // The old file is gone after all, so get the new one
+                return download_file(source, cache, &failure.path, failure.sha.as_deref(), &new_path).await;
+            }
+            if let Some(parent) = new_path.parent() {
+                fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+            fs::rename(&old_path, &new_path).map_err(|e| format!("Failed to rename {}: {}", from, e))
+        }
+        _ => {
+            let dest_path = if failure.to_disabled {
+                slus_path.join(convention.disabled_path(&failure.path))
+            } else {
+                slus_path.join(&failure.path)
+            };
+            download_file(source, cache, &failure.path, failure.sha.as_deref(), &dest_path).await
+        }
+    }
+}
+
+/// Retry failed downloads, deletes and renames a couple of times, with a pause before each pass
+/// (transient network errors and briefly locked files usually clear up)
+/// Files that fail every pass stay in the action log; returns how many downloads and deletes succeeded
+async fn retry_failures(
+    source: &impl PackSource,
+    cache: &BlobCache,
+    slus_path: &Path,
+    window: &Window,
+    actions: &mut ActionLog,
+) -> (u32, u32) {
+    if actions.failed.is_empty() {
+        return (0, 0);
+    }
+    let convention = load_disable_convention(window.app_handle());
+    let delete_permanently = load_delete_permanently(window.app_handle());
+
+    let mut downloaded: u32 = 0;
+    let mut deleted: u32 = 0;
+
+    for pass in 1..=RETRY_PASSES {
+        let failures = std::mem::take(&mut actions.failed);
+        if failures.is_empty() {
+            break;
+        }
+
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Syncing,
+            key: "sync.retrying_failed",
+            params: MessageParams::from([("count", failures.len().to_string())]),
+            message: format!("Retrying {} failed files...", failures.len()),
+            current: None,
+            total: None,
+        });
+        tokio::time::sleep(RETRY_PASS_BASE_DELAY * 2u32.pow(pass - 1)).await;
+
+        for mut failure in failures {
+            match retry_failure(source, cache, slus_path, &failure, convention, delete_permanently).await {
+                Ok(()) => {
+                    emit_log(window, LogLevel::Info, &failure.action, Some(&failure.path), "Succeeded on retry");
+                    actions.ok(&failure.path, &failure.action);
+                    match failure.action.as_str() {
+                        "delete" => deleted += 1,
+                        "rename" => {}
+                        _ => downloaded += 1,
+                    }
+                }
+                Err(e) => {
+                    let level = if pass == RETRY_PASSES { LogLevel::Error } else { LogLevel::Warn };
+                    emit_log(window, level, &failure.action, Some(&failure.path), format!("Failed again: {}", e));
+                    failure.error = e;
+                    actions.fail(failure);
+                }
+            }
+        }
+    }
+
+    emit_mirror_usage(source, window);
+
+    (downloaded, deleted)
+}
+
+/// Pair files to download with files about to be deleted that already have the same content
+/// (blob SHA), so a file the repository moved becomes a local rename instead of a delete plus a
+/// download of identical bytes. Each deleted file is used at most once
+/// Returns (download index, delete index) pairs
+fn pair_moved_files(download_shas: &[Option<&str>], delete_shas: &[Option<&str>]) -> Vec<(usize, usize)> {
+    let mut deletes_by_sha: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, sha) in delete_shas.iter().enumerate().rev() {
+        if let Some(sha) = sha {
+            deletes_by_sha.entry(sha).or_default().push(i);
+        }
+    }
+
+    download_shas
+        .iter()
+        .enumerate()
+        .filter_map(|(i, sha)| deletes_by_sha.get_mut((*sha)?)?.pop().map(|j| (i, j)))
+        .collect()
+}
+
+/// Rename deleted files to the paths the repository moved them to (see pair_moved_files)
+/// `downloads` are (path, to_disabled, expected SHA), `deletes` are (local path, local SHA)
+/// Returns the download paths and deleted paths that were handled; a failed rename is left to
+/// the normal download and delete
+fn move_local_files(
+    slus_path: &Path,
+    convention: DisableConvention,
+    downloads: &[(&str, bool, Option<&str>)],
+    deletes: &[(&str, Option<&str>)],
+    window: &Window,
+    actions: &mut ActionLog,
+) -> (HashSet<String>, HashSet<String>) {
+    let download_shas: Vec<Option<&str>> = downloads.iter().map(|(_, _, sha)| *sha).collect();
+    let delete_shas: Vec<Option<&str>> = deletes.iter().map(|(_, sha)| *sha).collect();
+    let mut moved_to = HashSet::new();
+    let mut moved_from = HashSet::new();
+
+    for (i, j) in pair_moved_files(&download_shas, &delete_shas) {
+        let (to, to_disabled, _) = downloads[i];
+        let (from, _) = deletes[j];
+        let from_path = slus_path.join(from);
+        let to_path = if to_disabled {
+            slus_path.join(convention.disabled_path(to))
+        } else {
+            slus_path.join(to)
+        };
+
+        let result = to_path
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::rename(&from_path, &to_path));
+
+        match result {
+            Ok(()) => {
+                emit_log(window, LogLevel::Info, "rename", Some(to), format!("Moved from {} (same content)", from));
+                actions.ok(to, "rename");
+                moved_to.insert(to.to_string());
+                moved_from.insert(from.to_string());
+
+                if let Some(parent) = from_path.parent() {
+                    let _ = fs::remove_dir(parent);
+                }
+            }
+            Err(e) => {
+                emit_log(window, LogLevel::Warn, "rename", Some(to), format!("Couldn't move {} locally, downloading instead: {}", from, e));
+            }
+        }
+    }
+
+    (moved_to, moved_from)
+}
+
+/// Run full sync (compare all files)
+async fn run_full_sync(
+    config: &RepoConfig,
+    source: &impl PackSource,
+    textures_dir: &str,
+    window: &Window,
+) -> Result<SyncResult, String> {
+    let textures_path = PathBuf::from(textures_dir);
+    let slus_path = textures_path.join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let delete_permanently = load_delete_permanently(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Fetching,
+        key: "sync.fetching_tree",
+        params: MessageParams::new(),
+        message: "Fetching repository tree (this may take a while)...".to_string(),
+        current: None,
+        total: None,
+    });
+
+    // Fetch the pack's file list
+    let (remote_files, remote_sizes, commit_sha) = fetch_latest_tree(source, window).await?;
+    // Count excluding user-customs and hidden files for accurate comparison
+    let remote_count = remote_files.keys().filter(|p| !should_skip_path(p, &protected_paths)).count();
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Scanning,
+        key: "sync.remote_files_found",
+        params: MessageParams::from([("count", remote_count.to_string())]),
+        message: format!("Found {} files in repository", remote_count),
+        current: None,
         total: None,
     });
 
     // Build local file map
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
+        stage: Stage::Scanning,
+        key: "sync.scanning_local",
+        params: MessageParams::new(),
         message: "Scanning local files (this may take a few minutes)...".to_string(),
         current: None,
         total: None,
     });
 
-    let local_files = build_local_file_map(&textures_path)?;
+    let local_files = build_local_file_map(&slus_path, convention)?;
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    disabled_registry.observe(&local_files, &remote_files, convention);
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
+        stage: Stage::Scanning,
+        key: "sync.local_files_found",
+        params: MessageParams::from([("count", local_files.len().to_string())]),
         message: format!("Found {} local files (excluding user-customs)...", local_files.len()),
         current: None,
         total: None,
     });
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "comparing".to_string(),
+        stage: Stage::Comparing,
+        key: "sync.comparing_hashes",
+        params: MessageParams::new(),
         message: "Comparing file hashes...".to_string(),
         current: None,
         total: None,
@@ -952,13 +2338,19 @@ async fn run_full_sync(
         if compared % 1000 == 0 {
             let percent = (compared * 100) / total_to_compare;
             let _ = window.emit("sync-progress", SyncProgressPayload {
-                stage: "comparing".to_string(),
+                stage: Stage::Comparing,
+                key: "sync.comparing_hashes_progress",
+                params: MessageParams::from([
+                    ("current", compared.to_string()),
+                    ("total", total_to_compare.to_string()),
+                    ("percent", percent.to_string()),
+                ]),
                 message: format!("Comparing file hashes ({}/{}) {}%...", compared, total_to_compare, percent),
                 current: Some(compared as u32),
                 total: Some(total_to_compare as u32),
             });
         }
-        if should_skip_path(path) {
+        if should_skip_path(path, &protected_paths) {
             continue;
         }
 
@@ -976,7 +2368,7 @@ async fn run_full_sync(
         }
 
         // Check disabled version
-        let disabled_path = get_disabled_path(path);
+        let disabled_path = convention.disabled_path(path);
         if local_files.contains_key(&disabled_path) {
             // Disabled file exists - check SHA with normalization support
             let local_path = slus_path.join(&disabled_path);
@@ -989,15 +2381,15 @@ async fn run_full_sync(
             continue;
         }
 
-        // File doesn't exist locally
-        files_to_download.push((path.clone(), false));
+        // File doesn't exist locally (restore the user's choice if they had disabled it)
+        files_to_download.push((path.clone(), disabled_registry.contains(path)));
     }
 
     // Determine files to delete (in local but not in remote)
     let mut files_to_delete: Vec<String> = Vec::new();
 
     for local_path in local_files.keys() {
-        if should_skip_path(local_path) {
+        if should_skip_path(local_path, &protected_paths) {
             continue;
         }
 
@@ -1007,19 +2399,17 @@ async fn run_full_sync(
             continue;
         }
 
-        // If this looks like a disabled file (dash prefix), check if enabled version exists
-        if is_disabled_filename(get_filename(local_path)) {
-            if let Some(enabled_path) = get_enabled_path(local_path) {
-                // If enabled version exists LOCALLY, delete the disabled version
-                // (having both doesn't make sense - enabled takes precedence)
-                if local_files.contains_key(&enabled_path) {
-                    files_to_delete.push(local_path.clone());
-                    continue;
-                }
-                // If enabled version exists in remote (but not locally), keep disabled version
-                if remote_files.contains_key(&enabled_path) {
-                    continue; // This is a user-disabled version of a repo file
-                }
+        // If this looks like a disabled file, check if enabled version exists
+        if let Some(enabled_path) = convention.enabled_path(local_path) {
+            // If enabled version exists LOCALLY, delete the disabled version
+            // (having both doesn't make sense - enabled takes precedence)
+            if local_files.contains_key(&enabled_path) {
+                files_to_delete.push(local_path.clone());
+                continue;
+            }
+            // If enabled version exists in remote (but not locally), keep disabled version
+            if remote_files.contains_key(&enabled_path) {
+                continue; // This is a user-disabled version of a repo file
             }
         }
 
@@ -1027,44 +2417,119 @@ async fn run_full_sync(
         files_to_delete.push(local_path.clone());
     }
 
+    // Files the repository moved are already here under their old path
+    let mut actions = ActionLog::new(window);
+    let (moved_to, moved_from) = {
+        let downloads: Vec<(&str, bool, Option<&str>)> = files_to_download
+            .iter()
+            .map(|(path, is_disabled)| (path.as_str(), *is_disabled, remote_files.get(path).map(String::as_str)))
+            .collect();
+        let deletes: Vec<(&str, Option<&str>)> = files_to_delete
+            .iter()
+            .map(|path| (path.as_str(), local_files.get(path).map(String::as_str)))
+            .collect();
+        move_local_files(&slus_path, convention, &downloads, &deletes, window, &mut actions)
+    };
+    files_to_download.retain(|(path, _)| !moved_to.contains(path));
+    files_to_delete.retain(|path| !moved_from.contains(path));
+    let renamed = moved_to.len() as u32;
+
     let download_count = files_to_download.len() as u32;
     let delete_count = files_to_delete.len() as u32;
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "comparing".to_string(),
+        stage: Stage::Comparing,
+        key: "sync.changes_found",
+        params: MessageParams::from([
+            ("downloads", download_count.to_string()),
+            ("deletes", delete_count.to_string()),
+        ]),
         message: format!("Changes: {} to download, {} to delete", download_count, delete_count),
         current: None,
         total: None,
     });
 
     // Download files
-    let client = Client::new();
+    let cache = BlobCache::open(window.app_handle())?.with_local_copies(&slus_path);
+    configure_download_throttle(window.app_handle());
     let mut downloaded: u32 = 0;
+    let mut extracted = HashSet::new();
+    if !files_to_download.is_empty() {
+        rank_mirrors(source, window).await;
+
+        let targets = files_to_download
+            .iter()
+            .map(|(path, is_disabled)| {
+                let dest_path = if *is_disabled {
+                    slus_path.join(convention.disabled_path(path))
+                } else {
+                    slus_path.join(path)
+                };
+                (path.clone(), ArchiveTarget { dest_path, sha: remote_files.get(path).cloned() })
+            })
+            .collect();
+        extracted = download_via_archive(source, &commit_sha, targets, Some(&remote_sizes), window).await;
+    }
 
     for (i, (path, is_disabled)) in files_to_download.iter().enumerate() {
+        if i > 0 && i % SYNC_JOURNAL_CHUNK_SIZE == 0 {
+            emit_mirror_usage(source, window);
+        }
+
+        if extracted.contains(path) {
+            emit_log(window, LogLevel::Info, "download", Some(path), "Downloaded (from archive)");
+            actions.ok(path, "download");
+            downloaded += 1;
+            continue;
+        }
+
         let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "downloading".to_string(),
+            stage: Stage::Downloading,
+            key: "sync.downloading_file",
+            params: MessageParams::from([("path", path.to_string())]),
             message: format!("Downloading: {}", path),
             current: Some(i as u32 + 1),
             total: Some(download_count),
         });
 
         let dest_path = if *is_disabled {
-            slus_path.join(get_disabled_path(path))
+            slus_path.join(convention.disabled_path(path))
         } else {
             slus_path.join(path)
         };
 
-        download_file(&client, path, &dest_path, token).await?;
-        downloaded += 1;
+        let sha = remote_files.get(path).cloned();
+        match download_file(source, &cache, path, sha.as_deref(), &dest_path).await {
+            Ok(()) => {
+                emit_log(window, LogLevel::Info, "download", Some(path), "Downloaded");
+                actions.ok(path, "download");
+                downloaded += 1;
+            }
+            Err(e) => {
+                // Keep going - one bad file shouldn't leave the rest of the folder outdated
+                emit_log(window, LogLevel::Error, "download", Some(path), e.clone());
+                actions.fail(SyncFailure {
+                    path: path.clone(),
+                    action: "download".to_string(),
+                    to_disabled: *is_disabled,
+                    sha,
+                    from: None,
+                    error: e,
+                });
+            }
+        }
     }
 
+    emit_mirror_usage(source, window);
+
     // Delete files
     let mut deleted: u32 = 0;
 
     for (i, path) in files_to_delete.iter().enumerate() {
         let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "deleting".to_string(),
+            stage: Stage::Deleting,
+            key: "sync.deleting_file",
+            params: MessageParams::from([("path", path.to_string())]),
             message: format!("Deleting: {}", path),
             current: Some(i as u32 + 1),
             total: Some(delete_count),
@@ -1072,8 +2537,21 @@ async fn run_full_sync(
 
         let file_path = slus_path.join(path);
         if file_path.exists() {
-            fs::remove_file(&file_path)
-                .map_err(|e| format!("Failed to delete {}: {}", path, e))?;
+            if let Err(e) = delete_path(&file_path, delete_permanently) {
+                let error = format!("Failed to delete {}: {}", path, e);
+                emit_log(window, LogLevel::Error, "delete", Some(path), error.clone());
+                actions.fail(SyncFailure {
+                    path: path.clone(),
+                    action: "delete".to_string(),
+                    to_disabled: false,
+                    sha: None,
+                    from: None,
+                    error,
+                });
+                continue;
+            }
+            emit_log(window, LogLevel::Info, "delete", Some(path), "Deleted (not in repository)");
+            actions.ok(path, "delete");
             deleted += 1;
 
             if let Some(parent) = file_path.parent() {
@@ -1082,8 +2560,22 @@ async fn run_full_sync(
         }
     }
 
+    let (retried_downloads, retried_deletes) =
+        retry_failures(source, &cache, &slus_path, window, &mut actions).await;
+    downloaded += retried_downloads;
+    deleted += retried_deletes;
+    let (failures, inline_actions, actions_report_path) = actions.finish(window.app_handle());
+
+    // Keep the download cache within its size limit
+    cache.enforce_limit();
+
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "complete".to_string(),
+        stage: Stage::Complete,
+        key: "sync.complete",
+        params: MessageParams::from([
+            ("downloaded", downloaded.to_string()),
+            ("deleted", deleted.to_string()),
+        ]),
         message: format!("Sync complete! Downloaded: {}, Deleted: {}", downloaded, deleted),
         current: None,
         total: None,
@@ -1092,9 +2584,15 @@ async fn run_full_sync(
     Ok(SyncResult {
         files_downloaded: downloaded,
         files_deleted: deleted,
-        files_renamed: 0,
+        files_renamed: renamed,
         files_skipped: 0,
+        retry_plan_token: issue_retry_plan(&commit_sha, &failures),
         new_commit_sha: commit_sha,
+        failed_files: failures,
+        actions: inline_actions,
+        actions_report_path,
+        cleanup: None,
+        verification: None,
     })
 }
 
@@ -1105,44 +2603,278 @@ pub async fn run_verification_scan(
     github_token: Option<String>,
     window: Window,
 ) -> Result<VerificationResult, String> {
-    let textures_path = PathBuf::from(&textures_dir);
+    verify_scope(textures_dir, None, github_token, window).await
+}
+
+/// Run the verification scan for a single folder of the pack (e.g. "uniforms"), much faster
+/// than a full scan when the problem is known to be in one area
+/// Fix what it finds with apply_verification_fixes, as for a full scan
+#[tauri::command]
+pub async fn verify_folder(
+    textures_dir: String,
+    subpath: String,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<VerificationResult, String> {
+    let folder = subpath.replace('\\', "/");
+    let folder = folder.trim().trim_matches('/');
+    if folder.is_empty() || folder.split('/').any(|part| part == ".." || part == ".") {
+        return Err(format!("Invalid folder: {}", subpath));
+    }
+    verify_scope(textures_dir, Some(format!("{}/", folder)), github_token, window).await
+}
+
+/// Whether a file exists and has no content
+fn is_empty_file(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() == 0)
+}
+
+/// Fast check for files left empty or cut short by failed downloads: compares sizes from the
+/// file index against the repository's instead of hashing everything
+/// Files whose size differs are hashed to rule out line ending conversions
+/// Fix what it finds with apply_verification_fixes, as for a full scan
+#[tauri::command]
+pub async fn scan_truncated_files(
+    textures_dir: String,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<Vec<VerificationFile>, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    start_verification_session();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
+        stage: Stage::Verifying,
+        key: "verify.fetching_file_list",
+        params: MessageParams::new(),
         message: "Fetching repository file list...".to_string(),
         current: None,
         total: None,
     });
 
-    // Fetch full repo tree
-    let (remote_files, _) = fetch_github_tree(&github_token).await?;
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    let (remote_files, remote_sizes, _) = fetch_latest_tree(&source, &window).await?;
+    let protected_paths = load_protected_paths(window.app_handle());
+    let convention = load_disable_convention(window.app_handle());
 
-    // Count remote files excluding user-customs and hidden files
-    let remote_file_count = remote_files.keys().filter(|p| !should_skip_path(p)).count();
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Verifying,
+        key: "verify.checking_sizes",
+        params: MessageParams::new(),
+        message: "Checking file sizes...".to_string(),
+        current: None,
+        total: None,
+    });
+
+    let mut index = FileIndex::open(&slus_path)?;
+    index.refresh(convention)?;
+    let local_sizes = index.size_map()?;
+
+    let mut truncated = Vec::new();
+    for (repo_path, repo_sha) in &remote_files {
+        if should_skip_path(repo_path, &protected_paths) {
+            continue;
+        }
+
+        let disabled_path = convention.disabled_path(repo_path);
+        let (local_path, to_disabled) = if local_sizes.contains_key(repo_path) {
+            (repo_path.as_str(), false)
+        } else if local_sizes.contains_key(&disabled_path) {
+            (disabled_path.as_str(), true)
+        } else {
+            continue; // Missing files are the full verification's business
+        };
+
+        let local_size = local_sizes[local_path];
+        let looks_truncated = match remote_sizes.get(repo_path) {
+            Some(&remote_size) => local_size != remote_size,
+            None => local_size == 0,
+        };
+        if !looks_truncated {
+            continue;
+        }
+
+        let full_path = slus_path.join(local_path);
+        if local_size > 0 && compute_git_blob_sha_with_normalization(&full_path, Some(repo_sha)).ok().as_ref() == Some(repo_sha) {
+            continue;
+        }
+
+        truncated.push(VerificationFile {
+            path: repo_path.clone(),
+            to_disabled,
+            sha: Some(repo_sha.clone()),
+            reason: DownloadReason::Truncated,
+        });
+    }
+    truncated.sort_by(|a, b| a.path.cmp(&b.path));
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
-        message: format!("Scanning local files and computing hashes (this may take a few minutes)..."),
+        stage: Stage::Verifying,
+        key: "verify.truncated_found",
+        params: MessageParams::from([("count", truncated.len().to_string())]),
+        message: format!("Found {} empty or incomplete files", truncated.len()),
         current: None,
         total: None,
     });
+    record_verification(window.app_handle(), VerificationMode::Truncated, None, truncated.len(), 0);
 
-    // Build local file map (with hashes)
-    let local_files = build_local_file_map(&textures_path)?;
+    Ok(truncated)
+}
+
+/// Verification scan of the whole SLUS folder, or of the folder at `folder_prefix` ("uniforms/")
+async fn verify_scope(
+    textures_dir: String,
+    folder_prefix: Option<String>,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<VerificationResult, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let _sleep_guard = prevent_sleep("Verifying NCAA NEXT textures");
+    let textures_path = PathBuf::from(&textures_dir);
+    let slus_path = textures_path.join(&config.slus_folder);
+    start_verification_session();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
-        message: format!("Comparing {} local files against {} repo files (this may take a few minutes)...", local_files.len(), remote_file_count),
+        stage: Stage::Verifying,
+        key: "verify.fetching_file_list",
+        params: MessageParams::new(),
+        message: "Fetching repository file list...".to_string(),
         current: None,
         total: None,
     });
 
+    // Fetch the pack's full file list
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    let (remote_files, _, latest_ref) = fetch_latest_tree(&source, &window).await?;
+    if let Some(prefix) = &folder_prefix {
+        if !remote_files.keys().any(|path| path.starts_with(prefix)) {
+            return Err(format!("There is no folder {} in the texture pack", prefix.trim_end_matches('/')));
+        }
+    }
+
+    let junk_rules = load_junk_rules(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    let convention = load_disable_convention(window.app_handle());
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    let scope = ScanScope {
+        slus_path: &slus_path,
+        folder_prefix: folder_prefix.as_deref(),
+    };
+    let mut verification = scan_scope_for_discrepancies(
+        scope,
+        &remote_files,
+        &junk_rules,
+        &protected_paths,
+        convention,
+        &mut disabled_registry,
+        |key, params, message| {
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Verifying,
+                key,
+                params,
+                message,
+                current: None,
+                total: None,
+            });
+        },
+    )?;
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
+
+    let mode = if folder_prefix.is_some() { VerificationMode::Folder } else { VerificationMode::Full };
+    record_verification(
+        window.app_handle(),
+        mode,
+        folder_prefix.as_deref(),
+        verification.files_to_download.len(),
+        verification.files_to_delete.len(),
+    );
+
+    let downloads: Vec<&str> = verification.files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = verification.files_to_delete.iter().map(String::as_str).collect();
+    verification.plan_token = issue_plan(&latest_ref, &downloads, &deletes);
+    Ok(verification)
+}
+
+/// What a verification scan covers: the whole SLUS folder, or one folder in it
+#[derive(Clone, Copy)]
+pub(crate) struct ScanScope<'a> {
+    pub slus_path: &'a Path,
+    /// Folder relative to the SLUS folder, with a trailing slash ("uniforms/")
+    pub folder_prefix: Option<&'a str>,
+}
+
+impl ScanScope<'_> {
+    fn contains(&self, path: &str) -> bool {
+        self.folder_prefix.is_none_or(|prefix| path.starts_with(prefix))
+    }
+}
+
+/// Compare local files against a remote file map (path -> sha)
+/// Progress messages are passed to `progress` so callers can route them to their own event
+/// The disabled registry is updated from what's on disk; callers save it
+pub(crate) fn scan_for_discrepancies(
+    slus_path: &Path,
+    remote_files: &HashMap<String, String>,
+    junk_rules: &JunkRules,
+    protected_paths: &[String],
+    convention: DisableConvention,
+    disabled_registry: &mut DisabledRegistry,
+    progress: impl Fn(&'static str, MessageParams, String),
+) -> Result<VerificationResult, String> {
+    let scope = ScanScope { slus_path, folder_prefix: None };
+    scan_scope_for_discrepancies(scope, remote_files, junk_rules, protected_paths, convention, disabled_registry, progress)
+}
+
+/// scan_for_discrepancies limited to a scope: files outside it are neither hashed nor reported
+/// Remote paths that are unsafe to write to (see repo_paths) are left out as well
+pub(crate) fn scan_scope_for_discrepancies(
+    scope: ScanScope,
+    remote_files: &HashMap<String, String>,
+    junk_rules: &JunkRules,
+    protected_paths: &[String],
+    convention: DisableConvention,
+    disabled_registry: &mut DisabledRegistry,
+    progress: impl Fn(&'static str, MessageParams, String),
+) -> Result<VerificationResult, String> {
+    let slus_path = scope.slus_path;
+    let remote_files: HashMap<String, String> = remote_files
+        .iter()
+        .filter(|(path, _)| scope.contains(path) && check_repo_path(path).is_ok())
+        .map(|(path, sha)| (path.clone(), sha.clone()))
+        .collect();
+    let remote_files = &remote_files;
+
+    // Count remote files excluding user-customs and hidden files
+    let remote_file_count = remote_files.keys().filter(|p| !should_skip_path(p, protected_paths)).count();
+
+    progress(
+        "verify.scanning_local",
+        MessageParams::new(),
+        "Scanning local files and computing hashes (this may take a few minutes)...".to_string(),
+    );
+
+    // Build local file map (with hashes)
+    let local_files = build_local_file_map_under(slus_path, scope.folder_prefix.unwrap_or(""), convention)?;
+    // Following upstream renames needs the whole pack, a scoped scan only reads the registry
+    if scope.folder_prefix.is_none() {
+        disabled_registry.observe(&local_files, remote_files, convention);
+    }
+
+    progress(
+        "verify.comparing",
+        MessageParams::from([
+            ("local", local_files.len().to_string()),
+            ("remote", remote_file_count.to_string()),
+        ]),
+        format!("Comparing {} local files against {} repo files (this may take a few minutes)...", local_files.len(), remote_file_count),
+    );
+
     // Find files that need to be downloaded (missing or hash mismatch)
     let mut files_to_download: Vec<VerificationFile> = Vec::new();
-    let slus_path = textures_path.join(SLUS_FOLDER);
+    let mut verified: Vec<String> = Vec::new();
 
-    for (repo_path, repo_sha) in &remote_files {
-        if should_skip_path(repo_path) {
+    for (repo_path, repo_sha) in remote_files {
+        if should_skip_path(repo_path, protected_paths) {
             continue;
         }
 
@@ -1152,6 +2884,7 @@ pub async fn run_verification_scan(
             let local_path = slus_path.join(repo_path);
             if let Ok(local_sha) = compute_git_blob_sha_with_normalization(&local_path, Some(repo_sha)) {
                 if &local_sha == repo_sha {
+                    verified.push(repo_path.clone());
                     continue; // File exists and matches (raw or normalized)
                 }
             }
@@ -1159,17 +2892,20 @@ pub async fn run_verification_scan(
             files_to_download.push(VerificationFile {
                 path: repo_path.clone(),
                 to_disabled: false,
+                sha: Some(repo_sha.clone()),
+                reason: if is_empty_file(&local_path) { DownloadReason::Truncated } else { DownloadReason::Corrupted },
             });
             continue;
         }
 
         // Check if disabled version exists and matches
-        let disabled_path = get_disabled_path(repo_path);
+        let disabled_path = convention.disabled_path(repo_path);
         if local_files.contains_key(&disabled_path) {
             // Disabled file exists - check SHA with normalization support
             let local_path = slus_path.join(&disabled_path);
             if let Ok(local_sha) = compute_git_blob_sha_with_normalization(&local_path, Some(repo_sha)) {
                 if &local_sha == repo_sha {
+                    verified.push(disabled_path);
                     continue; // Disabled version exists and matches (raw or normalized)
                 }
             }
@@ -1177,22 +2913,32 @@ pub async fn run_verification_scan(
             files_to_download.push(VerificationFile {
                 path: repo_path.clone(),
                 to_disabled: true,
+                sha: Some(repo_sha.clone()),
+                reason: DownloadReason::DisabledOutdated,
             });
             continue;
         }
 
-        // File doesn't exist locally at all
+        // File doesn't exist locally at all (restore the user's choice if they had disabled it)
         files_to_download.push(VerificationFile {
             path: repo_path.clone(),
-            to_disabled: false,
+            to_disabled: disabled_registry.contains(repo_path),
+            sha: Some(repo_sha.clone()),
+            reason: DownloadReason::Missing,
         });
     }
 
     // Find files that need to be deleted (local but not in repo)
-    let mut files_to_delete: Vec<String> = Vec::new();
+    let mut orphaned_files: Vec<String> = Vec::new();
+    let mut junk_files: Vec<String> = Vec::new();
 
     for local_path in local_files.keys() {
-        if should_skip_path(local_path) {
+        if should_skip_path(local_path, protected_paths) {
+            continue;
+        }
+
+        if junk_rules.matches(get_filename(local_path)) {
+            junk_files.push(local_path.clone());
             continue;
         }
 
@@ -1202,82 +2948,184 @@ pub async fn run_verification_scan(
             continue;
         }
 
-        // If this looks like a disabled file (dash prefix), check if enabled version exists
-        if is_disabled_filename(get_filename(local_path)) {
-            if let Some(enabled_path) = get_enabled_path(local_path) {
-                if remote_files.contains_key(&enabled_path) {
-                    continue; // This is a user-disabled version of a repo file
-                }
+        // If this looks like a disabled file, check if enabled version exists
+        if let Some(enabled_path) = convention.enabled_path(local_path) {
+            if remote_files.contains_key(&enabled_path) {
+                continue; // This is a user-disabled version of a repo file
             }
         }
 
         // File doesn't exist in remote (neither exact path nor enabled version)
-        files_to_delete.push(local_path.clone());
+        orphaned_files.push(local_path.clone());
+    }
+
+    let files_to_delete: Vec<String> = orphaned_files.iter().chain(junk_files.iter()).cloned().collect();
+
+    // Record verification time in the file index (best effort)
+    if let Ok(mut index) = FileIndex::open(slus_path) {
+        let _ = index.mark_verified(verified.iter());
     }
 
     let has_discrepancies = !files_to_download.is_empty() || !files_to_delete.is_empty();
 
-    let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
-        message: if has_discrepancies {
-            format!("Found {} files to download, {} files to delete", files_to_download.len(), files_to_delete.len())
-        } else {
-            "Verification complete - no discrepancies found!".to_string()
-        },
-        current: None,
-        total: None,
-    });
+    if has_discrepancies {
+        progress(
+            "verify.discrepancies_found",
+            MessageParams::from([
+                ("downloads", files_to_download.len().to_string()),
+                ("deletes", files_to_delete.len().to_string()),
+            ]),
+            format!("Found {} files to download, {} files to delete", files_to_download.len(), files_to_delete.len()),
+        );
+    } else {
+        progress(
+            "verify.no_discrepancies",
+            MessageParams::new(),
+            "Verification complete - no discrepancies found!".to_string(),
+        );
+    }
 
     Ok(VerificationResult {
         files_to_download,
         files_to_delete,
+        orphaned_files,
+        junk_files,
         has_discrepancies,
+        // Issued by whoever shows the result to the user
+        plan_token: String::new(),
     })
 }
 
+/// Number of files verification fixes download at once
+const VERIFICATION_FIX_CONCURRENCY: usize = 6;
+
 /// Apply verification fixes after user approval
+/// plan_token is the token of the verification result (or of the sync being retried); it's
+/// required when many files would be deleted, and the fixes are refused if the file lists differ
+/// from that plan or the pack has changed since
 #[tauri::command]
 pub async fn apply_verification_fixes(
     textures_dir: String,
     files_to_download: Vec<VerificationFile>,
     files_to_delete: Vec<String>,
+    plan_token: Option<String>,
     github_token: Option<String>,
     window: Window,
-) -> Result<(u32, u32), String> {
+) -> Result<VerificationFixResult, String> {
+    let downloads: Vec<&str> = files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = files_to_delete.iter().map(String::as_str).collect();
+    if let Some(reference) = check_plan(plan_token.as_deref(), &downloads, &deletes)? {
+        let config = resolve_repo_config(window.app_handle())?;
+        let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+        if source.latest_ref().await? != reference {
+            return Err("The texture pack was updated after this scan. Verify again before applying fixes.".to_string());
+        }
+    }
+
+    apply_fixes(textures_dir, files_to_download, files_to_delete, github_token, window).await
+}
+
+/// Apply verification fixes without a confirmed plan (for fixes the app decides on itself)
+/// Downloads run in parallel and a failed file doesn't stop the others; files that still fail
+/// after the retries are listed in the result with their reason
+pub(crate) async fn apply_fixes(
+    textures_dir: String,
+    files_to_download: Vec<VerificationFile>,
+    files_to_delete: Vec<String>,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<VerificationFixResult, String> {
+    // The lists come back from the frontend
+    check_repo_paths(
+        files_to_download
+            .iter()
+            .map(|file| file.path.as_str())
+            .chain(files_to_delete.iter().map(String::as_str)),
+    )?;
+    files_to_download.iter().filter_map(|file| file.sha.as_deref()).try_for_each(check_blob_sha)?;
+
+    let config = resolve_repo_config(window.app_handle())?;
+    // Our own writes are not drift
+    let _drift_guard = suppress_drift_detection();
+    let _sleep_guard = prevent_sleep("Repairing NCAA NEXT textures");
+    let _byte_progress = report_byte_progress(&window);
     let textures_path = PathBuf::from(&textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
-    let client = Client::new();
+    let slus_path = textures_path.join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let delete_permanently = load_delete_permanently(window.app_handle());
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    let cache = BlobCache::open(window.app_handle())?.with_local_copies(&slus_path);
+    configure_download_throttle(window.app_handle());
 
     let mut downloaded: u32 = 0;
     let mut deleted: u32 = 0;
+    let mut actions = ActionLog::new(&window);
 
-    // Download missing/mismatched files
+    // Download missing/mismatched files, a few at a time; failures are retried at the end
     if !files_to_download.is_empty() {
         let total = files_to_download.len() as u32;
         let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "verifying".to_string(),
+            stage: Stage::Verifying,
+            key: "verify.downloading_files",
+            params: MessageParams::from([("count", total.to_string())]),
             message: format!("Downloading {} files...", total),
             current: None,
             total: None,
         });
 
-        for (i, file) in files_to_download.iter().enumerate() {
+        rank_mirrors(&source, &window).await;
+
+        // Downloads run concurrently on this task (so they can borrow the source and cache);
+        // results are recorded one at a time as they finish. The futures are collected first
+        // (they don't start until polled) so the command's future stays Send
+        let (source_ref, cache_ref, slus_ref) = (&source, &cache, &slus_path);
+        let downloads: Vec<_> = files_to_download
+            .iter()
+            .map(|file| async move {
+                let dest_path = if file.to_disabled {
+                    slus_ref.join(convention.disabled_path(&file.path))
+                } else {
+                    slus_ref.join(&file.path)
+                };
+                let result = download_file(source_ref, cache_ref, &file.path, file.sha.as_deref(), &dest_path).await;
+                (file, result)
+            })
+            .collect();
+        let mut results = stream::iter(downloads).buffer_unordered(VERIFICATION_FIX_CONCURRENCY);
+
+        let mut finished: u32 = 0;
+        while let Some((file, result)) = results.next().await {
+            match result {
+                Ok(()) => {
+                    emit_log(&window, LogLevel::Info, "download", Some(&file.path), "Downloaded (verification fix)");
+                    actions.ok(&file.path, "download");
+                    downloaded += 1;
+                }
+                Err(e) => {
+                    emit_log(&window, LogLevel::Warn, "download", Some(&file.path), e.clone());
+                    actions.fail(SyncFailure {
+                        path: file.path.clone(),
+                        action: "download".to_string(),
+                        to_disabled: file.to_disabled,
+                        sha: file.sha.clone(),
+                        from: None,
+                        error: e,
+                    });
+                }
+            }
+
+            finished += 1;
             let _ = window.emit("sync-progress", SyncProgressPayload {
-                stage: "verifying".to_string(),
+                stage: Stage::Verifying,
+                key: "verify.downloading_file",
+                params: MessageParams::from([("path", file.path.to_string())]),
                 message: format!("Downloading: {}", file.path),
-                current: Some(i as u32 + 1),
+                current: Some(finished),
                 total: Some(total),
             });
-
-            let dest_path = if file.to_disabled {
-                slus_path.join(get_disabled_path(&file.path))
-            } else {
-                slus_path.join(&file.path)
-            };
-
-            download_file(&client, &file.path, &dest_path, &github_token).await?;
-            downloaded += 1;
         }
+
+        emit_mirror_usage(&source, &window);
     }
 
     // Delete orphaned files
@@ -1285,7 +3133,9 @@ pub async fn apply_verification_fixes(
         let total = files_to_delete.len() as u32;
         for (i, path) in files_to_delete.iter().enumerate() {
             let _ = window.emit("sync-progress", SyncProgressPayload {
-                stage: "verifying".to_string(),
+                stage: Stage::Verifying,
+                key: "verify.deleting_file",
+                params: MessageParams::from([("path", path.to_string())]),
                 message: format!("Deleting: {}", path),
                 current: Some(i as u32 + 1),
                 total: Some(total),
@@ -1293,8 +3143,21 @@ pub async fn apply_verification_fixes(
 
             let file_path = slus_path.join(path);
             if file_path.exists() {
-                fs::remove_file(&file_path)
-                    .map_err(|e| format!("Failed to delete {}: {}", path, e))?;
+                if let Err(e) = delete_path(&file_path, delete_permanently) {
+                    let error = format!("Failed to delete {}: {}", path, e);
+                    emit_log(&window, LogLevel::Warn, "delete", Some(path), error.clone());
+                    actions.fail(SyncFailure {
+                        path: path.clone(),
+                        action: "delete".to_string(),
+                        to_disabled: false,
+                        sha: None,
+                        from: None,
+                        error,
+                    });
+                    continue;
+                }
+                emit_log(&window, LogLevel::Info, "delete", Some(path), "Deleted (verification fix)");
+                actions.ok(path, "delete");
                 deleted += 1;
 
                 if let Some(parent) = file_path.parent() {
@@ -1304,30 +3167,54 @@ pub async fn apply_verification_fixes(
         }
     }
 
+    let (retried_downloads, retried_deletes) = retry_failures(&source, &cache, &slus_path, &window, &mut actions).await;
+    downloaded += retried_downloads;
+    deleted += retried_deletes;
+    let (failures, inline_actions, actions_report_path) = actions.finish(window.app_handle());
+    record_fixes_applied(window.app_handle(), failures.len());
+
     // Clean up empty directories
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
+        stage: Stage::Verifying,
+        key: "cleanup.empty_dirs",
+        params: MessageParams::new(),
         message: "Cleaning up empty directories...".to_string(),
         current: None,
         total: None,
     });
 
-    let dirs_removed = cleanup_empty_directories(&slus_path, &window);
+    let dirs_removed = cleanup_empty_directories(&slus_path, false, &window).removed_dirs.len();
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "verifying".to_string(),
+        stage: Stage::Verifying,
+        key: "cleanup.empty_dirs_removed",
+        params: MessageParams::from([("count", dirs_removed.to_string())]),
         message: format!("Removed {} empty directories", dirs_removed),
         current: None,
         total: None,
     });
 
+    // Keep the download cache within its size limit
+    cache.enforce_limit();
+
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "complete".to_string(),
+        stage: Stage::Complete,
+        key: "verify.fixes_applied",
+        params: MessageParams::from([
+            ("downloaded", downloaded.to_string()),
+            ("deleted", deleted.to_string()),
+        ]),
         message: format!("Verification fixes applied! Downloaded: {}, Deleted: {}", downloaded, deleted),
         current: None,
         total: None,
     });
 
-    Ok((downloaded, deleted))
+    Ok(VerificationFixResult {
+        files_downloaded: downloaded,
+        files_deleted: deleted,
+        failed_files: failures,
+        actions: inline_actions,
+        actions_report_path,
+    })
 }
 
 /// Run the sync operation (does NOT run verification - call run_verification_scan separately)
@@ -1339,48 +3226,87 @@ pub async fn run_sync(
     full_sync: bool,
     window: Window,
 ) -> Result<SyncResult, String> {
-    let result = if full_sync || last_sync_commit.is_none() {
-        run_full_sync(&textures_dir, &github_token, &window).await?
-    } else {
-        // Try incremental sync, fall back to full sync if it fails (e.g., commit not found or too many changes)
-        match run_incremental_sync(&textures_dir, last_sync_commit.as_ref().unwrap(), &github_token, &window).await {
-            Ok(r) => r,
-            Err(e) if e.contains("404") || e.contains("Not Found") => {
-                let _ = window.emit("sync-progress", SyncProgressPayload {
-                    stage: "fetching".to_string(),
-                    message: "Previous sync commit not found, running full sync...".to_string(),
-                    current: None,
-                    total: None,
-                });
-                run_full_sync(&textures_dir, &github_token, &window).await?
-            }
-            Err(e) if e.contains("TRUNCATED") => {
-                let _ = window.emit("sync-progress", SyncProgressPayload {
-                    stage: "fetching".to_string(),
-                    message: "Too many changes since last sync (300+), running full sync...".to_string(),
-                    current: None,
-                    total: None,
-                });
-                run_full_sync(&textures_dir, &github_token, &window).await?
+    let config = resolve_repo_config(window.app_handle())?;
+    // Our own writes are not drift
+    let _drift_guard = suppress_drift_detection();
+    let _sleep_guard = prevent_sleep("Syncing NCAA NEXT textures");
+    let _byte_progress = report_byte_progress(&window);
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    start_sync_session();
+
+    // The full sync policy turns an incremental sync into a full one every N syncs or days
+    let mut ran_full = true;
+    let incremental_possible = last_sync_commit.is_some() && !full_sync && source.has_change_history();
+    let forced_full = if incremental_possible { full_sync_due(window.app_handle()) } else { None };
+    if let Some(reason) = &forced_full {
+        let (key, params, message) = reason.describe();
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Fetching,
+            key,
+            params,
+            message,
+            current: None,
+            total: None,
+        });
+    }
+
+    // Sources without change history (archives, folders, LAN peers) always compare every file
+    let mut result = match last_sync_commit.as_deref() {
+        Some(last_commit) if incremental_possible && forced_full.is_none() => {
+            // Try incremental sync, fall back to full sync if it fails (e.g., commit not found or too many changes)
+            match run_incremental_sync(&config, &source, &textures_dir, last_commit, &window).await {
+                Ok(r) => {
+                    ran_full = false;
+                    r
+                }
+                Err(e) if e.contains("404") || e.contains("Not Found") => {
+                    let _ = window.emit("sync-progress", SyncProgressPayload {
+                        stage: Stage::Fetching,
+                        key: "sync.fallback_commit_not_found",
+                        params: MessageParams::new(),
+                        message: "Previous sync commit not found, running full sync...".to_string(),
+                        current: None,
+                        total: None,
+                    });
+                    run_full_sync(&config, &source, &textures_dir, &window).await?
+                }
+                Err(e) if e.contains("TRUNCATED") => {
+                    let _ = window.emit("sync-progress", SyncProgressPayload {
+                        stage: Stage::Fetching,
+                        key: "sync.fallback_too_many_changes",
+                        params: MessageParams::new(),
+                        message: "Too many changes since last sync (300+), running full sync...".to_string(),
+                        current: None,
+                        total: None,
+                    });
+                    run_full_sync(&config, &source, &textures_dir, &window).await?
+                }
+                Err(e) => return Err(e),
             }
-            Err(e) => return Err(e),
         }
+        _ => run_full_sync(&config, &source, &textures_dir, &window).await?,
     };
 
     // Clean up empty directories
     let textures_path = PathBuf::from(&textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
+    let slus_path = textures_path.join(&config.slus_folder);
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "sync_complete".to_string(),
+        stage: Stage::SyncComplete,
+        key: "cleanup.empty_dirs",
+        params: MessageParams::new(),
         message: "Cleaning up empty directories...".to_string(),
         current: None,
         total: None,
     });
 
-    let dirs_removed = cleanup_empty_directories(&slus_path, &window);
+    let cleanup = cleanup_empty_directories(&slus_path, false, &window);
+    let dirs_removed = cleanup.removed_dirs.len();
+    result.cleanup = Some(cleanup);
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "sync_complete".to_string(),
+        stage: Stage::SyncComplete,
+        key: "cleanup.empty_dirs_removed",
+        params: MessageParams::from([("count", dirs_removed.to_string())]),
         message: format!("Removed {} empty directories", dirs_removed),
         current: None,
         total: None,
@@ -1388,7 +3314,13 @@ pub async fn run_sync(
 
     // Sync portion complete - verification will be triggered separately by frontend
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "sync_complete".to_string(),
+        stage: Stage::SyncComplete,
+        key: "sync.complete_verifying",
+        params: MessageParams::from([
+            ("downloaded", result.files_downloaded.to_string()),
+            ("deleted", result.files_deleted.to_string()),
+            ("renamed", result.files_renamed.to_string()),
+        ]),
         message: format!(
             "Sync complete! Downloaded: {}, Deleted: {}, Renamed: {}. Running verification...",
             result.files_downloaded, result.files_deleted, result.files_renamed
@@ -1397,6 +3329,9 @@ pub async fn run_sync(
         total: None,
     });
 
+    record_sync_mode(window.app_handle(), ran_full);
+    result.verification = verify_after_sync(&textures_dir, &github_token, &window).await;
+
     Ok(result)
 }
 
@@ -1406,74 +3341,321 @@ pub async fn check_sync_status(
     _textures_dir: String,
     last_sync_commit: Option<String>,
     github_token: Option<String>,
+    app: AppHandle,
 ) -> Result<SyncStatusResult, String> {
-    // Get latest commit details
-    let (latest_sha, latest_date) = get_commit_details_with_token("main", &github_token).await?;
+    let config = resolve_repo_config(&app)?;
+
+    // Get latest commit details (other sources only have a version, no date)
+    let (latest_sha, latest_date) = match open_pack_source(&app, &config, &github_token)? {
+        ConfiguredSource::Repository(_) => get_commit_details_with_token(&config, &config.branch, &github_token).await?,
+        source => (source.latest_ref().await?, String::new()),
+    };
 
     let has_changes = match &last_sync_commit {
         Some(last) if last == &latest_sha => false,
         _ => true,
     };
 
+    // How far behind the install is, from the commit list (best effort, the status doesn't need it)
+    let (commits_behind, commits_behind_capped, days_behind) = match &last_sync_commit {
+        Some(_) if !has_changes => (Some(0), false, Some(0)),
+        Some(last) if !latest_date.is_empty() => {
+            let client = http_client();
+            let (commits_behind, capped) =
+                match fetch_commits_between(&config, &client, last, &latest_sha, &github_token).await {
+                    Ok(commits) => (Some(commits.len()), false),
+                    Err(e) if e.starts_with("TRUNCATED: More than") => (Some(MAX_CHAINED_COMMITS), true),
+                    Err(_) => (None, false),
+                };
+            let days_behind = get_commit_details_with_token(&config, last, &github_token)
+                .await
+                .ok()
+                .and_then(|(_, last_date)| days_between(&last_date, &latest_date));
+            (commits_behind, capped, days_behind)
+        }
+        _ => (None, false, None),
+    };
+
     Ok(SyncStatusResult {
         latest_commit_sha: latest_sha,
         latest_commit_date: latest_date,
         last_sync_commit,
         has_changes,
+        commits_behind,
+        commits_behind_capped,
+        days_behind,
+        local_changes: detected_local_changes(),
     })
 }
 
+/// Whole days from one RFC 3339 date to a later one (None if either doesn't parse)
+fn days_between(from: &str, to: &str) -> Option<i64> {
+    let from = chrono::DateTime::parse_from_rfc3339(from).ok()?;
+    let to = chrono::DateTime::parse_from_rfc3339(to).ok()?;
+    Some((to - from).num_days().max(0))
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct SyncStatusResult {
     pub latest_commit_sha: String,
     pub latest_commit_date: String,
     pub last_sync_commit: Option<String>,
     pub has_changes: bool,
+    /// Commits since the last synced one (None when unknown, e.g. for sources without history)
+    pub commits_behind: Option<usize>,
+    /// More commits than could be listed, so commits_behind is a lower bound
+    pub commits_behind_capped: bool,
+    /// Days between the last synced commit and the newest one
+    pub days_behind: Option<i64>,
+    /// Changes made to the install by other programs since the last acknowledgement
+    pub local_changes: Option<LocalChanges>,
+}
+
+/// Number of recent commits the "what's new" feed looks at by default
+const DEFAULT_WHATS_NEW_COMMITS: usize = 10;
+
+/// A texture updated in a recent commit
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdatedTexture {
+    /// Path relative to the SLUS folder
+    pub path: String,
+    /// "added", "modified" or "renamed"
+    pub status: String,
+    /// Commit date of the latest update
+    pub updated_at: String,
 }
 
-/// Quick count check - compares file counts without computing SHA hashes
+/// Recently updated textures in one folder (e.g. a team's uniforms or a stadium)
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdatedFolder {
+    pub folder: String,
+    /// Date of the most recent update in the folder
+    pub updated_at: String,
+    pub textures: Vec<UpdatedTexture>,
+}
+
+/// List textures updated by the most recent commits, grouped by folder, newest first
+#[tauri::command]
+pub async fn get_whats_new(
+    github_token: Option<String>,
+    commit_limit: Option<usize>,
+    app: AppHandle,
+) -> Result<Vec<UpdatedFolder>, String> {
+    let config = resolve_repo_config(&app)?;
+    let protected_paths = load_protected_paths(&app);
+    let client = http_client();
+    let limit = commit_limit.unwrap_or(DEFAULT_WHATS_NEW_COMMITS).clamp(1, GITHUB_PAGE_SIZE);
+
+    // Only commits that touched the pack
+    let url = format!(
+        "{}/commits?sha={}&path={}&{}={}",
+        config.api_repo_url(),
+        config.branch,
+        config.sparse_path,
+        config.forge.page_size_param(),
+        limit
+    );
+    let response = build_request(&config, &client, &url, &github_token)
+        .send_traced()
+        .await
+        .map_err(|e| format!("Failed to list commits: {}", describe_request_error(&e)))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "GitHub API error: {} - {}",
+            response.status(),
+            response.text().await.unwrap_or_default()
+        ));
+    }
+
+    let commits: Vec<CommitResponse> = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse commit list: {}", e))?;
+
+    // Newest commit first, so the first update seen for a path is its latest
+    let prefix = format!("{}/", config.sparse_path);
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut folders: BTreeMap<String, Vec<UpdatedTexture>> = BTreeMap::new();
+
+    for commit in &commits {
+        let files = fetch_commit_files(&config, &client, &commit.sha, &github_token).await?;
+        for file in files {
+            if !matches!(file.status.as_str(), "added" | "modified" | "changed" | "renamed" | "copied") {
+                continue;
+            }
+            let Some(path) = file.filename.strip_prefix(&prefix) else {
+                continue;
+            };
+            if should_skip_path(path, &protected_paths) || !seen.insert(path.to_string()) {
+                continue;
+            }
+
+            let folder = path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("").to_string();
+            let status = match file.status.as_str() {
+                "changed" => "modified",
+                "copied" => "added",
+                other => other,
+            };
+            folders.entry(folder).or_default().push(UpdatedTexture {
+                path: path.to_string(),
+                status: status.to_string(),
+                updated_at: commit.commit.committer.date.clone(),
+            });
+        }
+    }
+
+    // Textures within a folder keep commit order (newest first); folders sort by their latest update
+    let mut result: Vec<UpdatedFolder> = folders
+        .into_iter()
+        .map(|(folder, textures)| UpdatedFolder {
+            updated_at: textures[0].updated_at.clone(),
+            folder,
+            textures,
+        })
+        .collect();
+    result.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+
+    Ok(result)
+}
+
+/// Quick count check - compares file counts and hashes a random sample of files per folder
 #[tauri::command]
 pub async fn run_quick_count_check(
     textures_dir: String,
     github_token: Option<String>,
+    sample_size: Option<usize>,
     window: Window,
 ) -> Result<QuickCheckResult, String> {
-    let textures_path = PathBuf::from(&textures_dir);
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    start_verification_session();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "counting".to_string(),
+        stage: Stage::Counting,
+        key: "count.counting_local",
+        params: MessageParams::new(),
         message: "Counting local files...".to_string(),
         current: None,
         total: None,
     });
 
     // Count local files (fast, no SHA)
-    let local_count = count_local_files(&textures_path)?;
+    let local_counts = count_local_files(&slus_path, &protected_paths)?;
+    let local_count = local_counts.values().sum::<usize>();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "counting".to_string(),
+        stage: Stage::Counting,
+        key: "count.fetching_remote",
+        params: MessageParams::from([("local", local_count.to_string())]),
         message: format!("Local: {} files. Fetching remote count...", local_count),
         current: None,
         total: None,
     });
 
     // Fetch remote tree and count (excluding user-customs)
-    let (remote_files, _) = fetch_github_tree(&github_token).await?;
-    let remote_count = remote_files.keys().filter(|p| !should_skip_path(p)).count();
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    let (remote_files, _, _) = fetch_latest_tree(&source, &window).await?;
+    let mut remote_counts: BTreeMap<String, usize> = BTreeMap::new();
+    for path in remote_files.keys().filter(|p| !should_skip_path(p, &protected_paths)) {
+        *remote_counts.entry(top_level_folder(path).to_string()).or_default() += 1;
+    }
+    let remote_count = remote_counts.values().sum::<usize>();
 
     let counts_match = local_count == remote_count;
 
+    // Per-folder counts, so the UI can point at the part of the pack that's off
+    let folder_names: BTreeSet<&String> = local_counts.keys().chain(remote_counts.keys()).collect();
+    let folder_counts: Vec<FolderCount> = folder_names
+        .into_iter()
+        .map(|folder| {
+            let local = local_counts.get(folder).copied().unwrap_or(0);
+            let remote = remote_counts.get(folder).copied().unwrap_or(0);
+            FolderCount {
+                folder: folder.clone(),
+                local_count: local,
+                remote_count: remote,
+                delta: local as i64 - remote as i64,
+            }
+        })
+        .collect();
+
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "counting".to_string(),
+        stage: Stage::Counting,
+        key: "count.result",
+        params: MessageParams::from([
+            ("local", local_count.to_string()),
+            ("remote", remote_count.to_string()),
+            ("match", counts_match.to_string()),
+        ]),
         message: format!("Local: {} files, Remote: {} files. Match: {}", local_count, remote_count, counts_match),
         current: None,
         total: None,
     });
 
+    // Spot check: hash a random sample of files per top-level folder to catch corruption cheaply
+    let sample_size = sample_size.unwrap_or(DEFAULT_SPOT_CHECK_SAMPLE_SIZE);
+    let mut by_folder: HashMap<String, Vec<(&String, &String)>> = HashMap::new();
+    for (path, sha) in remote_files.iter().filter(|(p, _)| !should_skip_path(p, &protected_paths)) {
+        by_folder.entry(top_level_folder(path).to_string()).or_default().push((path, sha));
+    }
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Counting,
+        key: "count.spot_checking",
+        params: MessageParams::from([
+            ("count", sample_size.to_string()),
+            ("folders", by_folder.len().to_string()),
+        ]),
+        message: format!("Spot-checking {} files in {} folders...", sample_size, by_folder.len()),
+        current: None,
+        total: None,
+    });
+
+    let mut rng = rand::thread_rng();
+    let mut spot_check_discrepancies = Vec::new();
+    let mut spot_check_sampled = 0;
+
+    for (folder, files) in by_folder {
+        let sample: Vec<_> = files.choose_multiple(&mut rng, sample_size).collect();
+        spot_check_sampled += sample.len();
+
+        let mismatched: Vec<String> = sample
+            .iter()
+            .filter(|(path, sha)| {
+                let (exists, _, local_path) = find_local_file(&slus_path, path, convention);
+                !exists
+                    || compute_git_blob_sha_with_normalization(&local_path, Some(sha))
+                        .map(|local_sha| &local_sha != *sha)
+                        .unwrap_or(true)
+            })
+            .map(|(path, _)| (*path).clone())
+            .collect();
+
+        if !mismatched.is_empty() {
+            spot_check_discrepancies.push(FolderSpotCheck {
+                folder,
+                sampled: sample.len(),
+                mismatched,
+            });
+        }
+    }
+
+    // Counts can only tell how many files are missing or extra; mismatched samples need downloading too
+    let missing = folder_counts.iter().map(|f| (-f.delta).max(0) as usize).sum::<usize>();
+    let extra = folder_counts.iter().map(|f| f.delta.max(0) as usize).sum::<usize>();
+    let mismatched = spot_check_discrepancies.iter().map(|f| f.mismatched.len()).sum::<usize>();
+    record_verification(window.app_handle(), VerificationMode::Quick, None, missing + mismatched, extra);
+
     Ok(QuickCheckResult {
         local_count,
         remote_count,
         counts_match,
+        folder_counts,
+        spot_check_discrepancies,
+        spot_check_sampled,
     })
 }
 
@@ -1484,22 +3666,32 @@ pub async fn analyze_full_sync(
     github_token: Option<String>,
     window: Window,
 ) -> Result<SyncAnalysis, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let _sleep_guard = prevent_sleep("Comparing NCAA NEXT textures");
     let textures_path = PathBuf::from(&textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
+    let slus_path = textures_path.join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let protected_paths = load_protected_paths(window.app_handle());
+    start_sync_session();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "fetching".to_string(),
+        stage: Stage::Fetching,
+        key: "sync.fetching_tree",
+        params: MessageParams::new(),
         message: "Fetching repository tree (this may take a while)...".to_string(),
         current: None,
         total: None,
     });
 
-    // Fetch GitHub tree
-    let (remote_files, commit_sha) = fetch_github_tree(&github_token).await?;
-    let remote_count = remote_files.keys().filter(|p| !should_skip_path(p)).count();
+    // Fetch the pack's file list
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    let (remote_files, remote_sizes, commit_sha) = fetch_latest_tree(&source, &window).await?;
+    let remote_count = remote_files.keys().filter(|p| !should_skip_path(p, &protected_paths)).count();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
+        stage: Stage::Scanning,
+        key: "sync.remote_files_found",
+        params: MessageParams::from([("count", remote_count.to_string())]),
         message: format!("Found {} files in repository", remote_count),
         current: None,
         total: None,
@@ -1507,23 +3699,32 @@ pub async fn analyze_full_sync(
 
     // Build local file map
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
+        stage: Stage::Scanning,
+        key: "sync.scanning_local",
+        params: MessageParams::new(),
         message: "Scanning local files (this may take a few minutes)...".to_string(),
         current: None,
         total: None,
     });
 
-    let local_files = build_local_file_map(&textures_path)?;
+    let local_files = build_local_file_map(&slus_path, convention)?;
+    let mut disabled_registry = load_disabled_registry(window.app_handle());
+    disabled_registry.observe(&local_files, &remote_files, convention);
+    save_disabled_registry(window.app_handle(), &disabled_registry)?;
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "scanning".to_string(),
+        stage: Stage::Scanning,
+        key: "sync.local_files_found",
+        params: MessageParams::from([("count", local_files.len().to_string())]),
         message: format!("Found {} local files (excluding user-customs)...", local_files.len()),
         current: None,
         total: None,
     });
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "comparing".to_string(),
+        stage: Stage::Comparing,
+        key: "sync.comparing_hashes",
+        params: MessageParams::new(),
         message: "Comparing file hashes...".to_string(),
         current: None,
         total: None,
@@ -1540,14 +3741,20 @@ pub async fn analyze_full_sync(
         if compared % 1000 == 0 {
             let percent = (compared * 100) / total_to_compare;
             let _ = window.emit("sync-progress", SyncProgressPayload {
-                stage: "comparing".to_string(),
+                stage: Stage::Comparing,
+                key: "sync.comparing_hashes_progress",
+                params: MessageParams::from([
+                    ("current", compared.to_string()),
+                    ("total", total_to_compare.to_string()),
+                    ("percent", percent.to_string()),
+                ]),
                 message: format!("Comparing file hashes ({}/{}) {}%...", compared, total_to_compare, percent),
                 current: Some(compared as u32),
                 total: Some(total_to_compare as u32),
             });
         }
 
-        if should_skip_path(path) {
+        if should_skip_path(path, &protected_paths) {
             continue;
         }
 
@@ -1560,12 +3767,12 @@ pub async fn analyze_full_sync(
                 }
             }
             // File exists but different - will be REPLACED
-            files_to_replace.push(SyncFile { path: path.clone(), to_disabled: false });
+            files_to_replace.push(SyncFile { path: path.clone(), to_disabled: false, sha: Some(remote_sha.clone()) });
             continue;
         }
 
         // Check disabled version
-        let disabled_path = get_disabled_path(path);
+        let disabled_path = convention.disabled_path(path);
         if local_files.contains_key(&disabled_path) {
             let local_path = slus_path.join(&disabled_path);
             if let Ok(local_sha) = compute_git_blob_sha_with_normalization(&local_path, Some(remote_sha)) {
@@ -1574,19 +3781,23 @@ pub async fn analyze_full_sync(
                 }
             }
             // Disabled file exists but different - will be REPLACED
-            files_to_replace.push(SyncFile { path: path.clone(), to_disabled: true });
+            files_to_replace.push(SyncFile { path: path.clone(), to_disabled: true, sha: Some(remote_sha.clone()) });
             continue;
         }
 
-        // File doesn't exist locally - will be ADDED
-        files_to_add.push(SyncFile { path: path.clone(), to_disabled: false });
+        // File doesn't exist locally - will be ADDED (disabled if the user had disabled it before)
+        files_to_add.push(SyncFile {
+            path: path.clone(),
+            to_disabled: disabled_registry.contains(path),
+            sha: Some(remote_sha.clone()),
+        });
     }
 
     // Determine files to delete
     let mut files_to_delete: Vec<String> = Vec::new();
 
     for local_path in local_files.keys() {
-        if should_skip_path(local_path) {
+        if should_skip_path(local_path, &protected_paths) {
             continue;
         }
 
@@ -1594,18 +3805,16 @@ pub async fn analyze_full_sync(
             continue;
         }
 
-        if is_disabled_filename(get_filename(local_path)) {
-            if let Some(enabled_path) = get_enabled_path(local_path) {
-                // If enabled version exists LOCALLY, delete the disabled version
-                // (having both doesn't make sense - enabled takes precedence)
-                if local_files.contains_key(&enabled_path) {
-                    files_to_delete.push(local_path.clone());
-                    continue;
-                }
-                // If enabled version exists in remote (but not locally), keep disabled version
-                if remote_files.contains_key(&enabled_path) {
-                    continue;
-                }
+        if let Some(enabled_path) = convention.enabled_path(local_path) {
+            // If enabled version exists LOCALLY, delete the disabled version
+            // (having both doesn't make sense - enabled takes precedence)
+            if local_files.contains_key(&enabled_path) {
+                files_to_delete.push(local_path.clone());
+                continue;
+            }
+            // If enabled version exists in remote (but not locally), keep disabled version
+            if remote_files.contains_key(&enabled_path) {
+                continue;
             }
         }
 
@@ -1613,7 +3822,13 @@ pub async fn analyze_full_sync(
     }
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "analysis_complete".to_string(),
+        stage: Stage::AnalysisComplete,
+        key: "sync.analysis_complete",
+        params: MessageParams::from([
+            ("new", files_to_add.len().to_string()),
+            ("replace", files_to_replace.len().to_string()),
+            ("delete", files_to_delete.len().to_string()),
+        ]),
         message: format!(
             "Analysis complete: {} new, {} to replace, {} to delete",
             files_to_add.len(), files_to_replace.len(), files_to_delete.len()
@@ -1622,92 +3837,381 @@ pub async fn analyze_full_sync(
         total: None,
     });
 
+    let files_to_download: Vec<&SyncFile> = files_to_add.iter().chain(files_to_replace.iter()).collect();
+    let folders = summarize_changes_by_folder(&slus_path, &files_to_download, &files_to_delete, &remote_sizes);
+    let downloads: Vec<&str> = files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = files_to_delete.iter().map(String::as_str).collect();
+    let plan_token = issue_plan(&commit_sha, &downloads, &deletes);
+
     Ok(SyncAnalysis {
         files_to_add,
         files_to_replace,
         files_to_delete,
         commit_sha,
+        folders,
+        plan_token,
     })
 }
 
+/// Preview how many bytes a pending sync or verification fix adds, removes and changes on disk
+/// Takes the same file lists as execute_analyzed_sync and apply_verification_fixes; remote sizes
+/// come from the pack at `commit_sha` (the latest version when None)
+#[tauri::command]
+pub async fn preview_disk_delta(
+    textures_dir: String,
+    files_to_download: Vec<SyncFile>,
+    files_to_delete: Vec<String>,
+    commit_sha: Option<String>,
+    github_token: Option<String>,
+    window: Window,
+) -> Result<DiskDelta, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+
+    let remote_sizes = if files_to_download.is_empty() {
+        HashMap::new()
+    } else {
+        let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+        let reference = match commit_sha {
+            Some(sha) => sha,
+            None => source.latest_ref().await?,
+        };
+        source.list_tree(&reference).await?.1
+    };
+
+    Ok(compute_disk_delta(&files_to_download, &files_to_delete, &remote_sizes, convention, |path| {
+        fs::metadata(slus_path.join(path)).map(|m| m.len()).unwrap_or(0)
+    }))
+}
+
 /// Execute sync with pre-analyzed file lists (skips analysis phase)
+/// plan_token comes from the analysis; it's required when many files would be deleted, and the
+/// sync is refused if the file lists differ from the analysis or a newer version was published since
+/// The plan is journaled so an interrupted sync can be resumed with resume_analyzed_sync
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn execute_analyzed_sync(
     textures_dir: String,
     files_to_add: Vec<SyncFile>,
     files_to_replace: Vec<SyncFile>,
     files_to_delete: Vec<String>,
     commit_sha: String,
+    plan_token: Option<String>,
     github_token: Option<String>,
     window: Window,
 ) -> Result<SyncResult, String> {
-    let textures_path = PathBuf::from(&textures_dir);
-    let slus_path = textures_path.join(SLUS_FOLDER);
+    let config = resolve_repo_config(window.app_handle())?;
 
     // Combine add and replace into single download list
     let mut files_to_download: Vec<SyncFile> = Vec::new();
     files_to_download.extend(files_to_add);
     files_to_download.extend(files_to_replace);
 
-    let download_count = files_to_download.len() as u32;
-    let delete_count = files_to_delete.len() as u32;
+    let downloads: Vec<&str> = files_to_download.iter().map(|file| file.path.as_str()).collect();
+    let deletes: Vec<&str> = files_to_delete.iter().map(String::as_str).collect();
+    // The lists come back from the frontend
+    check_repo_paths(downloads.iter().chain(&deletes).copied())?;
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    if let Some(reference) = check_plan(plan_token.as_deref(), &downloads, &deletes)? {
+        if reference != commit_sha || source.latest_ref().await? != commit_sha {
+            return Err("The texture pack was updated after this analysis. Analyze again before syncing.".to_string());
+        }
+    }
+
+    let journal = SyncJournal {
+        textures_dir: textures_dir.clone(),
+        repo_url: config.repo_url.clone(),
+        commit_sha,
+        files_to_download,
+        files_to_delete,
+        files_downloaded: 0,
+        files_deleted: 0,
+        failed_files: Vec::new(),
+        started_at: Utc::now().to_rfc3339(),
+    };
+    save_sync_journal(window.app_handle(), &journal)?;
+
+    let _byte_progress = report_byte_progress(&window);
+    let mut result = apply_sync_journal(&config, &source, journal, &window).await?;
+    // Analyzed syncs compare every file
+    record_sync_mode(window.app_handle(), true);
+    result.verification = verify_after_sync(&textures_dir, &github_token, &window).await;
+    Ok(result)
+}
+
+/// Resume an analyzed sync that was interrupted (crash, app closed) from its journal
+#[tauri::command]
+pub async fn resume_analyzed_sync(
+    github_token: Option<String>,
+    window: Window,
+) -> Result<SyncResult, String> {
+    let config = resolve_repo_config(window.app_handle())?;
+    let journal = load_sync_journal(window.app_handle())?
+        .ok_or_else(|| "No interrupted sync to resume".to_string())?;
+
+    if journal.repo_url != config.repo_url {
+        return Err("The interrupted sync belongs to a different texture pack. Switch back to that pack or discard it.".to_string());
+    }
+    check_repo_paths(
+        journal
+            .files_to_download
+            .iter()
+            .map(|file| file.path.as_str())
+            .chain(journal.files_to_delete.iter().map(String::as_str)),
+    )?;
+
+    // The remaining files are fetched from the current version, so it has to be the journaled one
+    // A new analysis compares every file, which also covers what this sync had left to do
+    let source = open_pack_source(window.app_handle(), &config, &github_token)?;
+    if source.latest_ref().await? != journal.commit_sha {
+        clear_sync_journal(window.app_handle())?;
+        return Err("The texture pack was updated after this sync was interrupted. Analyze again to finish updating.".to_string());
+    }
+    start_sync_session();
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Syncing,
+        key: "sync.resuming",
+        params: MessageParams::from([
+            ("downloaded", journal.files_downloaded.to_string()),
+            ("deleted", journal.files_deleted.to_string()),
+        ]),
+        message: format!(
+            "Resuming interrupted sync ({} already downloaded, {} already deleted)",
+            journal.files_downloaded, journal.files_deleted
+        ),
+        current: None,
+        total: None,
+    });
+
+    let textures_dir = journal.textures_dir.clone();
+    let _byte_progress = report_byte_progress(&window);
+    let mut result = apply_sync_journal(&config, &source, journal, &window).await?;
+    // Analyzed syncs compare every file
+    record_sync_mode(window.app_handle(), true);
+    result.verification = verify_after_sync(&textures_dir, &github_token, &window).await;
+    Ok(result)
+}
+
+/// Apply the remaining plan in a journal, persisting progress after each chunk
+async fn apply_sync_journal(
+    config: &RepoConfig,
+    source: &impl PackSource,
+    mut journal: SyncJournal,
+    window: &Window,
+) -> Result<SyncResult, String> {
+    if load_state(window.app_handle().clone())?.transactional_sync {
+        return apply_sync_transactionally(config, source, journal, window).await;
+    }
+
+    let app = window.app_handle();
+    let slus_path = PathBuf::from(&journal.textures_dir).join(&config.slus_folder);
+    let convention = load_disable_convention(window.app_handle());
+    let delete_permanently = load_delete_permanently(window.app_handle());
+    let _drift_guard = suppress_drift_detection();
+    let _sleep_guard = prevent_sleep("Syncing NCAA NEXT textures");
+
+    // Files the repository moved are already here under their old path
+    let mut actions = ActionLog::new(window);
+    let (moved_to, moved_from) = {
+        let local_shas: Vec<Option<String>> = journal
+            .files_to_delete
+            .iter()
+            .map(|path| compute_git_blob_sha(&slus_path.join(path)).ok())
+            .collect();
+        let downloads: Vec<(&str, bool, Option<&str>)> = journal
+            .files_to_download
+            .iter()
+            .map(|file| (file.path.as_str(), file.to_disabled, file.sha.as_deref()))
+            .collect();
+        let deletes: Vec<(&str, Option<&str>)> = journal
+            .files_to_delete
+            .iter()
+            .zip(&local_shas)
+            .map(|(path, sha)| (path.as_str(), sha.as_deref()))
+            .collect();
+        move_local_files(&slus_path, convention, &downloads, &deletes, window, &mut actions)
+    };
+    let renamed = moved_to.len() as u32;
+    if renamed > 0 {
+        journal.files_to_download.retain(|file| !moved_to.contains(&file.path));
+        journal.files_to_delete.retain(|path| !moved_from.contains(path));
+        save_sync_journal(app, &journal)?;
+    }
+
+    let download_count = journal.files_downloaded + journal.files_to_download.len() as u32;
+    let delete_count = journal.files_deleted + journal.files_to_delete.len() as u32;
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "syncing".to_string(),
-        message: format!("Starting sync: {} to download, {} to delete", download_count, delete_count),
+        stage: Stage::Syncing,
+        key: "sync.starting",
+        params: MessageParams::from([
+            ("downloads", journal.files_to_download.len().to_string()),
+            ("deletes", journal.files_to_delete.len().to_string()),
+        ]),
+        message: format!(
+            "Starting sync: {} to download, {} to delete",
+            journal.files_to_download.len(), journal.files_to_delete.len()
+        ),
         current: None,
         total: None,
     });
 
     // Download files
-    let client = Client::new();
-    let mut downloaded: u32 = 0;
+    let cache = BlobCache::open(window.app_handle())?.with_local_copies(&slus_path);
+    configure_download_throttle(window.app_handle());
 
-    for (i, file) in files_to_download.iter().enumerate() {
-        let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "downloading".to_string(),
-            message: format!("Downloading: {}", file.path),
-            current: Some(i as u32 + 1),
-            total: Some(download_count),
-        });
+    if !journal.files_to_download.is_empty() {
+        rank_mirrors(source, window).await;
 
-        let dest_path = if file.to_disabled {
-            slus_path.join(get_disabled_path(&file.path))
+        // The archive decision needs the size of the whole pack, only worth listing for large plans
+        let pack_sizes = if source.supports_archive() && journal.files_to_download.len() >= ARCHIVE_MIN_FILES {
+            source.list_tree(&journal.commit_sha).await.map(|(_, sizes)| sizes).ok()
         } else {
-            slus_path.join(&file.path)
+            None
         };
+        let targets = journal
+            .files_to_download
+            .iter()
+            .map(|file| {
+                let dest_path = if file.to_disabled {
+                    slus_path.join(convention.disabled_path(&file.path))
+                } else {
+                    slus_path.join(&file.path)
+                };
+                (file.path.clone(), ArchiveTarget { dest_path, sha: file.sha.clone() })
+            })
+            .collect();
+        let extracted = download_via_archive(source, &journal.commit_sha, targets, pack_sizes.as_ref(), window).await;
+
+        if !extracted.is_empty() {
+            journal.files_to_download.retain(|file| {
+                if !extracted.contains(&file.path) {
+                    return true;
+                }
+                emit_log(window, LogLevel::Info, "download", Some(&file.path), "Downloaded (from archive)");
+                actions.ok(&file.path, "download");
+                false
+            });
+            journal.files_downloaded += extracted.len() as u32;
+            save_sync_journal(app, &journal)?;
+        }
+    }
 
-        download_file(&client, &file.path, &dest_path, &github_token).await?;
-        downloaded += 1;
+    while !journal.files_to_download.is_empty() {
+        let chunk_len = journal.files_to_download.len().min(SYNC_JOURNAL_CHUNK_SIZE);
+
+        for file in &journal.files_to_download[..chunk_len] {
+            journal.files_downloaded += 1;
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Downloading,
+                key: "sync.downloading_file",
+                params: MessageParams::from([("path", file.path.to_string())]),
+                message: format!("Downloading: {}", file.path),
+                current: Some(journal.files_downloaded),
+                total: Some(download_count),
+            });
+
+            let dest_path = if file.to_disabled {
+                slus_path.join(convention.disabled_path(&file.path))
+            } else {
+                slus_path.join(&file.path)
+            };
+
+            match download_file(source, &cache, &file.path, file.sha.as_deref(), &dest_path).await {
+                Ok(()) => {
+                    emit_log(window, LogLevel::Info, "download", Some(&file.path), "Downloaded");
+                    actions.ok(&file.path, "download");
+                }
+                Err(e) => {
+                    emit_log(window, LogLevel::Error, "download", Some(&file.path), e.clone());
+                    journal.failed_files.push(SyncFailure {
+                        path: file.path.clone(),
+                        action: "download".to_string(),
+                        to_disabled: file.to_disabled,
+                        sha: file.sha.clone(),
+                        from: None,
+                        error: e,
+                    });
+                }
+            }
+        }
+
+        journal.files_to_download.drain(..chunk_len);
+        save_sync_journal(app, &journal)?;
+        emit_mirror_usage(source, window);
     }
 
     // Delete files
-    let mut deleted: u32 = 0;
+    let mut processed = journal.files_deleted;
 
-    for (i, path) in files_to_delete.iter().enumerate() {
-        let _ = window.emit("sync-progress", SyncProgressPayload {
-            stage: "deleting".to_string(),
-            message: format!("Deleting: {}", path),
-            current: Some(i as u32 + 1),
-            total: Some(delete_count),
-        });
+    while !journal.files_to_delete.is_empty() {
+        let chunk_len = journal.files_to_delete.len().min(SYNC_JOURNAL_CHUNK_SIZE);
 
-        let file_path = slus_path.join(path);
-        if file_path.exists() {
-            fs::remove_file(&file_path)
-                .map_err(|e| format!("Failed to delete {}: {}", path, e))?;
-            deleted += 1;
+        for path in &journal.files_to_delete[..chunk_len] {
+            processed += 1;
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Deleting,
+                key: "sync.deleting_file",
+                params: MessageParams::from([("path", path.to_string())]),
+                message: format!("Deleting: {}", path),
+                current: Some(processed),
+                total: Some(delete_count),
+            });
+
+            let file_path = slus_path.join(path);
+            if file_path.exists() {
+                if let Err(e) = delete_path(&file_path, delete_permanently) {
+                    let error = format!("Failed to delete {}: {}", path, e);
+                    emit_log(window, LogLevel::Error, "delete", Some(path), error.clone());
+                    journal.failed_files.push(SyncFailure {
+                        path: path.clone(),
+                        action: "delete".to_string(),
+                        to_disabled: false,
+                        sha: None,
+                        from: None,
+                        error,
+                    });
+                    continue;
+                }
+                emit_log(window, LogLevel::Info, "delete", Some(path), "Deleted");
+                actions.ok(path, "delete");
+                journal.files_deleted += 1;
+            }
         }
+
+        journal.files_to_delete.drain(..chunk_len);
+        save_sync_journal(app, &journal)?;
     }
 
+    actions.failed = std::mem::take(&mut journal.failed_files);
+    let (_, retried_deletes) = retry_failures(source, &cache, &slus_path, window, &mut actions).await;
+    journal.files_deleted += retried_deletes;
+    let (failures, inline_actions, actions_report_path) = actions.finish(app);
+
     // Cleanup empty directories
-    cleanup_empty_directories(&slus_path, &window);
+    let cleanup = cleanup_empty_directories(&slus_path, false, window);
+
+    clear_sync_journal(app)?;
+
+    // files_downloaded counts attempts (it drives progress), so leave out what still failed
+    let download_failures = failures.iter().filter(|f| f.action == "download").count() as u32;
+    let downloaded = journal.files_downloaded.saturating_sub(download_failures);
+
+    // Keep the download cache within its size limit
+    cache.enforce_limit();
 
     let _ = window.emit("sync-progress", SyncProgressPayload {
-        stage: "complete".to_string(),
+        stage: Stage::Complete,
+        key: "sync.complete",
+        params: MessageParams::from([
+            ("downloaded", downloaded.to_string()),
+            ("deleted", journal.files_deleted.to_string()),
+        ]),
         message: format!(
             "Sync complete! Downloaded: {}, Deleted: {}",
-            downloaded, deleted
+            downloaded, journal.files_deleted
         ),
         current: None,
         total: None,
@@ -1715,9 +4219,462 @@ pub async fn execute_analyzed_sync(
 
     Ok(SyncResult {
         files_downloaded: downloaded,
-        files_deleted: deleted,
+        files_deleted: journal.files_deleted,
+        files_renamed: renamed,
+        files_skipped: 0,
+        retry_plan_token: issue_retry_plan(&journal.commit_sha, &failures),
+        new_commit_sha: journal.commit_sha,
+        failed_files: failures,
+        actions: inline_actions,
+        actions_report_path,
+        cleanup: Some(cleanup),
+        verification: None,
+    })
+}
+
+/// Apply a journal's plan as a transaction: download everything into a staging folder, then swap
+/// it in and delete in one quick phase. Any download that still fails after a retry, or any
+/// failing swap step, leaves the SLUS folder as it was before the sync
+/// The journal is only cleared on success, so an aborted transactional sync can be resumed
+async fn apply_sync_transactionally(
+    config: &RepoConfig,
+    source: &impl PackSource,
+    mut journal: SyncJournal,
+    window: &Window,
+) -> Result<SyncResult, String> {
+    let app = window.app_handle();
+    let textures_path = PathBuf::from(&journal.textures_dir);
+    let slus_path = textures_path.join(&config.slus_folder);
+    let convention = load_disable_convention(app);
+    let delete_permanently = load_delete_permanently(app);
+    let _drift_guard = suppress_drift_detection();
+    let _sleep_guard = prevent_sleep("Syncing NCAA NEXT textures");
+
+    let transaction = SyncTransaction::begin(&textures_path, &slus_path)?;
+    let download_count = journal.files_to_download.len() as u32;
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Syncing,
+        key: "sync.staging",
+        params: MessageParams::from([
+            ("downloads", journal.files_to_download.len().to_string()),
+            ("deletes", journal.files_to_delete.len().to_string()),
+        ]),
+        message: format!(
+            "Staging sync: {} to download, {} to delete. Nothing changes until every download is done",
+            journal.files_to_download.len(), journal.files_to_delete.len()
+        ),
+        current: None,
+        total: None,
+    });
+
+    // Paths relative to the SLUS folder, as the files will be named once swapped in
+    let relative_dest = |file: &SyncFile| -> String {
+        if file.to_disabled {
+            convention.disabled_path(&file.path)
+        } else {
+            file.path.clone()
+        }
+    };
+
+    let cache = BlobCache::open(app)?.with_local_copies(&slus_path);
+    configure_download_throttle(app);
+    let mut actions = ActionLog::new(window);
+    let mut pending: Vec<&SyncFile> = journal.files_to_download.iter().collect();
+
+    if !pending.is_empty() {
+        rank_mirrors(source, window).await;
+
+        let pack_sizes = if source.supports_archive() && pending.len() >= ARCHIVE_MIN_FILES {
+            source.list_tree(&journal.commit_sha).await.map(|(_, sizes)| sizes).ok()
+        } else {
+            None
+        };
+        let targets = pending
+            .iter()
+            .map(|&file| {
+                let dest_path = transaction.staged_path(&relative_dest(file));
+                (file.path.clone(), ArchiveTarget { dest_path, sha: file.sha.clone() })
+            })
+            .collect();
+        let extracted = download_via_archive(source, &journal.commit_sha, targets, pack_sizes.as_ref(), window).await;
+        pending.retain(|file| !extracted.contains(&file.path));
+    }
+
+    // Files that fail get one more attempt before the transaction gives up
+    let mut staged = download_count - pending.len() as u32;
+    for attempt in 0..2 {
+        let mut failed = Vec::new();
+        for file in pending {
+            let _ = window.emit("sync-progress", SyncProgressPayload {
+                stage: Stage::Downloading,
+                key: "sync.downloading_file",
+                params: MessageParams::from([("path", file.path.to_string())]),
+                message: format!("Downloading: {}", file.path),
+                current: Some(staged + 1),
+                total: Some(download_count),
+            });
+
+            let dest_path = transaction.staged_path(&relative_dest(file));
+            match download_file(source, &cache, &file.path, file.sha.as_deref(), &dest_path).await {
+                Ok(()) => staged += 1,
+                Err(e) => {
+                    if attempt > 0 {
+                        emit_log(window, LogLevel::Error, "download", Some(&file.path), e.clone());
+                        actions.fail(SyncFailure {
+                            path: file.path.clone(),
+                            action: "download".to_string(),
+                            to_disabled: file.to_disabled,
+                            sha: file.sha.clone(),
+                            from: None,
+                            error: e,
+                        });
+                    }
+                    failed.push(file);
+                }
+            }
+        }
+        pending = failed;
+        if pending.is_empty() {
+            break;
+        }
+    }
+    emit_mirror_usage(source, window);
+
+    if !actions.failed.is_empty() {
+        let failed = actions.failed.len();
+        drop(transaction);
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Syncing,
+            key: "sync.transaction_aborted",
+            params: MessageParams::from([("count", failed.to_string())]),
+            message: format!("{} files failed to download. The sync was cancelled and no files were changed", failed),
+            current: None,
+            total: None,
+        });
+        return Err(format!(
+            "{} files failed to download. The sync was cancelled and no files were changed, run it again to retry",
+            failed
+        ));
+    }
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Syncing,
+        key: "sync.swapping",
+        params: MessageParams::new(),
+        message: "All files downloaded, applying changes...".to_string(),
+        current: None,
+        total: None,
+    });
+
+    let staged_paths: Vec<String> = journal.files_to_download.iter().map(relative_dest).collect();
+    let deletions: Vec<String> = journal
+        .files_to_delete
+        .iter()
+        .filter(|path| slus_path.join(path).exists())
+        .cloned()
+        .collect();
+    if let Err(e) = transaction.commit(&staged_paths, &deletions, delete_permanently) {
+        let _ = window.emit("sync-progress", SyncProgressPayload {
+            stage: Stage::Syncing,
+            key: "sync.transaction_rolled_back",
+            params: MessageParams::from([("error", e.clone())]),
+            message: e.clone(),
+            current: None,
+            total: None,
+        });
+        return Err(e);
+    }
+
+    for file in &journal.files_to_download {
+        emit_log(window, LogLevel::Info, "download", Some(&file.path), "Downloaded");
+        actions.ok(&file.path, "download");
+    }
+    for path in &deletions {
+        emit_log(window, LogLevel::Info, "delete", Some(path), "Deleted");
+        actions.ok(path, "delete");
+    }
+    journal.files_downloaded += staged;
+    journal.files_deleted += deletions.len() as u32;
+    let (failures, inline_actions, actions_report_path) = actions.finish(app);
+
+    let cleanup = cleanup_empty_directories(&slus_path, false, window);
+    clear_sync_journal(app)?;
+    cache.enforce_limit();
+
+    let _ = window.emit("sync-progress", SyncProgressPayload {
+        stage: Stage::Complete,
+        key: "sync.complete",
+        params: MessageParams::from([
+            ("downloaded", journal.files_downloaded.to_string()),
+            ("deleted", journal.files_deleted.to_string()),
+        ]),
+        message: format!(
+            "Sync complete! Downloaded: {}, Deleted: {}",
+            journal.files_downloaded, journal.files_deleted
+        ),
+        current: None,
+        total: None,
+    });
+
+    Ok(SyncResult {
+        files_downloaded: journal.files_downloaded,
+        files_deleted: journal.files_deleted,
         files_renamed: 0,
         files_skipped: 0,
-        new_commit_sha: commit_sha,
+        retry_plan_token: issue_retry_plan(&journal.commit_sha, &failures),
+        new_commit_sha: journal.commit_sha,
+        failed_files: failures,
+        actions: inline_actions,
+        actions_report_path,
+        cleanup: Some(cleanup),
+        verification: None,
+    })
+}
+
+/// Get the folders and files (relative to the SLUS folder) that sync and verification leave alone
+#[tauri::command]
+pub fn get_protected_paths(app: AppHandle) -> Result<Vec<String>, String> {
+    Ok(load_state(app)?.protected_paths)
+}
+
+/// Set the protected folders and files, e.g. for personal mixes kept alongside the official pack
+#[tauri::command]
+pub fn set_protected_paths(app: AppHandle, paths: Vec<String>) -> Result<(), String> {
+    let mut protected_paths: Vec<String> = Vec::new();
+    for path in paths {
+        let path = path.trim().replace('\\', "/").trim_matches('/').to_string();
+        if !path.is_empty() && !protected_paths.contains(&path) {
+            protected_paths.push(path);
+        }
+    }
+    check_repo_paths(protected_paths.iter().map(String::as_str))?;
+
+    with_state_mut(&app, |state| {
+        state.protected_paths = protected_paths;
+        Ok(())
     })
 }
+
+/// Turn transactional (all-or-nothing) mode for analyzed syncs on or off
+#[tauri::command]
+pub fn set_transactional_sync(app: AppHandle, enabled: bool) -> Result<(), String> {
+    with_state_mut(&app, |state| {
+        state.transactional_sync = enabled;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PREFIX: &str = "textures/SLUS-21214/";
+
+    fn compare_file(status: &str, filename: &str, previous_filename: Option<&str>) -> CompareFile {
+        CompareFile {
+            filename: filename.to_string(),
+            status: status.to_string(),
+            previous_filename: previous_filename.map(|p| p.to_string()),
+            sha: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+        }
+    }
+
+    #[test]
+    fn download_temp_paths_keep_the_extension() {
+        assert_eq!(download_temp_path(Path::new("uniforms/home.png")), Path::new("uniforms/home.png.download-tmp"));
+        assert_ne!(download_temp_path(Path::new("home.png")), download_temp_path(Path::new("home.dds")));
+    }
+
+    #[test]
+    fn content_matches_sha_accepts_crlf_checkouts() {
+        let sha = compute_sha_for_content(b"a\nb\n");
+        assert!(content_matches_sha(b"a\nb\n", &sha));
+        assert!(content_matches_sha(b"a\r\nb\r\n", &sha));
+        assert!(!content_matches_sha(b"a\nb", &sha));
+    }
+
+    #[test]
+    fn moved_files_pair_each_deletion_once() {
+        let downloads = [Some("aaa"), Some("bbb"), Some("aaa"), None];
+        let deletes = [Some("ccc"), Some("aaa"), None, Some("bbb")];
+        assert_eq!(pair_moved_files(&downloads, &deletes), vec![(0, 1), (1, 3)]);
+    }
+
+    #[test]
+    fn added_is_downloaded() {
+        let file = compare_file("added", "textures/SLUS-21214/a/new.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn modified_is_downloaded() {
+        let file = compare_file("modified", "textures/SLUS-21214/a/tex.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn changed_is_downloaded() {
+        let file = compare_file("changed", "textures/SLUS-21214/a/tex.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn removed_is_deleted() {
+        let file = compare_file("removed", "textures/SLUS-21214/a/old.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Delete);
+    }
+
+    #[test]
+    fn renamed_within_pack_is_moved() {
+        let file = compare_file("renamed", "textures/SLUS-21214/b/tex.png", Some("textures/SLUS-21214/a/tex.png"));
+        assert_eq!(
+            plan_incremental_action(&file, PREFIX, &[]),
+            IncrementalAction::Rename { from: "a/tex.png".to_string() }
+        );
+    }
+
+    #[test]
+    fn renamed_into_pack_is_downloaded() {
+        let file = compare_file("renamed", "textures/SLUS-21214/b/tex.png", Some("staging/tex.png"));
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn renamed_from_protected_path_is_downloaded() {
+        let file = compare_file("renamed", "textures/SLUS-21214/b/tex.png", Some("textures/SLUS-21214/a/tex.png"));
+        let protected_paths = vec!["a".to_string()];
+        assert_eq!(plan_incremental_action(&file, PREFIX, &protected_paths), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn copied_within_pack_is_copied() {
+        let file = compare_file("copied", "textures/SLUS-21214/b/tex.png", Some("textures/SLUS-21214/a/tex.png"));
+        assert_eq!(
+            plan_incremental_action(&file, PREFIX, &[]),
+            IncrementalAction::Copy { from: "a/tex.png".to_string() }
+        );
+    }
+
+    #[test]
+    fn copied_into_pack_is_downloaded() {
+        let file = compare_file("copied", "textures/SLUS-21214/b/tex.png", Some("staging/tex.png"));
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn copied_without_source_is_downloaded() {
+        let file = compare_file("copied", "textures/SLUS-21214/b/tex.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Download);
+    }
+
+    #[test]
+    fn unchanged_is_skipped() {
+        let file = compare_file("unchanged", "textures/SLUS-21214/a/tex.png", None);
+        assert_eq!(plan_incremental_action(&file, PREFIX, &[]), IncrementalAction::Skip);
+    }
+
+    fn change(status: &str, filename: &str, previous_filename: Option<&str>) -> CompareFile {
+        compare_file(status, &format!("{}{}", PREFIX, filename), previous_filename.map(|p| format!("{}{}", PREFIX, p)).as_deref())
+    }
+
+    fn statuses(files: &[CompareFile]) -> Vec<(String, String)> {
+        files
+            .iter()
+            .map(|f| (f.filename.trim_start_matches(PREFIX).to_string(), f.status.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn aggregate_keeps_last_change_per_path() {
+        let files = aggregate_commit_changes(vec![
+            vec![change("added", "a.png", None)],
+            vec![change("modified", "a.png", None), change("added", "b.png", None)],
+            vec![change("removed", "b.png", None)],
+        ]);
+        assert_eq!(
+            statuses(&files),
+            vec![("a.png".to_string(), "modified".to_string()), ("b.png".to_string(), "removed".to_string())]
+        );
+    }
+
+    #[test]
+    fn aggregate_keeps_untouched_rename() {
+        let files = aggregate_commit_changes(vec![vec![change("renamed", "b.png", Some("a.png"))]]);
+        assert_eq!(statuses(&files), vec![("b.png".to_string(), "renamed".to_string())]);
+        assert_eq!(files[0].previous_filename.as_deref(), Some("textures/SLUS-21214/a.png"));
+    }
+
+    #[test]
+    fn aggregate_splits_rename_when_old_path_is_reused() {
+        let files = aggregate_commit_changes(vec![
+            vec![change("renamed", "b.png", Some("a.png"))],
+            vec![change("added", "a.png", None)],
+        ]);
+        assert_eq!(
+            statuses(&files),
+            vec![("a.png".to_string(), "added".to_string()), ("b.png".to_string(), "added".to_string())]
+        );
+    }
+
+    #[test]
+    fn aggregate_splits_chained_renames() {
+        let files = aggregate_commit_changes(vec![
+            vec![change("renamed", "b.png", Some("a.png"))],
+            vec![change("renamed", "c.png", Some("b.png"))],
+        ]);
+        // a -> b no longer exists as a rename, so a is deleted; b -> c is kept (and downloads if b is missing)
+        assert_eq!(
+            statuses(&files),
+            vec![("a.png".to_string(), "removed".to_string()), ("c.png".to_string(), "renamed".to_string())]
+        );
+    }
+
+    #[test]
+    fn protected_paths_cover_their_contents() {
+        let protected = vec!["my-mix".to_string(), "a/keep.png".to_string()];
+        assert!(should_skip_path("my-mix", &protected));
+        assert!(should_skip_path("my-mix/b/tex.png", &protected));
+        assert!(should_skip_path("a/keep.png", &protected));
+        assert!(!should_skip_path("my-mix-2/tex.png", &protected));
+        assert!(!should_skip_path("a/keep.png.bak", &protected));
+        assert!(!should_skip_path("a/other.png", &protected));
+    }
+
+    #[test]
+    fn small_syncs_download_per_file() {
+        assert_eq!(choose_download_strategy(10, 1024, 1024), DownloadStrategy::PerFile);
+        assert_eq!(choose_download_strategy(ARCHIVE_MIN_FILES - 1, 0, 0), DownloadStrategy::PerFile);
+    }
+
+    #[test]
+    fn many_files_use_archive_when_cheaper() {
+        let pack_bytes = 4 * 1024 * 1024 * 1024;
+        assert_eq!(choose_download_strategy(10_000, 1024 * 1024 * 1024, pack_bytes), DownloadStrategy::Archive);
+        assert_eq!(choose_download_strategy(ARCHIVE_MIN_FILES, 1024, pack_bytes), DownloadStrategy::PerFile);
+    }
+
+    #[test]
+    fn disk_delta_counts_replacements_as_removed() {
+        let sync_file = |path: &str| SyncFile { path: path.to_string(), to_disabled: false, sha: None };
+        let remote_sizes = HashMap::from([("a.png".to_string(), 100), ("b.png".to_string(), 40)]);
+        let local_sizes = HashMap::from([("b.png", 60), ("old.png", 30)]);
+
+        let delta = compute_disk_delta(
+            &[sync_file("a.png"), sync_file("b.png"), sync_file("c.png")],
+            &["old.png".to_string()],
+            &remote_sizes,
+            DisableConvention::default(),
+            |path| local_sizes.get(path).copied().unwrap_or(0),
+        );
+
+        assert_eq!(delta, DiskDelta { bytes_added: 140, bytes_removed: 90, net_bytes: 50, unknown_sizes: 1 });
+    }
+
+    #[test]
+    fn days_between_commit_dates() {
+        assert_eq!(days_between("2024-01-01T12:00:00Z", "2024-01-31T11:00:00Z"), Some(29));
+        assert_eq!(days_between("2024-01-31T00:00:00Z", "2024-01-01T00:00:00Z"), Some(0));
+        assert_eq!(days_between("", "2024-01-01T00:00:00Z"), None);
+    }
+}
@@ -0,0 +1,97 @@
+use crate::commands::sync::{SyncFailure, SyncFile};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// Number of plan entries applied between journal writes
+pub const SYNC_JOURNAL_CHUNK_SIZE: usize = 50;
+
+/// Persisted plan for an in-progress analyzed sync
+/// Completed entries are removed after each chunk so a crashed sync can resume where it stopped
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncJournal {
+    pub textures_dir: String,
+    /// Repository the plan was built against (guards against resuming after a profile switch)
+    pub repo_url: String,
+    pub commit_sha: String,
+    /// Files still to download (adds and replacements)
+    pub files_to_download: Vec<SyncFile>,
+    /// Files still to delete
+    pub files_to_delete: Vec<String>,
+    /// Files already downloaded by earlier chunks
+    pub files_downloaded: u32,
+    /// Files already deleted by earlier chunks
+    pub files_deleted: u32,
+    /// Files that failed in earlier chunks (retried once at the end)
+    #[serde(default)]
+    pub failed_files: Vec<SyncFailure>,
+    pub started_at: String,
+}
+
+fn get_journal_path(app: &AppHandle) -> Result<PathBuf, String> {
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data directory: {}", e))?;
+
+    fs::create_dir_all(&app_data_dir)
+        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+
+    Ok(app_data_dir.join("sync-journal.json"))
+}
+
+/// Load the pending sync journal, if any
+pub fn load_sync_journal(app: &AppHandle) -> Result<Option<SyncJournal>, String> {
+    let journal_path = get_journal_path(app)?;
+
+    if !journal_path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(&journal_path)
+        .map_err(|e| format!("Failed to read sync journal: {}", e))?;
+
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse sync journal: {}", e))
+}
+
+/// Write the journal atomically (temp file + rename)
+pub fn save_sync_journal(app: &AppHandle, journal: &SyncJournal) -> Result<(), String> {
+    let journal_path = get_journal_path(app)?;
+    let temp_path = journal_path.with_extension("json.tmp");
+
+    let contents = serde_json::to_string(journal)
+        .map_err(|e| format!("Failed to serialize sync journal: {}", e))?;
+
+    fs::write(&temp_path, contents)
+        .map_err(|e| format!("Failed to write sync journal: {}", e))?;
+
+    fs::rename(&temp_path, &journal_path)
+        .map_err(|e| format!("Failed to write sync journal: {}", e))
+}
+
+/// Remove the journal once a sync completes (no-op if it doesn't exist)
+pub fn clear_sync_journal(app: &AppHandle) -> Result<(), String> {
+    let journal_path = get_journal_path(app)?;
+
+    if journal_path.exists() {
+        fs::remove_file(&journal_path)
+            .map_err(|e| format!("Failed to remove sync journal: {}", e))?;
+    }
+
+    Ok(())
+}
+
+/// Get the interrupted sync plan, if one is waiting to be resumed
+#[tauri::command]
+pub fn get_pending_sync(app: AppHandle) -> Result<Option<SyncJournal>, String> {
+    load_sync_journal(&app)
+}
+
+/// Discard an interrupted sync plan
+#[tauri::command]
+pub fn discard_pending_sync(app: AppHandle) -> Result<(), String> {
+    clear_sync_journal(&app)
+}
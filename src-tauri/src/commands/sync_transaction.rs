@@ -0,0 +1,204 @@
+//! Transactional sync through a staging folder, with a backup folder to roll back to
+
+use crate::commands::filesystem::delete_path;
+use crate::commands::repo_paths::check_repo_path;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Folder (in the textures directory) downloads are staged in
+pub(crate) const STAGING_DIR_NAME: &str = "_ncaa_sync_staging";
+
+/// Folder (in the textures directory) replaced and deleted files are kept in until the swap is done
+pub(crate) const BACKUP_DIR_NAME: &str = "_ncaa_sync_backup";
+
+/// File in the backup folder listing the files the swap added, one path per line
+/// Written before each one is moved in, so restoring a backup can take them out again
+const ADDED_LIST_NAME: &str = ".added-files";
+
+/// A change made to the SLUS folder by the swap, undone in reverse order on rollback
+enum SwapChange {
+    /// New file with nothing at its path before
+    Added(String),
+    /// Existing file, original moved to the backup folder
+    Replaced(String),
+    /// Deleted file, moved to the backup folder
+    Removed(String),
+}
+
+pub struct SyncTransaction {
+    slus_path: PathBuf,
+    staging_dir: PathBuf,
+    backup_dir: PathBuf,
+    applied: Vec<SwapChange>,
+}
+
+/// Move a file, creating the destination's parent folders
+fn move_file(from: &Path, to: &Path) -> Result<(), String> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    fs::rename(from, to).map_err(|e| format!("Failed to move {} to {}: {}", from.display(), to.display(), e))
+}
+
+/// Relative paths of every file below a folder
+fn files_below(root: &Path, dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files_below(root, &path, files);
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.push(relative.to_path_buf());
+        }
+    }
+}
+
+impl SyncTransaction {
+    /// Start a transaction for the SLUS folder in `textures_dir`
+    /// Backups left by a swap that was cut off are restored first, and old staged files dropped
+    pub fn begin(textures_dir: &Path, slus_path: &Path) -> Result<Self, String> {
+        let transaction = SyncTransaction {
+            slus_path: slus_path.to_path_buf(),
+            staging_dir: textures_dir.join(STAGING_DIR_NAME),
+            backup_dir: textures_dir.join(BACKUP_DIR_NAME),
+            applied: Vec::new(),
+        };
+
+        if transaction.backup_dir.exists() {
+            transaction.restore_leftover_backup()?;
+        }
+        if transaction.staging_dir.exists() {
+            fs::remove_dir_all(&transaction.staging_dir)
+                .map_err(|e| format!("Failed to clear the staging folder: {}", e))?;
+        }
+        fs::create_dir_all(&transaction.staging_dir)
+            .map_err(|e| format!("Failed to create the staging folder: {}", e))?;
+
+        Ok(transaction)
+    }
+
+    /// Where the file for a path (relative to the SLUS folder) is staged
+    pub fn staged_path(&self, relative_path: &str) -> PathBuf {
+        self.staging_dir.join(relative_path)
+    }
+
+    /// Undo a swap that was cut off: remove the files it added and put back every backed-up file
+    fn restore_leftover_backup(&self) -> Result<(), String> {
+        let added_list = self.backup_dir.join(ADDED_LIST_NAME);
+        if let Ok(contents) = fs::read_to_string(&added_list) {
+            for relative in contents.lines().filter(|line| check_repo_path(line).is_ok()) {
+                let path = self.slus_path.join(relative);
+                if path.is_file() {
+                    fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", relative, e))?;
+                }
+            }
+            fs::remove_file(&added_list).map_err(|e| format!("Failed to remove the list of added files: {}", e))?;
+        }
+
+        let mut files = Vec::new();
+        files_below(&self.backup_dir, &self.backup_dir, &mut files);
+        for relative in files {
+            move_file(&self.backup_dir.join(&relative), &self.slus_path.join(&relative))?;
+        }
+        fs::remove_dir_all(&self.backup_dir)
+            .map_err(|e| format!("Failed to remove the sync backup folder: {}", e))
+    }
+
+    /// Swap the staged files into the SLUS folder and delete `deletions`, all or nothing
+    /// `staged` are the paths (relative to the SLUS folder) passed to staged_path
+    /// On failure every change made so far is undone and the error returned
+    /// Once it succeeded, deleted files go to the Recycle Bin/Trash unless `delete_permanently`
+    pub fn commit(mut self, staged: &[String], deletions: &[String], delete_permanently: bool) -> Result<(), String> {
+        if let Err(e) = self.swap(staged, deletions) {
+            return Err(match self.rollback() {
+                Ok(()) => format!("{}. The sync was rolled back, no files were changed", e),
+                Err(rollback_error) => format!(
+                    "{}. Rolling back also failed ({}), the original files are in {}",
+                    e,
+                    rollback_error,
+                    self.backup_dir.display()
+                ),
+            });
+        }
+
+        // Everything is in place, the originals are no longer needed
+        let _ = fs::remove_file(self.backup_dir.join(ADDED_LIST_NAME));
+        for change in &self.applied {
+            if let SwapChange::Removed(relative) = change {
+                let _ = delete_path(&self.backup_dir.join(relative), delete_permanently);
+            }
+        }
+        let _ = fs::remove_dir_all(&self.backup_dir);
+        Ok(())
+    }
+
+    fn swap(&mut self, staged: &[String], deletions: &[String]) -> Result<(), String> {
+        for relative in staged {
+            let dest = self.slus_path.join(relative);
+            if dest.exists() {
+                move_file(&dest, &self.backup_dir.join(relative))?;
+                self.applied.push(SwapChange::Replaced(relative.clone()));
+            } else {
+                self.record_added(relative)?;
+                self.applied.push(SwapChange::Added(relative.clone()));
+            }
+            move_file(&self.staged_path(relative), &dest)?;
+        }
+
+        for relative in deletions {
+            let path = self.slus_path.join(relative);
+            if path.exists() {
+                move_file(&path, &self.backup_dir.join(relative))?;
+                self.applied.push(SwapChange::Removed(relative.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Note a file the swap is about to add, for restore_leftover_backup after a crash
+    fn record_added(&self, relative: &str) -> Result<(), String> {
+        fs::create_dir_all(&self.backup_dir).map_err(|e| format!("Failed to create the sync backup folder: {}", e))?;
+        let mut list = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.backup_dir.join(ADDED_LIST_NAME))
+            .map_err(|e| format!("Failed to record added files: {}", e))?;
+        writeln!(list, "{}", relative)
+            .and_then(|_| list.sync_data())
+            .map_err(|e| format!("Failed to record added files: {}", e))
+    }
+
+    /// Undo the swap, newest change first
+    fn rollback(&mut self) -> Result<(), String> {
+        while let Some(change) = self.applied.pop() {
+            match change {
+                SwapChange::Added(relative) => {
+                    let path = self.slus_path.join(&relative);
+                    if path.exists() {
+                        fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", relative, e))?;
+                    }
+                }
+                SwapChange::Replaced(relative) | SwapChange::Removed(relative) => {
+                    let original = self.backup_dir.join(&relative);
+                    // A replacement may have failed before its original was moved away
+                    if original.exists() {
+                        move_file(&original, &self.slus_path.join(&relative))?;
+                    }
+                }
+            }
+        }
+        let _ = fs::remove_dir_all(&self.backup_dir);
+        Ok(())
+    }
+}
+
+impl Drop for SyncTransaction {
+    fn drop(&mut self) {
+        // Staged files are never reused: a new transaction downloads again (from the blob cache)
+        let _ = fs::remove_dir_all(&self.staging_dir);
+    }
+}
@@ -0,0 +1,118 @@
+//! When the pack was last verified and what was found, kept in state per profile
+
+use crate::commands::state::{load_state, with_state_mut};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::AppHandle;
+
+/// Days after which another verification is suggested
+const REVERIFY_AFTER_DAYS: i64 = 30;
+
+/// Kind of check that was run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationMode {
+    /// Every file hashed against the repository
+    Full,
+    /// A full check of one folder
+    Folder,
+    /// File counts plus a small sample of hashes
+    Quick,
+    /// Sizes compared to find empty and cut-off files
+    Truncated,
+}
+
+/// Summary of the last verification (stored in state)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastVerification {
+    /// When it finished (ISO 8601 UTC)
+    pub timestamp: String,
+    pub mode: VerificationMode,
+    /// Folder checked by a folder verification
+    #[serde(default)]
+    pub folder: Option<String>,
+    /// Files it found missing or different
+    pub files_to_download: usize,
+    /// Files it found that aren't in the repository
+    pub files_to_delete: usize,
+    /// Whether the fixes were applied afterwards
+    #[serde(default)]
+    pub fixes_applied: bool,
+    /// Files that still failed when the fixes were applied
+    #[serde(default)]
+    pub fixes_failed: usize,
+}
+
+impl LastVerification {
+    /// Whether the pack was in order after this verification (and its fixes)
+    pub fn all_good(&self) -> bool {
+        if self.fixes_applied {
+            self.fixes_failed == 0
+        } else {
+            self.files_to_download == 0 && self.files_to_delete == 0
+        }
+    }
+}
+
+/// Last verification plus how long ago it was
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationStatus {
+    pub last: Option<LastVerification>,
+    pub days_since: Option<i64>,
+    pub all_good: bool,
+    /// No verification yet, or the last one is older than REVERIFY_AFTER_DAYS
+    pub reverify_suggested: bool,
+}
+
+/// Remember a verification that just finished
+/// Bookkeeping only, so failing to save the state doesn't fail the verification
+pub(crate) fn record_verification(
+    app: &AppHandle,
+    mode: VerificationMode,
+    folder: Option<&str>,
+    files_to_download: usize,
+    files_to_delete: usize,
+) {
+    let _ = with_state_mut(app, |state| {
+        state.last_verification = Some(LastVerification {
+            timestamp: Utc::now().to_rfc3339(),
+            mode,
+            folder: folder.map(|f| f.trim_end_matches('/').to_string()),
+            files_to_download,
+            files_to_delete,
+            fixes_applied: false,
+            fixes_failed: 0,
+        });
+        Ok(())
+    });
+}
+
+/// Note that the fixes of the last verification were applied, and how many files still failed
+pub(crate) fn record_fixes_applied(app: &AppHandle, fixes_failed: usize) {
+    let _ = with_state_mut(app, |state| {
+        if let Some(last) = state.last_verification.as_mut() {
+            last.fixes_applied = true;
+            last.fixes_failed = fixes_failed;
+        }
+        Ok(())
+    });
+}
+
+pub(crate) fn verification_status(last: Option<LastVerification>, now: DateTime<Utc>) -> VerificationStatus {
+    let days_since = last
+        .as_ref()
+        .and_then(|last| DateTime::parse_from_rfc3339(&last.timestamp).ok())
+        .map(|timestamp| (now - timestamp.with_timezone(&Utc)).num_days());
+    VerificationStatus {
+        all_good: last.as_ref().is_some_and(LastVerification::all_good),
+        reverify_suggested: days_since.is_none_or(|days| days >= REVERIFY_AFTER_DAYS),
+        days_since,
+        last,
+    }
+}
+
+/// Get the last verification of the active pack and whether another one is due
+#[tauri::command]
+pub fn get_verification_status(app: AppHandle) -> Result<VerificationStatus, String> {
+    Ok(verification_status(load_state(app)?.last_verification, Utc::now()))
+}
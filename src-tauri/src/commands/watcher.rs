@@ -0,0 +1,190 @@
+//! Watches the SLUS folder while the app is open to detect changes made by other programs
+
+use crate::commands::file_index::FileIndex;
+use crate::commands::sync::{load_protected_paths, should_skip_path};
+use crate::config::resolve_repo_config;
+use chrono::Utc;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+
+/// Active watcher (only the active pack's folder is watched)
+static WATCHER: Mutex<Option<(RecommendedWatcher, PathBuf)>> = Mutex::new(None);
+
+/// Changes detected since the last acknowledgement
+static LOCAL_CHANGES: Mutex<Option<LocalChanges>> = Mutex::new(None);
+
+/// Number of app operations currently writing to the SLUS folder (their changes aren't drift)
+static SUPPRESS_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// When the last suppressing operation finished
+static SUPPRESS_ENDED: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// Protected paths the watcher ignores, loaded on the first event and replaced when the state is saved
+static PROTECTED_PATHS: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+/// Events arriving this soon after an app operation are still attributed to it (delivery is delayed)
+const SUPPRESS_GRACE: Duration = Duration::from_secs(3);
+
+/// Summary of changes made to the install by other programs
+#[derive(Debug, Clone, Serialize)]
+pub struct LocalChanges {
+    pub changed_files: usize,
+    /// Most recently changed path (relative to the SLUS folder)
+    pub last_path: String,
+    pub first_detected: String,
+}
+
+/// Ignores watcher events while alive - hold one during any operation that modifies the install
+pub struct DriftSuppressGuard;
+
+impl Drop for DriftSuppressGuard {
+    fn drop(&mut self) {
+        if let Ok(mut ended) = SUPPRESS_ENDED.lock() {
+            *ended = Some(Instant::now());
+        }
+        SUPPRESS_COUNT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Suppress drift detection until the returned guard is dropped
+pub fn suppress_drift_detection() -> DriftSuppressGuard {
+    SUPPRESS_COUNT.fetch_add(1, Ordering::SeqCst);
+    DriftSuppressGuard
+}
+
+/// Whether a sync, install or repair is writing to the install right now
+pub(crate) fn install_writes_in_progress() -> bool {
+    SUPPRESS_COUNT.load(Ordering::SeqCst) > 0
+}
+
+/// Get changes detected since the last acknowledgement, if any
+pub fn detected_local_changes() -> Option<LocalChanges> {
+    LOCAL_CHANGES.lock().ok().and_then(|changes| changes.clone())
+}
+
+/// Whether an app operation is (or was just) writing to the install
+fn is_suppressed() -> bool {
+    if SUPPRESS_COUNT.load(Ordering::SeqCst) > 0 {
+        return true;
+    }
+    matches!(SUPPRESS_ENDED.lock().ok().and_then(|ended| *ended), Some(ended) if ended.elapsed() < SUPPRESS_GRACE)
+}
+
+/// Replace the protected paths the watcher ignores (None reloads them from the state on the next event)
+pub(crate) fn set_watched_protected_paths(paths: Option<Vec<String>>) {
+    if let Ok(mut cached) = PROTECTED_PATHS.lock() {
+        *cached = paths;
+    }
+}
+
+/// Protected paths from the cache, reading the state only when nothing is cached
+fn watched_protected_paths(app: &AppHandle) -> Vec<String> {
+    if let Some(paths) = PROTECTED_PATHS.lock().ok().and_then(|cached| cached.clone()) {
+        return paths;
+    }
+    let paths = load_protected_paths(app);
+    if let Ok(mut cached) = PROTECTED_PATHS.lock() {
+        // A save that landed meanwhile has the newer list
+        cached.get_or_insert_with(|| paths.clone());
+    }
+    paths
+}
+
+fn handle_event(app: &AppHandle, slus_path: &Path, event: Event) {
+    if matches!(event.kind, EventKind::Access(_)) || is_suppressed() {
+        return;
+    }
+
+    let protected_paths = watched_protected_paths(app);
+    let relative_paths: Vec<String> = event
+        .paths
+        .iter()
+        .filter_map(|p| p.strip_prefix(slus_path).ok())
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .filter(|p| !p.is_empty() && !should_skip_path(p, &protected_paths))
+        .collect();
+
+    if relative_paths.is_empty() {
+        return;
+    }
+
+    // Force the index to re-stat and re-hash these files on next use
+    if let Ok(index) = FileIndex::open(slus_path) {
+        for path in &relative_paths {
+            let _ = index.invalidate(path);
+        }
+    }
+
+    let Ok(mut changes) = LOCAL_CHANGES.lock() else {
+        return;
+    };
+    let last_path = relative_paths[relative_paths.len() - 1].clone();
+    let updated = match changes.take() {
+        Some(existing) => LocalChanges {
+            changed_files: existing.changed_files + relative_paths.len(),
+            last_path,
+            first_detected: existing.first_detected,
+        },
+        None => LocalChanges {
+            changed_files: relative_paths.len(),
+            last_path,
+            first_detected: Utc::now().to_rfc3339(),
+        },
+    };
+    let _ = app.emit("local-changes-detected", &updated);
+    *changes = Some(updated);
+}
+
+/// Start watching the active pack's SLUS folder (replaces any previous watch)
+#[tauri::command]
+pub fn start_folder_watch(app: AppHandle, textures_dir: String) -> Result<(), String> {
+    let config = resolve_repo_config(&app)?;
+    let slus_path = PathBuf::from(&textures_dir).join(&config.slus_folder);
+
+    let mut guard = WATCHER.lock().map_err(|_| "Folder watcher state is poisoned".to_string())?;
+    if matches!(guard.as_ref(), Some((_, watched)) if *watched == slus_path) {
+        return Ok(());
+    }
+    *guard = None;
+
+    if !slus_path.is_dir() {
+        return Ok(());
+    }
+
+    let handler_app = app.clone();
+    let handler_path = slus_path.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            handle_event(&handler_app, &handler_path, event);
+        }
+    })
+    .map_err(|e| format!("Failed to create folder watcher: {}", e))?;
+
+    watcher
+        .watch(&slus_path, RecursiveMode::Recursive)
+        .map_err(|e| format!("Failed to watch {}: {}", slus_path.display(), e))?;
+
+    *guard = Some((watcher, slus_path));
+    Ok(())
+}
+
+/// Stop watching the SLUS folder
+#[tauri::command]
+pub fn stop_folder_watch() -> Result<(), String> {
+    let mut guard = WATCHER.lock().map_err(|_| "Folder watcher state is poisoned".to_string())?;
+    *guard = None;
+    Ok(())
+}
+
+/// Clear the "local changes detected" flag
+#[tauri::command]
+pub fn acknowledge_local_changes() -> Result<(), String> {
+    let mut changes = LOCAL_CHANGES.lock().map_err(|_| "Folder watcher state is poisoned".to_string())?;
+    *changes = None;
+    Ok(())
+}
@@ -1,25 +1,249 @@
-// Configurable constants for the PS2 Textures Downloader
-// Modify these values to adapt this app for other PS2 texture mod projects
-// Note: Also update frontend/config.ts to match these values
+// Configuration for the PS2 Textures Downloader
+// Repository values are loaded from repo-config.json (bundled at build time) and can be
+// overridden at runtime through the app state, so forks for other mods don't need to touch code
+// Note: Also update frontend/config.ts to match the bundled values
+
+use crate::commands::repo_paths::check_repo_path;
+use crate::commands::{active_profile, load_state};
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, OnceLock};
+use tauri::AppHandle;
 
 /// Application title (also update in tauri.conf.json and frontend/config.ts)
 #[allow(dead_code)]
 pub const APP_TITLE: &str = "NCAA NEXT Textures Downloader";
 
-/// Repository owner (GitHub username or organization)
-pub const REPO_OWNER: &str = "ncaanext";
+/// Central catalog listing all supported texture packs
+pub const PACK_CATALOG_URL: &str =
+    "https://raw.githubusercontent.com/ncaanext/ncaanext-textures-downloader-v2/main/pack-catalog.json";
+
+/// Default base URL of the GitHub REST API
+pub const DEFAULT_API_BASE_URL: &str = "https://api.github.com";
+
+/// Default base URL of raw file content
+pub const DEFAULT_RAW_BASE_URL: &str = "https://raw.githubusercontent.com";
+
+/// Code hosting software serving the pack repository
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Forge {
+    /// github.com
+    #[default]
+    #[serde(rename = "github")]
+    GitHub,
+    /// Self-hosted GitHub Enterprise (api_base_url "https://host/api/v3", raw_base_url "https://host/raw")
+    #[serde(rename = "github_enterprise")]
+    GitHubEnterprise,
+    /// Gitea or Forgejo (api_base_url "https://host/api/v1", raw_base_url "https://host")
+    Gitea,
+}
+
+impl Forge {
+    /// Authorization header value for an access token
+    pub fn authorization(self, token: &str) -> String {
+        match self {
+            Forge::GitHub | Forge::GitHubEnterprise => format!("Bearer {}", token),
+            Forge::Gitea => format!("token {}", token),
+        }
+    }
+
+    /// Query parameter setting the page size of list endpoints
+    pub fn page_size_param(self) -> &'static str {
+        match self {
+            Forge::GitHub | Forge::GitHubEnterprise => "per_page",
+            Forge::Gitea => "limit",
+        }
+    }
+
+    /// Endpoint of a single commit, relative to the repository API URL
+    pub fn commit_endpoint(self) -> &'static str {
+        match self {
+            Forge::GitHub | Forge::GitHubEnterprise => "commits",
+            Forge::Gitea => "git/commits",
+        }
+    }
+
+    /// Endpoint of a gzipped tarball of the repository at a commit, relative to the repository API URL
+    pub fn archive_endpoint(self, reference: &str) -> String {
+        match self {
+            Forge::GitHub | Forge::GitHubEnterprise => format!("tarball/{}", reference),
+            Forge::Gitea => format!("archive/{}.tar.gz", reference),
+        }
+    }
+
+    /// Whether the compare endpoint lists changed files (otherwise changes are walked commit by commit)
+    pub fn compare_lists_files(self) -> bool {
+        !matches!(self, Forge::Gitea)
+    }
+}
+
+/// Bundled repository configuration
+const BUNDLED_REPO_CONFIG: &str = include_str!("../repo-config.json");
+
+/// Repository configuration for the texture pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoConfig {
+    /// Repository owner (GitHub username or organization)
+    pub repo_owner: String,
+    /// Name of the texture mod repository
+    pub repo_name: String,
+    /// Full URL to the git repository
+    pub repo_url: String,
+    /// Branch to install and sync from
+    pub branch: String,
+    /// The target folder name (typically the PS2 game identifier like SLUS-XXXXX)
+    pub slus_folder: String,
+    /// Path within the repo to sparse checkout
+    pub sparse_path: String,
+    /// Temporary directory name used during clone
+    pub temp_dir_name: String,
+    /// Base URL of the REST API (for self-hosted mirrors and test servers)
+    #[serde(default = "default_api_base_url")]
+    pub api_base_url: String,
+    /// Base URL of raw file content (the layout after it depends on the forge)
+    #[serde(default = "default_raw_base_url")]
+    pub raw_base_url: String,
+    /// Code hosting software serving the repository
+    #[serde(default)]
+    pub forge: Forge,
+    /// Extra servers hosting the repository's files, tried when raw content is slow or failing
+    #[serde(default)]
+    pub mirrors: Vec<String>,
+    /// Places sparse_path may move to in the repository (e.g. a new season's folder), checked
+    /// when it's gone
+    #[serde(default)]
+    pub sparse_path_aliases: Vec<String>,
+}
+
+fn default_api_base_url() -> String {
+    DEFAULT_API_BASE_URL.to_string()
+}
+
+fn default_raw_base_url() -> String {
+    DEFAULT_RAW_BASE_URL.to_string()
+}
+
+/// Optional per-field overrides of the bundled repository configuration (stored in state)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoConfigOverrides {
+    pub repo_owner: Option<String>,
+    pub repo_name: Option<String>,
+    pub repo_url: Option<String>,
+    pub branch: Option<String>,
+    pub slus_folder: Option<String>,
+    pub sparse_path: Option<String>,
+    pub temp_dir_name: Option<String>,
+    pub api_base_url: Option<String>,
+    pub raw_base_url: Option<String>,
+    pub forge: Option<Forge>,
+    pub mirrors: Option<Vec<String>>,
+}
+
+impl RepoConfig {
+    /// The configuration bundled with the app (parsed once)
+    pub fn bundled() -> &'static RepoConfig {
+        static BUNDLED: OnceLock<RepoConfig> = OnceLock::new();
+        BUNDLED.get_or_init(|| {
+            serde_json::from_str(BUNDLED_REPO_CONFIG).expect("bundled repo-config.json is invalid")
+        })
+    }
+
+    /// Apply overrides on top of this configuration
+    pub fn with_overrides(&self, overrides: &RepoConfigOverrides) -> RepoConfig {
+        let pick = |value: &Option<String>, default: &String| value.clone().unwrap_or_else(|| default.clone());
+        RepoConfig {
+            repo_owner: pick(&overrides.repo_owner, &self.repo_owner),
+            repo_name: pick(&overrides.repo_name, &self.repo_name),
+            repo_url: pick(&overrides.repo_url, &self.repo_url),
+            branch: pick(&overrides.branch, &self.branch),
+            slus_folder: pick(&overrides.slus_folder, &self.slus_folder),
+            sparse_path: pick(&overrides.sparse_path, &self.sparse_path),
+            temp_dir_name: pick(&overrides.temp_dir_name, &self.temp_dir_name),
+            api_base_url: pick(&overrides.api_base_url, &self.api_base_url),
+            raw_base_url: pick(&overrides.raw_base_url, &self.raw_base_url),
+            forge: overrides.forge.unwrap_or(self.forge),
+            mirrors: overrides.mirrors.clone().unwrap_or_else(|| self.mirrors.clone()),
+            sparse_path_aliases: self.sparse_path_aliases.clone(),
+        }
+    }
+
+    /// Check the values that are joined onto local paths (see repo_paths)
+    /// Overrides, profiles and catalog packs are checked with this before they're used
+    pub fn check_paths(&self) -> Result<(), String> {
+        let fields = [
+            ("slus_folder", &self.slus_folder),
+            ("sparse_path", &self.sparse_path),
+            ("temp_dir_name", &self.temp_dir_name),
+        ];
+        let aliases = self.sparse_path_aliases.iter().map(|alias| ("sparse_path_aliases", alias));
+        for (field, value) in fields.into_iter().chain(aliases) {
+            check_repo_path(value).map_err(|reason| format!("Invalid {} {:?} ({})", field, value, reason))?;
+        }
+        Ok(())
+    }
+
+    /// REST API URL of the repository ("<api>/repos/owner/name")
+    pub fn api_repo_url(&self) -> String {
+        format!(
+            "{}/repos/{}/{}",
+            self.api_base_url.trim_end_matches('/'),
+            self.repo_owner,
+            self.repo_name
+        )
+    }
+
+    /// Raw content URL of a file on the configured branch (path relative to the repo root)
+    pub fn raw_url(&self, path: &str) -> String {
+        let base = self.raw_base_url.trim_end_matches('/');
+        match self.forge {
+            Forge::GitHub | Forge::GitHubEnterprise => {
+                format!("{}/{}/{}/{}/{}", base, self.repo_owner, self.repo_name, self.branch, path)
+            }
+            Forge::Gitea => {
+                format!("{}/{}/{}/raw/branch/{}/{}", base, self.repo_owner, self.repo_name, self.branch, path)
+            }
+        }
+    }
+}
 
-/// Name of the texture mod repository
-pub const REPO_NAME: &str = "ncaa-next-26";
+/// Effective configuration, resolved on first use and forgotten whenever the state is saved or deleted
+/// The counter goes up on every change, so a config resolved from state that was replaced
+/// meanwhile isn't cached
+static RESOLVED_CONFIG: Mutex<(u64, Option<RepoConfig>)> = Mutex::new((0, None));
 
-/// Full URL to the git repository
-pub const REPO_URL: &str = "https://github.com/ncaanext/ncaa-next-26.git";
+/// Resolve the effective repository configuration
+/// Uses the active pack profile if one is selected, otherwise the bundled values plus state overrides
+/// Values that are unsafe to join onto local paths are refused (state may predate the checks)
+/// Cached until the state is next saved, as it's needed many times per sync
+pub fn resolve_repo_config(app: &AppHandle) -> Result<RepoConfig, String> {
+    let generation = {
+        let cached = RESOLVED_CONFIG.lock().map_err(|_| "Config cache is unavailable".to_string())?;
+        if let Some(config) = &cached.1 {
+            return Ok(config.clone());
+        }
+        cached.0
+    };
 
-/// The target folder name (typically the PS2 game identifier like SLUS-XXXXX)
-pub const SLUS_FOLDER: &str = "SLUS-21214";
+    let state = load_state(app.clone())?;
+    let config = match (active_profile(&state), &state.repo_config_overrides) {
+        (Some(profile), _) => profile.config.clone(),
+        (None, Some(overrides)) => RepoConfig::bundled().with_overrides(overrides),
+        (None, None) => RepoConfig::bundled().clone(),
+    };
+    config.check_paths()?;
 
-/// Path within the repo to sparse checkout
-pub const SPARSE_PATH: &str = "textures/SLUS-21214";
+    if let Ok(mut cached) = RESOLVED_CONFIG.lock() {
+        if cached.0 == generation {
+            cached.1 = Some(config.clone());
+        }
+    }
+    Ok(config)
+}
 
-/// Temporary directory name used during clone
-pub const TEMP_DIR_NAME: &str = "_temp_ncaa_repo";
+/// Drop the cached configuration (called by save_state and delete_state_files)
+pub(crate) fn forget_resolved_repo_config() {
+    if let Ok(mut cached) = RESOLVED_CONFIG.lock() {
+        cached.0 += 1;
+        cached.1 = None;
+    }
+}
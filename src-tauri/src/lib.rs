@@ -1,35 +1,95 @@
 mod commands;
 mod config;
+mod secrets;
+
+use tauri::Manager;
 
 use commands::{
-    backup_existing_folder, check_existing_folder, check_git_installed, cleanup_processes,
-    delete_existing_folder, get_git_error, start_installation, validate_directory,
+    backup_existing_folder, check_existing_folder, inspect_existing_folder, check_git_installed, shutdown_git_operations,
+    delete_existing_folder, set_delete_permanently, cancel_backup, estimate_folder_size, reveal_in_file_manager, get_disk_usage, remove_disk_usage_entry, uninstall_pack, get_pack_health, get_git_status, start_installation, install_and_verify, detect_partial_install, repair_install, validate_directory,
+    get_git_info,
     // State management
     load_state, save_state, set_textures_path, mark_setup_complete,
-    update_last_sync_commit, set_initial_setup_done, set_github_token,
-    set_sync_disclaimer_acknowledged,
+    update_last_sync_commit, set_initial_setup_done, set_github_token, get_github_token,
+    set_sync_disclaimer_acknowledged, export_settings, import_settings, backup_state, restore_state, reset_app,
+    get_repo_config, set_repo_config_overrides,
+    // Pack profiles
+    list_profiles, add_profile, remove_profile, switch_profile,
+    detect_sparse_path_relocation, apply_sparse_path_relocation,
     // Sync
-    get_latest_commit, run_sync, check_sync_status,
-    run_verification_scan, apply_verification_fixes, run_quick_count_check,
-    analyze_full_sync, execute_analyzed_sync,
+    get_latest_commit, run_sync, check_sync_status, get_whats_new, measure_download_speed, get_protected_paths, set_protected_paths,
+    set_transactional_sync, preview_disk_delta,
+    run_verification_scan, verify_folder, scan_truncated_files, apply_verification_fixes, run_quick_count_check,
+    analyze_full_sync, execute_analyzed_sync, resume_analyzed_sync, get_pending_sync, discard_pending_sync,
+    get_verification_status,
+    generate_manifest, verify_against_manifest,
+    // Progress events
+    get_operation_status,
+    // Pack source
+    get_pack_source, set_pack_source,
+    // Download cache
+    get_cache_info, set_cache_size_limit, clear_cache,
+    // Bandwidth
+    get_download_rate_limit, set_download_rate_limit, get_download_connections, set_download_connections,
+    // Network
+    check_connectivity, set_prefer_ipv4, set_ca_bundle, set_http_tracing,
+    // Cleanup
+    get_junk_rules, set_junk_rules, preview_cleanup, run_cleanup,
+    // Disabled textures
+    get_disable_convention, set_disable_convention, get_disabled_textures, restore_disabled_textures,
+    // Hardlink dedup
+    dedup_installs,
+    // Local file index
+    search_local_files, get_local_file_stats, list_disabled_files, refresh_file_index,
+    // Drift detection
+    start_folder_watch, stop_folder_watch, acknowledge_local_changes,
+    // LAN sharing
+    start_lan_share, stop_lan_share, get_lan_share_status, analyze_lan_peer, install_from_lan_peer,
+    // Auto-sync
+    start_auto_sync, cancel_auto_sync, get_auto_sync_settings, set_auto_sync_settings,
+    get_auto_verify_settings, set_auto_verify_settings, get_full_sync_policy, set_full_sync_policy,
     // App info
-    get_app_version, fetch_installer_data, compare_versions,
+    get_app_info, fetch_installer_data, compare_versions, check_version_compatibility, fetch_contributors,
+    // Pack catalog
+    fetch_pack_catalog, add_profile_from_catalog,
 };
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        // Registered first: a second launch hands over to the running app and exits, so two
+        // copies never sync the same folder or write the state file at once
+        .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }))
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .invoke_handler(tauri::generate_handler![
             check_existing_folder,
+            inspect_existing_folder,
             backup_existing_folder,
+            cancel_backup,
+            estimate_folder_size,
+            reveal_in_file_manager,
+            get_disk_usage,
+            remove_disk_usage_entry,
+            uninstall_pack,
+            get_pack_health,
             delete_existing_folder,
+            set_delete_permanently,
             validate_directory,
             check_git_installed,
-            get_git_error,
+            get_git_status,
+            get_git_info,
             start_installation,
+            install_and_verify,
+            detect_partial_install,
+            repair_install,
             // State management
             load_state,
             save_state,
@@ -38,25 +98,119 @@ pub fn run() {
             update_last_sync_commit,
             set_initial_setup_done,
             set_github_token,
+            get_github_token,
             set_sync_disclaimer_acknowledged,
+            export_settings,
+            import_settings,
+            backup_state,
+            restore_state,
+            reset_app,
+            get_repo_config,
+            set_repo_config_overrides,
+            // Pack profiles
+            list_profiles,
+            add_profile,
+            remove_profile,
+            switch_profile,
+            detect_sparse_path_relocation,
+            apply_sparse_path_relocation,
             // Sync
             get_latest_commit,
             run_sync,
             check_sync_status,
+            get_whats_new,
+            measure_download_speed,
+            get_protected_paths,
+            set_protected_paths,
+            set_transactional_sync,
+            preview_disk_delta,
             run_verification_scan,
+            verify_folder,
+            scan_truncated_files,
             apply_verification_fixes,
             run_quick_count_check,
             analyze_full_sync,
             execute_analyzed_sync,
+            resume_analyzed_sync,
+            get_pending_sync,
+            discard_pending_sync,
+            get_verification_status,
+            generate_manifest,
+            verify_against_manifest,
+            // Progress events
+            get_operation_status,
+            // Pack source
+            get_pack_source,
+            set_pack_source,
+            // Download cache
+            get_cache_info,
+            set_cache_size_limit,
+            clear_cache,
+            // Bandwidth
+            get_download_rate_limit,
+            set_download_rate_limit,
+            get_download_connections,
+            set_download_connections,
+            // Network
+            check_connectivity,
+            set_prefer_ipv4,
+            set_ca_bundle,
+            set_http_tracing,
+            // Cleanup
+            get_junk_rules,
+            set_junk_rules,
+            preview_cleanup,
+            run_cleanup,
+            // Disabled textures
+            get_disable_convention,
+            set_disable_convention,
+            get_disabled_textures,
+            restore_disabled_textures,
+            // Hardlink dedup
+            dedup_installs,
+            // Local file index
+            search_local_files,
+            get_local_file_stats,
+            list_disabled_files,
+            refresh_file_index,
+            // Drift detection
+            start_folder_watch,
+            stop_folder_watch,
+            acknowledge_local_changes,
+            // LAN sharing
+            start_lan_share,
+            stop_lan_share,
+            get_lan_share_status,
+            analyze_lan_peer,
+            install_from_lan_peer,
+            // Auto-sync
+            cancel_auto_sync,
+            get_auto_sync_settings,
+            set_auto_sync_settings,
+            get_auto_verify_settings,
+            set_auto_verify_settings,
+            get_full_sync_policy,
+            set_full_sync_policy,
             // App info
-            get_app_version,
+            get_app_info,
             fetch_installer_data,
             compare_versions,
+            check_version_compatibility,
+            fetch_contributors,
+            // Pack catalog
+            fetch_pack_catalog,
+            add_profile_from_catalog,
         ])
+        .setup(|app| {
+            // Checks for updates shortly after launch and syncs them if auto-sync is on
+            start_auto_sync(app.handle().clone());
+            Ok(())
+        })
         .on_window_event(|_window, event| {
             if let tauri::WindowEvent::Destroyed = event {
-                // Kill any running git processes when window is closed
-                cleanup_processes();
+                // Stop running git processes and clean up after them when the window is closed
+                shutdown_git_operations();
+                let _ = stop_lan_share();
             }
         })
         .run(tauri::generate_context!())
@@ -0,0 +1,151 @@
+//! Secret storage backed by the OS keychain
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Nonce};
+use keyring::{Entry, Error as KeyringError};
+use sha2::{Digest, Sha256};
+
+/// AES-GCM nonce length in bytes
+const NONCE_LEN: usize = 12;
+
+/// Service name used for all keychain entries (matches the bundle identifier)
+const KEYRING_SERVICE: &str = "com.ncaanext.textures-downloader";
+
+/// Keychain account name for the GitHub API token
+pub const GITHUB_TOKEN_KEY: &str = "github_token";
+
+fn get_entry(key: &str) -> Result<Entry, String> {
+    Entry::new(KEYRING_SERVICE, key).map_err(|e| format!("Failed to access keychain: {}", e))
+}
+
+/// Store a secret in the OS keychain, replacing any existing value
+pub fn store_secret(key: &str, value: &str) -> Result<(), String> {
+    get_entry(key)?
+        .set_password(value)
+        .map_err(|e| format!("Failed to store secret in keychain: {}", e))
+}
+
+/// Load a secret from the OS keychain
+/// Returns Ok(None) if no entry exists for the key
+pub fn load_secret(key: &str) -> Result<Option<String>, String> {
+    match get_entry(key)?.get_password() {
+        Ok(value) => Ok(Some(value)),
+        Err(KeyringError::NoEntry) => Ok(None),
+        Err(e) => Err(format!("Failed to read secret from keychain: {}", e)),
+    }
+}
+
+/// Remove a secret from the OS keychain (no-op if it doesn't exist)
+pub fn delete_secret(key: &str) -> Result<(), String> {
+    match get_entry(key)?.delete_credential() {
+        Ok(()) | Err(KeyringError::NoEntry) => Ok(()),
+        Err(e) => Err(format!("Failed to delete secret from keychain: {}", e)),
+    }
+}
+
+/// Prefix marking a value produced by encrypt_secret (versioned for future key/cipher changes)
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+/// Derive a 256-bit key bound to this machine
+/// Used when no OS keychain is available so secrets aren't stored in plain text
+fn machine_key() -> Result<[u8; 32], String> {
+    let machine_id = machine_uid::get().map_err(|e| format!("Failed to get machine id: {}", e))?;
+    let mut hasher = Sha256::new();
+    hasher.update(KEYRING_SERVICE.as_bytes());
+    hasher.update(machine_id.as_bytes());
+    Ok(hasher.finalize().into())
+}
+
+/// Encrypt a secret with the machine-bound key
+/// Returns a self-describing string ("enc:v1:<hex nonce+ciphertext>") safe to store in the state file
+pub fn encrypt_secret(value: &str) -> Result<String, String> {
+    let key = machine_key()?;
+    let cipher = Aes256Gcm::new(&key.into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, value.as_bytes())
+        .map_err(|e| format!("Failed to encrypt secret: {}", e))?;
+
+    let mut payload = nonce.to_vec();
+    payload.extend(ciphertext);
+    Ok(format!("{}{}", ENCRYPTED_PREFIX, hex::encode(payload)))
+}
+
+/// Decrypt a value produced by encrypt_secret
+/// Fails if the state file was copied from another machine
+pub fn decrypt_secret(value: &str) -> Result<String, String> {
+    let encoded = value
+        .strip_prefix(ENCRYPTED_PREFIX)
+        .ok_or_else(|| "Unsupported encrypted secret format".to_string())?;
+    let payload = hex::decode(encoded).map_err(|e| format!("Invalid encrypted secret: {}", e))?;
+
+    if payload.len() < NONCE_LEN {
+        return Err("Invalid encrypted secret: payload too short".to_string());
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+
+    let key = machine_key()?;
+    let cipher = Aes256Gcm::new(&key.into());
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Failed to decrypt secret (was the state file moved from another machine?)".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("Invalid decrypted secret: {}", e))
+}
+
+/// Prefix marking a value produced by encrypt_with_password
+const PASSWORD_ENCRYPTED_PREFIX: &str = "pw:v1:";
+
+/// Salt length in bytes for password-derived keys
+const SALT_LEN: usize = 16;
+
+/// PBKDF2-HMAC-SHA256 rounds for password-derived keys
+const PBKDF2_ROUNDS: u32 = 600_000;
+
+fn password_key(password: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, PBKDF2_ROUNDS, &mut key);
+    key
+}
+
+/// Encrypt a secret with a key derived from a password
+/// Returns a self-describing string ("pw:v1:<hex salt+nonce+ciphertext>") that any machine can
+/// decrypt given the password
+pub fn encrypt_with_password(value: &str, password: &str) -> Result<String, String> {
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = Aes256Gcm::new(&password_key(password, &salt).into());
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+
+    let ciphertext = cipher
+        .encrypt(&nonce, value.as_bytes())
+        .map_err(|e| format!("Failed to encrypt secret: {}", e))?;
+
+    let mut payload = salt.to_vec();
+    payload.extend(nonce.as_slice());
+    payload.extend(ciphertext);
+    Ok(format!("{}{}", PASSWORD_ENCRYPTED_PREFIX, hex::encode(payload)))
+}
+
+/// Decrypt a secret produced by encrypt_with_password
+pub fn decrypt_with_password(value: &str, password: &str) -> Result<String, String> {
+    let encoded = value
+        .strip_prefix(PASSWORD_ENCRYPTED_PREFIX)
+        .ok_or_else(|| "Unsupported encrypted secret format".to_string())?;
+    let payload = hex::decode(encoded).map_err(|e| format!("Invalid encrypted secret: {}", e))?;
+
+    if payload.len() < SALT_LEN + NONCE_LEN {
+        return Err("Invalid encrypted secret: payload too short".to_string());
+    }
+    let (salt, rest) = payload.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new(&password_key(password, salt).into());
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Failed to decrypt secret (wrong password?)".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| format!("Invalid decrypted secret: {}", e))
+}